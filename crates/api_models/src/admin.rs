@@ -9,9 +9,9 @@ use utoipa::ToSchema;
 
 use super::payments::AddressDetails;
 use crate::{
-    enums,
+    disputes, enums,
     enums::{self as api_enums},
-    payment_methods,
+    payment_methods, routing,
 };
 
 #[derive(Clone, Debug, Deserialize, ToSchema)]
@@ -90,7 +90,7 @@ pub struct MerchantAccountCreate {
     pub primary_business_details: Option<Vec<PrimaryBusinessDetails>>,
 
     /// The frm routing algorithm to be used for routing payments to desired FRM's
-    #[schema(value_type = Option<Object>,example = json!({"type": "single", "data": "signifyd"}))]
+    #[schema(value_type = Option<Object>,example = json!({"type": "single", "data": "mock_frm"}))]
     pub frm_routing_algorithm: Option<serde_json::Value>,
 
     ///Will be used to expire client secret after certain amount of time to be supplied in seconds
@@ -180,7 +180,7 @@ pub struct MerchantAccountUpdate {
     pub primary_business_details: Option<Vec<PrimaryBusinessDetails>>,
 
     /// The frm routing algorithm to be used for routing payments to desired FRM's
-    #[schema(value_type = Option<Object>,example = json!({"type": "single", "data": "signifyd"}))]
+    #[schema(value_type = Option<Object>,example = json!({"type": "single", "data": "mock_frm"}))]
     pub frm_routing_algorithm: Option<serde_json::Value>,
 
     ///Will be used to expire client secret after certain amount of time to be supplied in seconds
@@ -447,6 +447,23 @@ pub mod payout_routing_algorithm {
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
 pub enum RoutingAlgorithm {
     Single(api_enums::RoutableConnectors),
+    /// Connectors are tried in the order in which they are listed
+    Priority(Vec<api_enums::RoutableConnectors>),
+    /// Traffic is distributed across connectors based on the configured percentages
+    VolumeSplit(Vec<routing::ConnectorVolumeSplit>),
+    /// Connectors are decided by the first rule matching the payment
+    Advanced(routing::RoutingRules),
+}
+
+impl RoutingAlgorithm {
+    pub fn validate(&self) -> Result<(), common_utils::errors::ValidationError> {
+        match self {
+            Self::Single(_) => Ok(()),
+            Self::Priority(connectors) => routing::validate_priority(connectors),
+            Self::VolumeSplit(splits) => routing::validate_volume_split(splits),
+            Self::Advanced(rules) => rules.validate(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -513,6 +530,25 @@ pub struct PrimaryBusinessDetails {
 pub struct PaymentLinkConfig {
     pub merchant_logo: Option<String>,
     pub color_scheme: Option<PaymentLinkColorSchema>,
+    /// The name to be displayed on the payment link instead of the merchant name
+    #[schema(example = "Bob's Bakery")]
+    pub display_name: Option<String>,
+    /// The font family used on the payment link page, the font has to be available to the browser.
+    /// It can only contain letters, digits, spaces, commas, hyphens, underscores and quotes
+    #[schema(example = "Verdana, sans-serif")]
+    pub font_family: Option<String>,
+    /// The locale in which the payment link page is rendered, one of `en`, `en-GB`, `ar`, `ca`,
+    /// `de`, `es`, `fr`, `fr-BE`, `he`, `it`, `ja`, `nl`, `pl`, `pt`, `ru`, `sv` and `zh`
+    #[schema(example = "en-GB")]
+    pub locale: Option<String>,
+    /// The number of order items visible on the payment link page before the item list is
+    /// collapsed, defaults to 3
+    #[schema(example = 3)]
+    pub max_items_visible_after_collapse: Option<i8>,
+    /// A `tera` template which is rendered instead of the default payment link page. The
+    /// template has access to the `hyperloader_sdk_link`, `css_color_scheme` and
+    /// `payment_details_js_script` variables.
+    pub custom_template: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize, PartialEq)]
@@ -954,6 +990,16 @@ pub struct ToggleKVRequest {
     pub kv_enabled: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MerchantKeyRotationResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+    /// Version of the data encryption key of the merchant after the rotation
+    #[schema(example = 2)]
+    pub key_version: i32,
+}
+
 #[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct MerchantConnectorDetailsWrap {
     /// Creds Identifier is to uniquely identify the credentials. Do not send any sensitive info in this field. And do not send the string "null".
@@ -1037,7 +1083,7 @@ pub struct BusinessProfileCreate {
     pub intent_fulfillment_time: Option<u32>,
 
     /// The frm routing algorithm to be used for routing payments to desired FRM's
-    #[schema(value_type = Option<Object>,example = json!({"type": "single", "data": "signifyd"}))]
+    #[schema(value_type = Option<Object>,example = json!({"type": "single", "data": "mock_frm"}))]
     pub frm_routing_algorithm: Option<serde_json::Value>,
 
     /// The routing algorithm to be  used for routing payouts to desired connectors
@@ -1051,6 +1097,13 @@ pub struct BusinessProfileCreate {
 
     /// Verified applepay domains for a particular profile
     pub applepay_verified_domains: Option<Vec<String>>,
+
+    /// Actions to be taken on disputes before their evidence deadline is reached
+    pub dispute_policy: Option<disputes::DisputePolicy>,
+
+    /// Configuration of the payment links created under this profile, the configuration of the
+    /// merchant account is used for the settings which are not set here
+    pub payment_link_config: Option<PaymentLinkConfig>,
 }
 
 #[derive(Clone, Debug, ToSchema, Serialize)]
@@ -1101,7 +1154,7 @@ pub struct BusinessProfileResponse {
     pub intent_fulfillment_time: Option<i64>,
 
     /// The frm routing algorithm to be used for routing payments to desired FRM's
-    #[schema(value_type = Option<Object>,example = json!({"type": "single", "data": "signifyd"}))]
+    #[schema(value_type = Option<Object>,example = json!({"type": "single", "data": "mock_frm"}))]
     pub frm_routing_algorithm: Option<serde_json::Value>,
 
     /// The routing algorithm to be  used for routing payouts to desired connectors
@@ -1115,6 +1168,13 @@ pub struct BusinessProfileResponse {
 
     /// Verified applepay domains for a particular profile
     pub applepay_verified_domains: Option<Vec<String>>,
+
+    /// Actions to be taken on disputes before their evidence deadline is reached
+    pub dispute_policy: Option<disputes::DisputePolicy>,
+
+    /// Configuration of the payment links created under this profile, the configuration of the
+    /// merchant account is used for the settings which are not set here
+    pub payment_link_config: Option<PaymentLinkConfig>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema)]
@@ -1158,7 +1218,7 @@ pub struct BusinessProfileUpdate {
     pub intent_fulfillment_time: Option<u32>,
 
     /// The frm routing algorithm to be used for routing payments to desired FRM's
-    #[schema(value_type = Option<Object>,example = json!({"type": "single", "data": "signifyd"}))]
+    #[schema(value_type = Option<Object>,example = json!({"type": "single", "data": "mock_frm"}))]
     pub frm_routing_algorithm: Option<serde_json::Value>,
 
     /// The routing algorithm to be  used for routing payouts to desired connectors
@@ -1172,4 +1232,11 @@ pub struct BusinessProfileUpdate {
 
     /// Verified applepay domains for a particular profile
     pub applepay_verified_domains: Option<Vec<String>>,
+
+    /// Actions to be taken on disputes before their evidence deadline is reached
+    pub dispute_policy: Option<disputes::DisputePolicy>,
+
+    /// Configuration of the payment links created under this profile, the configuration of the
+    /// merchant account is used for the settings which are not set here
+    pub payment_link_config: Option<PaymentLinkConfig>,
 }
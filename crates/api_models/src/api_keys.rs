@@ -23,6 +23,17 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The scopes the API Key is restricted to, a request is permitted if any of the scopes
+    /// permits it. The API Key has access to all the APIs of the merchant account if no scopes are
+    /// specified.
+    #[schema(value_type = Option<Vec<ApiKeyScope>>, example = json!(["payments:write", "refunds:write"]))]
+    pub scopes: Option<Vec<ApiKeyScope>>,
+
+    /// The IP addresses from which the API Key can be used. The API Key can be used from any IP
+    /// address if no IP addresses are specified.
+    #[schema(example = json!(["203.0.113.10"]))]
+    pub allowed_ips: Option<Vec<String>>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +71,15 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The scopes the API Key is restricted to. The API Key has access to all the APIs of the
+    /// merchant account if it is not restricted to any scopes.
+    #[schema(value_type = Option<Vec<ApiKeyScope>>, example = json!(["payments:write", "refunds:write"]))]
+    pub scopes: Option<Vec<ApiKeyScope>>,
+
+    /// The IP addresses from which the API Key can be used.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["203.0.113.10"]))]
+    pub allowed_ips: Option<Vec<String>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,6 +122,15 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The scopes the API Key is restricted to. The API Key has access to all the APIs of the
+    /// merchant account if it is not restricted to any scopes.
+    #[schema(value_type = Option<Vec<ApiKeyScope>>, example = json!(["payments:write", "refunds:write"]))]
+    pub scopes: Option<Vec<ApiKeyScope>>,
+
+    /// The IP addresses from which the API Key can be used.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["203.0.113.10"]))]
+    pub allowed_ips: Option<Vec<String>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -129,6 +158,16 @@ pub struct UpdateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: Option<ApiKeyExpiration>,
+
+    /// The scopes the API Key is restricted to, a request is permitted if any of the scopes
+    /// permits it. The restriction to scopes cannot be removed from an API Key once it is added.
+    #[schema(value_type = Option<Vec<ApiKeyScope>>, example = json!(["payments:write", "refunds:write"]))]
+    pub scopes: Option<Vec<ApiKeyScope>>,
+
+    /// The IP addresses from which the API Key can be used, an empty list allows the API Key to be
+    /// used from any IP address.
+    #[schema(example = json!(["203.0.113.10"]))]
+    pub allowed_ips: Option<Vec<String>>,
 }
 
 /// The response body for revoking an API Key.
@@ -157,6 +196,47 @@ pub struct ListApiKeyConstraints {
     pub skip: Option<i64>,
 }
 
+/// The scopes an API Key can be restricted to.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    ToSchema,
+    strum::Display,
+    strum::EnumString,
+)]
+pub enum ApiKeyScope {
+    /// Create and modify payments, along with the customers, payment methods and mandates used
+    /// in them.
+    #[serde(rename = "payments:write")]
+    #[strum(serialize = "payments:write")]
+    PaymentsWrite,
+
+    /// Create and modify refunds.
+    #[serde(rename = "refunds:write")]
+    #[strum(serialize = "refunds:write")]
+    RefundsWrite,
+
+    /// Create and modify payouts.
+    #[serde(rename = "payouts:write")]
+    #[strum(serialize = "payouts:write")]
+    PayoutsWrite,
+
+    /// Retrieve and list resources, without modifying them.
+    #[serde(rename = "read_only")]
+    #[strum(serialize = "read_only")]
+    ReadOnly,
+
+    /// Retrieve disputes, and challenge them by submitting evidence.
+    #[serde(rename = "disputes")]
+    #[strum(serialize = "disputes")]
+    Disputes,
+}
+
 /// The expiration date and time for an API Key.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 #[serde(untagged)]
@@ -296,3 +376,31 @@ mod api_key_expiration_tests {
         assert_eq!(result, None);
     }
 }
+
+#[cfg(test)]
+mod api_key_scope_tests {
+    #![allow(clippy::unwrap_used)]
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_serialization_matches_string_representation() {
+        for scope in [
+            ApiKeyScope::PaymentsWrite,
+            ApiKeyScope::RefundsWrite,
+            ApiKeyScope::PayoutsWrite,
+            ApiKeyScope::ReadOnly,
+            ApiKeyScope::Disputes,
+        ] {
+            let serialized = serde_json::to_string(&scope).unwrap();
+            assert_eq!(serialized, format!(r#""{scope}""#));
+            assert_eq!(ApiKeyScope::from_str(&scope.to_string()).unwrap(), scope);
+        }
+
+        assert_eq!(
+            serde_json::from_str::<ApiKeyScope>(r#""payments:write""#).unwrap(),
+            ApiKeyScope::PaymentsWrite
+        );
+    }
+}
@@ -1,9 +1,11 @@
+use std::collections::HashMap;
+
 use masking::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
-use super::enums::{DisputeStage, DisputeStatus};
-use crate::files;
+use super::enums::{Currency, DisputeStage, DisputeStatus};
+use crate::{files, payments::TimeRange};
 
 #[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
 pub struct DisputeResponse {
@@ -91,6 +93,22 @@ pub enum EvidenceType {
     UncategorizedFile,
 }
 
+/// Actions taken on behalf of the merchant on disputes received for a business profile, before the
+/// evidence deadline of the dispute is reached
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputePolicy {
+    /// Disputes with an amount lower than the amount configured for the currency of the dispute
+    /// (in the lowest denomination of the currency) are accepted as soon as they are opened,
+    /// disputes in other currencies are not accepted automatically
+    #[schema(value_type = Option<Object>, example = json!({"USD": 1000, "EUR": 900}))]
+    pub auto_accept_below_amount: Option<HashMap<Currency, i64>>,
+    /// The evidence attached to a dispute is submitted once the evidence deadline is this many
+    /// seconds away, disputes without any evidence attached are left as is
+    #[schema(example = 86400)]
+    pub auto_submit_evidence_before: Option<u32>,
+}
+
 #[derive(Clone, Debug, Serialize, ToSchema)]
 pub struct DisputeEvidenceBlock {
     /// Evidence type
@@ -135,6 +153,93 @@ pub struct DisputeListConstraints {
     pub received_time_gte: Option<PrimitiveDateTime>,
 }
 
+/// Length of the time buckets in which disputes are grouped for analytics
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DisputeAnalyticsGranularity {
+    Hour,
+    #[default]
+    Day,
+    Week,
+    Month,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeAnalyticsRequest {
+    /// The time range in which the disputes were received
+    #[schema(value_type = Object)]
+    pub time_range: TimeRange,
+    /// The identifier for business profile
+    pub profile_id: Option<String>,
+    /// connector linked to the disputes
+    pub connector: Option<String>,
+    /// Length of the time buckets in which the disputes are grouped, defaults to a day
+    #[serde(default)]
+    pub granularity: DisputeAnalyticsGranularity,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, ToSchema)]
+pub struct DisputeMetrics {
+    /// Number of disputes
+    pub dispute_count: u32,
+    /// Total disputed amount for every currency in which disputes were received
+    pub amounts: Vec<DisputeAmount>,
+    /// Number of disputes which were won
+    pub won_count: u32,
+    /// Number of disputes which were lost
+    pub lost_count: u32,
+    /// Share of the disputes which were won, out of the disputes which were either won or lost.
+    /// Absent if none of the disputes have been won or lost yet
+    #[schema(example = 0.75)]
+    pub win_rate: Option<f64>,
+    /// Share of the disputes which were lost, out of the disputes which were either won or lost.
+    /// Absent if none of the disputes have been won or lost yet
+    #[schema(example = 0.25)]
+    pub loss_rate: Option<f64>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, ToSchema)]
+pub struct DisputeAmount {
+    /// Currency of the disputes
+    pub currency: String,
+    /// Total disputed amount in the lowest denomination of the currency
+    pub amount: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, ToSchema)]
+pub struct DisputeMetricsBucket {
+    /// The value of the dimension by which the disputes are grouped
+    pub key: String,
+    #[serde(flatten)]
+    pub metrics: DisputeMetrics,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, ToSchema)]
+pub struct DisputeTimeBucket {
+    /// Start of the time bucket
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub start_time: PrimitiveDateTime,
+    #[serde(flatten)]
+    pub metrics: DisputeMetrics,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, ToSchema)]
+pub struct DisputeAnalyticsResponse {
+    /// Metrics of all the disputes received in the time range
+    pub total: DisputeMetrics,
+    /// Metrics grouped by connector
+    pub by_connector: Vec<DisputeMetricsBucket>,
+    /// Metrics grouped by the reason code sent by the connector
+    pub by_reason_code: Vec<DisputeMetricsBucket>,
+    /// Metrics grouped by dispute stage
+    pub by_stage: Vec<DisputeMetricsBucket>,
+    /// Metrics grouped by dispute status
+    pub by_status: Vec<DisputeMetricsBucket>,
+    /// Metrics grouped by the time bucket in which the disputes were received
+    pub by_time_bucket: Vec<DisputeTimeBucket>,
+}
+
 #[derive(Default, Clone, Debug, Serialize, Deserialize, ToSchema)]
 pub struct SubmitEvidenceRequest {
     ///Dispute Id
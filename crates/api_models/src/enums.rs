@@ -494,6 +494,28 @@ pub enum FrmPreferredFlowTypes {
     Pre,
     Post,
 }
+
+/// The connectors which can be used for fraud and risk management checks
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum FrmConnectors {
+    /// A local FRM connector which decides on the payment amount alone, without calling any
+    /// external service. Meant for testing integrations with the FRM flows.
+    MockFrm,
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
 pub struct UnresolvedResponseReason {
     pub code: String,
@@ -559,3 +581,25 @@ pub enum RetryAction {
     /// Denotes that the payment is requeued
     Requeue,
 }
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Hash,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum InstallmentInterestBearer {
+    /// The interest on the installments is absorbed by the merchant, the customer pays no interest
+    Merchant,
+    /// The interest on the installments is charged to the customer
+    Customer,
+}
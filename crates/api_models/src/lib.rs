@@ -16,5 +16,8 @@ pub mod payments;
 #[cfg(feature = "payouts")]
 pub mod payouts;
 pub mod refunds;
+pub mod routing;
+pub mod subscriptions;
 pub mod verifications;
+pub mod webhook_events;
 pub mod webhooks;
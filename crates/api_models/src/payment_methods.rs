@@ -279,6 +279,21 @@ pub struct CardNetworkTypes {
     pub eligible_connectors: Vec<String>,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema, PartialEq, Eq)]
+pub struct InstallmentPlanTypes {
+    /// The connector offering the installment plans
+    #[schema(example = "adyen")]
+    pub connector: String,
+
+    /// The number of installments the payment amount can be split into
+    #[schema(example = json!([3, 6, 12]))]
+    pub number_of_installments: Vec<u8>,
+
+    /// The identifiers of the installment plans at the connector, if the connector offers more than one plan
+    #[schema(example = json!(["regular", "revolving"]))]
+    pub plan_ids: Option<Vec<String>>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema, PartialEq, Eq)]
 pub struct BankDebitTypes {
     pub eligible_connectors: Vec<String>,
@@ -322,6 +337,9 @@ pub struct ResponsePaymentMethodTypes {
         }
     "#)]
     pub surcharge_details: Option<SurchargeDetailsResponse>,
+
+    /// The installment plans offered by each connector, if applicable for a payment method type
+    pub installment_plans: Option<Vec<InstallmentPlanTypes>>,
 }
 #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
@@ -371,6 +389,58 @@ pub enum Surcharge {
     Rate(Percentage<SURCHARGE_PERCENTAGE_PRECISION_LENGTH>),
 }
 
+/// Surcharge rules configured for a merchant, the first rule matching a payment is applied to it
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SurchargeRules {
+    pub rules: Vec<SurchargeRule>,
+}
+
+/// A surcharge applied to payments matching all of the conditions set on the rule
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SurchargeRule {
+    /// The payment method for which the surcharge is applicable, applies to all payment methods if not set
+    pub payment_method: Option<api_enums::PaymentMethod>,
+    /// The payment method type for which the surcharge is applicable, applies to all payment method types if not set
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+    /// The card network for which the surcharge is applicable, applies to all card networks if not set
+    pub card_network: Option<api_enums::CardNetwork>,
+    /// The currency for which the surcharge is applicable, applies to all currencies if not set
+    pub currency: Option<api_enums::Currency>,
+    /// The surcharge to be applied on the payment amount
+    pub surcharge: Surcharge,
+    /// The tax to be applied on the surcharge amount
+    pub tax_on_surcharge: Option<Percentage<SURCHARGE_PERCENTAGE_PRECISION_LENGTH>>,
+}
+
+impl SurchargeRule {
+    pub fn is_applicable(
+        &self,
+        payment_method: &api_enums::PaymentMethod,
+        payment_method_type: &api_enums::PaymentMethodType,
+        card_network: Option<&api_enums::CardNetwork>,
+        currency: &api_enums::Currency,
+    ) -> bool {
+        self.payment_method
+            .as_ref()
+            .map_or(true, |rule_payment_method| rule_payment_method == payment_method)
+            && self
+                .payment_method_type
+                .as_ref()
+                .map_or(true, |rule_payment_method_type| {
+                    rule_payment_method_type == payment_method_type
+                })
+            && self.card_network.as_ref().map_or(true, |rule_card_network| {
+                card_network == Some(rule_card_network)
+            })
+            && self
+                .currency
+                .as_ref()
+                .map_or(true, |rule_currency| rule_currency == currency)
+    }
+}
+
 /// Required fields info used while listing the payment_method_data
 #[derive(Debug, serde::Deserialize, serde::Serialize, Clone, PartialEq, Eq, ToSchema, Hash)]
 pub struct RequiredFieldInfo {
@@ -309,6 +309,29 @@ pub struct PaymentsRequest {
     /// The type of the payment that differentiates between normal and various types of mandate payments
     #[schema(value_type = Option<PaymentType>)]
     pub payment_type: Option<api_enums::PaymentType>,
+
+    /// Installment plan to be used for the payment, if the customer chose to pay in installments
+    pub installment_data: Option<InstallmentData>,
+
+    /// Request the connector to allow raising the authorized amount of the payment later through incremental authorizations. Applicable only for payments with manual capture
+    #[schema(example = true)]
+    pub request_incremental_authorization: Option<bool>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InstallmentData {
+    /// The number of installments the payment amount is split into
+    #[schema(example = 6)]
+    pub number_of_installments: u8,
+
+    /// Identifier of the installment plan at the connector, for connectors that offer more than one plan
+    #[schema(example = "regular")]
+    pub plan_id: Option<String>,
+
+    /// The party that bears the interest on the installments
+    #[schema(value_type = Option<InstallmentInterestBearer>, example = "merchant")]
+    pub interest_bearer: Option<api_enums::InstallmentInterestBearer>,
 }
 
 #[derive(
@@ -407,6 +430,16 @@ pub struct CaptureResponse {
     pub reference_id: Option<String>,
 }
 
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct CaptureListResponse {
+    /// The identifier for the payment
+    pub payment_id: String,
+    /// The number of captures included in the list
+    pub count: usize,
+    /// The list of captures made on the latest attempt of the payment
+    pub data: Vec<CaptureResponse>,
+}
+
 impl PaymentsRequest {
     pub fn get_feature_metadata_as_value(
         &self,
@@ -432,6 +465,18 @@ impl PaymentsRequest {
             .transpose()
     }
 
+    pub fn get_installment_data_as_value(
+        &self,
+    ) -> common_utils::errors::CustomResult<
+        Option<serde_json::Value>,
+        common_utils::errors::ParsingError,
+    > {
+        self.installment_data
+            .as_ref()
+            .map(Encode::<InstallmentData>::encode_to_value)
+            .transpose()
+    }
+
     pub fn get_allowed_payment_method_types_as_value(
         &self,
     ) -> common_utils::errors::CustomResult<
@@ -588,6 +633,16 @@ pub struct MandateAmountData {
         "frequency": "DAILY"
     }"#)]
     pub metadata: Option<pii::SecretSerdeValue>,
+    /// The maximum amount which can be debited in a single transaction. For multi use mandates
+    /// `amount` is the total amount which can be debited over the lifetime of the mandate
+    #[schema(example = 1000)]
+    pub max_amount_per_transaction: Option<i64>,
+    /// The calendar period over which the number of debits is limited
+    #[schema(value_type = Option<MandateFrequency>, example = "monthly")]
+    pub frequency: Option<api_enums::MandateFrequency>,
+    /// The maximum number of debits allowed in every `frequency` period
+    #[schema(example = 4)]
+    pub max_debits_per_frequency: Option<i32>,
 }
 
 #[derive(Eq, PartialEq, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -1702,6 +1757,8 @@ pub struct PaymentsCaptureRequest {
     pub amount_to_capture: Option<i64>,
     /// Decider to refund the uncaptured amount
     pub refund_uncaptured_amount: Option<bool>,
+    /// Marks this as the last capture of a partially captured payment. The remaining authorized amount is released at the connector once the capture succeeds
+    pub final_capture: Option<bool>,
     /// Provides information about a card payment that customers see on their statements.
     pub statement_descriptor_suffix: Option<String>,
     /// Concatenated with the statement descriptor suffix that’s set on the account to form the complete statement descriptor.
@@ -1943,6 +2000,11 @@ pub struct PaymentsResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub captures: Option<Vec<CaptureResponse>>,
 
+    /// The capture whose update triggered the webhook, only populated in outgoing webhooks
+    #[schema(value_type = Option<CaptureResponse>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_capture: Option<CaptureResponse>,
+
     /// A unique identifier to link the payment to a mandate, can be use instead of payment_method_data
     #[schema(max_length = 255, example = "mandate_iwer89rnjef349dni3")]
     pub mandate_id: Option<String>,
@@ -2106,6 +2168,12 @@ pub struct PaymentsResponse {
 
     /// Denotes the action(approve or reject) taken by merchant in case of manual review. Manual review can occur when the transaction is marked as risky by the frm_processor, payment processor or when there is underpayment/over payment incase of crypto payment
     pub merchant_decision: Option<String>,
+
+    /// The installment plan used for the payment, if the customer chose to pay in installments
+    pub installment_data: Option<InstallmentData>,
+
+    /// List of incremental authorizations done on the latest attempt
+    pub incremental_authorizations: Option<Vec<IncrementalAuthorizationResponse>>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, ToSchema)]
@@ -2891,6 +2959,39 @@ pub struct PaymentsRejectRequest {
     pub payment_id: String,
 }
 
+#[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentsIncrementalAuthorizationRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: String,
+    /// The total amount that should be authorized after the increment, in the lowest denomination of the currency. It must be greater than the currently authorized amount
+    #[schema(example = 6540)]
+    pub amount: i64,
+    /// The reason for raising the authorized amount, sent to the connector where supported
+    #[schema(example = "Extended hotel stay")]
+    pub reason: Option<String>,
+}
+
+#[derive(Default, Debug, serde::Serialize, Clone, PartialEq, ToSchema)]
+pub struct IncrementalAuthorizationResponse {
+    /// The unique identifier of the incremental authorization
+    pub authorization_id: String,
+    /// The total amount authorized after this increment
+    pub amount: i64,
+    /// The amount that was authorized before this increment
+    pub previous_amount: i64,
+    /// The status of the incremental authorization
+    #[schema(value_type = AuthorizationStatus, example = "success")]
+    pub status: enums::AuthorizationStatus,
+    /// The reason given for raising the authorized amount
+    pub reason: Option<String>,
+    /// If there was an error while calling the connector the code is received here
+    pub error_code: Option<String>,
+    /// If there was an error while calling the connector the error message is received here
+    pub error_message: Option<String>,
+}
+
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, ToSchema, Clone)]
 pub struct PaymentsStartRequest {
     /// Unique identifier for the payment. This ensures idempotency for multiple payments
@@ -3123,6 +3224,53 @@ pub struct RetrievePaymentLinkResponse {
     pub last_modified_at: PrimitiveDateTime,
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub link_expiry: Option<PrimitiveDateTime>,
+    #[schema(value_type = PaymentLinkStatus, example = "active")]
+    pub status: api_enums::PaymentLinkStatus,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkListConstraints {
+    /// limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The identifier for the payment
+    pub payment_id: Option<String>,
+    /// The status of the payment link
+    #[schema(value_type = Option<PaymentLinkStatus>)]
+    pub status: Option<api_enums::PaymentLinkStatus>,
+    /// The time at which the payment link was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    pub created: Option<PrimitiveDateTime>,
+    /// Time less than the payment link created time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(rename = "created.lt")]
+    pub created_lt: Option<PrimitiveDateTime>,
+    /// Time greater than the payment link created time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(rename = "created.gt")]
+    pub created_gt: Option<PrimitiveDateTime>,
+    /// Time less than or equals to the payment link created time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(rename = "created.lte")]
+    pub created_lte: Option<PrimitiveDateTime>,
+    /// Time greater than or equals to the payment link created time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(rename = "created.gte")]
+    pub created_gte: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkUpdateRequest {
+    /// The new amount of the payment, in the lowest denomination of the currency. Can only be
+    /// updated until the payment is attempted.
+    #[schema(example = 6540)]
+    pub amount: Option<i64>,
+    /// The new time at which the payment link expires, the payment is cancelled if it has not
+    /// been made by then
+    #[schema(example = "2022-09-10T11:12:13Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub link_expiry: Option<PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, ToSchema)]
@@ -3142,7 +3290,9 @@ pub struct PaymentLinkDetails {
     pub expiry: PrimitiveDateTime,
     pub merchant_logo: String,
     pub return_url: String,
-    pub merchant_name: crypto::OptionalEncryptableName,
+    pub merchant_name: Option<String>,
     pub order_details: Vec<pii::SecretSerdeValue>,
     pub max_items_visible_after_collapse: i8,
+    pub locale: Option<String>,
+    pub font_family: Option<String>,
 }
@@ -47,6 +47,10 @@ pub struct RefundRequest {
     /// Merchant connector details used to make payments.
     #[schema(value_type = Option<MerchantConnectorDetailsWrap>)]
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
+
+    /// The identifier of the capture to be refunded, for payments captured through multiple partial captures. If not provided, the refund is made against the payment. Currently supported only for payments processed through Adyen
+    #[schema(max_length = 64, example = "pay_mbabizu24mvu3mela5njyhpit4_1_1")]
+    pub capture_id: Option<String>,
 }
 
 #[derive(Default, Debug, Clone, Deserialize)]
@@ -124,6 +128,8 @@ pub struct RefundResponse {
     /// The connector used for the refund and the corresponding payment
     #[schema(example = "stripe")]
     pub connector: String,
+    /// The identifier of the capture against which the refund was made
+    pub capture_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
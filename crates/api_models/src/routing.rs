@@ -0,0 +1,117 @@
+use common_utils::errors::ValidationError;
+use serde::{Deserialize, Serialize};
+
+use crate::enums as api_enums;
+
+/// A connector along with the share of traffic it should receive in a volume split
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorVolumeSplit {
+    pub connector: api_enums::RoutableConnectors,
+    /// Percentage of traffic to be routed through this connector (0 - 100)
+    pub split: u8,
+}
+
+/// The connectors to be used when a routing rule matches, or when none of the rules match
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum RoutingRuleOutput {
+    Priority(Vec<api_enums::RoutableConnectors>),
+    VolumeSplit(Vec<ConnectorVolumeSplit>),
+}
+
+/// An inclusive amount range, in the lowest denomination of the currency
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct AmountRange {
+    pub min: Option<i64>,
+    pub max: Option<i64>,
+}
+
+/// A single condition of a routing rule. A condition on a list of values matches when the
+/// payment has any one of the values.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(tag = "field", content = "value", rename_all = "snake_case")]
+pub enum RoutingCondition {
+    Amount(AmountRange),
+    Currency(Vec<api_enums::Currency>),
+    CardNetwork(Vec<api_enums::CardNetwork>),
+    PaymentMethod(Vec<api_enums::PaymentMethod>),
+    PaymentMethodType(Vec<api_enums::PaymentMethodType>),
+    BillingCountry(Vec<api_enums::CountryAlpha2>),
+    Metadata { key: String, value: String },
+}
+
+/// A routing rule, which matches when all of its conditions are satisfied
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingRule {
+    pub name: String,
+    pub conditions: Vec<RoutingCondition>,
+    pub connectors: RoutingRuleOutput,
+}
+
+/// An ordered set of routing rules. The first matching rule decides the connectors, and
+/// `default_selection` is used when none of them match.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingRules {
+    pub rules: Vec<RoutingRule>,
+    pub default_selection: RoutingRuleOutput,
+}
+
+/// The FRM connector to be used for the payments of a business profile, configured through its
+/// `frm_routing_algorithm`
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum FrmRoutingAlgorithm {
+    Single(api_enums::FrmConnectors),
+}
+
+impl RoutingRuleOutput {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            Self::Priority(connectors) => validate_priority(connectors),
+            Self::VolumeSplit(splits) => validate_volume_split(splits),
+        }
+    }
+}
+
+impl RoutingRules {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        self.rules.iter().try_for_each(|rule| {
+            if rule.conditions.is_empty() {
+                Err(ValidationError::InvalidValue {
+                    message: format!("routing rule '{}' has no conditions", rule.name),
+                })
+            } else {
+                rule.connectors.validate()
+            }
+        })?;
+        self.default_selection.validate()
+    }
+}
+
+pub fn validate_priority(
+    connectors: &[api_enums::RoutableConnectors],
+) -> Result<(), ValidationError> {
+    if connectors.is_empty() {
+        return Err(ValidationError::InvalidValue {
+            message: "priority list must contain at least one connector".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn validate_volume_split(splits: &[ConnectorVolumeSplit]) -> Result<(), ValidationError> {
+    let total = splits
+        .iter()
+        .map(|split| u32::from(split.split))
+        .sum::<u32>();
+    if splits.is_empty() || total != 100 {
+        return Err(ValidationError::InvalidValue {
+            message: format!("volume split percentages must add up to 100, found {total}"),
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use common_utils::pii;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+#[derive(Default, Debug, Deserialize, Serialize)]
+pub struct SubscriptionId {
+    pub subscription_id: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionCreateRequest {
+    /// The identifier for the customer who is billed
+    #[schema(max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: String,
+
+    /// The identifier for the multi-use mandate through which the customer is billed
+    #[schema(max_length = 64, example = "man_SMUvnT5ncdBnVMgbKpXD")]
+    pub mandate_id: String,
+
+    /// The amount charged every billing cycle, in the lowest denomination of the currency
+    #[schema(minimum = 1, example = 6540)]
+    pub amount: i64,
+
+    /// The currency of the amount charged every billing cycle
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The unit of the interval at which the customer is billed
+    #[schema(value_type = SubscriptionInterval, example = "month")]
+    pub interval: api_enums::SubscriptionInterval,
+
+    /// The number of intervals between two billing cycles
+    #[schema(minimum = 1, default = 1, example = 1)]
+    #[serde(default = "default_interval_count")]
+    pub interval_count: u16,
+
+    /// The end of the trial period, the customer is billed for the first time at this time
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2023-11-01T00:00:00Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub trial_end: Option<PrimitiveDateTime>,
+
+    /// The time from which billing cycles are counted, defaults to the time of creation.
+    /// Ignored when a trial period is provided
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2023-11-01T00:00:00Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub billing_anchor: Option<PrimitiveDateTime>,
+
+    /// The business profile through which the renewal payments are made
+    pub profile_id: Option<String>,
+
+    /// A description of the subscription, used as the description of the renewal payments
+    #[schema(max_length = 255, example = "Monthly premium plan")]
+    pub description: Option<String>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long.
+    #[schema(value_type = Option<Object>, example = r#"{ "plan": "premium" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct SubscriptionResponse {
+    /// The identifier for the subscription
+    pub subscription_id: String,
+    /// The identifier for the merchant
+    pub merchant_id: String,
+    /// The identifier for the customer who is billed
+    pub customer_id: String,
+    /// The identifier for the mandate through which the customer is billed
+    pub mandate_id: String,
+    /// The business profile through which the renewal payments are made
+    pub profile_id: Option<String>,
+    /// The status of the subscription
+    #[schema(value_type = SubscriptionStatus)]
+    pub status: api_enums::SubscriptionStatus,
+    /// The amount charged every billing cycle
+    pub amount: i64,
+    /// The currency of the amount charged every billing cycle
+    #[schema(value_type = Currency)]
+    pub currency: api_enums::Currency,
+    /// The unit of the interval at which the customer is billed
+    #[schema(value_type = SubscriptionInterval)]
+    pub interval: api_enums::SubscriptionInterval,
+    /// The number of intervals between two billing cycles
+    pub interval_count: i32,
+    /// The end of the trial period
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub trial_end: Option<PrimitiveDateTime>,
+    /// The start of the billing cycle which was last paid for
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub current_period_start: Option<PrimitiveDateTime>,
+    /// The end of the billing cycle which was last paid for
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub current_period_end: Option<PrimitiveDateTime>,
+    /// The time at which the customer is billed next, absent once the subscription is no longer billed
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub next_billing_at: Option<PrimitiveDateTime>,
+    /// The identifier for the payment made for the latest billing cycle
+    pub latest_payment_id: Option<String>,
+    /// A description of the subscription
+    pub description: Option<String>,
+    /// The metadata provided when creating the subscription
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+    /// The time at which the subscription was cancelled
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    /// The time at which the subscription was created
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+fn default_interval_count() -> u16 {
+    1
+}
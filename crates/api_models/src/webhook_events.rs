@@ -0,0 +1,103 @@
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums::{EventType, WebhookDeliveryAttempt};
+
+#[derive(Clone, Debug, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EventListConstraints {
+    /// Limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The number of objects to skip
+    pub offset: Option<i64>,
+    /// The identifier for business profile
+    pub profile_id: Option<String>,
+    /// The identifier of the object (payment, refund, dispute or mandate) the event is about
+    pub object_id: Option<String>,
+    /// Type of the event
+    pub event_type: Option<EventType>,
+    /// Whether the webhook for the event was successfully delivered to the merchant
+    pub is_delivered: Option<bool>,
+    /// Time less than the event created time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        with = "common_utils::custom_serde::iso8601::option",
+        rename = "created.lt"
+    )]
+    pub created_lt: Option<PrimitiveDateTime>,
+    /// Time greater than the event created time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        with = "common_utils::custom_serde::iso8601::option",
+        rename = "created.gt"
+    )]
+    pub created_gt: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EventListItemResponse {
+    /// The identifier for the event
+    pub event_id: String,
+    /// The identifier for the merchant account
+    pub merchant_id: Option<String>,
+    /// The identifier for the business profile
+    pub profile_id: Option<String>,
+    /// The identifier of the object (payment, refund, dispute or mandate) the event is about
+    pub object_id: String,
+    /// Type of the event
+    pub event_type: EventType,
+    /// Whether the webhook for the event was successfully delivered to the merchant
+    pub is_delivered: bool,
+    /// Time at which the event was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EventRetrieveResponse {
+    #[serde(flatten)]
+    pub event_information: EventListItemResponse,
+    /// The webhook request that was sent to the merchant, not available for events created
+    /// before the requests were being stored
+    pub request: Option<OutgoingWebhookRequestContent>,
+    /// All the attempts made to deliver the webhook, in the order in which they were made
+    pub delivery_attempts: Vec<WebhookDeliveryAttemptResponse>,
+}
+
+/// The body and headers of an outgoing webhook request
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct OutgoingWebhookRequestContent {
+    /// The request body
+    #[schema(value_type = String)]
+    pub body: Secret<String>,
+    /// The request headers
+    #[schema(value_type = Vec<(String, String)>)]
+    pub headers: Vec<(String, Secret<String>)>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookDeliveryAttemptResponse {
+    /// The identifier for the delivery attempt
+    pub attempt_id: String,
+    /// The identifier for the event
+    pub event_id: String,
+    /// What triggered the delivery attempt
+    pub delivery_attempt: WebhookDeliveryAttempt,
+    /// Whether the webhook was received by the merchant
+    pub is_success: bool,
+    /// The HTTP status code returned by the merchant server
+    pub response_status_code: Option<u16>,
+    /// The response body returned by the merchant server, truncated if too long
+    pub response_body: Option<String>,
+    /// The reason the request could not be sent, if it failed before receiving a response
+    pub error_message: Option<String>,
+    /// Time taken by the merchant server to respond, in milliseconds
+    pub latency_ms: Option<i64>,
+    /// Time at which the delivery was attempted
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
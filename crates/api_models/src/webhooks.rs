@@ -3,7 +3,9 @@ use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
-use crate::{disputes, enums as api_enums, mandates, payments, refunds};
+#[cfg(feature = "payouts")]
+use crate::payouts;
+use crate::{disputes, enums as api_enums, mandates, payments, refunds, subscriptions};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
 #[serde(rename_all = "snake_case")]
@@ -14,6 +16,9 @@ pub enum IncomingWebhookEvent {
     PaymentIntentPartiallyFunded,
     PaymentIntentCancelled,
     PaymentActionRequired,
+    // outcome of an incremental authorization which the connector processes asynchronously
+    PaymentIntentAuthorizationSuccess,
+    PaymentIntentAuthorizationFailure,
     EventNotSupported,
     SourceChargeable,
     SourceTransactionCreated,
@@ -31,6 +36,12 @@ pub enum IncomingWebhookEvent {
     MandateActive,
     MandateRevoked,
     EndpointVerification,
+    PayoutSuccess,
+    PayoutFailure,
+    PayoutProcessing,
+    PayoutCancelled,
+    PayoutExpired,
+    PayoutReversed,
 }
 
 pub enum WebhookFlow {
@@ -41,6 +52,8 @@ pub enum WebhookFlow {
     ReturnResponse,
     BankTransfer,
     Mandate,
+    Payout,
+    IncrementalAuthorization,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -64,6 +77,10 @@ pub enum WebhookResponseTracker {
         mandate_id: String,
         status: common_enums::MandateStatus,
     },
+    Payout {
+        payout_id: String,
+        status: common_enums::PayoutStatus,
+    },
     NoEffect,
 }
 
@@ -73,7 +90,7 @@ impl WebhookResponseTracker {
             Self::Payment { payment_id, .. }
             | Self::Refund { payment_id, .. }
             | Self::Dispute { payment_id, .. } => Some(payment_id.to_string()),
-            Self::NoEffect | Self::Mandate { .. } => None,
+            Self::NoEffect | Self::Mandate { .. } | Self::Payout { .. } => None,
         }
     }
 }
@@ -87,6 +104,10 @@ impl From<IncomingWebhookEvent> for WebhookFlow {
             | IncomingWebhookEvent::PaymentActionRequired
             | IncomingWebhookEvent::PaymentIntentPartiallyFunded
             | IncomingWebhookEvent::PaymentIntentCancelled => Self::Payment,
+            IncomingWebhookEvent::PaymentIntentAuthorizationSuccess
+            | IncomingWebhookEvent::PaymentIntentAuthorizationFailure => {
+                Self::IncrementalAuthorization
+            }
             IncomingWebhookEvent::EventNotSupported => Self::ReturnResponse,
             IncomingWebhookEvent::RefundSuccess | IncomingWebhookEvent::RefundFailure => {
                 Self::Refund
@@ -104,6 +125,12 @@ impl From<IncomingWebhookEvent> for WebhookFlow {
             IncomingWebhookEvent::EndpointVerification => Self::ReturnResponse,
             IncomingWebhookEvent::SourceChargeable
             | IncomingWebhookEvent::SourceTransactionCreated => Self::BankTransfer,
+            IncomingWebhookEvent::PayoutSuccess
+            | IncomingWebhookEvent::PayoutFailure
+            | IncomingWebhookEvent::PayoutProcessing
+            | IncomingWebhookEvent::PayoutCancelled
+            | IncomingWebhookEvent::PayoutExpired
+            | IncomingWebhookEvent::PayoutReversed => Self::Payout,
         }
     }
 }
@@ -122,11 +149,18 @@ pub enum MandateIdType {
     ConnectorMandateId(String),
 }
 
+#[derive(Clone)]
+pub enum PayoutIdType {
+    PayoutId(String),
+    ConnectorPayoutId(String),
+}
+
 #[derive(Clone)]
 pub enum ObjectReferenceId {
     PaymentId(payments::PaymentIdType),
     RefundId(RefundIdType),
     MandateId(MandateIdType),
+    PayoutId(PayoutIdType),
 }
 
 pub struct IncomingWebhookDetails {
@@ -165,6 +199,11 @@ pub enum OutgoingWebhookContent {
     DisputeDetails(Box<disputes::DisputeResponse>),
     #[schema(value_type = MandateResponse)]
     MandateDetails(Box<mandates::MandateResponse>),
+    #[cfg(feature = "payouts")]
+    #[schema(value_type = PayoutCreateResponse)]
+    PayoutDetails(Box<payouts::PayoutCreateResponse>),
+    #[schema(value_type = SubscriptionResponse)]
+    SubscriptionDetails(Box<subscriptions::SubscriptionResponse>),
 }
 
 #[derive(Debug, Clone, Serialize)]
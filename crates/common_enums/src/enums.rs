@@ -50,6 +50,7 @@ pub enum AttemptStatus {
     VoidFailed,
     AutoRefunded,
     PartialCharged,
+    PartialChargedAndChargeable,
     Unresolved,
     #[default]
     Pending,
@@ -68,6 +69,7 @@ impl AttemptStatus {
             | Self::Voided
             | Self::VoidFailed
             | Self::CaptureFailed
+            | Self::PartialCharged
             | Self::Failure => true,
             Self::Started
             | Self::AuthenticationFailed
@@ -79,7 +81,7 @@ impl AttemptStatus {
             | Self::CodInitiated
             | Self::VoidInitiated
             | Self::CaptureInitiated
-            | Self::PartialCharged
+            | Self::PartialChargedAndChargeable
             | Self::Unresolved
             | Self::Pending
             | Self::PaymentMethodAwaited
@@ -795,6 +797,7 @@ pub enum EventType {
     PaymentFailed,
     PaymentProcessing,
     PaymentCancelled,
+    PaymentCaptured,
     ActionRequired,
     RefundSucceeded,
     RefundFailed,
@@ -805,8 +808,150 @@ pub enum EventType {
     DisputeChallenged,
     DisputeWon,
     DisputeLost,
+    DisputeEvidenceDue,
     MandateActive,
     MandateRevoked,
+    PayoutInitiated,
+    PayoutSuccess,
+    PayoutFailed,
+    PayoutCancelled,
+    PayoutReversed,
+    SubscriptionRenewalSucceeded,
+    SubscriptionRenewalFailed,
+}
+
+/// Indicates what triggered a delivery attempt of an outgoing webhook
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookDeliveryAttempt {
+    /// The first delivery made when the event occurred
+    InitialAttempt,
+    /// A delivery retried by the scheduler after a failed attempt
+    AutomaticRetry,
+    /// A delivery requested by the merchant through the API
+    ManualRetry,
+}
+
+/// The status of a subscription billed through a mandate
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SubscriptionStatus {
+    /// The subscription is in its trial period and has not been charged yet
+    Trialing,
+    /// The latest renewal was charged successfully
+    Active,
+    /// The latest renewal failed and is being retried
+    PastDue,
+    /// All retries of a renewal failed, the subscription is no longer billed
+    Unpaid,
+    /// The subscription was cancelled by the merchant
+    Cancelled,
+}
+
+/// The unit of the interval at which a subscription is billed
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SubscriptionInterval {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// The status of a hosted payment link
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentLinkStatus {
+    /// The link can be used to make the payment
+    #[default]
+    Active,
+    /// The link was not used before its expiry, the payment has been cancelled
+    Expired,
+    /// The link was cancelled by the merchant
+    Cancelled,
+    /// The payment was made through the link
+    Paid,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuthorizationStatus {
+    /// The authorized amount was increased at the connector
+    Success,
+    /// The connector declined the increase, the previously authorized amount still holds
+    Failure,
+    /// The increase request has been sent to the connector
+    #[default]
+    Processing,
+    /// The connector did not confirm the outcome of the increase
+    Unresolved,
 }
 
 // TODO: This decision about using KV mode or not,
@@ -861,6 +1006,7 @@ pub enum IntentStatus {
     RequiresConfirmation,
     RequiresCapture,
     PartiallyCaptured,
+    PartiallyCapturedAndCapturable,
 }
 
 #[derive(
@@ -1164,6 +1310,29 @@ pub enum MandateStatus {
     Revoked,
 }
 
+/// The calendar period over which the number of debits made through a mandate is limited
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum MandateFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
 #[derive(
     Clone,
     Debug,
@@ -1701,6 +1870,7 @@ pub enum PayoutStatus {
     RequiresCreation,
     RequiresPayoutMethodData,
     RequiresFulfillment,
+    Reversed,
 }
 
 #[derive(
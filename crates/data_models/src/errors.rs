@@ -24,6 +24,8 @@ pub enum StorageError {
     MockDbError,
     #[error("Customer with this id is Redacted")]
     CustomerRedacted,
+    #[error("The debit exceeds the limits of the mandate")]
+    MandateDebitLimitExceeded,
     #[error("Deserialization failure")]
     DeserializationFailed,
     #[error("Error while encrypting data")]
@@ -107,6 +107,7 @@ pub struct PaymentIntentNew {
 
     pub updated_by: String,
     pub surcharge_applicable: Option<bool>,
+    pub request_incremental_authorization: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -182,6 +183,10 @@ pub enum PaymentIntentUpdate {
         surcharge_applicable: bool,
         updated_by: String,
     },
+    IncrementalAuthorizationAmountUpdate {
+        amount: i64,
+        updated_by: String,
+    },
 }
 
 #[derive(Clone, Debug, Default)]
@@ -398,6 +403,13 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 updated_by,
                 ..Default::default()
             },
+            PaymentIntentUpdate::IncrementalAuthorizationAmountUpdate { amount, updated_by } => {
+                Self {
+                    amount: Some(amount),
+                    updated_by,
+                    ..Default::default()
+                }
+            }
         }
     }
 }
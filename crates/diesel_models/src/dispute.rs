@@ -1,12 +1,12 @@
 use common_utils::custom_serde;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use masking::Secret;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
 use crate::{enums as storage_enums, schema::dispute};
 
-#[derive(Clone, Debug, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize, router_derive::DebugAsDisplay)]
 #[diesel(table_name = dispute)]
 #[serde(deny_unknown_fields)]
 pub struct DisputeNew {
@@ -30,10 +30,10 @@ pub struct DisputeNew {
     pub profile_id: Option<String>,
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Identifiable, Queryable)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Identifiable, Queryable)]
 #[diesel(table_name = dispute)]
 pub struct Dispute {
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing, default)]
     pub id: i32,
     pub dispute_id: String,
     pub amount: String,
@@ -59,7 +59,19 @@ pub struct Dispute {
     pub profile_id: Option<String>,
 }
 
-#[derive(Debug)]
+/// The fields of a dispute which are aggregated for dispute analytics
+#[derive(Clone, Debug, Queryable)]
+pub struct DisputeAnalyticsRecord {
+    pub connector: String,
+    pub connector_reason_code: Option<String>,
+    pub dispute_stage: storage_enums::DisputeStage,
+    pub dispute_status: storage_enums::DisputeStatus,
+    pub currency: String,
+    pub amount: String,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum DisputeUpdate {
     Update {
         dispute_stage: storage_enums::DisputeStage,
@@ -93,6 +105,34 @@ pub struct DisputeUpdateInternal {
     evidence: Option<Secret<serde_json::Value>>,
 }
 
+impl DisputeUpdate {
+    pub fn apply_changeset(self, source: Dispute) -> Dispute {
+        let DisputeUpdateInternal {
+            dispute_stage,
+            dispute_status,
+            connector_status,
+            connector_reason,
+            connector_reason_code,
+            challenge_required_by,
+            connector_updated_at,
+            modified_at,
+            evidence,
+        } = self.into();
+        Dispute {
+            dispute_stage: dispute_stage.unwrap_or(source.dispute_stage),
+            dispute_status: dispute_status.unwrap_or(source.dispute_status),
+            connector_status: connector_status.unwrap_or(source.connector_status),
+            connector_reason: connector_reason.or(source.connector_reason),
+            connector_reason_code: connector_reason_code.or(source.connector_reason_code),
+            challenge_required_by: challenge_required_by.or(source.challenge_required_by),
+            connector_updated_at: connector_updated_at.or(source.connector_updated_at),
+            modified_at: modified_at.unwrap_or(source.modified_at),
+            evidence: evidence.unwrap_or(source.evidence),
+            ..source
+        }
+    }
+}
+
 impl From<DisputeUpdate> for DisputeUpdateInternal {
     fn from(merchant_account_update: DisputeUpdate) -> Self {
         match merchant_account_update {
@@ -131,3 +171,10 @@ impl From<DisputeUpdate> for DisputeUpdateInternal {
         }
     }
 }
+
+/// Tracking data of the process tracker task which acts on the evidence deadline of a dispute
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DisputeDeadlineTrackingData {
+    pub merchant_id: String,
+    pub dispute_id: String,
+}
@@ -40,6 +40,8 @@ pub enum EventClass {
     Refunds,
     Disputes,
     Mandates,
+    Payouts,
+    Subscriptions,
 }
 
 #[derive(
@@ -61,6 +63,8 @@ pub enum EventObjectType {
     RefundDetails,
     DisputeDetails,
     MandateDetails,
+    PayoutDetails,
+    SubscriptionDetails,
 }
 
 #[derive(
@@ -180,6 +184,9 @@ pub struct MandateAmountData {
     pub start_date: Option<PrimitiveDateTime>,
     pub end_date: Option<PrimitiveDateTime>,
     pub metadata: Option<pii::SecretSerdeValue>,
+    pub max_amount_per_transaction: Option<i64>,
+    pub frequency: Option<MandateFrequency>,
+    pub max_debits_per_frequency: Option<i32>,
 }
 
 #[derive(
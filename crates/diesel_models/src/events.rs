@@ -1,4 +1,4 @@
-use common_utils::custom_serde;
+use common_utils::{custom_serde, pii};
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
@@ -16,23 +16,28 @@ pub struct EventNew {
     pub intent_reference_id: Option<String>,
     pub primary_object_id: String,
     pub primary_object_type: storage_enums::EventObjectType,
+    pub merchant_id: Option<String>,
+    pub business_profile_id: Option<String>,
+    pub request: Option<pii::SecretSerdeValue>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum EventUpdate {
     UpdateWebhookNotified { is_webhook_notified: Option<bool> },
+    UpdateRequest { request: pii::SecretSerdeValue },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
 #[diesel(table_name = events)]
 pub struct EventUpdateInternal {
     pub is_webhook_notified: Option<bool>,
+    pub request: Option<pii::SecretSerdeValue>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
 #[diesel(table_name = events)]
 pub struct Event {
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing, default)]
     pub id: i32,
     pub event_id: String,
     pub event_type: storage_enums::EventType,
@@ -43,6 +48,31 @@ pub struct Event {
     pub primary_object_type: storage_enums::EventObjectType,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    pub merchant_id: Option<String>,
+    pub business_profile_id: Option<String>,
+    pub request: Option<pii::SecretSerdeValue>,
+}
+
+// Tracking data by process_tracker
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OutgoingWebhookTrackingData {
+    // The webhook request is read from the event on every retry, so that the request body and
+    // headers are not stored in the process tracker
+    pub event_id: String,
+}
+
+impl EventUpdate {
+    pub fn apply_changeset(self, source: Event) -> Event {
+        let EventUpdateInternal {
+            is_webhook_notified,
+            request,
+        } = self.into();
+        Event {
+            is_webhook_notified: is_webhook_notified.unwrap_or(source.is_webhook_notified),
+            request: request.or(source.request),
+            ..source
+        }
+    }
 }
 
 impl From<EventUpdate> for EventUpdateInternal {
@@ -52,6 +82,11 @@ impl From<EventUpdate> for EventUpdateInternal {
                 is_webhook_notified,
             } => Self {
                 is_webhook_notified,
+                request: None,
+            },
+            EventUpdate::UpdateRequest { request } => Self {
+                is_webhook_notified: None,
+                request: Some(request),
             },
         }
     }
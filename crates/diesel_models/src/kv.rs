@@ -3,10 +3,16 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     address::{Address, AddressNew, AddressUpdateInternal},
+    capture::{Capture, CaptureNew, CaptureUpdate},
     connector_response::{ConnectorResponse, ConnectorResponseNew, ConnectorResponseUpdate},
+    dispute::{Dispute, DisputeNew, DisputeUpdate},
     errors,
+    events::{Event, EventNew, EventUpdate},
+    mandate::{Mandate, MandateNew, MandateUpdate},
     payment_attempt::{PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate},
     payment_intent::{PaymentIntentNew, PaymentIntentUpdate},
+    payout_attempt::{PayoutAttempt, PayoutAttemptNew, PayoutAttemptUpdate},
+    payouts::{Payouts, PayoutsNew, PayoutsUpdate},
     refund::{Refund, RefundNew, RefundUpdate},
     reverse_lookup::ReverseLookupNew,
     PaymentIntent,
@@ -46,6 +52,12 @@ pub enum Insertable {
     ConnectorResponse(ConnectorResponseNew),
     Address(Box<AddressNew>),
     ReverseLookUp(ReverseLookupNew),
+    Capture(CaptureNew),
+    Dispute(DisputeNew),
+    Payouts(PayoutsNew),
+    PayoutAttempt(PayoutAttemptNew),
+    Mandate(MandateNew),
+    Event(EventNew),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -56,6 +68,12 @@ pub enum Updateable {
     RefundUpdate(RefundUpdateMems),
     ConnectorResponseUpdate(ConnectorResponseUpdateMems),
     AddressUpdate(Box<AddressUpdateMems>),
+    CaptureUpdate(CaptureUpdateMems),
+    DisputeUpdate(DisputeUpdateMems),
+    PayoutsUpdate(PayoutsUpdateMems),
+    PayoutAttemptUpdate(PayoutAttemptUpdateMems),
+    MandateUpdate(MandateUpdateMems),
+    EventUpdate(EventUpdateMems),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -87,3 +105,39 @@ pub struct RefundUpdateMems {
     pub orig: Refund,
     pub update_data: RefundUpdate,
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CaptureUpdateMems {
+    pub orig: Capture,
+    pub update_data: CaptureUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DisputeUpdateMems {
+    pub orig: Dispute,
+    pub update_data: DisputeUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PayoutsUpdateMems {
+    pub orig: Payouts,
+    pub update_data: PayoutsUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PayoutAttemptUpdateMems {
+    pub orig: PayoutAttempt,
+    pub update_data: PayoutAttemptUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MandateUpdateMems {
+    pub orig: Mandate,
+    pub update_data: MandateUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EventUpdateMems {
+    pub orig: Event,
+    pub update_data: EventUpdate,
+}
@@ -15,6 +15,7 @@ pub mod events;
 pub mod file;
 #[allow(unused)]
 pub mod fraud_check;
+pub mod incremental_authorization;
 #[cfg(feature = "kv_store")]
 pub mod kv;
 pub mod locker_mock_up;
@@ -35,6 +36,8 @@ pub mod refund;
 pub mod reverse_lookup;
 #[allow(unused_qualifications)]
 pub mod schema;
+pub mod subscription;
+pub mod webhook_delivery_attempt;
 
 use diesel_impl::{DieselArray, OptionalDieselArray};
 
@@ -1,11 +1,12 @@
 use common_utils::pii;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use masking::Secret;
+use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
 use crate::{enums as storage_enums, schema::mandate};
 
-#[derive(Clone, Debug, Identifiable, Queryable)]
+#[derive(Clone, Debug, Identifiable, Queryable, Serialize, Deserialize)]
 #[diesel(table_name = mandate)]
 pub struct Mandate {
     pub id: i32,
@@ -31,10 +32,24 @@ pub struct Mandate {
     pub metadata: Option<pii::SecretSerdeValue>,
     pub connector_mandate_ids: Option<pii::SecretSerdeValue>,
     pub original_payment_id: Option<String>,
+    pub max_amount_per_transaction: Option<i64>,
+    pub frequency: Option<storage_enums::MandateFrequency>,
+    pub max_debits_per_frequency: Option<i32>,
+    /// The start of the frequency period in which the mandate was last debited
+    pub frequency_period_start: Option<PrimitiveDateTime>,
+    /// The number of debits made in the frequency period starting at `frequency_period_start`
+    pub frequency_debit_count: Option<i32>,
 }
 
 #[derive(
-    router_derive::Setter, Clone, Debug, Default, Insertable, router_derive::DebugAsDisplay,
+    router_derive::Setter,
+    Clone,
+    Debug,
+    Default,
+    Insertable,
+    router_derive::DebugAsDisplay,
+    Serialize,
+    Deserialize,
 )]
 #[diesel(table_name = mandate)]
 pub struct MandateNew {
@@ -60,9 +75,12 @@ pub struct MandateNew {
     pub metadata: Option<pii::SecretSerdeValue>,
     pub connector_mandate_ids: Option<pii::SecretSerdeValue>,
     pub original_payment_id: Option<String>,
+    pub max_amount_per_transaction: Option<i64>,
+    pub frequency: Option<storage_enums::MandateFrequency>,
+    pub max_debits_per_frequency: Option<i32>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum MandateUpdate {
     StatusUpdate {
         mandate_status: storage_enums::MandateStatus,
@@ -73,6 +91,11 @@ pub enum MandateUpdate {
     ConnectorReferenceUpdate {
         connector_mandate_ids: Option<pii::SecretSerdeValue>,
     },
+    DebitUpdate {
+        amount_captured: Option<i64>,
+        frequency_period_start: Option<PrimitiveDateTime>,
+        frequency_debit_count: Option<i32>,
+    },
 }
 
 #[derive(Clone, Eq, PartialEq, Copy, Debug, Default, serde::Serialize, serde::Deserialize)]
@@ -87,6 +110,28 @@ pub struct MandateUpdateInternal {
     mandate_status: Option<storage_enums::MandateStatus>,
     amount_captured: Option<i64>,
     connector_mandate_ids: Option<pii::SecretSerdeValue>,
+    frequency_period_start: Option<PrimitiveDateTime>,
+    frequency_debit_count: Option<i32>,
+}
+
+impl MandateUpdate {
+    pub fn apply_changeset(self, source: Mandate) -> Mandate {
+        let MandateUpdateInternal {
+            mandate_status,
+            amount_captured,
+            connector_mandate_ids,
+            frequency_period_start,
+            frequency_debit_count,
+        } = self.into();
+        Mandate {
+            mandate_status: mandate_status.unwrap_or(source.mandate_status),
+            amount_captured: amount_captured.or(source.amount_captured),
+            connector_mandate_ids: connector_mandate_ids.or(source.connector_mandate_ids),
+            frequency_period_start: frequency_period_start.or(source.frequency_period_start),
+            frequency_debit_count: frequency_debit_count.or(source.frequency_debit_count),
+            ..source
+        }
+    }
 }
 
 impl From<MandateUpdate> for MandateUpdateInternal {
@@ -94,13 +139,11 @@ impl From<MandateUpdate> for MandateUpdateInternal {
         match mandate_update {
             MandateUpdate::StatusUpdate { mandate_status } => Self {
                 mandate_status: Some(mandate_status),
-                connector_mandate_ids: None,
-                amount_captured: None,
+                ..Default::default()
             },
             MandateUpdate::CaptureAmountUpdate { amount_captured } => Self {
-                mandate_status: None,
                 amount_captured,
-                connector_mandate_ids: None,
+                ..Default::default()
             },
             MandateUpdate::ConnectorReferenceUpdate {
                 connector_mandate_ids: connector_mandate_id,
@@ -108,6 +151,77 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 connector_mandate_ids: connector_mandate_id,
                 ..Default::default()
             },
+            MandateUpdate::DebitUpdate {
+                amount_captured,
+                frequency_period_start,
+                frequency_debit_count,
+            } => Self {
+                amount_captured,
+                frequency_period_start,
+                frequency_debit_count,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl Mandate {
+    /// Get the update which records a debit of `amount` made in the frequency period starting at
+    /// `frequency_period_start`, the debit count is restarted when a new period has begun
+    pub fn get_debit_update(
+        &self,
+        amount: i64,
+        frequency_period_start: Option<PrimitiveDateTime>,
+    ) -> MandateUpdate {
+        let frequency_debit_count = match (self.frequency_period_start, frequency_period_start) {
+            (Some(last_period_start), Some(period_start)) if last_period_start == period_start => {
+                self.frequency_debit_count.unwrap_or(0) + 1
+            }
+            _ => 1,
+        };
+        MandateUpdate::DebitUpdate {
+            amount_captured: Some(self.amount_captured.unwrap_or(0) + amount),
+            frequency_period_start,
+            frequency_debit_count: Some(frequency_debit_count),
+        }
+    }
+
+    /// Check whether a debit of `amount` made in the frequency period starting at
+    /// `frequency_period_start` is within the mandate amount and the maximum number of debits
+    /// allowed in the period
+    pub fn is_debit_within_limits(
+        &self,
+        amount: i64,
+        frequency_period_start: Option<PrimitiveDateTime>,
+    ) -> bool {
+        let within_mandate_amount = self.mandate_amount.map_or(true, |mandate_amount| {
+            self.amount_captured.unwrap_or(0) + amount <= mandate_amount
+        });
+        let within_max_debits = self.max_debits_per_frequency.map_or(true, |max_debits| {
+            self.frequency_period_start != frequency_period_start
+                || self.frequency_debit_count.unwrap_or(0) < max_debits
+        });
+        within_mandate_amount && within_max_debits
+    }
+
+    /// Get the update which reverts a debit of `amount` recorded in the frequency period starting
+    /// at `frequency_period_start`, the debit count is left as is if a new period has begun since
+    pub fn get_debit_release_update(
+        &self,
+        amount: i64,
+        frequency_period_start: Option<PrimitiveDateTime>,
+    ) -> MandateUpdate {
+        let frequency_debit_count = match (self.frequency_period_start, frequency_period_start) {
+            (Some(last_period_start), Some(period_start)) if last_period_start == period_start => {
+                self.frequency_debit_count
+                    .map(|frequency_debit_count| (frequency_debit_count - 1).max(0))
+            }
+            _ => self.frequency_debit_count,
+        };
+        MandateUpdate::DebitUpdate {
+            amount_captured: Some((self.amount_captured.unwrap_or(0) - amount).max(0)),
+            frequency_period_start: self.frequency_period_start,
+            frequency_debit_count,
         }
     }
 }
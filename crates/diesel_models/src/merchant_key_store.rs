@@ -20,6 +20,8 @@ pub struct MerchantKeyStore {
     pub key: Encryption,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    pub version: i32,
+    pub previous_key: Option<Encryption>,
 }
 
 #[derive(
@@ -30,6 +32,7 @@ pub struct MerchantKeyStoreNew {
     pub merchant_id: String,
     pub key: Encryption,
     pub created_at: PrimitiveDateTime,
+    pub version: i32,
 }
 
 #[derive(
@@ -37,6 +40,29 @@ pub struct MerchantKeyStoreNew {
 )]
 #[diesel(table_name = merchant_key_store)]
 pub struct MerchantKeyStoreUpdateInternal {
-    pub merchant_id: String,
     pub key: Encryption,
+    pub version: i32,
+    pub previous_key: Option<Encryption>,
+}
+
+/// Entities whose encrypted fields are re-encrypted when a merchant key is rotated, in the order
+/// in which they are processed
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyRotationStage {
+    MerchantAccount,
+    MerchantConnectorAccounts,
+    Customers,
+    Addresses,
+    PaymentMethods,
+}
+
+/// Tracking data of the process tracker task which re-encrypts merchant data after a key rotation
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct KeyRotationTrackingData {
+    pub merchant_id: String,
+    pub version: i32,
+    pub stage: KeyRotationStage,
+    /// Identifier of the last record re-encrypted in the current stage
+    pub last_processed_id: Option<String>,
 }
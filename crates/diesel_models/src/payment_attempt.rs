@@ -58,6 +58,7 @@ pub struct PaymentAttempt {
     pub connector_response_reference_id: Option<String>,
     pub amount_capturable: i64,
     pub updated_by: String,
+    pub installment_data: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Queryable, Serialize, Deserialize)]
@@ -117,6 +118,7 @@ pub struct PaymentAttemptNew {
     pub multiple_capture_count: Option<i16>,
     pub amount_capturable: i64,
     pub updated_by: String,
+    pub installment_data: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -166,6 +168,7 @@ pub enum PaymentAttemptUpdate {
         amount_capturable: Option<i64>,
         surcharge_amount: Option<i64>,
         tax_amount: Option<i64>,
+        installment_data: Option<serde_json::Value>,
         updated_by: String,
     },
     VoidUpdate {
@@ -228,6 +231,11 @@ pub enum PaymentAttemptUpdate {
         amount_capturable: i64,
         updated_by: String,
     },
+    IncrementalAuthorizationAmountUpdate {
+        amount: i64,
+        amount_capturable: i64,
+        updated_by: String,
+    },
     PreprocessingUpdate {
         status: storage_enums::AttemptStatus,
         payment_method_id: Option<Option<String>>,
@@ -272,6 +280,7 @@ pub struct PaymentAttemptUpdateInternal {
     surcharge_amount: Option<i64>,
     tax_amount: Option<i64>,
     amount_capturable: Option<i64>,
+    installment_data: Option<serde_json::Value>,
     updated_by: String,
 }
 
@@ -323,6 +332,7 @@ impl PaymentAttemptUpdate {
             amount_capturable: pa_update
                 .amount_capturable
                 .unwrap_or(source.amount_capturable),
+            installment_data: pa_update.installment_data.or(source.installment_data),
             updated_by: pa_update.updated_by,
             ..source
         }
@@ -393,6 +403,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 amount_capturable,
                 surcharge_amount,
                 tax_amount,
+                installment_data,
                 updated_by,
             } => Self {
                 amount: Some(amount),
@@ -414,6 +425,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 amount_capturable,
                 surcharge_amount,
                 tax_amount,
+                installment_data,
                 updated_by,
                 ..Default::default()
             },
@@ -570,6 +582,16 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 updated_by,
                 ..Default::default()
             },
+            PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
+                amount,
+                amount_capturable,
+                updated_by,
+            } => Self {
+                amount: Some(amount),
+                amount_capturable: Some(amount_capturable),
+                updated_by,
+                ..Default::default()
+            },
         }
     }
 }
@@ -1,4 +1,4 @@
-use diesel::{Identifiable, Insertable, Queryable};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use serde::{self, Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
@@ -20,6 +20,7 @@ pub struct PaymentLink {
     pub last_modified_at: PrimitiveDateTime,
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub fulfilment_time: Option<PrimitiveDateTime>,
+    pub status: storage_enums::PaymentLinkStatus,
 }
 
 #[derive(
@@ -47,4 +48,72 @@ pub struct PaymentLinkNew {
     pub last_modified_at: Option<PrimitiveDateTime>,
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub fulfilment_time: Option<PrimitiveDateTime>,
+    pub status: storage_enums::PaymentLinkStatus,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum PaymentLinkUpdate {
+    Update {
+        amount: Option<i64>,
+        fulfilment_time: Option<PrimitiveDateTime>,
+    },
+    StatusUpdate {
+        status: storage_enums::PaymentLinkStatus,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_link)]
+pub struct PaymentLinkUpdateInternal {
+    amount: Option<i64>,
+    fulfilment_time: Option<PrimitiveDateTime>,
+    status: Option<storage_enums::PaymentLinkStatus>,
+    last_modified_at: Option<PrimitiveDateTime>,
+}
+
+impl PaymentLinkUpdate {
+    pub fn apply_changeset(self, source: PaymentLink) -> PaymentLink {
+        let PaymentLinkUpdateInternal {
+            amount,
+            fulfilment_time,
+            status,
+            last_modified_at,
+        } = self.into();
+        PaymentLink {
+            amount: amount.unwrap_or(source.amount),
+            fulfilment_time: fulfilment_time.or(source.fulfilment_time),
+            status: status.unwrap_or(source.status),
+            last_modified_at: last_modified_at.unwrap_or(source.last_modified_at),
+            ..source
+        }
+    }
+}
+
+impl From<PaymentLinkUpdate> for PaymentLinkUpdateInternal {
+    fn from(payment_link_update: PaymentLinkUpdate) -> Self {
+        let last_modified_at = Some(common_utils::date_time::now());
+        match payment_link_update {
+            PaymentLinkUpdate::Update {
+                amount,
+                fulfilment_time,
+            } => Self {
+                amount,
+                fulfilment_time,
+                last_modified_at,
+                ..Default::default()
+            },
+            PaymentLinkUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                last_modified_at,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Tracking data of the process tracker task which expires a payment link
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentLinkTrackingData {
+    pub merchant_id: String,
+    pub payment_link_id: String,
 }
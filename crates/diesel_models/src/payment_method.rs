@@ -116,9 +116,14 @@ pub struct PaymentMethodUpdateInternal {
 
 impl PaymentMethodUpdateInternal {
     pub fn create_payment_method(self, source: PaymentMethod) -> PaymentMethod {
-        let metadata = self.metadata.map(Secret::new);
+        let metadata = self.metadata.map(Secret::new).or(source.metadata);
+        let payment_method_data = self.payment_method_data.or(source.payment_method_data);
 
-        PaymentMethod { metadata, ..source }
+        PaymentMethod {
+            metadata,
+            payment_method_data,
+            ..source
+        }
     }
 }
 
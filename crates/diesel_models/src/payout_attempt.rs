@@ -90,7 +90,7 @@ pub struct PayoutAttemptNew {
     pub profile_id: Option<String>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum PayoutAttemptUpdate {
     StatusUpdate {
         connector_payout_id: String,
@@ -125,6 +125,34 @@ pub struct PayoutAttemptUpdateInternal {
     pub last_modified_at: Option<PrimitiveDateTime>,
 }
 
+impl PayoutAttemptUpdate {
+    pub fn apply_changeset(self, source: PayoutAttempt) -> PayoutAttempt {
+        let PayoutAttemptUpdateInternal {
+            payout_token,
+            connector_payout_id,
+            status,
+            error_message,
+            error_code,
+            is_eligible,
+            business_country,
+            business_label,
+            last_modified_at,
+        } = self.into();
+        PayoutAttempt {
+            payout_token: payout_token.or(source.payout_token),
+            connector_payout_id: connector_payout_id.unwrap_or(source.connector_payout_id),
+            status: status.unwrap_or(source.status),
+            error_message: error_message.or(source.error_message),
+            error_code: error_code.or(source.error_code),
+            is_eligible: is_eligible.or(source.is_eligible),
+            business_country: business_country.or(source.business_country),
+            business_label: business_label.or(source.business_label),
+            last_modified_at: last_modified_at.unwrap_or(source.last_modified_at),
+            ..source
+        }
+    }
+}
+
 impl From<PayoutAttemptUpdate> for PayoutAttemptUpdateInternal {
     fn from(payout_update: PayoutAttemptUpdate) -> Self {
         match payout_update {
@@ -92,7 +92,7 @@ pub struct PayoutsNew {
     pub last_modified_at: Option<PrimitiveDateTime>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum PayoutsUpdate {
     Update {
         amount: i64,
@@ -132,6 +132,38 @@ pub struct PayoutsUpdateInternal {
     pub payout_method_id: Option<String>,
 }
 
+impl PayoutsUpdate {
+    pub fn apply_changeset(self, source: Payouts) -> Payouts {
+        let PayoutsUpdateInternal {
+            amount,
+            destination_currency,
+            source_currency,
+            description,
+            recurring,
+            auto_fulfill,
+            return_url,
+            entity_type,
+            metadata,
+            last_modified_at,
+            payout_method_id,
+        } = self.into();
+        Payouts {
+            amount: amount.unwrap_or(source.amount),
+            destination_currency: destination_currency.unwrap_or(source.destination_currency),
+            source_currency: source_currency.unwrap_or(source.source_currency),
+            description: description.or(source.description),
+            recurring: recurring.unwrap_or(source.recurring),
+            auto_fulfill: auto_fulfill.unwrap_or(source.auto_fulfill),
+            return_url: return_url.or(source.return_url),
+            entity_type: entity_type.unwrap_or(source.entity_type),
+            metadata: metadata.or(source.metadata),
+            last_modified_at: last_modified_at.unwrap_or(source.last_modified_at),
+            payout_method_id: payout_method_id.or(source.payout_method_id),
+            ..source
+        }
+    }
+}
+
 impl From<PayoutsUpdate> for PayoutsUpdateInternal {
     fn from(payout_update: PayoutsUpdate) -> Self {
         match payout_update {
@@ -11,6 +11,7 @@ pub mod events;
 pub mod file;
 pub mod fraud_check;
 pub mod generics;
+pub mod incremental_authorization;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -25,3 +26,5 @@ pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
 pub mod reverse_lookup;
+pub mod subscription;
+pub mod webhook_delivery_attempt;
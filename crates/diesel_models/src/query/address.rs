@@ -140,6 +140,25 @@ impl Address {
         }
     }
 
+    #[instrument(skip(conn))]
+    pub async fn list_by_merchant_id_after_address_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        last_address_id: &str,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::address_id.gt(last_address_id.to_owned())),
+            Some(limit),
+            None,
+            Some(dsl::address_id.asc()),
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_optional_by_address_id<'a>(
         conn: &PgPooledConn,
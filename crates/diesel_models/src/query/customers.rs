@@ -88,6 +88,25 @@ impl Customer {
         .await
     }
 
+    #[instrument(skip(conn))]
+    pub async fn list_by_merchant_id_after_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        last_customer_id: &str,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.gt(last_customer_id.to_owned())),
+            Some(limit),
+            None,
+            Some(dsl::customer_id.asc()),
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_optional_by_customer_id_merchant_id(
         conn: &PgPooledConn,
@@ -1,10 +1,17 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
-use router_env::{instrument, tracing};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+    QueryDsl, Table,
+};
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{
-    dispute::{Dispute, DisputeNew, DisputeUpdate, DisputeUpdateInternal},
+    dispute::{Dispute, DisputeAnalyticsRecord, DisputeNew, DisputeUpdate, DisputeUpdateInternal},
     errors,
+    query::generics::db_metrics,
     schema::dispute::dsl,
     PgPooledConn, StorageResult,
 };
@@ -70,6 +77,50 @@ impl Dispute {
         .await
     }
 
+    /// Get the fields aggregated for analytics of the disputes received in the given time range
+    #[instrument(skip(conn))]
+    pub async fn get_analytics_records(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        profile_id: Option<String>,
+        connector: Option<String>,
+        start_time: PrimitiveDateTime,
+        end_time: PrimitiveDateTime,
+    ) -> StorageResult<Vec<DisputeAnalyticsRecord>> {
+        let mut query = <Self as HasTable>::table()
+            .select((
+                dsl::connector,
+                dsl::connector_reason_code,
+                dsl::dispute_stage,
+                dsl::dispute_status,
+                dsl::currency,
+                dsl::amount,
+                dsl::created_at,
+            ))
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::created_at.ge(start_time))
+            .filter(dsl::created_at.le(end_time))
+            .into_boxed();
+
+        if let Some(profile_id) = profile_id {
+            query = query.filter(dsl::profile_id.eq(profile_id));
+        }
+        if let Some(connector) = connector {
+            query = query.filter(dsl::connector.eq(connector));
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error fetching dispute analytics records")
+    }
+
     #[instrument(skip(conn))]
     pub async fn update(self, conn: &PgPooledConn, dispute: DisputeUpdate) -> StorageResult<Self> {
         match generics::generic_update_with_unique_predicate_get_result::<
@@ -1,6 +1,12 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
+use diesel::{
+    associations::HasTable,
+    dsl::sql,
+    sql_types::{BigInt, Bool, Integer, Nullable, Timestamp},
+    BoolExpressionMethods, ExpressionMethods, Table,
+};
 use error_stack::report;
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{errors, mandate::*, schema::mandate::dsl, PgPooledConn, StorageResult};
@@ -84,4 +90,92 @@ impl Mandate {
                 .attach_printable("Error while updating mandate")
         })
     }
+
+    /// Records a debit of `amount` made in the frequency period starting at
+    /// `frequency_period_start`. The captured amount and the debit count are incremented by the
+    /// database rather than computed from a previously read mandate, so that concurrent debits
+    /// are not lost. The mandate is not updated, and `None` is returned, if the mandate is not
+    /// found or if the debit would exceed the mandate amount or the maximum number of debits
+    /// allowed in the period.
+    #[instrument(skip(conn))]
+    pub async fn record_debit_by_merchant_id_mandate_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+        frequency_period_start: Option<PrimitiveDateTime>,
+    ) -> StorageResult<Option<Self>> {
+        let amount_captured =
+            sql::<Nullable<BigInt>>("COALESCE(amount_captured, 0) + ").bind::<BigInt, _>(amount);
+        // Comparing with a null period start is never true, so the count restarts at 1 both when
+        // a new period has begun and when the mandate has no frequency
+        let frequency_debit_count = sql::<Nullable<Integer>>("CASE WHEN frequency_period_start = ")
+            .bind::<Nullable<Timestamp>, _>(frequency_period_start)
+            .sql(" THEN COALESCE(frequency_debit_count, 0) + 1 ELSE 1 END");
+        let within_mandate_amount =
+            sql::<Bool>("(mandate_amount IS NULL OR COALESCE(amount_captured, 0) + ")
+                .bind::<BigInt, _>(amount)
+                .sql(" <= mandate_amount)");
+        let within_max_debits = sql::<Bool>(
+            "(max_debits_per_frequency IS NULL OR frequency_period_start IS DISTINCT FROM ",
+        )
+        .bind::<Nullable<Timestamp>, _>(frequency_period_start)
+        .sql(" OR COALESCE(frequency_debit_count, 0) < max_debits_per_frequency)");
+
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::mandate_id.eq(mandate_id.to_owned()))
+                .and(within_mandate_amount)
+                .and(within_max_debits),
+            (
+                dsl::amount_captured.eq(amount_captured),
+                dsl::frequency_period_start.eq(frequency_period_start),
+                dsl::frequency_debit_count.eq(frequency_debit_count),
+            ),
+        )
+        .await
+        .map(|mandates| mandates.first().cloned())
+    }
+
+    /// Reverts a debit of `amount` recorded in the frequency period starting at
+    /// `frequency_period_start`, when the payment for which it was recorded has failed. The debit
+    /// count is only decremented if the period has not changed since the debit was recorded.
+    #[instrument(skip(conn))]
+    pub async fn release_debit_by_merchant_id_mandate_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+        frequency_period_start: Option<PrimitiveDateTime>,
+    ) -> StorageResult<Self> {
+        let amount_captured = sql::<Nullable<BigInt>>("GREATEST(COALESCE(amount_captured, 0) - ")
+            .bind::<BigInt, _>(amount)
+            .sql(", 0)");
+        let frequency_debit_count = sql::<Nullable<Integer>>("CASE WHEN frequency_period_start = ")
+            .bind::<Nullable<Timestamp>, _>(frequency_period_start)
+            .sql(
+                " THEN GREATEST(COALESCE(frequency_debit_count, 0) - 1, 0) \
+                 ELSE frequency_debit_count END",
+            );
+
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::mandate_id.eq(mandate_id.to_owned())),
+            (
+                dsl::amount_captured.eq(amount_captured),
+                dsl::frequency_debit_count.eq(frequency_debit_count),
+            ),
+        )
+        .await?
+        .first()
+        .cloned()
+        .ok_or_else(|| {
+            report!(errors::DatabaseError::NotFound)
+                .attach_printable("Error while releasing debit of mandate")
+        })
+    }
 }
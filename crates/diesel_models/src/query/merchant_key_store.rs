@@ -3,7 +3,7 @@ use router_env::{instrument, tracing};
 
 use super::generics;
 use crate::{
-    merchant_key_store::{MerchantKeyStore, MerchantKeyStoreNew},
+    merchant_key_store::{MerchantKeyStore, MerchantKeyStoreNew, MerchantKeyStoreUpdateInternal},
     schema::merchant_key_store::dsl,
     PgPooledConn, StorageResult,
 };
@@ -28,6 +28,25 @@ impl MerchantKeyStore {
         .await
     }
 
+    #[instrument(skip(conn))]
+    pub async fn update_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        merchant_key_store: MerchantKeyStoreUpdateInternal,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            merchant_key_store,
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn delete_by_merchant_id(
         conn: &PgPooledConn,
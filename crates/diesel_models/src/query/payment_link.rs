@@ -1,9 +1,11 @@
 use diesel::{associations::HasTable, ExpressionMethods};
+use error_stack::report;
 use router_env::{instrument, tracing};
 
 use super::generics;
 use crate::{
-    payment_link::{PaymentLink, PaymentLinkNew},
+    errors,
+    payment_link::{PaymentLink, PaymentLinkNew, PaymentLinkUpdate, PaymentLinkUpdateInternal},
     schema::payment_link::dsl,
     PgPooledConn, StorageResult,
 };
@@ -26,4 +28,24 @@ impl PaymentLink {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn update_by_payment_link_id(
+        conn: &PgPooledConn,
+        payment_link_id: &str,
+        payment_link: PaymentLinkUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::payment_link_id.eq(payment_link_id.to_owned()),
+            PaymentLinkUpdateInternal::from(payment_link),
+        )
+        .await?
+        .first()
+        .cloned()
+        .ok_or_else(|| {
+            report!(errors::DatabaseError::NotFound)
+                .attach_printable("Error while updating payment link")
+        })
+    }
 }
@@ -54,6 +54,8 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        scopes -> Nullable<Array<Nullable<Text>>>,
+        allowed_ips -> Nullable<Array<Nullable<Text>>>,
     }
 }
 
@@ -83,6 +85,8 @@ diesel::table! {
         payout_routing_algorithm -> Nullable<Jsonb>,
         is_recon_enabled -> Bool,
         applepay_verified_domains -> Nullable<Array<Nullable<Text>>>,
+        dispute_policy -> Nullable<Jsonb>,
+        payment_link_config -> Nullable<Jsonb>,
     }
 }
 
@@ -266,6 +270,11 @@ diesel::table! {
         primary_object_id -> Varchar,
         primary_object_type -> EventObjectType,
         created_at -> Timestamp,
+        #[max_length = 64]
+        merchant_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        business_profile_id -> Nullable<Varchar>,
+        request -> Nullable<Jsonb>,
     }
 }
 
@@ -328,6 +337,36 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    incremental_authorization (authorization_id) {
+        #[max_length = 64]
+        authorization_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        attempt_id -> Varchar,
+        amount -> Int8,
+        previous_amount -> Int8,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 255]
+        reason -> Nullable<Varchar>,
+        #[max_length = 255]
+        error_code -> Nullable<Varchar>,
+        #[max_length = 255]
+        error_message -> Nullable<Varchar>,
+        #[max_length = 128]
+        connector_authorization_id -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -404,6 +443,12 @@ diesel::table! {
         connector_mandate_ids -> Nullable<Jsonb>,
         #[max_length = 64]
         original_payment_id -> Nullable<Varchar>,
+        max_amount_per_transaction -> Nullable<Int8>,
+        #[max_length = 32]
+        frequency -> Nullable<Varchar>,
+        max_debits_per_frequency -> Nullable<Int4>,
+        frequency_period_start -> Nullable<Timestamp>,
+        frequency_debit_count -> Nullable<Int4>,
     }
 }
 
@@ -496,6 +541,8 @@ diesel::table! {
         merchant_id -> Varchar,
         key -> Bytea,
         created_at -> Timestamp,
+        version -> Int4,
+        previous_key -> Nullable<Bytea>,
     }
 }
 
@@ -561,6 +608,7 @@ diesel::table! {
         amount_capturable -> Int8,
         #[max_length = 32]
         updated_by -> Varchar,
+        installment_data -> Nullable<Jsonb>,
     }
 }
 
@@ -622,6 +670,7 @@ diesel::table! {
         #[max_length = 32]
         updated_by -> Varchar,
         surcharge_applicable -> Nullable<Bool>,
+        request_incremental_authorization -> Nullable<Bool>,
     }
 }
 
@@ -643,6 +692,8 @@ diesel::table! {
         created_at -> Timestamp,
         last_modified_at -> Timestamp,
         fulfilment_time -> Nullable<Timestamp>,
+        #[max_length = 32]
+        status -> Varchar,
     }
 }
 
@@ -828,6 +879,8 @@ diesel::table! {
         profile_id -> Nullable<Varchar>,
         #[max_length = 32]
         updated_by -> Varchar,
+        #[max_length = 64]
+        capture_id -> Nullable<Varchar>,
     }
 }
 
@@ -849,6 +902,68 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    subscription (id) {
+        id -> Int4,
+        #[max_length = 64]
+        subscription_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        mandate_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        #[max_length = 32]
+        billing_interval -> Varchar,
+        interval_count -> Int4,
+        trial_end -> Nullable<Timestamp>,
+        billing_anchor -> Timestamp,
+        current_period_start -> Nullable<Timestamp>,
+        current_period_end -> Nullable<Timestamp>,
+        next_billing_at -> Nullable<Timestamp>,
+        #[max_length = 64]
+        latest_payment_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        metadata -> Nullable<Jsonb>,
+        cancelled_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    webhook_delivery_attempt (id) {
+        id -> Int4,
+        #[max_length = 64]
+        attempt_id -> Varchar,
+        #[max_length = 64]
+        event_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 32]
+        delivery_attempt -> Varchar,
+        is_success -> Bool,
+        response_status_code -> Nullable<Int2>,
+        response_body -> Nullable<Text>,
+        error_message -> Nullable<Text>,
+        latency_ms -> Nullable<Int8>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
@@ -862,6 +977,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     events,
     file_metadata,
     fraud_check,
+    incremental_authorization,
     locker_mock_up,
     mandate,
     merchant_account,
@@ -876,4 +992,6 @@ diesel::allow_tables_to_appear_in_same_query!(
     process_tracker,
     refund,
     reverse_lookup,
+    subscription,
+    webhook_delivery_attempt,
 );
@@ -0,0 +1,151 @@
+use common_utils::pii;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::subscription};
+
+#[derive(Clone, Debug, Deserialize, Identifiable, Queryable, Serialize)]
+#[diesel(table_name = subscription)]
+pub struct Subscription {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub subscription_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub mandate_id: String,
+    pub profile_id: Option<String>,
+    pub status: storage_enums::SubscriptionStatus,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub billing_interval: storage_enums::SubscriptionInterval,
+    pub interval_count: i32,
+    pub trial_end: Option<PrimitiveDateTime>,
+    pub billing_anchor: PrimitiveDateTime,
+    pub current_period_start: Option<PrimitiveDateTime>,
+    pub current_period_end: Option<PrimitiveDateTime>,
+    pub next_billing_at: Option<PrimitiveDateTime>,
+    pub latest_payment_id: Option<String>,
+    pub description: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription)]
+pub struct SubscriptionNew {
+    pub subscription_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub mandate_id: String,
+    pub profile_id: Option<String>,
+    pub status: storage_enums::SubscriptionStatus,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub billing_interval: storage_enums::SubscriptionInterval,
+    pub interval_count: i32,
+    pub trial_end: Option<PrimitiveDateTime>,
+    pub billing_anchor: PrimitiveDateTime,
+    pub next_billing_at: Option<PrimitiveDateTime>,
+    pub description: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Clone, Debug)]
+pub enum SubscriptionUpdate {
+    RenewalSuccessUpdate {
+        current_period_start: PrimitiveDateTime,
+        current_period_end: PrimitiveDateTime,
+        latest_payment_id: Option<String>,
+    },
+    RenewalFailureUpdate {
+        status: storage_enums::SubscriptionStatus,
+        latest_payment_id: Option<String>,
+    },
+    CancelUpdate,
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription)]
+pub struct SubscriptionUpdateInternal {
+    status: Option<storage_enums::SubscriptionStatus>,
+    current_period_start: Option<PrimitiveDateTime>,
+    current_period_end: Option<PrimitiveDateTime>,
+    next_billing_at: Option<Option<PrimitiveDateTime>>,
+    latest_payment_id: Option<String>,
+    cancelled_at: Option<PrimitiveDateTime>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl SubscriptionUpdate {
+    pub fn apply_changeset(self, source: Subscription) -> Subscription {
+        let SubscriptionUpdateInternal {
+            status,
+            current_period_start,
+            current_period_end,
+            next_billing_at,
+            latest_payment_id,
+            cancelled_at,
+            modified_at,
+        } = self.into();
+        Subscription {
+            status: status.unwrap_or(source.status),
+            current_period_start: current_period_start.or(source.current_period_start),
+            current_period_end: current_period_end.or(source.current_period_end),
+            next_billing_at: next_billing_at.unwrap_or(source.next_billing_at),
+            latest_payment_id: latest_payment_id.or(source.latest_payment_id),
+            cancelled_at: cancelled_at.or(source.cancelled_at),
+            modified_at: modified_at.unwrap_or(source.modified_at),
+            ..source
+        }
+    }
+}
+
+impl From<SubscriptionUpdate> for SubscriptionUpdateInternal {
+    fn from(subscription_update: SubscriptionUpdate) -> Self {
+        let now = Some(common_utils::date_time::now());
+        match subscription_update {
+            SubscriptionUpdate::RenewalSuccessUpdate {
+                current_period_start,
+                current_period_end,
+                latest_payment_id,
+            } => Self {
+                status: Some(storage_enums::SubscriptionStatus::Active),
+                current_period_start: Some(current_period_start),
+                current_period_end: Some(current_period_end),
+                next_billing_at: Some(Some(current_period_end)),
+                latest_payment_id,
+                modified_at: now,
+                ..Default::default()
+            },
+            SubscriptionUpdate::RenewalFailureUpdate {
+                status,
+                latest_payment_id,
+            } => Self {
+                status: Some(status),
+                // An unpaid subscription is not billed again
+                next_billing_at: (status == storage_enums::SubscriptionStatus::Unpaid)
+                    .then_some(None),
+                latest_payment_id,
+                modified_at: now,
+                ..Default::default()
+            },
+            SubscriptionUpdate::CancelUpdate => Self {
+                status: Some(storage_enums::SubscriptionStatus::Cancelled),
+                next_billing_at: Some(None),
+                cancelled_at: now,
+                modified_at: now,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Tracking data of the process tracker task which bills a subscription
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SubscriptionTrackingData {
+    pub merchant_id: String,
+    pub subscription_id: String,
+}
@@ -0,0 +1,243 @@
+//! Entries of the drainer streams which could not be executed on the database are moved to a
+//! dead letter stream of the same shard, along with the error, so that they are not lost when the
+//! drainer stream is trimmed. They can be inspected, replayed or discarded using the `dead-letter`
+//! subcommand of the drainer.
+
+use std::{collections::HashMap, sync::Arc};
+
+use error_stack::IntoReport;
+use redis_interface as redis;
+
+use crate::{
+    errors::{self, DrainerError},
+    execute_stream_entry, logger, metrics,
+    services::Store,
+    utils,
+};
+
+const SOURCE_STREAM_FIELD: &str = "source_stream";
+const SOURCE_ENTRY_ID_FIELD: &str = "source_entry_id";
+const ERROR_FIELD: &str = "error";
+
+#[derive(clap::Subcommand, Debug)]
+pub enum DeadLetterCommand {
+    /// List the entries in the dead letter stream of a shard
+    List {
+        /// The shard of the drainer stream
+        #[arg(long)]
+        shard: u8,
+        /// The maximum number of entries to be listed
+        #[arg(long, default_value_t = 10)]
+        count: u64,
+    },
+    /// Execute the entries in the dead letter stream of a shard again, the entries which are
+    /// executed successfully are removed from the stream
+    Replay {
+        /// The shard of the drainer stream
+        #[arg(long)]
+        shard: u8,
+        /// The entry to be replayed, the first `count` entries are replayed if not provided
+        #[arg(long)]
+        entry_id: Option<String>,
+        /// The maximum number of entries to be replayed
+        #[arg(long, default_value_t = 100)]
+        count: u64,
+    },
+    /// Remove an entry from the dead letter stream of a shard without executing it
+    Discard {
+        /// The shard of the drainer stream
+        #[arg(long)]
+        shard: u8,
+        /// The entry to be discarded
+        #[arg(long)]
+        entry_id: String,
+    },
+}
+
+pub async fn run_command(
+    store: Arc<Store>,
+    command: DeadLetterCommand,
+) -> errors::DrainerResult<()> {
+    match command {
+        DeadLetterCommand::List { shard, count } => {
+            let stream_name = utils::get_dead_letter_stream_name(store.clone(), shard);
+            for (entry_id, entry) in read_entries(&store, &stream_name, count).await? {
+                let entry = serde_json::json!({ "id": entry_id, "fields": entry });
+                println!("{entry}");
+            }
+            Ok(())
+        }
+        DeadLetterCommand::Replay {
+            shard,
+            entry_id,
+            count,
+        } => {
+            let stream_name = utils::get_dead_letter_stream_name(store.clone(), shard);
+            let entries = match entry_id {
+                Some(entry_id) => read_entry(&store, &stream_name, &entry_id).await?,
+                None => read_entries(&store, &stream_name, count).await?,
+            };
+            let mut replayed_entries = 0;
+            for (id, entry) in entries.iter() {
+                match execute_stream_entry(&store, entry).await {
+                    Ok(()) => {
+                        delete_entry(&store, &stream_name, id).await?;
+                        metrics::DEAD_LETTER_ENTRIES_REPLAYED.add(
+                            &metrics::CONTEXT,
+                            1,
+                            &[metrics::KeyValue::new("stream", stream_name.clone())],
+                        );
+                        replayed_entries += 1;
+                    }
+                    Err(error) => logger::error!(entry_id = %id, ?error, "Failed to replay entry"),
+                }
+            }
+            logger::info!(%replayed_entries, stream = %stream_name, "Replayed dead letter entries");
+            record_dead_letter_stream_length(&store, &stream_name).await;
+            Ok(())
+        }
+        DeadLetterCommand::Discard { shard, entry_id } => {
+            let stream_name = utils::get_dead_letter_stream_name(store.clone(), shard);
+            delete_entry(&store, &stream_name, &entry_id).await?;
+            metrics::DEAD_LETTER_ENTRIES_DISCARDED.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::KeyValue::new("stream", stream_name.clone())],
+            );
+            logger::info!(%entry_id, stream = %stream_name, "Discarded dead letter entry");
+            record_dead_letter_stream_length(&store, &stream_name).await;
+            Ok(())
+        }
+    }
+}
+
+/// Appends a failed entry of a drainer stream to its dead letter stream. Failures are only logged
+/// along with the entry, as the drainer stream is trimmed irrespective of the outcome.
+pub(crate) async fn move_to_dead_letter_stream(
+    store: &Store,
+    stream_name: &str,
+    dead_letter_stream_name: &str,
+    entry_id: &str,
+    entry: &HashMap<String, String>,
+    error: &error_stack::Report<DrainerError>,
+) {
+    let mut fields: Vec<(String, String)> = entry
+        .iter()
+        .map(|(field, value)| (field.clone(), value.clone()))
+        .collect();
+    fields.push((SOURCE_STREAM_FIELD.to_string(), stream_name.to_string()));
+    fields.push((SOURCE_ENTRY_ID_FIELD.to_string(), entry_id.to_string()));
+    fields.push((ERROR_FIELD.to_string(), format!("{error:?}")));
+
+    match store
+        .redis_conn
+        .stream_append_entry(
+            dead_letter_stream_name,
+            &redis::RedisEntryId::AutoGeneratedID,
+            fields,
+        )
+        .await
+    {
+        Ok(()) => {
+            logger::warn!(%entry_id, stream = %stream_name, ?error, "Moved entry to dead letter stream");
+            metrics::ENTRIES_MOVED_TO_DEAD_LETTER_STREAM.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::KeyValue::new("stream", stream_name.to_owned())],
+            );
+        }
+        Err(redis_error) => {
+            logger::error!(
+                %entry_id,
+                stream = %stream_name,
+                ?entry,
+                ?error,
+                ?redis_error,
+                "Failed to move entry to dead letter stream"
+            );
+        }
+    }
+}
+
+pub(crate) async fn record_dead_letter_stream_length(store: &Store, dead_letter_stream_name: &str) {
+    match store
+        .redis_conn
+        .stream_get_length(dead_letter_stream_name)
+        .await
+    {
+        Ok(length) => metrics::DEAD_LETTER_STREAM_LENGTH.record(
+            &metrics::CONTEXT,
+            f64::from(u32::try_from(length).unwrap_or(u32::MAX)),
+            &[metrics::KeyValue::new(
+                "stream",
+                dead_letter_stream_name.to_owned(),
+            )],
+        ),
+        Err(error) => logger::error!(?error, "Failed to get dead letter stream length"),
+    }
+}
+
+async fn read_entries(
+    store: &Store,
+    stream_name: &str,
+    count: u64,
+) -> errors::DrainerResult<utils::StreamEntries> {
+    match utils::read_from_stream(stream_name, count, store.redis_conn.as_ref()).await {
+        Ok(mut stream_read) => Ok(stream_read.remove(stream_name).unwrap_or_default()),
+        Err(error) => match error.current_context() {
+            DrainerError::RedisError(redis_error)
+                if matches!(
+                    redis_error.current_context(),
+                    redis::errors::RedisError::StreamEmptyOrNotAvailable
+                ) =>
+            {
+                Ok(Vec::new())
+            }
+            _ => Err(error),
+        },
+    }
+}
+
+async fn read_entry(
+    store: &Store,
+    stream_name: &str,
+    entry_id: &str,
+) -> errors::DrainerResult<utils::StreamEntries> {
+    let entries = store
+        .redis_conn
+        .stream_read_entries_in_range(stream_name, entry_id, entry_id, Some(1))
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?;
+
+    if entries.is_empty() {
+        return Err(DrainerError::UnexpectedError(format!(
+            "Entry {entry_id} not found in stream {stream_name}"
+        )))
+        .into_report();
+    }
+
+    Ok(entries)
+}
+
+async fn delete_entry(
+    store: &Store,
+    stream_name: &str,
+    entry_id: &str,
+) -> errors::DrainerResult<()> {
+    let deleted_entries = store
+        .redis_conn
+        .stream_delete_entries(stream_name, entry_id)
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?;
+
+    if deleted_entries == 0 {
+        return Err(DrainerError::UnexpectedError(format!(
+            "Entry {entry_id} not found in stream {stream_name}"
+        )))
+        .into_report();
+    }
+
+    Ok(())
+}
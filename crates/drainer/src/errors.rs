@@ -11,6 +11,10 @@ pub enum DrainerError {
     ConfigurationError(config::ConfigError),
     #[error("Error while configuring signals: {0}")]
     SignalError(String),
+    #[error("Error while deserializing stream entry: {0}")]
+    DeserializationError(serde_json::Error),
+    #[error("Error during database operation : {0:?}")]
+    DatabaseError(error_stack::Report<diesel_models::errors::DatabaseError>),
     #[error("Unexpected error occurred: {0}")]
     UnexpectedError(String),
 }
@@ -28,3 +32,9 @@ impl From<error_stack::Report<redis::errors::RedisError>> for DrainerError {
         Self::RedisError(err)
     }
 }
+
+impl From<error_stack::Report<diesel_models::errors::DatabaseError>> for DrainerError {
+    fn from(err: error_stack::Report<diesel_models::errors::DatabaseError>) -> Self {
+        Self::DatabaseError(err)
+    }
+}
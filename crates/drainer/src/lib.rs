@@ -1,11 +1,15 @@
 mod connection;
+pub mod dead_letter;
 pub mod errors;
 pub mod logger;
 pub(crate) mod metrics;
 pub mod services;
 pub mod settings;
 mod utils;
-use std::sync::{atomic, Arc};
+use std::{
+    collections::HashMap,
+    sync::{atomic, Arc},
+};
 
 use common_utils::signals::get_allowed_signals;
 use diesel_models::kv;
@@ -122,8 +126,14 @@ async fn drainer_handler(
     active_tasks.fetch_add(1, atomic::Ordering::Release);
 
     let stream_name = utils::get_drainer_stream_name(store.clone(), stream_index);
-    let drainer_result =
-        Box::pin(drainer(store.clone(), max_read_count, stream_name.as_str())).await;
+    let dead_letter_stream_name = utils::get_dead_letter_stream_name(store.clone(), stream_index);
+    let drainer_result = Box::pin(drainer(
+        store.clone(),
+        max_read_count,
+        stream_name.as_str(),
+        dead_letter_stream_name.as_str(),
+    ))
+    .await;
 
     if let Err(error) = drainer_result {
         logger::error!(?error)
@@ -141,6 +151,7 @@ async fn drainer(
     store: Arc<Store>,
     max_read_count: u64,
     stream_name: &str,
+    dead_letter_stream_name: &str,
 ) -> errors::DrainerResult<()> {
     let stream_read =
         match utils::read_from_stream(stream_name, max_read_count, store.redis_conn.as_ref()).await
@@ -174,126 +185,18 @@ async fn drainer(
         }],
     );
 
-    // TODO: Handle errors when deserialization fails and when DB error occurs
-    for entry in entries {
-        let typed_sql = entry.1.get("typed_sql").map_or(String::new(), Clone::clone);
-        let result = serde_json::from_str::<kv::DBOperation>(&typed_sql);
-        let db_op = match result {
-            Ok(f) => f,
-            Err(_err) => continue, // TODO: handle error
-        };
-
-        let conn = pg_connection(&store.master_pool).await;
-        let insert_op = "insert";
-        let update_op = "update";
-        let payment_intent = "payment_intent";
-        let payment_attempt = "payment_attempt";
-        let refund = "refund";
-        let reverse_lookup = "reverse_lookup";
-        let connector_response = "connector_response";
-        let address = "address";
-        match db_op {
-            // TODO: Handle errors
-            kv::DBOperation::Insert { insertable } => {
-                let (_, execution_time) = common_utils::date_time::time_it(|| async {
-                    match insertable {
-                        kv::Insertable::PaymentIntent(a) => {
-                            macro_util::handle_resp!(
-                                a.insert(&conn).await,
-                                insert_op,
-                                payment_intent
-                            )
-                        }
-                        kv::Insertable::PaymentAttempt(a) => {
-                            macro_util::handle_resp!(
-                                a.insert(&conn).await,
-                                insert_op,
-                                payment_attempt
-                            )
-                        }
-                        kv::Insertable::Refund(a) => {
-                            macro_util::handle_resp!(a.insert(&conn).await, insert_op, refund)
-                        }
-                        kv::Insertable::ConnectorResponse(a) => {
-                            macro_util::handle_resp!(
-                                a.insert(&conn).await,
-                                insert_op,
-                                connector_response
-                            )
-                        }
-                        kv::Insertable::Address(addr) => {
-                            macro_util::handle_resp!(addr.insert(&conn).await, insert_op, address)
-                        }
-                        kv::Insertable::ReverseLookUp(rev) => {
-                            macro_util::handle_resp!(
-                                rev.insert(&conn).await,
-                                insert_op,
-                                reverse_lookup
-                            )
-                        }
-                    }
-                })
-                .await;
-                metrics::QUERY_EXECUTION_TIME.record(
-                    &metrics::CONTEXT,
-                    execution_time,
-                    &[metrics::KeyValue {
-                        key: "operation".into(),
-                        value: insert_op.into(),
-                    }],
-                );
-            }
-            kv::DBOperation::Update { updatable } => {
-                let (_, execution_time) = common_utils::date_time::time_it(|| async {
-                    match updatable {
-                        kv::Updateable::PaymentIntentUpdate(a) => {
-                            macro_util::handle_resp!(
-                                a.orig.update(&conn, a.update_data).await,
-                                update_op,
-                                payment_intent
-                            )
-                        }
-                        kv::Updateable::PaymentAttemptUpdate(a) => {
-                            macro_util::handle_resp!(
-                                a.orig.update_with_attempt_id(&conn, a.update_data).await,
-                                update_op,
-                                payment_attempt
-                            )
-                        }
-                        kv::Updateable::RefundUpdate(a) => {
-                            macro_util::handle_resp!(
-                                a.orig.update(&conn, a.update_data).await,
-                                update_op,
-                                refund
-                            )
-                        }
-                        kv::Updateable::ConnectorResponseUpdate(a) => macro_util::handle_resp!(
-                            a.orig.update(&conn, a.update_data).await,
-                            update_op,
-                            connector_response
-                        ),
-                        kv::Updateable::AddressUpdate(a) => macro_util::handle_resp!(
-                            a.orig.update(&conn, a.update_data).await,
-                            update_op,
-                            address
-                        ),
-                    }
-                })
-                .await;
-                metrics::QUERY_EXECUTION_TIME.record(
-                    &metrics::CONTEXT,
-                    execution_time,
-                    &[metrics::KeyValue {
-                        key: "operation".into(),
-                        value: update_op.into(),
-                    }],
-                );
-            }
-            kv::DBOperation::Delete => {
-                // [#224]: Implement this
-                logger::error!("Not implemented!");
-            }
-        };
+    for (entry_id, entry) in entries {
+        if let Err(error) = execute_stream_entry(&store, entry).await {
+            dead_letter::move_to_dead_letter_stream(
+                &store,
+                stream_name,
+                dead_letter_stream_name,
+                entry_id,
+                entry,
+                &error,
+            )
+            .await;
+        }
     }
 
     let entries_trimmed =
@@ -308,9 +211,201 @@ async fn drainer(
         );
     }
 
+    dead_letter::record_dead_letter_stream_length(&store, dead_letter_stream_name).await;
+
     Ok(())
 }
 
+/// Deserializes the query of a stream entry and executes it on the database
+pub(crate) async fn execute_stream_entry(
+    store: &Store,
+    entry: &HashMap<String, String>,
+) -> errors::DrainerResult<()> {
+    let typed_sql = entry.get("typed_sql").map_or(String::new(), Clone::clone);
+    let db_op = serde_json::from_str::<kv::DBOperation>(&typed_sql)
+        .map_err(errors::DrainerError::DeserializationError)
+        .into_report()?;
+
+    let conn = pg_connection(&store.master_pool).await;
+    let insert_op = "insert";
+    let update_op = "update";
+    let payment_intent = "payment_intent";
+    let payment_attempt = "payment_attempt";
+    let refund = "refund";
+    let reverse_lookup = "reverse_lookup";
+    let connector_response = "connector_response";
+    let address = "address";
+    let capture = "capture";
+    let dispute = "dispute";
+    let payouts = "payouts";
+    let payout_attempt = "payout_attempt";
+    let mandate = "mandate";
+    let event = "event";
+    match db_op {
+        kv::DBOperation::Insert { insertable } => {
+            let (result, execution_time) = common_utils::date_time::time_it(|| async {
+                match insertable {
+                    kv::Insertable::PaymentIntent(a) => {
+                        macro_util::handle_resp!(a.insert(&conn).await, insert_op, payment_intent)
+                    }
+                    kv::Insertable::PaymentAttempt(a) => {
+                        macro_util::handle_resp!(a.insert(&conn).await, insert_op, payment_attempt)
+                    }
+                    kv::Insertable::Refund(a) => {
+                        macro_util::handle_resp!(a.insert(&conn).await, insert_op, refund)
+                    }
+                    kv::Insertable::ConnectorResponse(a) => {
+                        macro_util::handle_resp!(
+                            a.insert(&conn).await,
+                            insert_op,
+                            connector_response
+                        )
+                    }
+                    kv::Insertable::Address(addr) => {
+                        macro_util::handle_resp!(addr.insert(&conn).await, insert_op, address)
+                    }
+                    kv::Insertable::ReverseLookUp(rev) => {
+                        macro_util::handle_resp!(rev.insert(&conn).await, insert_op, reverse_lookup)
+                    }
+                    kv::Insertable::Capture(a) => {
+                        macro_util::handle_resp!(a.insert(&conn).await, insert_op, capture)
+                    }
+                    kv::Insertable::Dispute(a) => {
+                        macro_util::handle_resp!(a.insert(&conn).await, insert_op, dispute)
+                    }
+                    kv::Insertable::Payouts(a) => {
+                        macro_util::handle_resp!(a.insert(&conn).await, insert_op, payouts)
+                    }
+                    kv::Insertable::PayoutAttempt(a) => {
+                        macro_util::handle_resp!(a.insert(&conn).await, insert_op, payout_attempt)
+                    }
+                    kv::Insertable::Mandate(a) => {
+                        macro_util::handle_resp!(a.insert(&conn).await, insert_op, mandate)
+                    }
+                    kv::Insertable::Event(a) => {
+                        macro_util::handle_resp!(a.insert(&conn).await, insert_op, event)
+                    }
+                }
+            })
+            .await;
+            metrics::QUERY_EXECUTION_TIME.record(
+                &metrics::CONTEXT,
+                execution_time,
+                &[metrics::KeyValue {
+                    key: "operation".into(),
+                    value: insert_op.into(),
+                }],
+            );
+            result
+        }
+        kv::DBOperation::Update { updatable } => {
+            let (result, execution_time) = common_utils::date_time::time_it(|| async {
+                match updatable {
+                    kv::Updateable::PaymentIntentUpdate(a) => {
+                        macro_util::handle_resp!(
+                            a.orig.update(&conn, a.update_data).await,
+                            update_op,
+                            payment_intent
+                        )
+                    }
+                    kv::Updateable::PaymentAttemptUpdate(a) => {
+                        macro_util::handle_resp!(
+                            a.orig.update_with_attempt_id(&conn, a.update_data).await,
+                            update_op,
+                            payment_attempt
+                        )
+                    }
+                    kv::Updateable::RefundUpdate(a) => {
+                        macro_util::handle_resp!(
+                            a.orig.update(&conn, a.update_data).await,
+                            update_op,
+                            refund
+                        )
+                    }
+                    kv::Updateable::ConnectorResponseUpdate(a) => macro_util::handle_resp!(
+                        a.orig.update(&conn, a.update_data).await,
+                        update_op,
+                        connector_response
+                    ),
+                    kv::Updateable::AddressUpdate(a) => macro_util::handle_resp!(
+                        a.orig.update(&conn, a.update_data).await,
+                        update_op,
+                        address
+                    ),
+                    kv::Updateable::CaptureUpdate(a) => macro_util::handle_resp!(
+                        a.orig.update_with_capture_id(&conn, a.update_data).await,
+                        update_op,
+                        capture
+                    ),
+                    kv::Updateable::DisputeUpdate(a) => macro_util::handle_resp!(
+                        a.orig.update(&conn, a.update_data).await,
+                        update_op,
+                        dispute
+                    ),
+                    kv::Updateable::PayoutsUpdate(a) => macro_util::handle_resp!(
+                        diesel_models::payouts::Payouts::update_by_merchant_id_payout_id(
+                            &conn,
+                            &a.orig.merchant_id,
+                            &a.orig.payout_id,
+                            a.update_data,
+                        )
+                        .await,
+                        update_op,
+                        payouts
+                    ),
+                    kv::Updateable::PayoutAttemptUpdate(a) => macro_util::handle_resp!(
+                        diesel_models::payout_attempt::PayoutAttempt::update_by_merchant_id_payout_id(
+                            &conn,
+                            &a.orig.merchant_id,
+                            &a.orig.payout_id,
+                            a.update_data,
+                        )
+                        .await,
+                        update_op,
+                        payout_attempt
+                    ),
+                    kv::Updateable::MandateUpdate(a) => macro_util::handle_resp!(
+                        diesel_models::mandate::Mandate::update_by_merchant_id_mandate_id(
+                            &conn,
+                            &a.orig.merchant_id,
+                            &a.orig.mandate_id,
+                            a.update_data,
+                        )
+                        .await,
+                        update_op,
+                        mandate
+                    ),
+                    kv::Updateable::EventUpdate(a) => macro_util::handle_resp!(
+                        diesel_models::events::Event::update(
+                            &conn,
+                            &a.orig.event_id,
+                            a.update_data,
+                        )
+                        .await,
+                        update_op,
+                        event
+                    ),
+                }
+            })
+            .await;
+            metrics::QUERY_EXECUTION_TIME.record(
+                &metrics::CONTEXT,
+                execution_time,
+                &[metrics::KeyValue {
+                    key: "operation".into(),
+                    value: update_op.into(),
+                }],
+            );
+            result
+        }
+        kv::DBOperation::Delete => {
+            // [#224]: Implement this
+            logger::error!("Not implemented!");
+            Ok(())
+        }
+    }
+}
+
 mod macro_util {
 
     macro_rules! handle_resp {
@@ -324,6 +419,7 @@ mod macro_util {
                             value: $table.into(),
                         }
                     ]);
+                    Ok(())
                 }
                 Err(err) => {
                     logger::error!(operation = %$op_type, table = %$table, ?err);
@@ -333,6 +429,7 @@ mod macro_util {
                             value: $table.into(),
                         }
                     ]);
+                    Err(error_stack::report!(errors::DrainerError::from(err)))
                 }
             }
         };
@@ -13,8 +13,12 @@ counter_metric!(SHUTDOWN_SIGNAL_RECEIVED, DRAINER_METER);
 counter_metric!(SUCCESSFUL_SHUTDOWN, DRAINER_METER);
 counter_metric!(STREAM_EMPTY, DRAINER_METER);
 counter_metric!(DRAINER_HEALTH, DRAINER_METER);
+counter_metric!(ENTRIES_MOVED_TO_DEAD_LETTER_STREAM, DRAINER_METER);
+counter_metric!(DEAD_LETTER_ENTRIES_REPLAYED, DRAINER_METER);
+counter_metric!(DEAD_LETTER_ENTRIES_DISCARDED, DRAINER_METER);
 
 histogram_metric!(QUERY_EXECUTION_TIME, DRAINER_METER); // Time in (ms) milliseconds
 histogram_metric!(REDIS_STREAM_READ_TIME, DRAINER_METER); // Time in (ms) milliseconds
 histogram_metric!(REDIS_STREAM_TRIM_TIME, DRAINER_METER); // Time in (ms) milliseconds
 histogram_metric!(CLEANUP_TIME, DRAINER_METER); // Time in (ms) milliseconds
+histogram_metric!(DEAD_LETTER_STREAM_LENGTH, DRAINER_METER); // Number of entries in the stream
@@ -37,4 +37,9 @@ impl Store {
         // Example: {shard_5}_drainer_stream
         format!("{{{}}}_{}", shard_key, self.config.drainer_stream_name,)
     }
+
+    pub fn drainer_dead_letter_stream(&self, shard_key: &str) -> String {
+        // Example: {shard_5}_drainer_stream_dead_letter
+        format!("{}_dead_letter", self.drainer_stream(shard_key))
+    }
 }
@@ -23,6 +23,16 @@ pub struct CmdLineConf {
     /// Application will look for "config/config.toml" if this option isn't specified.
     #[arg(short = 'f', long, value_name = "FILE")]
     pub config_path: Option<PathBuf>,
+
+    #[command(subcommand)]
+    pub subcommand: Option<Subcommand>,
+}
+
+#[derive(clap::Subcommand, Debug)]
+pub enum Subcommand {
+    /// Inspect, replay or discard the entries which the drainer failed to execute
+    #[command(subcommand)]
+    DeadLetter(crate::dead_letter::DeadLetterCommand),
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
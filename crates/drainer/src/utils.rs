@@ -150,3 +150,7 @@ pub(crate) fn get_stream_key_flag(store: Arc<services::Store>, stream_index: u8)
 pub(crate) fn get_drainer_stream_name(store: Arc<services::Store>, stream_index: u8) -> String {
     store.drainer_stream(format!("shard_{stream_index}").as_str())
 }
+
+pub(crate) fn get_dead_letter_stream_name(store: Arc<services::Store>, stream_index: u8) -> String {
+    store.drainer_dead_letter_stream(format!("shard_{stream_index}").as_str())
+}
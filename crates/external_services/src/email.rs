@@ -1,4 +1,6 @@
-//! Interactions with the AWS SES SDK
+//! Interactions with the services used to send emails
+
+use std::sync::Arc;
 
 use aws_config::meta::region::RegionProviderChain;
 use aws_sdk_sesv2::{
@@ -12,6 +14,11 @@ use error_stack::{IntoReport, ResultExt};
 use masking::PeekInterface;
 use serde::Deserialize;
 
+/// Writing emails to a local directory.
+pub mod file;
+/// Sending emails through an SMTP server.
+pub mod smtp;
+
 /// Custom Result type alias for Email operations.
 pub type EmailResult<T> = CustomResult<T, EmailError>;
 
@@ -29,6 +36,19 @@ pub trait EmailClient: Sync + Send + dyn_clone::DynClone {
 
 dyn_clone::clone_trait_object!(EmailClient);
 
+/// The service used to send emails.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum EmailClientKind {
+    /// AWS SES
+    #[default]
+    AwsSes,
+    /// An SMTP server
+    Smtp,
+    /// A local directory to which the emails are written
+    File,
+}
+
 /// Struct that contains the settings required to construct an EmailClient.
 #[derive(Debug, Clone, Default, Deserialize)]
 pub struct EmailSettings {
@@ -40,6 +60,44 @@ pub struct EmailSettings {
 
     /// Base-url used when adding links that should redirect to self
     pub base_url: String,
+
+    /// The service used to send emails.
+    #[serde(default)]
+    pub active_email_client: EmailClientKind,
+
+    /// Configuration of the SMTP server, used by the `smtp` email client.
+    #[serde(default)]
+    pub smtp: smtp::SmtpServerConfig,
+
+    /// Configuration of the `file` email client.
+    #[serde(default)]
+    pub file: file::FileEmailConfig,
+}
+
+impl EmailSettings {
+    /// Verifies that the configuration of the active email client is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        match self.active_email_client {
+            EmailClientKind::AwsSes => Ok(()),
+            EmailClientKind::Smtp => self.smtp.validate(),
+            EmailClientKind::File => self.file.validate(),
+        }
+    }
+}
+
+/// Constructs the email client chosen in the settings.
+pub async fn create_email_client(conf: &EmailSettings) -> EmailResult<Arc<dyn EmailClient>> {
+    Ok(match conf.active_email_client {
+        EmailClientKind::AwsSes => Arc::new(AwsSes::new(conf).await),
+        EmailClientKind::Smtp => Arc::new(smtp::SmtpEmailClient::new(
+            &conf.smtp,
+            conf.from_email.clone(),
+        )?),
+        EmailClientKind::File => Arc::new(file::FileEmailClient::new(
+            &conf.file,
+            conf.from_email.clone(),
+        )?),
+    })
 }
 
 /// Client for AWS SES operation
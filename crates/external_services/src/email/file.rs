@@ -0,0 +1,72 @@
+//! Writing emails to a local directory, meant for development and tests
+
+use common_utils::pii;
+use error_stack::{IntoReport, ResultExt};
+use lettre::{AsyncFileTransport, AsyncTransport, Tokio1Executor};
+use serde::Deserialize;
+
+use super::{smtp::build_message, EmailClient, EmailError, EmailResult};
+
+/// Configuration parameters required for constructing a [`FileEmailClient`].
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct FileEmailConfig {
+    /// The directory to which the emails are written.
+    pub directory: String,
+}
+
+impl FileEmailConfig {
+    /// Verifies that the [`FileEmailClient`] configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        when(self.directory.is_default_or_empty(), || {
+            Err("Email directory must not be empty")
+        })
+    }
+}
+
+/// Client which writes every email to a file (named `<uuid>.eml`) in a local directory instead
+/// of sending it
+#[derive(Debug, Clone)]
+pub struct FileEmailClient {
+    transport: AsyncFileTransport<Tokio1Executor>,
+    from_email: String,
+}
+
+impl FileEmailClient {
+    /// Constructs a new file email client, creating the directory if it does not exist
+    pub fn new(conf: &FileEmailConfig, from_email: String) -> EmailResult<Self> {
+        std::fs::create_dir_all(&conf.directory)
+            .into_report()
+            .change_context(EmailError::ClientBuildingFailure)
+            .attach_printable_lazy(|| {
+                format!("Failed to create the email directory: {}", conf.directory)
+            })?;
+
+        Ok(Self {
+            transport: AsyncFileTransport::new(&conf.directory),
+            from_email,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailClient for FileEmailClient {
+    async fn send_email(
+        &self,
+        recipient: pii::Email,
+        subject: String,
+        body: String,
+    ) -> EmailResult<()> {
+        let message = build_message(&self.from_email, &recipient, subject, body)?;
+
+        self.transport
+            .send(message)
+            .await
+            .into_report()
+            .change_context(EmailError::EmailSendingFailure)?;
+
+        Ok(())
+    }
+}
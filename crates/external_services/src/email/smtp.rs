@@ -0,0 +1,158 @@
+//! Sending emails through an SMTP server
+
+use common_utils::pii;
+use error_stack::{IntoReport, ResultExt};
+use lettre::{
+    message::{header::ContentType, Mailbox},
+    transport::smtp::authentication::Credentials,
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+use masking::{PeekInterface, Secret};
+use serde::Deserialize;
+
+use super::{EmailClient, EmailError, EmailResult};
+
+/// The security of the connection to the SMTP server.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SmtpConnection {
+    /// The connection is upgraded to TLS using STARTTLS, and fails if the server does not
+    /// support it
+    #[default]
+    StartTls,
+    /// The connection is not encrypted, only meant for local mail servers used in development
+    Plaintext,
+}
+
+/// Configuration parameters required for constructing an [`SmtpEmailClient`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SmtpServerConfig {
+    /// The hostname of the SMTP server.
+    pub host: String,
+
+    /// The port of the SMTP server.
+    pub port: u16,
+
+    /// The security of the connection to the SMTP server.
+    pub connection: SmtpConnection,
+
+    /// The username used to authenticate with the SMTP server, no authentication is performed
+    /// if it is empty.
+    pub username: String,
+
+    /// The password used to authenticate with the SMTP server.
+    pub password: Secret<String>,
+}
+
+impl Default for SmtpServerConfig {
+    fn default() -> Self {
+        Self {
+            host: String::new(),
+            port: 587,
+            connection: SmtpConnection::default(),
+            username: String::new(),
+            password: Secret::default(),
+        }
+    }
+}
+
+impl SmtpServerConfig {
+    /// Verifies that the [`SmtpEmailClient`] configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        when(self.host.is_default_or_empty(), || {
+            Err("SMTP server host must not be empty")
+        })
+    }
+}
+
+/// Client which sends emails through an SMTP server
+#[derive(Debug, Clone)]
+pub struct SmtpEmailClient {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from_email: String,
+}
+
+impl SmtpEmailClient {
+    /// Constructs a new SMTP email client
+    pub fn new(conf: &SmtpServerConfig, from_email: String) -> EmailResult<Self> {
+        let builder = match conf.connection {
+            SmtpConnection::StartTls => {
+                AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&conf.host)
+                    .into_report()
+                    .change_context(EmailError::ClientBuildingFailure)
+                    .attach_printable("Failed to set up the TLS parameters of the SMTP server")?
+            }
+            SmtpConnection::Plaintext => {
+                AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&conf.host)
+            }
+        }
+        .port(conf.port);
+
+        let builder = if conf.username.is_empty() {
+            builder
+        } else {
+            builder.credentials(Credentials::new(
+                conf.username.clone(),
+                conf.password.peek().clone(),
+            ))
+        };
+
+        Ok(Self {
+            transport: builder.build(),
+            from_email,
+        })
+    }
+}
+
+/// Builds a plain text email message
+pub(super) fn build_message(
+    from_email: &str,
+    recipient: &pii::Email,
+    subject: String,
+    body: String,
+) -> EmailResult<Message> {
+    let from = from_email
+        .parse::<Mailbox>()
+        .into_report()
+        .change_context(EmailError::EmailSendingFailure)
+        .attach_printable("Failed to parse the sender email address")?;
+    let to = recipient
+        .peek()
+        .parse::<Mailbox>()
+        .into_report()
+        .change_context(EmailError::EmailSendingFailure)
+        .attach_printable("Failed to parse the recipient email address")?;
+
+    Message::builder()
+        .from(from)
+        .to(to)
+        .subject(subject)
+        .header(ContentType::TEXT_PLAIN)
+        .body(body)
+        .into_report()
+        .change_context(EmailError::EmailSendingFailure)
+        .attach_printable("Failed to build the email message")
+}
+
+#[async_trait::async_trait]
+impl EmailClient for SmtpEmailClient {
+    async fn send_email(
+        &self,
+        recipient: pii::Email,
+        subject: String,
+        body: String,
+    ) -> EmailResult<()> {
+        let message = build_message(&self.from_email, &recipient, subject, body)?;
+
+        self.transport
+            .send(message)
+            .await
+            .into_report()
+            .change_context(EmailError::EmailSendingFailure)?;
+
+        Ok(())
+    }
+}
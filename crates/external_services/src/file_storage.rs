@@ -0,0 +1,98 @@
+//! Interactions with the storage used for the files uploaded to the router
+
+use std::sync::Arc;
+
+use common_utils::errors::CustomResult;
+use serde::Deserialize;
+
+#[cfg(feature = "aws_s3")]
+pub mod aws_s3;
+pub mod file_system;
+
+/// Storage to which the files uploaded to the router are written, and from which they are read
+#[async_trait::async_trait]
+pub trait FileStorageInterface: Sync + Send {
+    /// Writes the file under the provided key, replacing any file already stored under it
+    async fn upload_file(
+        &self,
+        file_key: &str,
+        file: Vec<u8>,
+    ) -> CustomResult<(), FileStorageError>;
+
+    /// Deletes the file stored under the provided key
+    async fn delete_file(&self, file_key: &str) -> CustomResult<(), FileStorageError>;
+
+    /// Reads the file stored under the provided key
+    async fn retrieve_file(&self, file_key: &str) -> CustomResult<Vec<u8>, FileStorageError>;
+}
+
+/// The file storage backends supported by the router.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FileStorageBackend {
+    /// Files are stored in a directory of the local file system
+    #[default]
+    FileSystem,
+    /// Files are stored in an AWS S3 bucket, or a bucket of an S3-compatible service such as MinIO
+    #[cfg(feature = "aws_s3")]
+    AwsS3,
+}
+
+/// Configuration of the storage used for the files uploaded to the router.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct FileStorageConfig {
+    /// The backend in which the files are stored.
+    pub backend: FileStorageBackend,
+
+    /// Configuration of the local file system backend.
+    pub file_system: file_system::FileSystemConfig,
+
+    /// Configuration of the S3 backend.
+    #[cfg(feature = "aws_s3")]
+    pub aws_s3: aws_s3::AwsS3Config,
+}
+
+impl FileStorageConfig {
+    /// Verifies that the configuration of the selected backend is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        match self.backend {
+            FileStorageBackend::FileSystem => self.file_system.validate(),
+            #[cfg(feature = "aws_s3")]
+            FileStorageBackend::AwsS3 => self.aws_s3.validate(),
+        }
+    }
+}
+
+/// Constructs the client of the file storage backend selected in the configuration
+pub async fn create_file_storage_client(conf: &FileStorageConfig) -> Arc<dyn FileStorageInterface> {
+    match conf.backend {
+        FileStorageBackend::FileSystem => {
+            Arc::new(file_system::FileSystemStorage::new(&conf.file_system))
+        }
+        #[cfg(feature = "aws_s3")]
+        FileStorageBackend::AwsS3 => {
+            Arc::new(aws_s3::AwsFileStorageClient::new(&conf.aws_s3).await)
+        }
+    }
+}
+
+/// Errors that could occur during file storage operations.
+#[derive(Debug, thiserror::Error)]
+pub enum FileStorageError {
+    /// The file key cannot be used to store a file.
+    #[error("Invalid file key")]
+    InvalidFileKey,
+
+    /// An error occurred when writing the file.
+    #[error("Failed to upload the file")]
+    UploadFailed,
+
+    /// An error occurred when deleting the file.
+    #[error("Failed to delete the file")]
+    DeleteFailed,
+
+    /// An error occurred when reading the file.
+    #[error("Failed to retrieve the file")]
+    RetrieveFailed,
+}
@@ -0,0 +1,147 @@
+//! Storing files in AWS S3, or in an S3-compatible service such as MinIO
+
+use aws_config::meta::region::RegionProviderChain;
+use aws_sdk_s3::{config::Region, Client};
+use common_utils::errors::CustomResult;
+use error_stack::{IntoReport, ResultExt};
+use router_env::logger;
+use serde::Deserialize;
+
+use super::{FileStorageError, FileStorageInterface};
+
+/// Configuration parameters required for constructing an [`AwsFileStorageClient`].
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct AwsS3Config {
+    /// The AWS region of the bucket.
+    pub region: String,
+
+    /// The name of the bucket in which the files are stored.
+    pub bucket_name: String,
+
+    /// The endpoint of an S3-compatible service, such as MinIO, used instead of AWS S3.
+    pub endpoint_url: Option<String>,
+
+    /// Whether the bucket is addressed in the path of the requests instead of the hostname, which
+    /// is usually required by S3-compatible services.
+    pub force_path_style: bool,
+}
+
+impl AwsS3Config {
+    /// Verifies that the [`AwsFileStorageClient`] configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        when(self.region.is_default_or_empty(), || {
+            Err("S3 region must not be empty")
+        })?;
+
+        when(self.bucket_name.is_default_or_empty(), || {
+            Err("S3 bucket name must not be empty")
+        })?;
+
+        when(
+            self.endpoint_url
+                .as_ref()
+                .map_or(false, |endpoint_url| endpoint_url.is_default_or_empty()),
+            || Err("S3 endpoint URL must not be empty"),
+        )
+    }
+}
+
+/// Client which stores files in an S3 bucket. We assume that the SDK has the credentials required
+/// to access the bucket either set in environment variables (`AWS_ACCESS_KEY_ID` and
+/// `AWS_SECRET_ACCESS_KEY`), or that it is running in a machine that is able to assume an IAM
+/// role.
+#[derive(Debug, Clone)]
+pub struct AwsFileStorageClient {
+    inner_client: Client,
+    bucket_name: String,
+}
+
+impl AwsFileStorageClient {
+    /// Constructs a new S3 file storage client
+    pub async fn new(conf: &AwsS3Config) -> Self {
+        let region_provider = RegionProviderChain::first_try(Region::new(conf.region.clone()));
+        let sdk_config = aws_config::from_env().region(region_provider).load().await;
+
+        let mut s3_config =
+            aws_sdk_s3::config::Builder::from(&sdk_config).force_path_style(conf.force_path_style);
+        if let Some(endpoint_url) = &conf.endpoint_url {
+            s3_config = s3_config.endpoint_url(endpoint_url);
+        }
+
+        Self {
+            inner_client: Client::from_conf(s3_config.build()),
+            bucket_name: conf.bucket_name.clone(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl FileStorageInterface for AwsFileStorageClient {
+    async fn upload_file(
+        &self,
+        file_key: &str,
+        file: Vec<u8>,
+    ) -> CustomResult<(), FileStorageError> {
+        self.inner_client
+            .put_object()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .body(file.into())
+            .send()
+            .await
+            .map_err(|error| {
+                logger::error!(s3_sdk_error=?error, "Failed to upload file to S3");
+                error
+            })
+            .into_report()
+            .change_context(FileStorageError::UploadFailed)?;
+
+        Ok(())
+    }
+
+    async fn delete_file(&self, file_key: &str) -> CustomResult<(), FileStorageError> {
+        self.inner_client
+            .delete_object()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .send()
+            .await
+            .map_err(|error| {
+                logger::error!(s3_sdk_error=?error, "Failed to delete file from S3");
+                error
+            })
+            .into_report()
+            .change_context(FileStorageError::DeleteFailed)?;
+
+        Ok(())
+    }
+
+    async fn retrieve_file(&self, file_key: &str) -> CustomResult<Vec<u8>, FileStorageError> {
+        let object = self
+            .inner_client
+            .get_object()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .send()
+            .await
+            .map_err(|error| {
+                logger::error!(s3_sdk_error=?error, "Failed to retrieve file from S3");
+                error
+            })
+            .into_report()
+            .change_context(FileStorageError::RetrieveFailed)?;
+
+        let file = object
+            .body
+            .collect()
+            .await
+            .into_report()
+            .change_context(FileStorageError::RetrieveFailed)
+            .attach_printable("Invalid file data received from S3")?;
+
+        Ok(file.into_bytes().to_vec())
+    }
+}
@@ -0,0 +1,165 @@
+//! Storing files in a directory of the local file system
+
+use std::{
+    fs::{self, File},
+    io::{Read, Write},
+    path::{Component, Path, PathBuf},
+};
+
+use common_utils::errors::CustomResult;
+use error_stack::{IntoReport, ResultExt};
+use serde::Deserialize;
+
+use super::{FileStorageError, FileStorageInterface};
+
+/// Configuration parameters required for constructing a [`FileSystemStorage`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct FileSystemConfig {
+    /// The directory in which the files are stored.
+    pub base_path: String,
+}
+
+impl Default for FileSystemConfig {
+    fn default() -> Self {
+        Self {
+            base_path: router_env::env::workspace_path()
+                .join("files")
+                .to_string_lossy()
+                .into_owned(),
+        }
+    }
+}
+
+impl FileSystemConfig {
+    /// Verifies that the [`FileSystemStorage`] configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        when(self.base_path.is_default_or_empty(), || {
+            Err("File storage base path must not be empty")
+        })
+    }
+}
+
+/// File storage which keeps every file under the configured directory, the file key being the
+/// path of the file relative to it
+#[derive(Debug, Clone)]
+pub struct FileSystemStorage {
+    base_path: PathBuf,
+}
+
+impl FileSystemStorage {
+    /// Constructs a new file system storage
+    pub fn new(conf: &FileSystemConfig) -> Self {
+        Self {
+            base_path: PathBuf::from(&conf.base_path),
+        }
+    }
+
+    /// Resolves the path of the file, rejecting keys which would point outside the base directory
+    fn get_file_path(&self, file_key: &str) -> CustomResult<PathBuf, FileStorageError> {
+        let file_key = Path::new(file_key);
+        let is_valid_key = file_key.components().count() > 0
+            && file_key
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)));
+
+        if is_valid_key {
+            Ok(self.base_path.join(file_key))
+        } else {
+            Err(FileStorageError::InvalidFileKey)
+                .into_report()
+                .attach_printable_lazy(|| format!("Invalid file key: {}", file_key.display()))
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl FileStorageInterface for FileSystemStorage {
+    async fn upload_file(
+        &self,
+        file_key: &str,
+        file: Vec<u8>,
+    ) -> CustomResult<(), FileStorageError> {
+        let file_path = self.get_file_path(file_key)?;
+        if let Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent)
+                .into_report()
+                .change_context(FileStorageError::UploadFailed)
+                .attach_printable("Failed to create the directory of the file")?;
+        }
+        let mut stored_file = File::create(file_path)
+            .into_report()
+            .change_context(FileStorageError::UploadFailed)
+            .attach_printable("Failed to create file")?;
+        stored_file
+            .write_all(&file)
+            .into_report()
+            .change_context(FileStorageError::UploadFailed)
+            .attach_printable("Failed while writing into file")
+    }
+
+    async fn delete_file(&self, file_key: &str) -> CustomResult<(), FileStorageError> {
+        let file_path = self.get_file_path(file_key)?;
+        fs::remove_file(file_path)
+            .into_report()
+            .change_context(FileStorageError::DeleteFailed)
+            .attach_printable("Failed while deleting the file")
+    }
+
+    async fn retrieve_file(&self, file_key: &str) -> CustomResult<Vec<u8>, FileStorageError> {
+        let file_path = self.get_file_path(file_key)?;
+        let mut received_data: Vec<u8> = Vec::new();
+        let mut stored_file = File::open(file_path)
+            .into_report()
+            .change_context(FileStorageError::RetrieveFailed)
+            .attach_printable("Failed while opening the file")?;
+        stored_file
+            .read_to_end(&mut received_data)
+            .into_report()
+            .change_context(FileStorageError::RetrieveFailed)
+            .attach_printable("Failed while reading the file")?;
+        Ok(received_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_file_system_storage() {
+        let base_path = std::env::temp_dir().join("file_system_storage_test");
+        let storage = FileSystemStorage::new(&FileSystemConfig {
+            base_path: base_path.to_string_lossy().into_owned(),
+        });
+
+        storage
+            .upload_file("merchant_1/file_1", b"evidence".to_vec())
+            .await
+            .unwrap();
+        assert_eq!(
+            storage.retrieve_file("merchant_1/file_1").await.unwrap(),
+            b"evidence"
+        );
+
+        storage.delete_file("merchant_1/file_1").await.unwrap();
+        assert!(storage.retrieve_file("merchant_1/file_1").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_file_system_storage_rejects_paths_outside_base_path() {
+        let storage = FileSystemStorage::new(&FileSystemConfig::default());
+
+        for file_key in ["", "../file_1", "/tmp/file_1", "merchant_1/../../file_1"] {
+            let error = storage.retrieve_file(file_key).await.unwrap_err();
+            assert!(matches!(
+                error.current_context(),
+                FileStorageError::InvalidFileKey
+            ));
+        }
+    }
+}
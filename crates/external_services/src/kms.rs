@@ -1,18 +1,17 @@
-//! Interactions with the AWS KMS SDK
+//! Interactions with the key management service used to decrypt secrets
 
-use std::time::Instant;
-
-use aws_config::meta::region::RegionProviderChain;
-use aws_sdk_kms::{config::Region, primitives::Blob, Client};
-use base64::Engine;
 use common_utils::errors::CustomResult;
-use error_stack::{IntoReport, ResultExt};
+use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
-use router_env::logger;
-/// decrypting data using the AWS KMS SDK.
-pub mod decrypt;
 
-use crate::{consts, metrics};
+/// Decrypting data using the AWS KMS SDK.
+pub mod aws;
+/// decrypting structures holding KMS encrypted values.
+pub mod decrypt;
+/// Decrypting data using the transit secrets engine of HashiCorp Vault.
+pub mod hashicorp_vault;
+/// Decrypting data using a key stored in a local file.
+pub mod local;
 
 static KMS_CLIENT: tokio::sync::OnceCell<KmsClient> = tokio::sync::OnceCell::const_new();
 
@@ -22,79 +21,76 @@ pub async fn get_kms_client(config: &KmsConfig) -> &'static KmsClient {
     KMS_CLIENT.get_or_init(|| KmsClient::new(config)).await
 }
 
+/// A key management service capable of decrypting the secrets in the application configuration.
+#[async_trait::async_trait]
+pub trait KeyManager: Send + Sync + std::fmt::Debug {
+    /// Decrypts the provided encrypted data, the format of which depends on the key manager.
+    async fn decrypt(&self, data: &[u8]) -> CustomResult<String, KmsError>;
+}
+
+/// The key management service used to decrypt data.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyManagerBackend {
+    /// AWS KMS
+    #[default]
+    Aws,
+    /// The transit secrets engine of HashiCorp Vault
+    HashicorpVault,
+    /// AES-256-GCM with a key stored in a local file, only meant for development
+    Local,
+}
+
 /// Configuration parameters required for constructing a [`KmsClient`].
 #[derive(Clone, Debug, Default, serde::Deserialize)]
 #[serde(default)]
 pub struct KmsConfig {
+    /// The key management service used to decrypt data.
+    pub backend: KeyManagerBackend,
+
     /// The AWS key identifier of the KMS key used to encrypt or decrypt data.
     pub key_id: String,
 
     /// The AWS region to send KMS requests to.
     pub region: String,
+
+    /// Configuration of the HashiCorp Vault backend.
+    pub vault: hashicorp_vault::VaultConfig,
+
+    /// Configuration of the local backend.
+    pub local: local::LocalKeyManagerConfig,
 }
 
-/// Client for KMS operations.
+/// Client for KMS operations, backed by the key manager chosen in the configuration.
 #[derive(Debug)]
 pub struct KmsClient {
-    inner_client: Client,
-    key_id: String,
+    inner: Box<dyn KeyManager>,
 }
 
 impl KmsClient {
     /// Constructs a new KMS client.
     pub async fn new(config: &KmsConfig) -> Self {
-        let region_provider = RegionProviderChain::first_try(Region::new(config.region.clone()));
-        let sdk_config = aws_config::from_env().region(region_provider).load().await;
-
-        Self {
-            inner_client: Client::new(&sdk_config),
-            key_id: config.key_id.clone(),
-        }
+        let inner: Box<dyn KeyManager> = match config.backend {
+            KeyManagerBackend::Aws => Box::new(aws::AwsKmsClient::new(config).await),
+            KeyManagerBackend::HashicorpVault => {
+                Box::new(hashicorp_vault::VaultKeyManager::new(&config.vault))
+            }
+            KeyManagerBackend::Local => Box::new(local::LocalKeyManager::new(&config.local)),
+        };
+
+        Self { inner }
     }
 
-    /// Decrypts the provided base64-encoded encrypted data using the AWS KMS SDK. We assume that
-    /// the SDK has the values required to interact with the AWS KMS APIs (`AWS_ACCESS_KEY_ID` and
-    /// `AWS_SECRET_ACCESS_KEY`) either set in environment variables, or that the SDK is running in
-    /// a machine that is able to assume an IAM role.
+    /// Decrypts the provided encrypted data using the configured key manager.
     pub async fn decrypt(&self, data: impl AsRef<[u8]>) -> CustomResult<String, KmsError> {
-        let start = Instant::now();
-        let data = consts::BASE64_ENGINE
-            .decode(data)
-            .into_report()
-            .change_context(KmsError::Base64DecodingFailed)?;
-        let ciphertext_blob = Blob::new(data);
-
-        let decrypt_output = self
-            .inner_client
-            .decrypt()
-            .key_id(&self.key_id)
-            .ciphertext_blob(ciphertext_blob)
-            .send()
-            .await
-            .map_err(|error| {
-                // Logging using `Debug` representation of the error as the `Display`
-                // representation does not hold sufficient information.
-                logger::error!(kms_sdk_error=?error, "Failed to KMS decrypt data");
-                metrics::AWS_KMS_FAILURES.add(&metrics::CONTEXT, 1, &[]);
-                error
-            })
-            .into_report()
-            .change_context(KmsError::DecryptionFailed)?;
-
-        let output = decrypt_output
-            .plaintext
-            .ok_or(KmsError::MissingPlaintextDecryptionOutput)
-            .into_report()
-            .and_then(|blob| {
-                String::from_utf8(blob.into_inner())
-                    .into_report()
-                    .change_context(KmsError::Utf8DecodingFailed)
-            })?;
-
-        let time_taken = start.elapsed();
-        metrics::AWS_KMS_DECRYPT_TIME.record(&metrics::CONTEXT, time_taken.as_secs_f64(), &[]);
-
-        Ok(output)
+        self.inner.decrypt(data.as_ref()).await
+    }
+}
+
+#[async_trait::async_trait]
+impl KeyManager for KmsClient {
+    async fn decrypt(&self, data: &[u8]) -> CustomResult<String, KmsError> {
+        self.inner.decrypt(data).await
     }
 }
 
@@ -120,6 +116,10 @@ pub enum KmsError {
     /// The KMS client has not been initialized.
     #[error("The KMS client has not been initialized")]
     KmsClientNotInitialized,
+
+    /// The key used to decrypt data could not be found.
+    #[error("Failed to find the key used to decrypt data")]
+    KeyNotFound,
 }
 
 impl KmsConfig {
@@ -127,13 +127,19 @@ impl KmsConfig {
     pub fn validate(&self) -> Result<(), &'static str> {
         use common_utils::{ext_traits::ConfigExt, fp_utils::when};
 
-        when(self.key_id.is_default_or_empty(), || {
-            Err("KMS AWS key ID must not be empty")
-        })?;
-
-        when(self.region.is_default_or_empty(), || {
-            Err("KMS AWS region must not be empty")
-        })
+        match self.backend {
+            KeyManagerBackend::Aws => {
+                when(self.key_id.is_default_or_empty(), || {
+                    Err("KMS AWS key ID must not be empty")
+                })?;
+
+                when(self.region.is_default_or_empty(), || {
+                    Err("KMS AWS region must not be empty")
+                })
+            }
+            KeyManagerBackend::HashicorpVault => self.vault.validate(),
+            KeyManagerBackend::Local => self.local.validate(),
+        }
     }
 }
 
@@ -7,8 +7,11 @@ use super::*;
 pub trait KmsDecrypt {
     /// The output type of the decryption
     type Output;
-    /// Decrypts the structure given a KMS client
-    async fn decrypt_inner(self, kms_client: &KmsClient) -> CustomResult<Self::Output, KmsError>
+    /// Decrypts the structure given a key manager
+    async fn decrypt_inner(
+        self,
+        kms_client: &dyn KeyManager,
+    ) -> CustomResult<Self::Output, KmsError>
     where
         Self: Sized;
 
@@ -25,9 +28,12 @@ pub trait KmsDecrypt {
 #[async_trait::async_trait]
 impl KmsDecrypt for &KmsValue {
     type Output = String;
-    async fn decrypt_inner(self, kms_client: &KmsClient) -> CustomResult<Self::Output, KmsError> {
+    async fn decrypt_inner(
+        self,
+        kms_client: &dyn KeyManager,
+    ) -> CustomResult<Self::Output, KmsError> {
         kms_client
-            .decrypt(self.0.peek())
+            .decrypt(self.0.peek().as_bytes())
             .await
             .attach_printable("Failed to decrypt KMS value")
     }
@@ -0,0 +1,140 @@
+//! Interactions with the transit secrets engine of HashiCorp Vault
+
+use base64::Engine;
+use common_utils::errors::CustomResult;
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::logger;
+
+use super::{KeyManager, KmsError};
+use crate::consts;
+
+/// Configuration parameters required for constructing a [`VaultKeyManager`].
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(default)]
+pub struct VaultConfig {
+    /// The address of the Vault server, for example `https://vault.example.com:8200`.
+    pub url: String,
+
+    /// The token used to authenticate the requests to Vault.
+    pub token: Secret<String>,
+
+    /// The path at which the transit secrets engine is mounted.
+    pub mount_path: String,
+
+    /// The name of the transit encryption key used to encrypt or decrypt data.
+    pub key_name: String,
+}
+
+impl Default for VaultConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            token: Secret::default(),
+            mount_path: "transit".to_string(),
+            key_name: String::new(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct VaultDecryptRequest<'a> {
+    ciphertext: &'a str,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct VaultDecryptResponse {
+    data: VaultDecryptResponseData,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct VaultDecryptResponseData {
+    plaintext: Secret<String>,
+}
+
+/// Key manager which decrypts data using the transit secrets engine of HashiCorp Vault.
+#[derive(Debug)]
+pub struct VaultKeyManager {
+    client: reqwest::Client,
+    decrypt_url: String,
+    token: Secret<String>,
+}
+
+impl VaultKeyManager {
+    /// Constructs a new Vault key manager.
+    pub fn new(config: &VaultConfig) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            decrypt_url: format!(
+                "{}/v1/{}/decrypt/{}",
+                config.url.trim_end_matches('/'),
+                config.mount_path.trim_matches('/'),
+                config.key_name
+            ),
+            token: config.token.clone(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl KeyManager for VaultKeyManager {
+    /// Decrypts the provided Vault ciphertext (of the form `vault:v1:...`) using the transit
+    /// secrets engine.
+    async fn decrypt(&self, data: &[u8]) -> CustomResult<String, KmsError> {
+        let ciphertext = std::str::from_utf8(data)
+            .into_report()
+            .change_context(KmsError::Utf8DecodingFailed)?;
+
+        let response = self
+            .client
+            .post(&self.decrypt_url)
+            .header("X-Vault-Token", self.token.peek())
+            .json(&VaultDecryptRequest { ciphertext })
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|error| {
+                logger::error!(vault_error=?error, "Failed to decrypt data using Vault");
+                error
+            })
+            .into_report()
+            .change_context(KmsError::DecryptionFailed)?
+            .json::<VaultDecryptResponse>()
+            .await
+            .into_report()
+            .change_context(KmsError::MissingPlaintextDecryptionOutput)?;
+
+        // The transit secrets engine returns the plaintext base64 encoded
+        let plaintext = consts::BASE64_ENGINE
+            .decode(response.data.plaintext.peek())
+            .into_report()
+            .change_context(KmsError::Base64DecodingFailed)?;
+
+        String::from_utf8(plaintext)
+            .into_report()
+            .change_context(KmsError::Utf8DecodingFailed)
+    }
+}
+
+impl VaultConfig {
+    /// Verifies that the [`VaultKeyManager`] configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        when(self.url.is_default_or_empty(), || {
+            Err("KMS Vault URL must not be empty")
+        })?;
+
+        when(self.token.peek().is_default_or_empty(), || {
+            Err("KMS Vault token must not be empty")
+        })?;
+
+        when(self.mount_path.is_default_or_empty(), || {
+            Err("KMS Vault transit engine mount path must not be empty")
+        })?;
+
+        when(self.key_name.is_default_or_empty(), || {
+            Err("KMS Vault key name must not be empty")
+        })
+    }
+}
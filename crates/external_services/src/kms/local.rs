@@ -0,0 +1,119 @@
+//! Decryption of data using a key stored in a local file, meant for development setups
+
+use base64::Engine;
+use common_utils::{
+    crypto::{DecodeMessage, GcmAes256},
+    errors::CustomResult,
+};
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+
+use super::{KeyManager, KmsError};
+use crate::consts;
+
+/// Configuration parameters required for constructing a [`LocalKeyManager`].
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct LocalKeyManagerConfig {
+    /// Path of the file containing the base64-encoded AES-256 key used to decrypt data.
+    pub key_file: String,
+}
+
+/// Key manager which decrypts data encrypted using AES-256-GCM with a key read from a local file.
+/// The encrypted data is expected to be base64-encoded, with the nonce prepended to the
+/// ciphertext.
+#[derive(Debug)]
+pub struct LocalKeyManager {
+    key_file: String,
+    key: tokio::sync::OnceCell<Secret<Vec<u8>>>,
+}
+
+impl LocalKeyManager {
+    /// Constructs a new local key manager.
+    pub fn new(config: &LocalKeyManagerConfig) -> Self {
+        Self {
+            key_file: config.key_file.clone(),
+            key: tokio::sync::OnceCell::new(),
+        }
+    }
+
+    /// Returns the key, reading it from the key file on first use.
+    async fn get_key(&self) -> CustomResult<&[u8], KmsError> {
+        self.key
+            .get_or_try_init(|| self.read_key())
+            .await
+            .map(|key| key.peek().as_slice())
+    }
+
+    async fn read_key(&self) -> CustomResult<Secret<Vec<u8>>, KmsError> {
+        let encoded_key = tokio::fs::read_to_string(&self.key_file)
+            .await
+            .into_report()
+            .change_context(KmsError::KeyNotFound)
+            .attach_printable_lazy(|| format!("Failed to read key file: {}", self.key_file))?;
+
+        consts::BASE64_ENGINE
+            .decode(encoded_key.trim())
+            .map(Secret::new)
+            .into_report()
+            .change_context(KmsError::Base64DecodingFailed)
+            .attach_printable("Failed to base64 decode the contents of the key file")
+    }
+}
+
+#[async_trait::async_trait]
+impl KeyManager for LocalKeyManager {
+    async fn decrypt(&self, data: &[u8]) -> CustomResult<String, KmsError> {
+        let key = self.get_key().await?;
+        let data = consts::BASE64_ENGINE
+            .decode(data)
+            .into_report()
+            .change_context(KmsError::Base64DecodingFailed)?;
+
+        let plaintext = GcmAes256
+            .decode_message(key, Secret::new(data))
+            .change_context(KmsError::DecryptionFailed)?;
+
+        String::from_utf8(plaintext)
+            .into_report()
+            .change_context(KmsError::Utf8DecodingFailed)
+    }
+}
+
+impl LocalKeyManagerConfig {
+    /// Verifies that the [`LocalKeyManager`] configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        when(self.key_file.is_default_or_empty(), || {
+            Err("KMS local key file path must not be empty")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use common_utils::crypto::EncodeMessage;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_local_key_manager_decrypt() {
+        let key = [7u8; 32];
+        let key_file = std::env::temp_dir().join("local_key_manager_test_key");
+        std::fs::write(&key_file, consts::BASE64_ENGINE.encode(key)).unwrap();
+
+        let ciphertext = GcmAes256.encode_message(&key, b"db_pass").unwrap();
+        let key_manager = LocalKeyManager::new(&LocalKeyManagerConfig {
+            key_file: key_file.to_string_lossy().into_owned(),
+        });
+
+        let plaintext = key_manager
+            .decrypt(consts::BASE64_ENGINE.encode(ciphertext).as_bytes())
+            .await
+            .unwrap();
+        assert_eq!(plaintext, "db_pass");
+    }
+}
@@ -6,6 +6,8 @@
 #[cfg(feature = "email")]
 pub mod email;
 
+pub mod file_storage;
+
 #[cfg(feature = "kms")]
 pub mod kms;
 
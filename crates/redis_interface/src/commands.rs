@@ -15,11 +15,11 @@ use common_utils::{
 };
 use error_stack::{IntoReport, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, StreamsInterface},
+    interfaces::{HashesInterface, KeysInterface, LuaInterface, StreamsInterface},
     prelude::RedisErrorKind,
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
-        RedisKey, RedisMap, RedisValue, Scanner, SetOptions, XCap, XReadResponse,
+        RedisKey, RedisMap, RedisValue, Scanner, SetOptions, XCap, XReadResponse, XReadValue,
     },
 };
 use futures::StreamExt;
@@ -173,6 +173,29 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::DeleteFailed)
     }
 
+    /// Delete the key only if it holds `value`, so that a key set by one owner, like a lock, is
+    /// not deleted by another after it has expired and been set again
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn delete_key_if_value_matches(
+        &self,
+        key: &str,
+        value: &str,
+    ) -> CustomResult<DelReply, errors::RedisError> {
+        self.pool
+            .eval(
+                "if redis.call('GET', KEYS[1]) == ARGV[1] then \
+                     return redis.call('DEL', KEYS[1]) \
+                 else \
+                     return 0 \
+                 end",
+                key,
+                value,
+            )
+            .await
+            .into_report()
+            .change_context(errors::RedisError::DeleteFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_key_with_expiry<V>(
         &self,
@@ -570,6 +593,24 @@ impl super::RedisConnectionPool {
             })
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn stream_read_entries_in_range<K>(
+        &self,
+        stream: K,
+        start: &str,
+        end: &str,
+        count: Option<u64>,
+    ) -> CustomResult<Vec<XReadValue<String, String, String>>, errors::RedisError>
+    where
+        K: Into<RedisKey> + Debug + Send + Sync,
+    {
+        self.pool
+            .xrange_values(stream, start, end, count)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::StreamReadFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_read_with_options<K, Ids>(
         &self,
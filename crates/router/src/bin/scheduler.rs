@@ -76,6 +76,13 @@ pub enum PTRunner {
     PaymentsSyncWorkflow,
     RefundWorkflowRouter,
     DeleteTokenizeDataWorkflow,
+    OutgoingWebhookRetryWorkflow,
+    SubscriptionBillingWorkflow,
+    DisputeDeadlineWorkflow,
+    PaymentLinkExpiryWorkflow,
+    MerchantKeyRotationWorkflow,
+    #[cfg(feature = "email")]
+    ApiKeyExpiryWorkflow,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -100,6 +107,25 @@ impl ProcessTrackerWorkflows<routes::AppState> for WorkflowRunner {
             Some(PTRunner::DeleteTokenizeDataWorkflow) => {
                 Box::new(workflows::tokenized_data::DeleteTokenizeDataWorkflow)
             }
+            Some(PTRunner::OutgoingWebhookRetryWorkflow) => {
+                Box::new(workflows::outgoing_webhook_retry::OutgoingWebhookRetryWorkflow)
+            }
+            Some(PTRunner::SubscriptionBillingWorkflow) => {
+                Box::new(workflows::subscription_billing::SubscriptionBillingWorkflow)
+            }
+            Some(PTRunner::DisputeDeadlineWorkflow) => {
+                Box::new(workflows::dispute_deadline::DisputeDeadlineWorkflow)
+            }
+            Some(PTRunner::PaymentLinkExpiryWorkflow) => {
+                Box::new(workflows::payment_link_expiry::PaymentLinkExpiryWorkflow)
+            }
+            Some(PTRunner::MerchantKeyRotationWorkflow) => {
+                Box::new(workflows::merchant_key_rotation::MerchantKeyRotationWorkflow)
+            }
+            #[cfg(feature = "email")]
+            Some(PTRunner::ApiKeyExpiryWorkflow) => {
+                Box::new(workflows::api_key_expiry::ApiKeyExpiryWorkflow)
+            }
             _ => Err(ProcessTrackerError::UnexpectedFlow)?,
         };
         let app_state = &state.clone();
@@ -393,7 +393,9 @@ pub enum StripePaymentStatus {
 impl From<api_enums::IntentStatus> for StripePaymentStatus {
     fn from(item: api_enums::IntentStatus) -> Self {
         match item {
-            api_enums::IntentStatus::Succeeded => Self::Succeeded,
+            api_enums::IntentStatus::Succeeded | api_enums::IntentStatus::PartiallyCaptured => {
+                Self::Succeeded
+            }
             api_enums::IntentStatus::Failed => Self::Canceled,
             api_enums::IntentStatus::Processing => Self::Processing,
             api_enums::IntentStatus::RequiresCustomerAction
@@ -401,7 +403,7 @@ impl From<api_enums::IntentStatus> for StripePaymentStatus {
             api_enums::IntentStatus::RequiresPaymentMethod => Self::RequiresPaymentMethod,
             api_enums::IntentStatus::RequiresConfirmation => Self::RequiresConfirmation,
             api_enums::IntentStatus::RequiresCapture
-            | api_enums::IntentStatus::PartiallyCaptured => Self::RequiresCapture,
+            | api_enums::IntentStatus::PartiallyCapturedAndCapturable => Self::RequiresCapture,
             api_enums::IntentStatus::Cancelled => Self::Canceled,
         }
     }
@@ -722,6 +724,9 @@ impl ForeignTryFrom<(Option<MandateData>, Option<String>)> for Option<payments::
                             start_date: mandate.start_date,
                             end_date: mandate.end_date,
                             metadata: None,
+                            max_amount_per_transaction: None,
+                            frequency: None,
+                            max_debits_per_frequency: None,
                         },
                     )),
                     StripeMandateType::MultiUse => Some(payments::MandateType::MultiUse(None)),
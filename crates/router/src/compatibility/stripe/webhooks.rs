@@ -1,5 +1,5 @@
 use api_models::{
-    enums::{DisputeStatus, MandateStatus},
+    enums::{DisputeStatus, MandateStatus, PayoutStatus, SubscriptionStatus},
     webhooks::{self as api},
 };
 use common_utils::{crypto::SignMessage, date_time, ext_traits};
@@ -74,6 +74,8 @@ pub enum StripeWebhookObject {
     Refund(StripeRefundResponse),
     Dispute(StripeDisputeResponse),
     Mandate(StripeMandateResponse),
+    Payout(StripePayoutResponse),
+    Subscription(StripeSubscriptionResponse),
 }
 
 #[derive(Serialize, Debug)]
@@ -94,6 +96,48 @@ pub struct StripeMandateResponse {
     pub payment_method: String,
 }
 
+#[derive(Serialize, Debug)]
+pub struct StripePayoutResponse {
+    pub id: String,
+    pub amount: i64,
+    pub currency: String,
+    pub payout_type: String,
+    pub status: StripePayoutStatus,
+    pub failure_code: Option<String>,
+    pub failure_message: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum StripePayoutStatus {
+    Paid,
+    Pending,
+    InTransit,
+    Canceled,
+    Failed,
+}
+
+#[derive(Serialize, Debug)]
+pub struct StripeSubscriptionResponse {
+    pub id: String,
+    pub customer: String,
+    pub status: StripeSubscriptionStatus,
+    pub current_period_start: Option<i64>,
+    pub current_period_end: Option<i64>,
+    pub latest_invoice: Option<String>,
+    pub metadata: Option<common_utils::pii::SecretSerdeValue>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeSubscriptionStatus {
+    Trialing,
+    Active,
+    PastDue,
+    Unpaid,
+    Canceled,
+}
+
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "snake_case")]
 pub enum StripeMandateStatus {
@@ -139,6 +183,66 @@ impl From<api_models::mandates::MandateResponse> for StripeMandateResponse {
     }
 }
 
+impl From<api_models::payouts::PayoutCreateResponse> for StripePayoutResponse {
+    fn from(res: api_models::payouts::PayoutCreateResponse) -> Self {
+        Self {
+            id: res.payout_id,
+            amount: res.amount,
+            currency: res.currency.to_string(),
+            payout_type: res.payout_type.to_string(),
+            status: StripePayoutStatus::from(res.status),
+            failure_code: res.error_code,
+            failure_message: res.error_message,
+        }
+    }
+}
+
+impl From<PayoutStatus> for StripePayoutStatus {
+    fn from(status: PayoutStatus) -> Self {
+        match status {
+            PayoutStatus::Success => Self::Paid,
+            PayoutStatus::Pending => Self::InTransit,
+            PayoutStatus::Cancelled => Self::Canceled,
+            PayoutStatus::Failed | PayoutStatus::Ineligible | PayoutStatus::Reversed => {
+                Self::Failed
+            }
+            PayoutStatus::RequiresCreation
+            | PayoutStatus::RequiresPayoutMethodData
+            | PayoutStatus::RequiresFulfillment => Self::Pending,
+        }
+    }
+}
+
+impl From<api_models::subscriptions::SubscriptionResponse> for StripeSubscriptionResponse {
+    fn from(res: api_models::subscriptions::SubscriptionResponse) -> Self {
+        Self {
+            id: res.subscription_id,
+            customer: res.customer_id,
+            status: StripeSubscriptionStatus::from(res.status),
+            current_period_start: res
+                .current_period_start
+                .map(|period_start| period_start.assume_utc().unix_timestamp()),
+            current_period_end: res
+                .current_period_end
+                .map(|period_end| period_end.assume_utc().unix_timestamp()),
+            latest_invoice: res.latest_payment_id,
+            metadata: res.metadata,
+        }
+    }
+}
+
+impl From<SubscriptionStatus> for StripeSubscriptionStatus {
+    fn from(status: SubscriptionStatus) -> Self {
+        match status {
+            SubscriptionStatus::Trialing => Self::Trialing,
+            SubscriptionStatus::Active => Self::Active,
+            SubscriptionStatus::PastDue => Self::PastDue,
+            SubscriptionStatus::Unpaid => Self::Unpaid,
+            SubscriptionStatus::Cancelled => Self::Canceled,
+        }
+    }
+}
+
 impl From<MandateStatus> for StripeMandateStatus {
     fn from(status: MandateStatus) -> Self {
         match status {
@@ -169,6 +273,7 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PaymentFailed => "payment_intent.payment_failed",
         api_models::enums::EventType::PaymentProcessing => "payment_intent.processing",
         api_models::enums::EventType::PaymentCancelled => "payment_intent.canceled",
+        api_models::enums::EventType::PaymentCaptured => "charge.captured",
 
         // the below are not really stripe compatible because stripe doesn't provide this
         api_models::enums::EventType::ActionRequired => "action.required",
@@ -181,8 +286,18 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::DisputeChallenged => "dispute.challenged",
         api_models::enums::EventType::DisputeWon => "dispute.won",
         api_models::enums::EventType::DisputeLost => "dispute.lost",
+        api_models::enums::EventType::DisputeEvidenceDue => "dispute.evidence_due",
         api_models::enums::EventType::MandateActive => "mandate.active",
         api_models::enums::EventType::MandateRevoked => "mandate.revoked",
+        api_models::enums::EventType::PayoutInitiated => "payout.created",
+        api_models::enums::EventType::PayoutSuccess => "payout.paid",
+        api_models::enums::EventType::PayoutFailed => "payout.failed",
+        api_models::enums::EventType::PayoutCancelled => "payout.canceled",
+
+        // stripe reports returned payouts as failed, there is no separate reversal event
+        api_models::enums::EventType::PayoutReversed => "payout.failed",
+        api_models::enums::EventType::SubscriptionRenewalSucceeded => "invoice.paid",
+        api_models::enums::EventType::SubscriptionRenewalFailed => "invoice.payment_failed",
     }
 }
 
@@ -223,6 +338,10 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             api::OutgoingWebhookContent::MandateDetails(mandate) => {
                 Self::Mandate((*mandate).into())
             }
+            api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout((*payout).into()),
+            api::OutgoingWebhookContent::SubscriptionDetails(subscription) => {
+                Self::Subscription((*subscription).into())
+            }
         }
     }
 }
@@ -13,7 +13,7 @@ use crate::{
 
 #[instrument(skip(request, payload, state, func, api_authentication))]
 pub async fn compatibility_api_wrap<'a, 'b, A, U, T, Q, F, Fut, S, E, E2>(
-    flow: impl router_env::types::FlowMetric,
+    flow: router_env::Flow,
     state: Arc<A>,
     request: &'a HttpRequest,
     payload: T,
@@ -90,6 +90,37 @@ impl Default for super::settings::EphemeralConfig {
     }
 }
 
+impl Default for super::settings::OutgoingWebhookRetrySettings {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            // 1 minute
+            initial_delay: 60,
+            // 6 hours
+            max_delay: 6 * 60 * 60,
+        }
+    }
+}
+
+impl Default for super::settings::SubscriptionSettings {
+    fn default() -> Self {
+        Self {
+            // 1 day, 3 days and 5 days
+            dunning_retry_delays: vec![24 * 60 * 60, 3 * 24 * 60 * 60, 5 * 24 * 60 * 60],
+        }
+    }
+}
+
+impl Default for super::settings::DisputeDeadlineSettings {
+    fn default() -> Self {
+        Self {
+            // 3 days and 1 day
+            reminder_offsets: vec![3 * 24 * 60 * 60, 24 * 60 * 60],
+            send_reminder_emails: false,
+        }
+    }
+}
+
 #[cfg(feature = "kv_store")]
 impl Default for super::settings::DrainerSettings {
     fn default() -> Self {
@@ -4209,7 +4240,6 @@ impl Default for super::settings::RequiredFields {
     }
 }
 
-#[allow(clippy::derivable_impls)]
 impl Default for super::settings::ApiKeys {
     fn default() -> Self {
         Self {
@@ -4224,6 +4254,21 @@ impl Default for super::settings::ApiKeys {
             // Specifies the number of days before API key expiry when email reminders should be sent
             #[cfg(feature = "email")]
             expiry_reminder_days: vec![7, 3, 1],
+
+            trusted_proxy_hops: 1,
+        }
+    }
+}
+
+impl Default for super::settings::FileUploadRestrictions {
+    fn default() -> Self {
+        Self {
+            max_file_size: 5 * 1024 * 1024, // Uploaded files are limited to 5MiB
+            allowed_content_types: HashSet::from([
+                "application/pdf".to_string(),
+                "image/jpeg".to_string(),
+                "image/png".to_string(),
+            ]),
         }
     }
 }
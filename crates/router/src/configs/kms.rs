@@ -1,5 +1,5 @@
 use common_utils::errors::CustomResult;
-use external_services::kms::{decrypt::KmsDecrypt, KmsClient, KmsError};
+use external_services::kms::{decrypt::KmsDecrypt, KeyManager, KmsError};
 use masking::ExposeInterface;
 
 use crate::configs::settings;
@@ -10,7 +10,7 @@ impl KmsDecrypt for settings::Jwekey {
 
     async fn decrypt_inner(
         mut self,
-        kms_client: &KmsClient,
+        kms_client: &dyn KeyManager,
     ) -> CustomResult<Self::Output, KmsError> {
         (
             self.locker_encryption_key1,
@@ -21,13 +21,13 @@ impl KmsDecrypt for settings::Jwekey {
             self.vault_private_key,
             self.tunnel_private_key,
         ) = tokio::try_join!(
-            kms_client.decrypt(self.locker_encryption_key1),
-            kms_client.decrypt(self.locker_encryption_key2),
-            kms_client.decrypt(self.locker_decryption_key1),
-            kms_client.decrypt(self.locker_decryption_key2),
-            kms_client.decrypt(self.vault_encryption_key),
-            kms_client.decrypt(self.vault_private_key),
-            kms_client.decrypt(self.tunnel_private_key),
+            kms_client.decrypt(self.locker_encryption_key1.as_bytes()),
+            kms_client.decrypt(self.locker_encryption_key2.as_bytes()),
+            kms_client.decrypt(self.locker_decryption_key1.as_bytes()),
+            kms_client.decrypt(self.locker_decryption_key2.as_bytes()),
+            kms_client.decrypt(self.vault_encryption_key.as_bytes()),
+            kms_client.decrypt(self.vault_private_key.as_bytes()),
+            kms_client.decrypt(self.tunnel_private_key.as_bytes()),
         )?;
         Ok(self)
     }
@@ -38,7 +38,7 @@ impl KmsDecrypt for settings::ActiveKmsSecrets {
     type Output = Self;
     async fn decrypt_inner(
         mut self,
-        kms_client: &KmsClient,
+        kms_client: &dyn KeyManager,
     ) -> CustomResult<Self::Output, KmsError> {
         self.jwekey = self.jwekey.expose().decrypt_inner(kms_client).await?.into();
         Ok(self)
@@ -51,7 +51,7 @@ impl KmsDecrypt for settings::Database {
 
     async fn decrypt_inner(
         mut self,
-        kms_client: &KmsClient,
+        kms_client: &dyn KeyManager,
     ) -> CustomResult<Self::Output, KmsError> {
         Ok(storage_impl::config::Database {
             host: self.host,
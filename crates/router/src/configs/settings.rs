@@ -9,6 +9,7 @@ use common_utils::ext_traits::ConfigExt;
 use config::{Environment, File};
 #[cfg(feature = "email")]
 use external_services::email::EmailSettings;
+use external_services::file_storage::FileStorageConfig;
 #[cfg(feature = "kms")]
 use external_services::kms;
 use redis_interface::RedisSettings;
@@ -77,10 +78,11 @@ pub struct Settings {
     pub webhooks: WebhooksSettings,
     pub pm_filters: ConnectorFilters,
     pub bank_config: BankRedirectConfig,
+    pub installment_config: InstallmentConfig,
     pub api_keys: ApiKeys,
     #[cfg(feature = "kms")]
     pub kms: kms::KmsConfig,
-    #[cfg(feature = "s3")]
+    pub file_storage: FileStorageConfig,
     pub file_upload_config: FileUploadConfig,
     pub tokenization: TokenizationConfig,
     pub connector_customer: ConnectorCustomer,
@@ -89,6 +91,8 @@ pub struct Settings {
     #[cfg(feature = "email")]
     pub email: EmailSettings,
     pub mandates: Mandates,
+    pub subscriptions: SubscriptionSettings,
+    pub dispute_deadline: DisputeDeadlineSettings,
     pub required_fields: RequiredFields,
     pub delayed_session_response: DelayedSessionConfig,
     pub webhook_source_verification_call: WebhookSourceVerificationCall,
@@ -197,6 +201,25 @@ pub struct Mandates {
     pub supported_payment_methods: SupportedPaymentMethodsForMandate,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SubscriptionSettings {
+    /// Delays (in seconds) before each retry of a failed renewal payment, the subscription is
+    /// marked as unpaid once all the retries have failed
+    pub dunning_retry_delays: Vec<u32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct DisputeDeadlineSettings {
+    /// Times (in seconds) before the evidence deadline of an open dispute at which the merchant
+    /// is reminded about the deadline
+    pub reminder_offsets: Vec<u32>,
+    /// Whether the reminders are also sent to the primary email of the merchant, applicable only
+    /// when the `email` feature is enabled
+    pub send_reminder_emails: bool,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct SupportedPaymentMethodsForMandate(
     pub HashMap<enums::PaymentMethod, SupportedPaymentMethodTypesForMandate>,
@@ -286,6 +309,22 @@ pub struct BanksVector {
     pub banks: HashSet<api_models::enums::BankNames>,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct InstallmentConfig(
+    pub HashMap<api_models::enums::PaymentMethodType, ConnectorInstallmentPlans>,
+);
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ConnectorInstallmentPlans(pub HashMap<String, InstallmentPlans>);
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct InstallmentPlans {
+    #[serde(deserialize_with = "installments_vec_deser")]
+    pub number_of_installments: Vec<u8>,
+    #[serde(default, deserialize_with = "plan_ids_vec_deser")]
+    pub plan_ids: Option<Vec<String>>,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 #[serde(transparent)]
 pub struct ConnectorFilters(pub HashMap<String, PaymentMethodFilters>);
@@ -387,6 +426,38 @@ where
         .collect())
 }
 
+fn installments_vec_deser<'a, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'a>,
+{
+    let value = <String>::deserialize(deserializer)?;
+    value
+        .trim()
+        .split(',')
+        .map(|installments| installments.trim().parse::<u8>())
+        .collect::<Result<_, _>>()
+        .map_err(D::Error::custom)
+}
+
+fn plan_ids_vec_deser<'a, D>(deserializer: D) -> Result<Option<Vec<String>>, D::Error>
+where
+    D: Deserializer<'a>,
+{
+    let value = <Option<String>>::deserialize(deserializer)?;
+    Ok(value.and_then(|inner| {
+        let list = inner
+            .trim()
+            .split(',')
+            .map(|plan_id| plan_id.trim().to_string())
+            .filter(|plan_id| !plan_id.is_empty())
+            .collect::<Vec<_>>();
+        match list.len() {
+            0 => None,
+            _ => Some(list),
+        }
+    }))
+}
+
 #[derive(Debug, Default, Deserialize, Clone)]
 #[serde(default)]
 pub struct Secrets {
@@ -625,6 +696,18 @@ pub struct DrainerSettings {
 pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
     pub ignore_error: WebhookIgnoreErrorSettings,
+    pub outgoing_retry: OutgoingWebhookRetrySettings,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct OutgoingWebhookRetrySettings {
+    /// Number of times a failed delivery is retried, retries are disabled when set to 0
+    pub max_retries: u32,
+    /// Delay before the first retry (in seconds), which is doubled on every subsequent retry
+    pub initial_delay: u32,
+    /// Upper bound on the delay between two retries (in seconds)
+    pub max_delay: u32,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -650,16 +733,35 @@ pub struct ApiKeys {
     // Specifies the number of days before API key expiry when email reminders should be sent
     #[cfg(feature = "email")]
     pub expiry_reminder_days: Vec<u8>,
+
+    /// Number of proxies in front of the application which append the address of their peer to
+    /// the `X-Forwarded-For` header, used to find the client IP address for the IP allow-lists of
+    /// API keys
+    pub trusted_proxy_hops: usize,
 }
 
-#[cfg(feature = "s3")]
 #[derive(Debug, Deserialize, Clone, Default)]
 #[serde(default)]
 pub struct FileUploadConfig {
-    /// The AWS region to send file uploads
-    pub region: String,
-    /// The AWS s3 bucket to send file uploads
-    pub bucket_name: String,
+    /// Restrictions on the files uploaded as dispute evidence
+    pub dispute_evidence: FileUploadRestrictions,
+
+    /// The S3 bucket in which files were stored before the file storage backend became
+    /// configurable, only read so that configurations which still set it are rejected
+    pub bucket_name: Option<String>,
+
+    /// The region of the S3 bucket in which files were stored before the file storage backend
+    /// became configurable, only read so that configurations which still set it are rejected
+    pub region: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct FileUploadRestrictions {
+    /// The maximum size of the uploaded files, in bytes
+    pub max_file_size: i32,
+    /// The content types of the files which can be uploaded
+    pub allowed_content_types: HashSet<String>,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -783,7 +885,13 @@ impl Settings {
         self.kms
             .validate()
             .map_err(|error| ApplicationError::InvalidConfigurationValueError(error.into()))?;
-        #[cfg(feature = "s3")]
+        #[cfg(feature = "email")]
+        self.email
+            .validate()
+            .map_err(|error| ApplicationError::InvalidConfigurationValueError(error.into()))?;
+        self.file_storage
+            .validate()
+            .map_err(|error| ApplicationError::InvalidConfigurationValueError(error.into()))?;
         self.file_upload_config.validate()?;
         self.lock_settings.validate()?;
         Ok(())
@@ -806,6 +914,17 @@ mod payment_method_deserialization_test {
         let test_pm = pm_deser(deserializer);
         assert!(test_pm.is_ok())
     }
+
+    #[test]
+    fn test_installments_deserializer() {
+        let deserializer: StrDeserializer<'_, ValueError> = "3, 6,12".into_deserializer();
+        let test_installments = installments_vec_deser(deserializer);
+        assert_eq!(test_installments.unwrap(), vec![3, 6, 12]);
+
+        let deserializer: StrDeserializer<'_, ValueError> = "3,six".into_deserializer();
+        let test_installments = installments_vec_deser(deserializer);
+        assert!(test_installments.is_err())
+    }
 }
 
 #[cfg(feature = "payouts")]
@@ -127,20 +127,40 @@ impl super::settings::DrainerSettings {
     }
 }
 
-#[cfg(feature = "s3")]
 impl super::settings::FileUploadConfig {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
 
-        when(self.region.is_default_or_empty(), || {
+        // Files would silently be stored in the default file storage backend instead of the
+        // bucket configured here
+        let is_legacy_bucket_configured = [&self.bucket_name, &self.region]
+            .into_iter()
+            .any(|value| value.as_ref().map_or(false, |value| !value.is_empty()));
+        when(is_legacy_bucket_configured, || {
             Err(ApplicationError::InvalidConfigurationValueError(
-                "s3 region must not be empty".into(),
+                "file_upload_config.bucket_name and file_upload_config.region are no longer \
+                 supported, configure the bucket in file_storage.aws_s3 instead"
+                    .into(),
             ))
         })?;
 
-        when(self.bucket_name.is_default_or_empty(), || {
+        self.dispute_evidence.validate()
+    }
+}
+
+impl super::settings::FileUploadRestrictions {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.max_file_size <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "maximum file upload size must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.allowed_content_types.is_empty(), || {
             Err(ApplicationError::InvalidConfigurationValueError(
-                "s3 bucket name must not be empty".into(),
+                "allowed file upload content types must not be empty".into(),
             ))
         })
     }
@@ -150,6 +170,13 @@ impl super::settings::ApiKeys {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
 
+        // The client IP address cannot be found without a proxy which sets `X-Forwarded-For`
+        when(self.trusted_proxy_hops == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "trusted proxy hops of API keys must be greater than 0".into(),
+            ))
+        })?;
+
         #[cfg(feature = "kms")]
         return when(self.kms_encrypted_hash_key.is_default_or_empty(), || {
             Err(ApplicationError::InvalidConfigurationValueError(
@@ -159,6 +159,15 @@ pub struct AdyenPaymentRequest<'a> {
     country_code: Option<api_enums::CountryAlpha2>,
     line_items: Option<Vec<LineItem>>,
     channel: Option<Channel>,
+    installments: Option<AdyenInstallments>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AdyenInstallments {
+    value: u8,
+    plan: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -1029,6 +1038,8 @@ pub struct AdyenRefundRequest {
     amount: Amount,
     merchant_refund_reason: Option<String>,
     reference: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    capture_psp_reference: Option<String>,
 }
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
@@ -1488,7 +1499,11 @@ fn get_additional_data(item: &types::PaymentsAuthorizeRouterData) -> Option<Addi
     match item.request.capture_method {
         Some(diesel_models::enums::CaptureMethod::Manual)
         | Some(diesel_models::enums::CaptureMethod::ManualMultiple) => Some(AdditionalData {
-            authorisation_type: Some(AuthType::PreAuth),
+            // A pre-authorisation is required for the authorised amount to be adjusted later
+            authorisation_type: item
+                .request
+                .request_incremental_authorization
+                .then_some(AuthType::PreAuth),
             manual_capture: Some(true),
             network_tx_reference: None,
             recurring_detail_reference: None,
@@ -1500,6 +1515,16 @@ fn get_additional_data(item: &types::PaymentsAuthorizeRouterData) -> Option<Addi
     }
 }
 
+fn get_installments(item: &types::PaymentsAuthorizeRouterData) -> Option<AdyenInstallments> {
+    item.request
+        .installment_data
+        .as_ref()
+        .map(|installment_data| AdyenInstallments {
+            value: installment_data.number_of_installments,
+            plan: installment_data.plan_id.clone(),
+        })
+}
+
 fn get_channel_type(pm_type: &Option<storage_enums::PaymentMethodType>) -> Option<Channel> {
     pm_type.as_ref().and_then(|pmt| match pmt {
         storage_enums::PaymentMethodType::GoPay => Some(Channel::Web),
@@ -2301,6 +2326,7 @@ impl<'a>
             shopper_reference,
             store_payment_method,
             channel: None,
+            installments: None,
         })
     }
 }
@@ -2327,6 +2353,7 @@ impl<'a>
         let additional_data = get_additional_data(item.router_data);
         let return_url = item.router_data.request.get_return_url()?;
         let payment_method = AdyenPaymentMethod::try_from(card_data)?;
+        let installments = get_installments(item.router_data);
         Ok(AdyenPaymentRequest {
             amount,
             merchant_account: auth_type.merchant_account,
@@ -2349,6 +2376,7 @@ impl<'a>
             shopper_reference,
             store_payment_method,
             channel: None,
+            installments,
         })
     }
 }
@@ -2399,6 +2427,7 @@ impl<'a>
             shopper_reference: None,
             store_payment_method: None,
             channel: None,
+            installments: None,
         };
         Ok(request)
     }
@@ -2450,6 +2479,7 @@ impl<'a>
             shopper_reference: None,
             store_payment_method: None,
             channel: None,
+            installments: None,
         };
         Ok(request)
     }
@@ -2497,6 +2527,7 @@ impl<'a>
             shopper_reference: None,
             store_payment_method: None,
             channel: None,
+            installments: None,
         };
         Ok(request)
     }
@@ -2544,6 +2575,7 @@ impl<'a>
             store_payment_method: None,
             channel: None,
             social_security_number: None,
+            installments: None,
         };
         Ok(request)
     }
@@ -2597,6 +2629,7 @@ impl<'a>
             shopper_reference,
             store_payment_method,
             channel: None,
+            installments: None,
         })
     }
 }
@@ -2691,6 +2724,7 @@ impl<'a>
             shopper_reference,
             store_payment_method,
             channel,
+            installments: None,
         })
     }
 }
@@ -2748,6 +2782,7 @@ impl<'a>
             shopper_reference,
             store_payment_method,
             channel: None,
+            installments: None,
         })
     }
 }
@@ -2803,6 +2838,7 @@ impl<'a>
             store_payment_method: None,
             channel: None,
             social_security_number: None,
+            installments: None,
         })
     }
 }
@@ -3488,6 +3524,94 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<AdyenCaptureResponse>>
     }
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenIncrementalAuthorizationRequest {
+    merchant_account: Secret<String>,
+    amount: Amount,
+    reference: String,
+    industry_usage: AdyenIndustryUsage,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AdyenIndustryUsage {
+    DelayedCharge,
+}
+
+impl TryFrom<&AdyenRouterData<&types::PaymentsIncrementalAuthorizationRouterData>>
+    for AdyenIncrementalAuthorizationRequest
+{
+    type Error = Error;
+    fn try_from(
+        item: &AdyenRouterData<&types::PaymentsIncrementalAuthorizationRouterData>,
+    ) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.router_data.connector_auth_type)?;
+        Ok(Self {
+            merchant_account: auth_type.merchant_account,
+            // Adyen expects the new total amount of the authorization, not the increment
+            amount: Amount {
+                currency: item.router_data.request.currency.to_string(),
+                value: item.amount.to_owned(),
+            },
+            reference: item.router_data.connector_request_reference_id.clone(),
+            industry_usage: AdyenIndustryUsage::DelayedCharge,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenIncrementalAuthorizationResponse {
+    psp_reference: String,
+    status: String,
+}
+
+impl<F>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            AdyenIncrementalAuthorizationResponse,
+            types::PaymentsIncrementalAuthorizationData,
+            types::PaymentsResponseData,
+        >,
+    >
+    for types::RouterData<
+        F,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    >
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            AdyenIncrementalAuthorizationResponse,
+            types::PaymentsIncrementalAuthorizationData,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        // The only value returned is "received", the outcome of the amount update is sent
+        // through the AUTHORISATION_ADJUSTMENT webhook
+        // For more info: https://docs.adyen.com/online-payments/adjust-authorisation
+        let status = match item.response.status.as_str() {
+            "received" => storage_enums::AuthorizationStatus::Processing,
+            _ => storage_enums::AuthorizationStatus::Unresolved,
+        };
+        Ok(Self {
+            response: Ok(
+                types::PaymentsResponseData::IncrementalAuthorizationResponse {
+                    status,
+                    connector_authorization_id: Some(item.response.psp_reference),
+                    error_code: None,
+                    error_message: None,
+                },
+            ),
+            ..item.data
+        })
+    }
+}
+
 /*
 // This is a repeated code block from Stripe inegration. Can we avoid the repetition in every integration
 #[derive(Debug, Serialize, Deserialize)]
@@ -3534,6 +3658,7 @@ impl<F> TryFrom<&AdyenRouterData<&types::RefundsRouterData<F>>> for AdyenRefundR
             },
             merchant_refund_reason: item.router_data.request.reason.clone(),
             reference: item.router_data.request.refund_id.clone(),
+            capture_psp_reference: item.router_data.request.connector_capture_id.clone(),
         })
     }
 }
@@ -3630,6 +3755,11 @@ pub enum WebhookEventCode {
     PrearbitrationLost,
     Capture,
     CaptureFailed,
+    PayoutThirdparty,
+    PayoutDecline,
+    PayoutExpire,
+    PaidoutReversed,
+    AuthorisationAdjustment,
     #[serde(other)]
     Unknown,
 }
@@ -3645,6 +3775,10 @@ pub fn is_capture_event(event_code: &WebhookEventCode) -> bool {
     )
 }
 
+pub fn is_authorisation_adjustment_event(event_code: &WebhookEventCode) -> bool {
+    matches!(event_code, WebhookEventCode::AuthorisationAdjustment)
+}
+
 pub fn is_refund_event(event_code: &WebhookEventCode) -> bool {
     matches!(
         event_code,
@@ -3666,6 +3800,16 @@ pub fn is_chargeback_event(event_code: &WebhookEventCode) -> bool {
     )
 }
 
+pub fn is_payout_event(event_code: &WebhookEventCode) -> bool {
+    matches!(
+        event_code,
+        WebhookEventCode::PayoutThirdparty
+            | WebhookEventCode::PayoutDecline
+            | WebhookEventCode::PayoutExpire
+            | WebhookEventCode::PaidoutReversed
+    )
+}
+
 impl ForeignFrom<(WebhookEventCode, Option<DisputeStatus>)> for webhooks::IncomingWebhookEvent {
     fn foreign_from((code, status): (WebhookEventCode, Option<DisputeStatus>)) -> Self {
         match (code, status) {
@@ -3691,6 +3835,13 @@ impl ForeignFrom<(WebhookEventCode, Option<DisputeStatus>)> for webhooks::Incomi
             (WebhookEventCode::Unknown, _) => Self::EventNotSupported,
             (WebhookEventCode::Capture, _) => Self::PaymentIntentSuccess,
             (WebhookEventCode::CaptureFailed, _) => Self::PaymentIntentFailure,
+            (WebhookEventCode::PayoutThirdparty, _) => Self::PayoutSuccess,
+            (WebhookEventCode::PayoutDecline, _) => Self::PayoutFailure,
+            (WebhookEventCode::PayoutExpire, _) => Self::PayoutExpired,
+            (WebhookEventCode::PaidoutReversed, _) => Self::PayoutReversed,
+            (WebhookEventCode::AuthorisationAdjustment, _) => {
+                Self::PaymentIntentAuthorizationSuccess
+            }
         }
     }
 }
@@ -814,6 +814,82 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<PaymentCaptureResponse>>
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct CheckoutIncrementalAuthorizationRequest {
+    /// The amount by which the authorization is increased
+    pub amount: i64,
+    pub reference: Option<String>,
+}
+
+impl TryFrom<&CheckoutRouterData<&types::PaymentsIncrementalAuthorizationRouterData>>
+    for CheckoutIncrementalAuthorizationRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &CheckoutRouterData<&types::PaymentsIncrementalAuthorizationRouterData>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.amount,
+            reference: item.router_data.request.reason.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutIncrementalAuthorizationResponse {
+    pub action_id: String,
+    pub approved: Option<bool>,
+    pub response_code: Option<String>,
+    pub response_summary: Option<String>,
+}
+
+impl<F>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            CheckoutIncrementalAuthorizationResponse,
+            types::PaymentsIncrementalAuthorizationData,
+            types::PaymentsResponseData,
+        >,
+    >
+    for types::RouterData<
+        F,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    >
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            CheckoutIncrementalAuthorizationResponse,
+            types::PaymentsIncrementalAuthorizationData,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let (status, error_code, error_message) = match item.response.approved {
+            Some(true) => (enums::AuthorizationStatus::Success, None, None),
+            Some(false) => (
+                enums::AuthorizationStatus::Failure,
+                item.response.response_code,
+                item.response.response_summary,
+            ),
+            None => (enums::AuthorizationStatus::Unresolved, None, None),
+        };
+        Ok(Self {
+            response: Ok(
+                types::PaymentsResponseData::IncrementalAuthorizationResponse {
+                    status,
+                    connector_authorization_id: Some(item.response.action_id),
+                    error_code,
+                    error_message,
+                },
+            ),
+            ..item.data
+        })
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RefundRequest {
     amount: Option<i64>,
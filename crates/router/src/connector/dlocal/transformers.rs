@@ -112,6 +112,7 @@ impl TryFrom<&DlocalRouterData<&types::PaymentsAuthorizeRouterData>> for DlocalP
                     item.router_data.request.capture_method,
                     Some(enums::CaptureMethod::Automatic)
                 );
+                let installment_data = item.router_data.request.installment_data.as_ref();
                 let payment_request = Self {
                     amount: item.amount,
                     currency: item.router_data.request.currency,
@@ -131,19 +132,27 @@ impl TryFrom<&DlocalRouterData<&types::PaymentsAuthorizeRouterData>> for DlocalP
                         expiration_month: ccard.card_exp_month.clone(),
                         expiration_year: ccard.card_exp_year.clone(),
                         capture: should_capture.to_string(),
-                        installments_id: item
-                            .router_data
-                            .request
-                            .mandate_id
-                            .as_ref()
-                            .map(|ids| ids.mandate_id.clone()),
+                        installments_id: installment_data
+                            .and_then(|installment_data| installment_data.plan_id.clone())
+                            .or_else(|| {
+                                item.router_data
+                                    .request
+                                    .mandate_id
+                                    .as_ref()
+                                    .map(|ids| ids.mandate_id.clone())
+                            }),
                         // [#595[FEATURE] Pass Mandate history information in payment flows/request]
-                        installments: item
-                            .router_data
-                            .request
-                            .mandate_id
-                            .clone()
-                            .map(|_| "1".to_string()),
+                        installments: installment_data
+                            .map(|installment_data| {
+                                installment_data.number_of_installments.to_string()
+                            })
+                            .or_else(|| {
+                                item.router_data
+                                    .request
+                                    .mandate_id
+                                    .clone()
+                                    .map(|_| "1".to_string())
+                            }),
                     }),
                     order_id: item.router_data.payment_id.clone(),
                     three_dsecure: match item.router_data.auth_type {
@@ -123,6 +123,14 @@ pub struct PaymentIntentRequest {
     pub off_session: Option<bool>,
     #[serde(rename = "payment_method_types[0]")]
     pub payment_method_types: Option<StripePaymentMethodType>,
+    #[serde(rename = "payment_method_options[card][request_incremental_authorization]")]
+    pub request_incremental_authorization: Option<StripeRequestIncrementalAuthorization>,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeRequestIncrementalAuthorization {
+    IfAvailable,
 }
 
 // Field rename is required only in case of serialization as it is passed in the request to the connector.
@@ -1892,6 +1900,10 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
             off_session: item.request.off_session,
             setup_future_usage: item.request.setup_future_usage,
             payment_method_types,
+            request_incremental_authorization: item
+                .request
+                .request_incremental_authorization
+                .then_some(StripeRequestIncrementalAuthorization::IfAvailable),
         })
     }
 }
@@ -2912,6 +2924,80 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for CaptureRequest {
     }
 }
 
+/// Represents the request body to increase the authorized amount of a payment intent.
+#[derive(Debug, Serialize)]
+pub struct StripeIncrementalAuthRequest {
+    /// The total amount that should be authorized, including the amount authorized earlier
+    amount: i64,
+    description: Option<String>,
+}
+
+impl TryFrom<&types::PaymentsIncrementalAuthorizationRouterData> for StripeIncrementalAuthRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.total_amount,
+            description: item.request.reason.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeIncrementalAuthResponse {
+    pub id: String,
+    pub amount: i64,
+    pub status: StripePaymentStatus,
+}
+
+impl<F>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            StripeIncrementalAuthResponse,
+            types::PaymentsIncrementalAuthorizationData,
+            types::PaymentsResponseData,
+        >,
+    >
+    for types::RouterData<
+        F,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    >
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            StripeIncrementalAuthResponse,
+            types::PaymentsIncrementalAuthorizationData,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        // Stripe keeps the payment intent in requires_capture and only updates the amount when
+        // the increment has been approved by the issuer
+        let status = if item.response.status == StripePaymentStatus::RequiresCapture
+            && item.response.amount == item.data.request.total_amount
+        {
+            enums::AuthorizationStatus::Success
+        } else {
+            enums::AuthorizationStatus::Failure
+        };
+        Ok(Self {
+            response: Ok(
+                types::PaymentsResponseData::IncrementalAuthorizationResponse {
+                    status,
+                    connector_authorization_id: Some(item.response.id),
+                    error_code: None,
+                    error_message: None,
+                },
+            ),
+            ..item.data
+        })
+    }
+}
+
 impl TryFrom<&types::PaymentsPreProcessingRouterData> for StripeCreditTransferSourceRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::PaymentsPreProcessingRouterData) -> Result<Self, Self::Error> {
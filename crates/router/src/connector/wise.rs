@@ -1,15 +1,19 @@
 pub mod transformers;
 use std::fmt::Debug;
 
+use base64::Engine;
 use error_stack::{IntoReport, ResultExt};
 #[cfg(feature = "payouts")]
 use masking::PeekInterface;
+use openssl::{hash::MessageDigest, pkey::PKey, sign::Verifier};
 #[cfg(feature = "payouts")]
 use router_env::{instrument, tracing};
 
 use self::transformers as wise;
 use crate::{
     configs::settings,
+    connector::utils as conn_utils,
+    consts,
     core::errors::{self, CustomResult},
     headers,
     services::{
@@ -20,6 +24,7 @@ use crate::{
     types::{
         self,
         api::{self, ConnectorCommon, ConnectorCommonExt},
+        domain,
     },
     utils::BytesExt,
 };
@@ -122,7 +127,11 @@ impl api::PaymentCapture for Wise {}
 impl api::MandateSetup for Wise {}
 impl api::ConnectorAccessToken for Wise {}
 impl api::PaymentToken for Wise {}
-impl ConnectorValidation for Wise {}
+impl ConnectorValidation for Wise {
+    fn is_webhook_source_verification_mandatory(&self) -> bool {
+        true
+    }
+}
 
 impl
     services::ConnectorIntegration<
@@ -677,24 +686,108 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Wise {
+    fn get_webhook_source_verification_signature(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _connector_webhook_secrets: &api_models::webhooks::ConnectorWebhookSecrets,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let signature = conn_utils::get_header_key_value("X-Signature-SHA256", request.headers)?;
+        consts::BASE64_ENGINE
+            .decode(signature)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _merchant_id: &str,
+        _connector_webhook_secrets: &api_models::webhooks::ConnectorWebhookSecrets,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        Ok(request.body.to_vec())
+    }
+
+    /// Wise signs the webhook body with its private key (RSA-SHA256), the public key of Wise is
+    /// configured as the merchant secret in the webhook details of the connector account
+    async fn verify_webhook_source(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        merchant_account: &domain::MerchantAccount,
+        merchant_connector_account: domain::MerchantConnectorAccount,
+        connector_label: &str,
+    ) -> CustomResult<bool, errors::ConnectorError> {
+        let connector_webhook_secrets = self
+            .get_webhook_source_verification_merchant_secret(
+                merchant_account,
+                connector_label,
+                merchant_connector_account,
+            )
+            .await
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+
+        let signature = self
+            .get_webhook_source_verification_signature(request, &connector_webhook_secrets)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+
+        let message = self
+            .get_webhook_source_verification_message(
+                request,
+                &merchant_account.merchant_id,
+                &connector_webhook_secrets,
+            )
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+
+        let public_key = PKey::public_key_from_pem(&connector_webhook_secrets.secret)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)
+            .attach_printable("Failed to parse the public key of Wise")?;
+        let mut verifier = Verifier::new(MessageDigest::sha256(), &public_key)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        verifier
+            .verify_oneshot(&signature, &message)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)
+    }
+
     fn get_webhook_object_reference_id(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api_models::webhooks::ObjectReferenceId, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let webhook_body: wise::WiseWebhookBody = request
+            .body
+            .parse_struct("WiseWebhookBody")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+        Ok(api_models::webhooks::ObjectReferenceId::PayoutId(
+            api_models::webhooks::PayoutIdType::ConnectorPayoutId(
+                webhook_body.data.resource.id.to_string(),
+            ),
+        ))
     }
 
     fn get_webhook_event_type(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let webhook_body: wise::WiseWebhookBody = request
+            .body
+            .parse_struct("WiseWebhookBody")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+        match webhook_body.event_type {
+            wise::WiseWebhookEventType::TransferStateChange => Ok(api::IncomingWebhookEvent::from(
+                webhook_body.data.current_state,
+            )),
+            wise::WiseWebhookEventType::Unknown => Ok(api::IncomingWebhookEvent::EventNotSupported),
+        }
     }
 
     fn get_webhook_resource_object(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        request
+            .body
+            .parse_struct("serde_json::Value")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)
     }
 }
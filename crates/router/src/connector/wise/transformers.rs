@@ -607,3 +607,60 @@ impl TryFrom<PayoutMethodData> for RecipientType {
         }
     }
 }
+
+#[derive(Debug, Deserialize)]
+pub struct WiseWebhookBody {
+    pub event_type: WiseWebhookEventType,
+    pub data: WiseWebhookData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WiseWebhookData {
+    pub resource: WiseWebhookResource,
+    pub current_state: WiseWebhookTransferState,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WiseWebhookResource {
+    pub id: i64,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub enum WiseWebhookEventType {
+    #[serde(rename = "transfers#state-change")]
+    TransferStateChange,
+    #[serde(other)]
+    Unknown,
+}
+
+// https://docs.wise.com/api-docs/guides/send-money/tracking#transfer-statuses
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WiseWebhookTransferState {
+    IncomingPaymentWaiting,
+    Processing,
+    FundsConverted,
+    OutgoingPaymentSent,
+    Cancelled,
+    FundsRefunded,
+    BouncedBack,
+    ChargedBack,
+    #[serde(other)]
+    Unknown,
+}
+
+impl From<WiseWebhookTransferState> for api_models::webhooks::IncomingWebhookEvent {
+    fn from(state: WiseWebhookTransferState) -> Self {
+        match state {
+            WiseWebhookTransferState::IncomingPaymentWaiting
+            | WiseWebhookTransferState::Processing
+            | WiseWebhookTransferState::FundsConverted => Self::PayoutProcessing,
+            WiseWebhookTransferState::OutgoingPaymentSent => Self::PayoutSuccess,
+            WiseWebhookTransferState::Cancelled => Self::PayoutCancelled,
+            WiseWebhookTransferState::FundsRefunded
+            | WiseWebhookTransferState::BouncedBack
+            | WiseWebhookTransferState::ChargedBack => Self::PayoutReversed,
+            WiseWebhookTransferState::Unknown => Self::EventNotSupported,
+        }
+    }
+}
@@ -46,3 +46,10 @@ pub(crate) const QR_IMAGE_DATA_SOURCE_STRING: &str = "data:image/png;base64";
 pub(crate) const MERCHANT_ID_FIELD_EXTENSION_ID: &str = "1.2.840.113635.100.6.32";
 
 pub(crate) const METRICS_HOST_TAG_NAME: &str = "host";
+
+/// Maximum length of the time range of dispute analytics (in days)
+pub(crate) const MAX_DISPUTE_ANALYTICS_TIME_RANGE_DAYS: i64 = 90;
+/// Maximum number of disputes in a dispute export
+pub(crate) const MAX_DISPUTE_EXPORT_LIMIT: i64 = 10_000;
+/// Maximum number of events in a single page of the event list
+pub(crate) const MAX_EVENT_LIST_LIMIT: i64 = 100;
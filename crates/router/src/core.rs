@@ -8,6 +8,7 @@ pub mod customers;
 pub mod disputes;
 pub mod errors;
 pub mod files;
+pub mod fraud_check;
 pub mod mandate;
 pub mod metrics;
 pub mod payment_link;
@@ -16,6 +17,8 @@ pub mod payments;
 #[cfg(feature = "payouts")]
 pub mod payouts;
 pub mod refunds;
+pub mod routing;
+pub mod subscriptions;
 pub mod utils;
 #[cfg(all(feature = "olap", feature = "kms"))]
 pub mod verification;
@@ -5,16 +5,18 @@ use common_utils::{
     ext_traits::{AsyncExt, ConfigExt, Encode, ValueExt},
     pii,
 };
-use error_stack::{report, FutureExt, ResultExt};
+use error_stack::{report, FutureExt, IntoReport, ResultExt};
 use masking::{PeekInterface, Secret};
+use scheduler::{db::process_tracker::ProcessTrackerExt, utils as pt_utils};
 use uuid::Uuid;
 
 use crate::{
     consts,
     core::{
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        fraud_check, payment_link,
         payments::helpers,
-        utils as core_utils,
+        routing, utils as core_utils,
     },
     db::StorageInterface,
     routes::{metrics, AppState},
@@ -29,6 +31,7 @@ use crate::{
         transformers::ForeignTryFrom,
     },
     utils::{self, OptionExt},
+    workflows::merchant_key_rotation,
 };
 
 const DEFAULT_ORG_ID: &str = "org_abcdefghijklmn";
@@ -87,13 +90,11 @@ pub async fn create_merchant_account(
             .transpose()?;
 
     if let Some(ref routing_algorithm) = req.routing_algorithm {
-        let _: api::RoutingAlgorithm = routing_algorithm
-            .clone()
-            .parse_value("RoutingAlgorithm")
-            .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                field_name: "routing_algorithm",
-            })
-            .attach_printable("Invalid routing algorithm given")?;
+        routing::validate_routing_algorithm(routing_algorithm)?;
+    }
+
+    if let Some(ref frm_routing_algorithm) = req.frm_routing_algorithm {
+        fraud_check::validate_frm_routing_algorithm(frm_routing_algorithm)?;
     }
 
     let key_store = domain::MerchantKeyStore {
@@ -103,6 +104,8 @@ pub async fn create_merchant_account(
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to decrypt data from key store")?,
         created_at: date_time::now(),
+        version: 1,
+        previous_key: None,
     };
 
     let enable_payment_response_hash = req.enable_payment_response_hash.unwrap_or(true);
@@ -135,6 +138,10 @@ pub async fn create_merchant_account(
         .transpose()?
         .map(Secret::new);
 
+    if let Some(ref payment_link_config) = req.payment_link_config {
+        payment_link::validate_payment_link_config(payment_link_config)?;
+    }
+
     let payment_link_config = req
         .payment_link_config
         .as_ref()
@@ -390,13 +397,22 @@ pub async fn merchant_account_update(
     }
 
     if let Some(ref routing_algorithm) = req.routing_algorithm {
-        let _: api::RoutingAlgorithm = routing_algorithm
+        routing::validate_routing_algorithm(routing_algorithm)?;
+    }
+
+    if let Some(ref frm_routing_algorithm) = req.frm_routing_algorithm {
+        fraud_check::validate_frm_routing_algorithm(frm_routing_algorithm)?;
+    }
+
+    if let Some(ref payment_link_config) = req.payment_link_config {
+        let payment_link_config = payment_link_config
             .clone()
-            .parse_value("RoutingAlgorithm")
-            .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                field_name: "routing_algorithm",
-            })
-            .attach_printable("Invalid routing algorithm given")?;
+            .parse_value::<admin_types::PaymentLinkConfig>("PaymentLinkConfig")
+            .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "payment_link_config".to_string(),
+                expected_format: "payment link config".to_string(),
+            })?;
+        payment_link::validate_payment_link_config(&payment_link_config)?;
     }
 
     let primary_business_details = req
@@ -1103,6 +1119,155 @@ pub async fn check_merchant_account_kv_status(
     ))
 }
 
+/// Rotate the data encryption key of the merchant. The key which was in use until now is kept as
+/// the previous key, and a process tracker task is scheduled to re-encrypt the merchant data with
+/// the new key.
+pub async fn rotate_merchant_key(
+    state: AppState,
+    merchant_id: String,
+) -> RouterResponse<api_models::admin::MerchantKeyRotationResponse> {
+    let db = state.store.as_ref();
+    let master_key = db.get_master_key();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &master_key.to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    // Only one previous key is kept, so the key cannot be rotated again while any data is still
+    // encrypted with the previous key
+    let rotation_task = db
+        .find_process_by_id(&get_key_rotation_task_id(&key_store))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant key rotation task")?;
+    match rotation_task {
+        Some(rotation_task)
+            if rotation_task.business_status
+                == merchant_key_rotation::KEY_ROTATION_COMPLETED_STATUS =>
+        {
+            // Bank details saved for payouts are encrypted with the merchant key and stored in
+            // the locker, where the rotation task does not re-encrypt them. Those saved before
+            // the last rotation are still encrypted with the previous key, which would be
+            // discarded by rotating the key again.
+            let payout_methods_with_previous_key = db
+                .find_payment_methods_by_merchant_id_payment_method_created_before(
+                    &merchant_id,
+                    storage::enums::PaymentMethod::BankTransfer,
+                    rotation_task.created_at,
+                    1,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the payout methods saved in the locker")?;
+            if !payout_methods_with_previous_key.is_empty() {
+                return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "The merchant key cannot be rotated again while payout methods \
+                              saved in the locker are encrypted with the previous key"
+                        .to_string(),
+                }));
+            }
+        }
+        Some(rotation_task) => {
+            // A task which failed is resumed from the last batch it had re-encrypted
+            if rotation_task.status == storage::enums::ProcessTrackerStatus::Finish {
+                rotation_task
+                    .reset(db.as_scheduler(), date_time::now())
+                    .await
+                    .into_report()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to resume the merchant key rotation task")?;
+            }
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message:
+                    "Re-encryption of the merchant data with the current key is still in progress"
+                        .to_string(),
+            }));
+        }
+        // The data encrypted with the previous key is never re-encrypted if the task is missing,
+        // so it is scheduled again
+        None if key_store.previous_key.is_some() => {
+            add_key_rotation_task(db, &key_store)
+                .await
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to schedule the merchant key rotation task")?;
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message:
+                    "Re-encryption of the merchant data with the current key is still in progress"
+                        .to_string(),
+            }));
+        }
+        None => {}
+    }
+
+    let key = services::generate_aes256_key()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to generate aes 256 key")?;
+
+    let key_store = db
+        .update_merchant_key_store_by_merchant_id(
+            &merchant_id,
+            domain::MerchantKeyStoreUpdate::KeyRotation {
+                key: domain_types::encrypt(key.to_vec().into(), master_key)
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to encrypt the new merchant key")?,
+                previous_key: key_store.key,
+                version: key_store.version + 1,
+            },
+            &master_key.to_vec().into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the merchant key store")?;
+
+    add_key_rotation_task(db, &key_store)
+        .await
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to schedule the merchant key rotation task")?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        api_models::admin::MerchantKeyRotationResponse {
+            merchant_id: key_store.merchant_id,
+            key_version: key_store.version,
+        },
+    ))
+}
+
+pub fn get_key_rotation_task_id(key_store: &domain::MerchantKeyStore) -> String {
+    pt_utils::get_process_tracker_id(
+        merchant_key_rotation::KEY_ROTATION_WORKFLOW,
+        merchant_key_rotation::KEY_ROTATION_TASK,
+        &key_store.version.to_string(),
+        &key_store.merchant_id,
+    )
+}
+
+/// Schedules the task which re-encrypts the merchant data with the current key of the merchant,
+/// starting right away
+pub async fn add_key_rotation_task(
+    db: &dyn StorageInterface,
+    key_store: &domain::MerchantKeyStore,
+) -> Result<(), scheduler::errors::ProcessTrackerError> {
+    let tracking_data = storage::KeyRotationTrackingData {
+        merchant_id: key_store.merchant_id.clone(),
+        version: key_store.version,
+        stage: storage::KeyRotationStage::MerchantAccount,
+        last_processed_id: None,
+    };
+    let process_tracker_entry = <storage::ProcessTracker>::make_process_tracker_new(
+        get_key_rotation_task_id(key_store),
+        merchant_key_rotation::KEY_ROTATION_TASK,
+        merchant_key_rotation::KEY_ROTATION_WORKFLOW,
+        tracking_data,
+        date_time::now(),
+    )?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
 pub fn get_frm_config_as_secret(
     frm_configs: Option<Vec<api_models::admin::FrmConfigs>>,
 ) -> Option<Vec<Secret<serde_json::Value>>> {
@@ -1164,13 +1329,15 @@ pub async fn create_business_profile(
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
     if let Some(ref routing_algorithm) = request.routing_algorithm {
-        let _: api::RoutingAlgorithm = routing_algorithm
-            .clone()
-            .parse_value("RoutingAlgorithm")
-            .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                field_name: "routing_algorithm",
-            })
-            .attach_printable("Invalid routing algorithm given")?;
+        routing::validate_routing_algorithm(routing_algorithm)?;
+    }
+
+    if let Some(ref frm_routing_algorithm) = request.frm_routing_algorithm {
+        fraud_check::validate_frm_routing_algorithm(frm_routing_algorithm)?;
+    }
+
+    if let Some(ref payment_link_config) = request.payment_link_config {
+        payment_link::validate_payment_link_config(payment_link_config)?;
     }
 
     let business_profile =
@@ -1275,14 +1442,38 @@ pub async fn update_business_profile(
         })
         .transpose()?;
 
+    let dispute_policy = request
+        .dispute_policy
+        .as_ref()
+        .map(|dispute_policy| {
+            utils::Encode::<api_models::disputes::DisputePolicy>::encode_to_value(dispute_policy)
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "dispute policy",
+                })
+        })
+        .transpose()?;
+
+    if let Some(ref payment_link_config) = request.payment_link_config {
+        payment_link::validate_payment_link_config(payment_link_config)?;
+    }
+
+    let payment_link_config = request
+        .payment_link_config
+        .as_ref()
+        .map(|payment_link_config| {
+            utils::Encode::<admin_types::PaymentLinkConfig>::encode_to_value(payment_link_config)
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "payment_link_config",
+                })
+        })
+        .transpose()?;
+
     if let Some(ref routing_algorithm) = request.routing_algorithm {
-        let _: api::RoutingAlgorithm = routing_algorithm
-            .clone()
-            .parse_value("RoutingAlgorithm")
-            .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                field_name: "routing_algorithm",
-            })
-            .attach_printable("Invalid routing algorithm given")?;
+        routing::validate_routing_algorithm(routing_algorithm)?;
+    }
+
+    if let Some(ref frm_routing_algorithm) = request.frm_routing_algorithm {
+        fraud_check::validate_frm_routing_algorithm(frm_routing_algorithm)?;
     }
 
     let business_profile_update = storage::business_profile::BusinessProfileUpdateInternal {
@@ -1300,6 +1491,8 @@ pub async fn update_business_profile(
         payout_routing_algorithm: request.payout_routing_algorithm,
         is_recon_enabled: None,
         applepay_verified_domains: request.applepay_verified_domains,
+        dispute_policy,
+        payment_link_config,
     };
 
     let updated_business_profile = db
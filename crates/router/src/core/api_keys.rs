@@ -1,3 +1,5 @@
+use std::net::IpAddr;
+
 use common_utils::date_time;
 #[cfg(feature = "email")]
 use diesel_models::{api_keys::ApiKey, enums as storage_enums};
@@ -135,6 +137,9 @@ pub async fn create_api_key(
     api_key: api::CreateApiKeyRequest,
     merchant_id: String,
 ) -> RouterResponse<api::CreateApiKeyResponse> {
+    validate_api_key_scopes(api_key.scopes.as_deref())?;
+    validate_api_key_allowed_ips(api_key.allowed_ips.as_deref())?;
+
     let api_key_config = &state.conf.api_keys;
     let store = state.store.as_ref();
     // We are not fetching merchant account as the merchant key store is needed to search for a
@@ -166,6 +171,12 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        scopes: api_key
+            .scopes
+            .map(|scopes| scopes.iter().map(ToString::to_string).collect()),
+        allowed_ips: api_key
+            .allowed_ips
+            .filter(|allowed_ips| !allowed_ips.is_empty()),
     };
 
     let api_key = store
@@ -298,6 +309,9 @@ pub async fn update_api_key(
     key_id: &str,
     api_key: api::UpdateApiKeyRequest,
 ) -> RouterResponse<api::RetrieveApiKeyResponse> {
+    validate_api_key_scopes(api_key.scopes.as_deref())?;
+    validate_api_key_allowed_ips(api_key.allowed_ips.as_deref())?;
+
     let store = state.store.as_ref();
 
     let api_key = store
@@ -517,6 +531,33 @@ pub async fn list_api_keys(
     Ok(ApplicationResponse::Json(api_keys))
 }
 
+// An API key restricted to an empty list of scopes would not be permitted to make any request
+fn validate_api_key_scopes(scopes: Option<&[api::ApiKeyScope]>) -> errors::RouterResult<()> {
+    utils::when(scopes.map_or(false, |scopes| scopes.is_empty()), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "scopes must not be empty".to_string(),
+        })
+        .into_report()
+    })
+}
+
+// The IP addresses are compared with the client IP address when the API key is used, so any
+// entry which cannot be parsed would never match
+fn validate_api_key_allowed_ips(allowed_ips: Option<&[String]>) -> errors::RouterResult<()> {
+    let invalid_ip = allowed_ips.and_then(|allowed_ips| {
+        allowed_ips
+            .iter()
+            .find(|allowed_ip| allowed_ip.parse::<IpAddr>().is_err())
+    });
+    match invalid_ip {
+        Some(invalid_ip) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("`{invalid_ip}` in allowed_ips is not a valid IP address"),
+        })
+        .into_report(),
+        None => Ok(()),
+    }
+}
+
 #[cfg(feature = "email")]
 fn generate_task_id_for_api_key_expiry_workflow(key_id: &str) -> String {
     format!("{API_KEY_EXPIRY_RUNNER}_{API_KEY_EXPIRY_NAME}_{key_id}")
@@ -1,7 +1,11 @@
+use std::collections::BTreeMap;
+
 use api_models::{disputes as dispute_models, files as files_api_models};
 use common_utils::ext_traits::ValueExt;
 use error_stack::{IntoReport, ResultExt};
 use router_env::{instrument, tracing};
+use scheduler::{db::process_tracker::ProcessTrackerExt, utils as pt_utils};
+use time::PrimitiveDateTime;
 pub mod transformers;
 
 use super::{
@@ -9,18 +13,21 @@ use super::{
     metrics,
 };
 use crate::{
+    consts,
     core::{files, payments, utils as core_utils},
+    db::StorageInterface,
     routes::AppState,
     services,
     types::{
         api::{self, disputes},
         domain,
-        storage::enums as storage_enums,
+        storage::{self, enums as storage_enums},
         transformers::ForeignFrom,
         AcceptDisputeRequestData, AcceptDisputeResponse, DefendDisputeRequestData,
         DefendDisputeResponse, SubmitEvidenceRequestData, SubmitEvidenceResponse,
     },
     utils,
+    workflows::dispute_deadline,
 };
 
 #[instrument(skip(state))]
@@ -31,7 +38,11 @@ pub async fn retrieve_dispute(
 ) -> RouterResponse<api_models::disputes::DisputeResponse> {
     let dispute = state
         .store
-        .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, &req.dispute_id)
+        .find_dispute_by_merchant_id_dispute_id(
+            &merchant_account.merchant_id,
+            &req.dispute_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: req.dispute_id,
@@ -69,7 +80,11 @@ pub async fn accept_dispute(
     let db = &state.store;
     let dispute = state
         .store
-        .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, &req.dispute_id)
+        .find_dispute_by_merchant_id_dispute_id(
+            &merchant_account.merchant_id,
+            &req.dispute_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: req.dispute_id,
@@ -149,7 +164,11 @@ pub async fn accept_dispute(
         connector_status: accept_dispute_response.connector_status.clone(),
     };
     let updated_dispute = db
-        .update_dispute(dispute.clone(), update_dispute)
+        .update_dispute(
+            dispute.clone(),
+            update_dispute,
+            merchant_account.storage_scheme,
+        )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable_lazy(|| {
@@ -169,7 +188,11 @@ pub async fn submit_evidence(
     let db = &state.store;
     let dispute = state
         .store
-        .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, &req.dispute_id)
+        .find_dispute_by_merchant_id_dispute_id(
+            &merchant_account.merchant_id,
+            &req.dispute_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: req.dispute_id.clone(),
@@ -309,7 +332,11 @@ pub async fn submit_evidence(
         connector_status,
     };
     let updated_dispute = db
-        .update_dispute(dispute.clone(), update_dispute)
+        .update_dispute(
+            dispute.clone(),
+            update_dispute,
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: dispute_id.to_owned(),
@@ -334,7 +361,11 @@ pub async fn attach_evidence(
         .clone()
         .ok_or(errors::ApiErrorResponse::MissingDisputeId)?;
     let dispute = db
-        .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, &dispute_id)
+        .find_dispute_by_merchant_id_dispute_id(
+            &merchant_account.merchant_id,
+            &dispute_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: dispute_id.clone(),
@@ -356,6 +387,7 @@ pub async fn attach_evidence(
             })
         },
     )?;
+    let storage_scheme = merchant_account.storage_scheme;
     let create_file_response = files::files_create_core(
         state.clone(),
         merchant_account,
@@ -386,7 +418,7 @@ pub async fn attach_evidence(
             .attach_printable("Error while encoding dispute evidence")?
             .into(),
     };
-    db.update_dispute(dispute, update_dispute)
+    db.update_dispute(dispute, update_dispute, storage_scheme)
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: dispute_id.to_owned(),
@@ -405,7 +437,11 @@ pub async fn retrieve_dispute_evidence(
 ) -> RouterResponse<Vec<api_models::disputes::DisputeEvidenceBlock>> {
     let dispute = state
         .store
-        .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, &req.dispute_id)
+        .find_dispute_by_merchant_id_dispute_id(
+            &merchant_account.merchant_id,
+            &req.dispute_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: req.dispute_id,
@@ -420,3 +456,456 @@ pub async fn retrieve_dispute_evidence(
         transformers::get_dispute_evidence_vec(&state, merchant_account, dispute_evidence).await?;
     Ok(services::ApplicationResponse::Json(dispute_evidence_vec))
 }
+
+#[instrument(skip(state))]
+pub async fn get_dispute_analytics(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: dispute_models::DisputeAnalyticsRequest,
+) -> RouterResponse<dispute_models::DisputeAnalyticsResponse> {
+    let start_time = req.time_range.start_time;
+    let end_time = req
+        .time_range
+        .end_time
+        .unwrap_or_else(common_utils::date_time::now);
+    utils::when(end_time < start_time, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "time_range.end_time must not be before time_range.start_time".to_string(),
+        })
+        .into_report()
+    })?;
+    let max_time_range = time::Duration::days(consts::MAX_DISPUTE_ANALYTICS_TIME_RANGE_DAYS);
+    utils::when(end_time - start_time > max_time_range, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "time_range can span at most {} days",
+                consts::MAX_DISPUTE_ANALYTICS_TIME_RANGE_DAYS
+            ),
+        })
+        .into_report()
+    })?;
+    let dispute_records = state
+        .store
+        .get_dispute_analytics_records(
+            &merchant_account.merchant_id,
+            req.profile_id,
+            req.connector,
+            start_time,
+            end_time,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve dispute analytics records")?;
+    Ok(services::ApplicationResponse::Json(
+        get_dispute_analytics_response(&dispute_records, req.granularity),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn export_disputes(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    mut constraints: dispute_models::DisputeListConstraints,
+) -> RouterResponse<()> {
+    utils::when(constraints.limit.map_or(false, |limit| limit <= 0), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "limit must be greater than zero".to_string(),
+        })
+        .into_report()
+    })?;
+    constraints.limit = Some(
+        constraints
+            .limit
+            .map_or(consts::MAX_DISPUTE_EXPORT_LIMIT, |limit| {
+                limit.min(consts::MAX_DISPUTE_EXPORT_LIMIT)
+            }),
+    );
+    let disputes = state
+        .store
+        .find_disputes_by_merchant_id(&merchant_account.merchant_id, constraints)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve disputes")?;
+    let disputes_csv = transformers::get_disputes_csv(&disputes)?;
+    Ok(services::ApplicationResponse::FileData((
+        disputes_csv.into_bytes(),
+        mime::TEXT_CSV,
+    )))
+}
+
+#[derive(Default)]
+struct DisputeMetricsBuilder {
+    dispute_count: u32,
+    won_count: u32,
+    lost_count: u32,
+    amounts: BTreeMap<String, i64>,
+}
+
+impl DisputeMetricsBuilder {
+    fn add(&mut self, record: &storage::DisputeAnalyticsRecord) {
+        self.dispute_count = self.dispute_count.saturating_add(1);
+        match record.dispute_status {
+            storage_enums::DisputeStatus::DisputeWon => {
+                self.won_count = self.won_count.saturating_add(1)
+            }
+            storage_enums::DisputeStatus::DisputeLost => {
+                self.lost_count = self.lost_count.saturating_add(1)
+            }
+            _ => {}
+        }
+        // Amounts are stored as strings, the ones which can't be parsed are left out of the sum
+        if let Ok(amount) = record.amount.parse::<i64>() {
+            let total_amount = self.amounts.entry(record.currency.clone()).or_default();
+            *total_amount = total_amount.saturating_add(amount);
+        }
+    }
+
+    fn build(self) -> dispute_models::DisputeMetrics {
+        let resolved_count = self.won_count.saturating_add(self.lost_count);
+        let get_rate =
+            |count: u32| (resolved_count > 0).then(|| f64::from(count) / f64::from(resolved_count));
+        dispute_models::DisputeMetrics {
+            dispute_count: self.dispute_count,
+            amounts: self
+                .amounts
+                .into_iter()
+                .map(|(currency, amount)| dispute_models::DisputeAmount { currency, amount })
+                .collect(),
+            won_count: self.won_count,
+            lost_count: self.lost_count,
+            win_rate: get_rate(self.won_count),
+            loss_rate: get_rate(self.lost_count),
+        }
+    }
+}
+
+fn get_metrics_buckets(
+    buckets: BTreeMap<String, DisputeMetricsBuilder>,
+) -> Vec<dispute_models::DisputeMetricsBucket> {
+    buckets
+        .into_iter()
+        .map(|(key, metrics)| dispute_models::DisputeMetricsBucket {
+            key,
+            metrics: metrics.build(),
+        })
+        .collect()
+}
+
+/// Get the start of the time bucket of the given granularity which contains the given time. Weeks
+/// start on Monday.
+fn get_time_bucket_start(
+    time: PrimitiveDateTime,
+    granularity: dispute_models::DisputeAnalyticsGranularity,
+) -> PrimitiveDateTime {
+    let date = time.date();
+    match granularity {
+        dispute_models::DisputeAnalyticsGranularity::Hour => date
+            .midnight()
+            .saturating_add(time::Duration::hours(i64::from(time.hour()))),
+        dispute_models::DisputeAnalyticsGranularity::Day => date.midnight(),
+        dispute_models::DisputeAnalyticsGranularity::Week => date
+            .saturating_sub(time::Duration::days(i64::from(
+                date.weekday().number_days_from_monday(),
+            )))
+            .midnight(),
+        dispute_models::DisputeAnalyticsGranularity::Month => date
+            .saturating_sub(time::Duration::days(i64::from(date.day()) - 1))
+            .midnight(),
+    }
+}
+
+fn get_dispute_analytics_response(
+    dispute_records: &[storage::DisputeAnalyticsRecord],
+    granularity: dispute_models::DisputeAnalyticsGranularity,
+) -> dispute_models::DisputeAnalyticsResponse {
+    let mut total = DisputeMetricsBuilder::default();
+    let mut by_connector = BTreeMap::<String, DisputeMetricsBuilder>::new();
+    let mut by_reason_code = BTreeMap::<String, DisputeMetricsBuilder>::new();
+    let mut by_stage = BTreeMap::<String, DisputeMetricsBuilder>::new();
+    let mut by_status = BTreeMap::<String, DisputeMetricsBuilder>::new();
+    let mut by_time_bucket = BTreeMap::<PrimitiveDateTime, DisputeMetricsBuilder>::new();
+
+    for record in dispute_records {
+        total.add(record);
+        by_connector
+            .entry(record.connector.clone())
+            .or_default()
+            .add(record);
+        by_reason_code
+            .entry(
+                record
+                    .connector_reason_code
+                    .clone()
+                    .unwrap_or_else(|| "unknown".to_string()),
+            )
+            .or_default()
+            .add(record);
+        by_stage
+            .entry(record.dispute_stage.to_string())
+            .or_default()
+            .add(record);
+        by_status
+            .entry(record.dispute_status.to_string())
+            .or_default()
+            .add(record);
+        by_time_bucket
+            .entry(get_time_bucket_start(record.created_at, granularity))
+            .or_default()
+            .add(record);
+    }
+
+    dispute_models::DisputeAnalyticsResponse {
+        total: total.build(),
+        by_connector: get_metrics_buckets(by_connector),
+        by_reason_code: get_metrics_buckets(by_reason_code),
+        by_stage: get_metrics_buckets(by_stage),
+        by_status: get_metrics_buckets(by_status),
+        by_time_bucket: by_time_bucket
+            .into_iter()
+            .map(|(start_time, metrics)| dispute_models::DisputeTimeBucket {
+                start_time,
+                metrics: metrics.build(),
+            })
+            .collect(),
+    }
+}
+
+pub fn get_dispute_deadline_task_id(dispute: &storage::Dispute) -> String {
+    pt_utils::get_process_tracker_id(
+        dispute_deadline::DISPUTE_DEADLINE_WORKFLOW,
+        dispute_deadline::DISPUTE_DEADLINE_TASK,
+        &dispute.dispute_id,
+        &dispute.merchant_id,
+    )
+}
+
+/// Schedules the task which acts on the evidence deadline of an open dispute. The task runs right
+/// away so that the dispute policy of the business profile is applied as soon as the dispute is
+/// opened, it is then rescheduled by the `DisputeDeadlineWorkflow` until the deadline is reached.
+pub async fn add_dispute_deadline_task(
+    db: &dyn StorageInterface,
+    dispute: &storage::Dispute,
+) -> Result<(), scheduler::errors::ProcessTrackerError> {
+    if dispute.dispute_status != storage_enums::DisputeStatus::DisputeOpened
+        || dispute.challenge_required_by.is_none()
+    {
+        return Ok(());
+    }
+
+    let task_id = get_dispute_deadline_task_id(dispute);
+    if db.find_process_by_id(&task_id).await?.is_some() {
+        return Ok(());
+    }
+
+    let tracking_data = storage::DisputeDeadlineTrackingData {
+        merchant_id: dispute.merchant_id.clone(),
+        dispute_id: dispute.dispute_id.clone(),
+    };
+    let process_tracker_entry = <storage::ProcessTracker>::make_process_tracker_new(
+        task_id,
+        dispute_deadline::DISPUTE_DEADLINE_TASK,
+        dispute_deadline::DISPUTE_DEADLINE_WORKFLOW,
+        tracking_data,
+        common_utils::date_time::now(),
+    )?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+pub fn get_time_before_deadline(deadline: PrimitiveDateTime, seconds: u32) -> PrimitiveDateTime {
+    deadline.saturating_sub(time::Duration::seconds(i64::from(seconds)))
+}
+
+/// Get the reminder offset (in seconds before the deadline) which was reached most recently, `None`
+/// if no reminder is due yet or the deadline has passed
+pub fn get_due_reminder_offset(
+    deadline: PrimitiveDateTime,
+    reminder_offsets: &[u32],
+    current_time: PrimitiveDateTime,
+) -> Option<u32> {
+    if current_time >= deadline {
+        return None;
+    }
+    reminder_offsets
+        .iter()
+        .copied()
+        .filter(|offset| get_time_before_deadline(deadline, *offset) <= current_time)
+        .min()
+}
+
+/// Get the time at which the dispute is to be checked next, which is the earliest upcoming
+/// reminder or automatic evidence submission. `None` once all of them have been reached.
+pub fn get_next_deadline_check_time(
+    deadline: PrimitiveDateTime,
+    reminder_offsets: &[u32],
+    auto_submit_evidence_before: Option<u32>,
+    current_time: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    reminder_offsets
+        .iter()
+        .copied()
+        .chain(auto_submit_evidence_before)
+        .map(|offset| get_time_before_deadline(deadline, offset))
+        .filter(|check_time| *check_time > current_time && *check_time < deadline)
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_dispute_deadline_check_times() {
+        let deadline = datetime!(2023-11-10 12:00);
+        let day = 24 * 60 * 60;
+        let reminder_offsets = [3 * day, day];
+
+        assert_eq!(
+            get_due_reminder_offset(deadline, &reminder_offsets, datetime!(2023-11-06 12:00)),
+            None
+        );
+        assert_eq!(
+            get_due_reminder_offset(deadline, &reminder_offsets, datetime!(2023-11-07 12:00)),
+            Some(3 * day)
+        );
+        assert_eq!(
+            get_due_reminder_offset(deadline, &reminder_offsets, datetime!(2023-11-10 00:00)),
+            Some(day)
+        );
+        assert_eq!(
+            get_due_reminder_offset(deadline, &reminder_offsets, deadline),
+            None
+        );
+
+        assert_eq!(
+            get_next_deadline_check_time(
+                deadline,
+                &reminder_offsets,
+                Some(2 * 60 * 60),
+                datetime!(2023-11-01 00:00)
+            ),
+            Some(datetime!(2023-11-07 12:00))
+        );
+        assert_eq!(
+            get_next_deadline_check_time(
+                deadline,
+                &reminder_offsets,
+                Some(2 * 60 * 60),
+                datetime!(2023-11-09 12:00)
+            ),
+            Some(datetime!(2023-11-10 10:00))
+        );
+        assert_eq!(
+            get_next_deadline_check_time(
+                deadline,
+                &reminder_offsets,
+                None,
+                datetime!(2023-11-09 12:00)
+            ),
+            None
+        );
+    }
+
+    fn get_analytics_record(
+        connector: &str,
+        dispute_status: storage_enums::DisputeStatus,
+        amount: &str,
+        created_at: PrimitiveDateTime,
+    ) -> storage::DisputeAnalyticsRecord {
+        storage::DisputeAnalyticsRecord {
+            connector: connector.to_string(),
+            connector_reason_code: None,
+            dispute_stage: storage_enums::DisputeStage::Dispute,
+            dispute_status,
+            currency: "USD".to_string(),
+            amount: amount.to_string(),
+            created_at,
+        }
+    }
+
+    #[test]
+    fn test_dispute_analytics_aggregation() {
+        let records = [
+            get_analytics_record(
+                "stripe",
+                storage_enums::DisputeStatus::DisputeWon,
+                "1000",
+                datetime!(2023-11-01 10:30),
+            ),
+            get_analytics_record(
+                "stripe",
+                storage_enums::DisputeStatus::DisputeLost,
+                "500",
+                datetime!(2023-11-02 09:00),
+            ),
+            get_analytics_record(
+                "adyen",
+                storage_enums::DisputeStatus::DisputeWon,
+                "250",
+                datetime!(2023-11-08 12:00),
+            ),
+            get_analytics_record(
+                "adyen",
+                storage_enums::DisputeStatus::DisputeOpened,
+                "invalid",
+                datetime!(2023-11-08 18:00),
+            ),
+        ];
+
+        let response = get_dispute_analytics_response(
+            &records,
+            dispute_models::DisputeAnalyticsGranularity::Week,
+        );
+
+        assert_eq!(response.total.dispute_count, 4);
+        assert_eq!(response.total.won_count, 2);
+        assert_eq!(response.total.lost_count, 1);
+        assert_eq!(
+            response.total.amounts,
+            vec![dispute_models::DisputeAmount {
+                currency: "USD".to_string(),
+                amount: 1750,
+            }]
+        );
+        assert_eq!(response.total.win_rate, Some(2.0 / 3.0));
+
+        let connectors: Vec<_> = response
+            .by_connector
+            .iter()
+            .map(|bucket| (bucket.key.as_str(), bucket.metrics.dispute_count))
+            .collect();
+        assert_eq!(connectors, vec![("adyen", 2), ("stripe", 2)]);
+        assert_eq!(response.by_reason_code[0].key, "unknown");
+        assert_eq!(response.by_connector[0].metrics.loss_rate, Some(0.0));
+
+        let time_buckets: Vec<_> = response
+            .by_time_bucket
+            .iter()
+            .map(|bucket| (bucket.start_time, bucket.metrics.dispute_count))
+            .collect();
+        assert_eq!(
+            time_buckets,
+            vec![
+                (datetime!(2023-10-30 00:00), 2),
+                (datetime!(2023-11-06 00:00), 2)
+            ]
+        );
+        assert_eq!(
+            get_time_bucket_start(
+                datetime!(2023-11-08 18:45),
+                dispute_models::DisputeAnalyticsGranularity::Month
+            ),
+            datetime!(2023-11-01 00:00)
+        );
+        assert_eq!(
+            get_time_bucket_start(
+                datetime!(2023-11-08 18:45),
+                dispute_models::DisputeAnalyticsGranularity::Hour
+            ),
+            datetime!(2023-11-08 18:00)
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use api_models::disputes::EvidenceType;
 use common_utils::errors::CustomResult;
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
+use time::{format_description::well_known::Iso8601, PrimitiveDateTime};
 
 use crate::{
     core::{errors, files::helpers::retrieve_file_and_provider_file_id_from_file_id},
@@ -202,6 +203,55 @@ pub fn update_dispute_evidence(
     }
 }
 
+/// Builds the evidence submission request from the files attached to the dispute, `None` if no
+/// file has been attached yet
+pub fn get_submit_evidence_request(
+    dispute_id: String,
+    dispute_evidence: DisputeEvidence,
+) -> Option<api_models::disputes::SubmitEvidenceRequest> {
+    let DisputeEvidence {
+        cancellation_policy,
+        customer_communication,
+        customer_signature,
+        receipt,
+        refund_policy,
+        service_documentation,
+        shipping_documentation,
+        invoice_showing_distinct_transactions,
+        recurring_transaction_agreement,
+        uncategorized_file,
+    } = dispute_evidence;
+    let is_evidence_attached = [
+        &cancellation_policy,
+        &customer_communication,
+        &customer_signature,
+        &receipt,
+        &refund_policy,
+        &service_documentation,
+        &shipping_documentation,
+        &invoice_showing_distinct_transactions,
+        &recurring_transaction_agreement,
+        &uncategorized_file,
+    ]
+    .into_iter()
+    .any(Option::is_some);
+
+    is_evidence_attached.then_some(api_models::disputes::SubmitEvidenceRequest {
+        dispute_id,
+        cancellation_policy,
+        customer_communication,
+        customer_signature,
+        receipt,
+        refund_policy,
+        service_documentation,
+        shipping_documentation,
+        invoice_showing_distinct_transactions,
+        recurring_transaction_agreement,
+        uncategorized_file,
+        ..Default::default()
+    })
+}
+
 pub async fn get_dispute_evidence_block(
     state: &AppState,
     merchant_account: &domain::MerchantAccount,
@@ -344,3 +394,104 @@ pub async fn get_dispute_evidence_vec(
     }
     Ok(dispute_evidence_blocks)
 }
+
+const DISPUTES_CSV_HEADER: [&str; 15] = [
+    "dispute_id",
+    "payment_id",
+    "attempt_id",
+    "amount",
+    "currency",
+    "dispute_stage",
+    "dispute_status",
+    "connector",
+    "connector_status",
+    "connector_dispute_id",
+    "connector_reason",
+    "connector_reason_code",
+    "challenge_required_by",
+    "connector_created_at",
+    "created_at",
+];
+
+/// Quote the field if it contains a delimiter, a quote or a line break, quotes are escaped by
+/// doubling them. Fields which spreadsheets would evaluate as a formula are prefixed with `'`.
+fn escape_csv_field(field: &str) -> String {
+    let field = if field.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{field}")
+    } else {
+        field.to_string()
+    };
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field
+    }
+}
+
+fn format_csv_time(
+    time: Option<PrimitiveDateTime>,
+) -> CustomResult<String, errors::ApiErrorResponse> {
+    time.map(|time| time.assume_utc().format(&Iso8601::DEFAULT))
+        .transpose()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to format dispute timestamp")
+        .map(Option::unwrap_or_default)
+}
+
+pub fn get_disputes_csv(
+    disputes: &[diesel_models::dispute::Dispute],
+) -> CustomResult<String, errors::ApiErrorResponse> {
+    let mut disputes_csv = DISPUTES_CSV_HEADER.join(",");
+    disputes_csv.push_str("\r\n");
+    for dispute in disputes {
+        let row = [
+            dispute.dispute_id.clone(),
+            dispute.payment_id.clone(),
+            dispute.attempt_id.clone(),
+            dispute.amount.clone(),
+            dispute.currency.clone(),
+            dispute.dispute_stage.to_string(),
+            dispute.dispute_status.to_string(),
+            dispute.connector.clone(),
+            dispute.connector_status.clone(),
+            dispute.connector_dispute_id.clone(),
+            dispute.connector_reason.clone().unwrap_or_default(),
+            dispute.connector_reason_code.clone().unwrap_or_default(),
+            format_csv_time(dispute.challenge_required_by)?,
+            format_csv_time(dispute.connector_created_at)?,
+            format_csv_time(Some(dispute.created_at))?,
+        ];
+        let row = row
+            .iter()
+            .map(|field| escape_csv_field(field))
+            .collect::<Vec<_>>()
+            .join(",");
+        disputes_csv.push_str(&row);
+        disputes_csv.push_str("\r\n");
+    }
+    Ok(disputes_csv)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_csv_field() {
+        assert_eq!(escape_csv_field("fraudulent"), "fraudulent");
+        assert_eq!(
+            escape_csv_field("product, not received"),
+            "\"product, not received\""
+        );
+        assert_eq!(escape_csv_field("said \"no\""), "\"said \"\"no\"\"\"");
+        assert_eq!(escape_csv_field("line\nbreak"), "\"line\nbreak\"");
+        assert_eq!(
+            escape_csv_field("=HYPERLINK(\"x\")"),
+            "\"'=HYPERLINK(\"\"x\"\")\""
+        );
+        assert_eq!(escape_csv_field("+1"), "'+1");
+        assert_eq!(escape_csv_field("-1"), "'-1");
+        assert_eq!(escape_csv_field("@SUM(A1)"), "'@SUM(A1)");
+    }
+}
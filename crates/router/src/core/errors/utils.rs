@@ -92,6 +92,11 @@ impl<T> StorageErrorExt<T, errors::ApiErrorResponse>
                 errors::StorageError::CustomerRedacted => {
                     err.change_context(errors::ApiErrorResponse::CustomerRedacted)
                 }
+                errors::StorageError::MandateDebitLimitExceeded => {
+                    err.change_context(errors::ApiErrorResponse::MandateValidationFailed {
+                        reason: "debit exceeds the limits of the mandate".to_string(),
+                    })
+                }
                 _ => err.change_context(errors::ApiErrorResponse::InternalServerError),
             }
         })
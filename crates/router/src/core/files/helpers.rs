@@ -1,12 +1,13 @@
 use actix_multipart::Field;
-use common_utils::errors::CustomResult;
+use common_utils::{errors::CustomResult, fp_utils::when};
 use error_stack::{IntoReport, ResultExt};
 use futures::TryStreamExt;
 
 use crate::{
+    configs::settings,
     core::{
         errors::{self, StorageErrorExt},
-        files, payments, utils,
+        payments, utils,
     },
     routes::AppState,
     services,
@@ -31,34 +32,69 @@ pub async fn get_file_purpose(field: &mut Field) -> Option<api::FilePurpose> {
 }
 
 pub async fn upload_file(
-    #[cfg(feature = "s3")] state: &AppState,
+    state: &AppState,
     file_key: String,
     file: Vec<u8>,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
-    #[cfg(feature = "s3")]
-    return files::s3_utils::upload_file_to_s3(state, file_key, file).await;
-    #[cfg(not(feature = "s3"))]
-    return files::fs_utils::save_file_to_fs(file_key, file);
+    state
+        .file_storage_client
+        .upload_file(&file_key, file)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to upload the file to the file storage")
 }
 
 pub async fn delete_file(
-    #[cfg(feature = "s3")] state: &AppState,
+    state: &AppState,
     file_key: String,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
-    #[cfg(feature = "s3")]
-    return files::s3_utils::delete_file_from_s3(state, file_key).await;
-    #[cfg(not(feature = "s3"))]
-    return files::fs_utils::delete_file_from_fs(file_key);
+    state
+        .file_storage_client
+        .delete_file(&file_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete the file from the file storage")
 }
 
 pub async fn retrieve_file(
-    #[cfg(feature = "s3")] state: &AppState,
+    state: &AppState,
     file_key: String,
 ) -> CustomResult<Vec<u8>, errors::ApiErrorResponse> {
-    #[cfg(feature = "s3")]
-    return files::s3_utils::retrieve_file_from_s3(state, file_key).await;
-    #[cfg(not(feature = "s3"))]
-    return files::fs_utils::retrieve_file_from_fs(file_key);
+    state
+        .file_storage_client
+        .retrieve_file(&file_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve the file from the file storage")
+}
+
+fn validate_file_restrictions(
+    restrictions: &settings::FileUploadRestrictions,
+    create_file_request: &api::CreateFileRequest,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    when(
+        create_file_request.file_size > restrictions.max_file_size,
+        || {
+            Err(errors::ApiErrorResponse::FileValidationFailed {
+                reason: format!(
+                    "file size should not exceed {} bytes",
+                    restrictions.max_file_size
+                ),
+            })
+            .into_report()
+        },
+    )?;
+
+    let content_type = create_file_request.file_type.essence_str();
+    when(
+        !restrictions.allowed_content_types.contains(content_type),
+        || {
+            Err(errors::ApiErrorResponse::FileValidationFailed {
+                reason: format!("file type {content_type} is not allowed"),
+            })
+            .into_report()
+        },
+    )
 }
 
 pub async fn validate_file_upload(
@@ -69,12 +105,20 @@ pub async fn validate_file_upload(
     //File Validation based on the purpose of file upload
     match create_file_request.purpose {
         api::FilePurpose::DisputeEvidence => {
+            validate_file_restrictions(
+                &state.conf.file_upload_config.dispute_evidence,
+                &create_file_request,
+            )?;
             let dispute_id = &create_file_request
                 .dispute_id
                 .ok_or(errors::ApiErrorResponse::MissingDisputeId)?;
             let dispute = state
                 .store
-                .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, dispute_id)
+                .find_dispute_by_merchant_id_dispute_id(
+                    &merchant_account.merchant_id,
+                    dispute_id,
+                    merchant_account.storage_scheme,
+                )
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
                     dispute_id: dispute_id.to_string(),
@@ -130,12 +174,7 @@ pub async fn delete_file_using_file_id(
     };
     match provider {
         diesel_models::enums::FileUploadProvider::Router => {
-            delete_file(
-                #[cfg(feature = "s3")]
-                state,
-                provider_file_id,
-            )
-            .await
+            delete_file(state, provider_file_id).await
         }
         _ => Err(errors::ApiErrorResponse::FileProviderNotSupported {
             message: "Not Supported because provider is not Router".to_string(),
@@ -229,14 +268,7 @@ pub async fn retrieve_file_and_provider_file_id_from_file_id(
             };
             match provider {
                 diesel_models::enums::FileUploadProvider::Router => Ok((
-                    Some(
-                        retrieve_file(
-                            #[cfg(feature = "s3")]
-                            state,
-                            provider_file_id.clone(),
-                        )
-                        .await?,
-                    ),
+                    Some(retrieve_file(state, provider_file_id.clone()).await?),
                     Some(provider_file_id),
                 )),
                 _ => {
@@ -282,7 +314,11 @@ pub async fn upload_and_get_provider_provider_file_id_profile_id(
                 .ok_or(errors::ApiErrorResponse::MissingDisputeId)?;
             let dispute = state
                 .store
-                .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, &dispute_id)
+                .find_dispute_by_merchant_id_dispute_id(
+                    &merchant_account.merchant_id,
+                    &dispute_id,
+                    merchant_account.storage_scheme,
+                )
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound { dispute_id })?;
             let connector_data = api::ConnectorData::get_connector_by_name(
@@ -356,13 +392,7 @@ pub async fn upload_and_get_provider_provider_file_id_profile_id(
                     payment_intent.profile_id,
                 ))
             } else {
-                upload_file(
-                    #[cfg(feature = "s3")]
-                    state,
-                    file_key.clone(),
-                    create_file_request.file.clone(),
-                )
-                .await?;
+                upload_file(state, file_key.clone(), create_file_request.file.clone()).await?;
                 Ok((
                     file_key,
                     api_models::enums::FileUploadProvider::Router,
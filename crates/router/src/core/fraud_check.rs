@@ -0,0 +1,518 @@
+pub mod mock_frm;
+pub mod types;
+
+use api_models::{
+    admin::{FrmConfigs, FrmPaymentMethodType},
+    enums::{FrmAction, FrmConnectors, FrmPreferredFlowTypes, FrmSuggestion},
+    routing::FrmRoutingAlgorithm,
+};
+use common_utils::date_time;
+use diesel_models::fraud_check::{FraudCheck, FraudCheckNew};
+use error_stack::ResultExt;
+use masking::ExposeInterface;
+use router_env::{instrument, logger, tracing};
+
+use self::types::{FraudCheckConnector, FrmCheckRequest, FrmCheckResponse, FrmData};
+use super::{
+    errors::{self, RouterResult, StorageErrorExt},
+    payments::{self, helpers, PaymentData},
+    refunds,
+};
+use crate::{
+    consts,
+    routes::AppState,
+    types::{
+        api, domain,
+        storage::{self, enums as storage_enums},
+    },
+    utils::{generate_id, Encode, ValueExt},
+};
+
+/// Parses an FRM routing algorithm configured on a merchant account or business profile
+pub fn validate_frm_routing_algorithm(
+    frm_routing_algorithm: &serde_json::Value,
+) -> RouterResult<FrmRoutingAlgorithm> {
+    frm_routing_algorithm
+        .clone()
+        .parse_value("FrmRoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "frm_routing_algorithm",
+        })
+        .attach_printable("Invalid frm routing algorithm given")
+}
+
+pub fn get_frm_connector(frm_connector: FrmConnectors) -> Box<dyn FraudCheckConnector> {
+    match frm_connector {
+        FrmConnectors::MockFrm => Box::new(mock_frm::MockFrm),
+    }
+}
+
+/// Finds the FRM configuration to be used for a payment, if the business profile of the payment
+/// has an FRM connector and the merchant connector account of the payment connector has an FRM
+/// config matching the payment method of the payment
+#[instrument(skip_all)]
+pub async fn get_frm_data<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<Option<FrmData>> {
+    let (profile_id, connector_name) = match (
+        payment_data.payment_intent.profile_id.clone(),
+        payment_data.payment_attempt.connector.clone(),
+    ) {
+        (Some(profile_id), Some(connector_name)) => (profile_id, connector_name),
+        _ => return Ok(None),
+    };
+
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id,
+        })?;
+
+    let frm_connector = match business_profile
+        .frm_routing_algorithm
+        .map(|frm_routing_algorithm| validate_frm_routing_algorithm(&frm_routing_algorithm))
+        .transpose()
+    {
+        Ok(Some(FrmRoutingAlgorithm::Single(frm_connector))) => frm_connector,
+        Ok(None) => return Ok(None),
+        // Profiles configured before the algorithm was being validated are skipped, instead of
+        // failing their payments
+        Err(error) => {
+            logger::error!(frm_routing_algorithm_parsing_error=?error);
+            return Ok(None);
+        }
+    };
+
+    let merchant_connector_account = payments::construct_profile_id_and_get_mca(
+        state,
+        merchant_account,
+        payment_data,
+        &connector_name,
+        key_store,
+        false,
+    )
+    .await?;
+
+    let frm_configs = match merchant_connector_account {
+        helpers::MerchantConnectorAccountType::DbVal(merchant_connector_account) => {
+            merchant_connector_account.frm_configs.unwrap_or_default()
+        }
+        helpers::MerchantConnectorAccountType::CacheVal(_) => vec![],
+    }
+    .into_iter()
+    .map(|frm_config| frm_config.expose().parse_value::<FrmConfigs>("FrmConfigs"))
+    .collect::<Result<Vec<_>, _>>()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to parse the frm configs of the merchant connector account")?;
+
+    let card_network = match payment_data.payment_method_data {
+        Some(api::PaymentMethodData::Card(ref card)) => card.card_network.clone(),
+        _ => None,
+    };
+
+    Ok(find_frm_config(
+        &frm_configs,
+        &connector_name,
+        payment_data.payment_attempt.payment_method,
+        payment_data.payment_attempt.payment_method_type,
+        card_network.as_ref(),
+    )
+    .map(|frm_config| FrmData {
+        frm_connector,
+        flow: frm_config.flow.clone(),
+        action: frm_config.action.clone(),
+    }))
+}
+
+/// Fields which are not set in a config match any value
+fn find_frm_config<'a>(
+    frm_configs: &'a [FrmConfigs],
+    connector_name: &str,
+    payment_method: Option<storage_enums::PaymentMethod>,
+    payment_method_type: Option<storage_enums::PaymentMethodType>,
+    card_network: Option<&api_models::enums::CardNetwork>,
+) -> Option<&'a FrmPaymentMethodType> {
+    frm_configs
+        .iter()
+        .filter(|frm_config| {
+            frm_config
+                .gateway
+                .as_ref()
+                .map_or(true, |gateway| gateway.to_string() == connector_name)
+        })
+        .flat_map(|frm_config| frm_config.payment_methods.iter())
+        .filter(|frm_payment_method| {
+            frm_payment_method
+                .payment_method
+                .map_or(true, |pm| Some(pm) == payment_method)
+        })
+        .flat_map(|frm_payment_method| frm_payment_method.payment_method_types.iter())
+        .find(|frm_payment_method_type| {
+            frm_payment_method_type
+                .payment_method_type
+                .map_or(true, |pmt| Some(pmt) == payment_method_type)
+                && frm_payment_method_type
+                    .card_networks
+                    .as_ref()
+                    .map_or(true, |card_networks| {
+                        card_network.map_or(false, |network| card_networks.contains(network))
+                    })
+        })
+}
+
+pub fn is_pre_authorization_check(frm_data: &FrmData) -> bool {
+    matches!(frm_data.flow, FrmPreferredFlowTypes::Pre)
+}
+
+/// Runs the pre-authorization check, and returns the suggestion to be applied to the payment
+/// when it must not be sent to the connector
+#[instrument(skip_all)]
+pub async fn call_frm_before_connector_call<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    frm_data: &FrmData,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<Option<FrmSuggestion>> {
+    let fraud_check = perform_fraud_check(
+        state,
+        merchant_account,
+        frm_data,
+        payment_data,
+        storage_enums::FraudCheckType::PreFrm,
+    )
+    .await?;
+
+    let frm_suggestion = get_pre_authorization_suggestion(fraud_check.frm_status, &frm_data.action);
+    payment_data.frm_message = Some(fraud_check);
+
+    Ok(frm_suggestion)
+}
+
+/// Runs the post-authorization check on an authorized or captured payment, and applies its
+/// outcome to the payment
+#[instrument(skip_all)]
+pub async fn call_frm_after_connector_call<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    frm_data: &FrmData,
+    mut payment_data: PaymentData<F>,
+) -> RouterResult<PaymentData<F>> {
+    if !matches!(
+        payment_data.payment_intent.status,
+        storage_enums::IntentStatus::Succeeded | storage_enums::IntentStatus::RequiresCapture
+    ) {
+        return Ok(payment_data);
+    }
+
+    let fraud_check = perform_fraud_check(
+        state,
+        merchant_account,
+        frm_data,
+        &payment_data,
+        storage_enums::FraudCheckType::PostFrm,
+    )
+    .await?;
+
+    let frm_suggestion = get_post_authorization_suggestion(
+        fraud_check.frm_status,
+        &frm_data.action,
+        payment_data.payment_intent.status,
+    );
+    payment_data.frm_message = Some(fraud_check);
+
+    match frm_suggestion {
+        Some(FrmSuggestion::FrmManualReview) => {
+            let storage_scheme = merchant_account.storage_scheme;
+            payment_data.payment_intent = state
+                .store
+                .update_payment_intent(
+                    payment_data.payment_intent,
+                    storage::PaymentIntentUpdate::PGStatusUpdate {
+                        status: storage_enums::IntentStatus::RequiresMerchantAction,
+                        updated_by: storage_scheme.to_string(),
+                    },
+                    storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+        }
+        Some(FrmSuggestion::FrmAutoRefund) => {
+            let refund_request = api_models::refunds::RefundRequest {
+                payment_id: payment_data.payment_intent.payment_id.clone(),
+                merchant_id: Some(merchant_account.merchant_id.clone()),
+                reason: Some("Payment was marked as fraud by the FRM connector".to_string()),
+                ..Default::default()
+            };
+            // The fraud check has been recorded against the payment, so a failed refund is left
+            // for the merchant to retry instead of failing the payment response
+            refunds::refund_create_core(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                refund_request,
+            )
+            .await
+            .map_err(|error| logger::error!(frm_auto_refund_error=?error))
+            .ok();
+        }
+        Some(FrmSuggestion::FrmCancelTransaction) | None => {}
+    }
+
+    Ok(payment_data)
+}
+
+/// Calls the FRM connector and records its decision in `fraud_check`. A failure of the FRM
+/// connector is recorded as a `transaction_failure`, and does not stop the payment.
+async fn perform_fraud_check<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    frm_data: &FrmData,
+    payment_data: &PaymentData<F>,
+    frm_transaction_type: storage_enums::FraudCheckType,
+) -> RouterResult<FraudCheck> {
+    let payment_attempt = &payment_data.payment_attempt;
+    let frm_request = FrmCheckRequest {
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        merchant_id: merchant_account.merchant_id.clone(),
+        frm_transaction_type,
+        amount: payment_data.amount.into(),
+        currency: payment_data.currency,
+        connector: payment_attempt.connector.clone(),
+        payment_method: payment_attempt.payment_method,
+        payment_method_type: payment_attempt.payment_method_type,
+        attempt_status: payment_attempt.status,
+    };
+
+    let frm_response = get_frm_connector(frm_data.frm_connector)
+        .check_transaction(state, &frm_request)
+        .await;
+
+    let (frm_response, frm_error) = match frm_response {
+        Ok(frm_response) => (frm_response, None),
+        Err(error) => {
+            logger::error!(frm_connector_error=?error);
+            (
+                FrmCheckResponse {
+                    frm_transaction_id: None,
+                    frm_status: storage_enums::FraudCheckStatus::TransactionFailure,
+                    frm_score: None,
+                    frm_reason: None,
+                },
+                Some(error.current_context().to_string()),
+            )
+        }
+    };
+
+    let payment_details = Encode::<FrmCheckRequest>::encode_to_value(&frm_request)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the frm request")?;
+    let last_step = match frm_transaction_type {
+        storage_enums::FraudCheckType::PreFrm => storage_enums::FraudCheckLastStep::CheckoutOrSale,
+        storage_enums::FraudCheckType::PostFrm => {
+            storage_enums::FraudCheckLastStep::TransactionOrRecordRefund
+        }
+    };
+    let now = date_time::now();
+
+    state
+        .store
+        .insert_fraud_check_response(FraudCheckNew {
+            frm_id: generate_id(consts::ID_LENGTH, "frm"),
+            payment_id: frm_request.payment_id,
+            merchant_id: frm_request.merchant_id,
+            attempt_id: frm_request.attempt_id,
+            created_at: now,
+            frm_name: frm_data.frm_connector.to_string(),
+            frm_transaction_id: frm_response.frm_transaction_id,
+            frm_transaction_type,
+            frm_status: frm_response.frm_status,
+            frm_score: frm_response.frm_score,
+            frm_reason: frm_response.frm_reason,
+            frm_error,
+            payment_details: Some(payment_details),
+            metadata: None,
+            modified_at: now,
+            last_step,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the fraud check")
+}
+
+/// Before authorization, a payment marked as fraud is either cancelled or sent for review as
+/// per the configured action. There is nothing to refund yet, so an auto refund action cancels
+/// the payment.
+fn get_pre_authorization_suggestion(
+    frm_status: storage_enums::FraudCheckStatus,
+    action: &FrmAction,
+) -> Option<FrmSuggestion> {
+    match (frm_status, action) {
+        (storage_enums::FraudCheckStatus::Fraud, FrmAction::ManualReview)
+        | (storage_enums::FraudCheckStatus::ManualReview, _) => {
+            Some(FrmSuggestion::FrmManualReview)
+        }
+        (
+            storage_enums::FraudCheckStatus::Fraud,
+            FrmAction::CancelTxn | FrmAction::AutoRefund,
+        ) => Some(FrmSuggestion::FrmCancelTransaction),
+        (
+            storage_enums::FraudCheckStatus::Legit
+            | storage_enums::FraudCheckStatus::Pending
+            | storage_enums::FraudCheckStatus::TransactionFailure,
+            _,
+        ) => None,
+    }
+}
+
+/// After authorization, a payment which is yet to be captured is held for review, so that the
+/// merchant can approve it before capturing it or reject it. A captured payment marked as
+/// fraud is refunded if the configured action is an auto refund, otherwise the fraud check is
+/// only recorded against it.
+fn get_post_authorization_suggestion(
+    frm_status: storage_enums::FraudCheckStatus,
+    action: &FrmAction,
+    intent_status: storage_enums::IntentStatus,
+) -> Option<FrmSuggestion> {
+    match (frm_status, intent_status) {
+        (
+            storage_enums::FraudCheckStatus::Fraud | storage_enums::FraudCheckStatus::ManualReview,
+            storage_enums::IntentStatus::RequiresCapture,
+        ) => Some(FrmSuggestion::FrmManualReview),
+        (storage_enums::FraudCheckStatus::Fraud, storage_enums::IntentStatus::Succeeded)
+            if matches!(action, FrmAction::AutoRefund) =>
+        {
+            Some(FrmSuggestion::FrmAutoRefund)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn frm_configs() -> Vec<FrmConfigs> {
+        serde_json::from_value(serde_json::json!([
+            {
+                "gateway": "stripe",
+                "payment_methods": [
+                    {
+                        "payment_method": "card",
+                        "payment_method_types": [
+                            {
+                                "payment_method_type": "credit",
+                                "card_networks": ["Visa"],
+                                "flow": "pre",
+                                "action": "cancel_txn"
+                            },
+                            {
+                                "payment_method_type": "debit",
+                                "flow": "post",
+                                "action": "manual_review"
+                            }
+                        ]
+                    }
+                ]
+            }
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_frm_config_matches_payment_method() {
+        let frm_configs = frm_configs();
+
+        let frm_config = find_frm_config(
+            &frm_configs,
+            "stripe",
+            Some(storage_enums::PaymentMethod::Card),
+            Some(storage_enums::PaymentMethodType::Debit),
+            None,
+        )
+        .unwrap();
+        assert!(matches!(frm_config.flow, FrmPreferredFlowTypes::Post));
+
+        // A config with card networks does not match a card of an unknown network
+        assert!(find_frm_config(
+            &frm_configs,
+            "stripe",
+            Some(storage_enums::PaymentMethod::Card),
+            Some(storage_enums::PaymentMethodType::Credit),
+            None,
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_frm_config_of_another_gateway_is_ignored() {
+        assert!(find_frm_config(
+            &frm_configs(),
+            "adyen",
+            Some(storage_enums::PaymentMethod::Card),
+            Some(storage_enums::PaymentMethodType::Debit),
+            None,
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_pre_authorization_suggestion() {
+        assert_eq!(
+            get_pre_authorization_suggestion(
+                storage_enums::FraudCheckStatus::Fraud,
+                &FrmAction::AutoRefund
+            ),
+            Some(FrmSuggestion::FrmCancelTransaction)
+        );
+        assert_eq!(
+            get_pre_authorization_suggestion(
+                storage_enums::FraudCheckStatus::ManualReview,
+                &FrmAction::CancelTxn
+            ),
+            Some(FrmSuggestion::FrmManualReview)
+        );
+        assert_eq!(
+            get_pre_authorization_suggestion(
+                storage_enums::FraudCheckStatus::TransactionFailure,
+                &FrmAction::CancelTxn
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_post_authorization_suggestion() {
+        assert_eq!(
+            get_post_authorization_suggestion(
+                storage_enums::FraudCheckStatus::Fraud,
+                &FrmAction::CancelTxn,
+                storage_enums::IntentStatus::RequiresCapture
+            ),
+            Some(FrmSuggestion::FrmManualReview)
+        );
+        assert_eq!(
+            get_post_authorization_suggestion(
+                storage_enums::FraudCheckStatus::Fraud,
+                &FrmAction::AutoRefund,
+                storage_enums::IntentStatus::Succeeded
+            ),
+            Some(FrmSuggestion::FrmAutoRefund)
+        );
+        assert_eq!(
+            get_post_authorization_suggestion(
+                storage_enums::FraudCheckStatus::ManualReview,
+                &FrmAction::ManualReview,
+                storage_enums::IntentStatus::Succeeded
+            ),
+            None
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use super::types::{FraudCheckConnector, FrmCheckRequest, FrmCheckResponse};
+use crate::{
+    consts,
+    core::errors::RouterResult,
+    routes::AppState,
+    types::storage::enums as storage_enums,
+    utils::generate_id,
+};
+
+/// Payments of at least this amount (in the lowest denomination of the currency) are marked
+/// as fraud
+pub const MOCK_FRM_FRAUD_AMOUNT: i64 = 100_000;
+/// Payments of at least this amount (in the lowest denomination of the currency) are sent for
+/// manual review
+pub const MOCK_FRM_MANUAL_REVIEW_AMOUNT: i64 = 50_000;
+
+/// An FRM connector which decides on the payment amount alone, without calling any external
+/// service
+pub struct MockFrm;
+
+impl MockFrm {
+    fn decide(amount: i64) -> (storage_enums::FraudCheckStatus, i32, &'static str) {
+        if amount >= MOCK_FRM_FRAUD_AMOUNT {
+            (
+                storage_enums::FraudCheckStatus::Fraud,
+                90,
+                "amount exceeds the fraud threshold",
+            )
+        } else if amount >= MOCK_FRM_MANUAL_REVIEW_AMOUNT {
+            (
+                storage_enums::FraudCheckStatus::ManualReview,
+                60,
+                "amount exceeds the manual review threshold",
+            )
+        } else {
+            (storage_enums::FraudCheckStatus::Legit, 10, "no risk found")
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl FraudCheckConnector for MockFrm {
+    async fn check_transaction(
+        &self,
+        _state: &AppState,
+        request: &FrmCheckRequest,
+    ) -> RouterResult<FrmCheckResponse> {
+        let (frm_status, frm_score, reason) = Self::decide(request.amount);
+
+        Ok(FrmCheckResponse {
+            frm_transaction_id: Some(generate_id(consts::ID_LENGTH, "mock_frm")),
+            frm_status,
+            frm_score: Some(frm_score),
+            frm_reason: Some(serde_json::json!({ "message": reason })),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock_frm_decision_by_amount() {
+        assert_eq!(
+            MockFrm::decide(MOCK_FRM_FRAUD_AMOUNT).0,
+            storage_enums::FraudCheckStatus::Fraud
+        );
+        assert_eq!(
+            MockFrm::decide(MOCK_FRM_MANUAL_REVIEW_AMOUNT).0,
+            storage_enums::FraudCheckStatus::ManualReview
+        );
+        assert_eq!(
+            MockFrm::decide(MOCK_FRM_MANUAL_REVIEW_AMOUNT - 1).0,
+            storage_enums::FraudCheckStatus::Legit
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use api_models::enums::{FrmAction, FrmConnectors, FrmPreferredFlowTypes};
+use serde::Serialize;
+
+use crate::{
+    core::errors::RouterResult,
+    routes::AppState,
+    types::storage::enums as storage_enums,
+};
+
+/// The FRM configuration which applies to a payment, resolved from the `frm_routing_algorithm`
+/// of its business profile and the `frm_configs` of the merchant connector account of its
+/// connector
+#[derive(Clone, Debug)]
+pub struct FrmData {
+    pub frm_connector: FrmConnectors,
+    pub flow: FrmPreferredFlowTypes,
+    pub action: FrmAction,
+}
+
+/// The details of a payment which are sent to the FRM connector
+#[derive(Clone, Debug, Serialize)]
+pub struct FrmCheckRequest {
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub merchant_id: String,
+    pub frm_transaction_type: storage_enums::FraudCheckType,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub connector: Option<String>,
+    pub payment_method: Option<storage_enums::PaymentMethod>,
+    pub payment_method_type: Option<storage_enums::PaymentMethodType>,
+    pub attempt_status: storage_enums::AttemptStatus,
+}
+
+/// The decision of the FRM connector on a payment
+#[derive(Clone, Debug)]
+pub struct FrmCheckResponse {
+    pub frm_transaction_id: Option<String>,
+    pub frm_status: storage_enums::FraudCheckStatus,
+    pub frm_score: Option<i32>,
+    pub frm_reason: Option<serde_json::Value>,
+}
+
+#[async_trait::async_trait]
+pub trait FraudCheckConnector: Send + Sync {
+    /// Checks the payment for fraud, before it is authorized with the connector in case of a
+    /// pre-authorization check, or after it in case of a post-authorization check
+    async fn check_transaction(
+        &self,
+        state: &AppState,
+        request: &FrmCheckRequest,
+    ) -> RouterResult<FrmCheckResponse>;
+}
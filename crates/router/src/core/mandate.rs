@@ -32,7 +32,11 @@ pub async fn get_mandate(
     let mandate = state
         .store
         .as_ref()
-        .find_mandate_by_merchant_id_mandate_id(&merchant_account.merchant_id, &req.mandate_id)
+        .find_mandate_by_merchant_id_mandate_id(
+            &merchant_account.merchant_id,
+            &req.mandate_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
     Ok(services::ApplicationResponse::Json(
@@ -54,6 +58,7 @@ pub async fn revoke_mandate(
             storage::MandateUpdate::StatusUpdate {
                 mandate_status: storage::enums::MandateStatus::Revoked,
             },
+            merchant_account.storage_scheme,
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
@@ -72,13 +77,14 @@ pub async fn update_connector_mandate_id(
     merchant_account: String,
     mandate_ids_opt: Option<api_models::payments::MandateIds>,
     resp: Result<types::PaymentsResponseData, types::ErrorResponse>,
+    storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> RouterResponse<mandates::MandateResponse> {
     let connector_mandate_id = Option::foreign_try_from(resp)?;
     //Ignore updation if the payment_attempt mandate_id or connector_mandate_id is not present
     if let Some((mandate_ids, connector_id)) = mandate_ids_opt.zip(connector_mandate_id) {
         let mandate_id = &mandate_ids.mandate_id;
         let mandate = db
-            .find_mandate_by_merchant_id_mandate_id(&merchant_account, mandate_id)
+            .find_mandate_by_merchant_id_mandate_id(&merchant_account, mandate_id, storage_scheme)
             .await
             .change_context(errors::ApiErrorResponse::MandateNotFound)?;
         // only update the connector_mandate_id if existing is none
@@ -89,6 +95,7 @@ pub async fn update_connector_mandate_id(
                 storage::MandateUpdate::ConnectorReferenceUpdate {
                     connector_mandate_ids: Some(connector_id),
                 },
+                storage_scheme,
             )
             .await
             .change_context(errors::ApiErrorResponse::MandateUpdateFailed)?;
@@ -143,6 +150,7 @@ pub async fn mandate_procedure<F, FData>(
     mut resp: types::RouterData<F, FData, types::PaymentsResponseData>,
     maybe_customer: &Option<domain::Customer>,
     pm_id: Option<String>,
+    storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> errors::RouterResult<types::RouterData<F, FData, types::PaymentsResponseData>>
 where
     FData: MandateBehaviour,
@@ -154,7 +162,11 @@ where
                 let mandate_id = &mandate_id.mandate_id;
                 let mandate = state
                     .store
-                    .find_mandate_by_merchant_id_mandate_id(resp.merchant_id.as_ref(), mandate_id)
+                    .find_mandate_by_merchant_id_mandate_id(
+                        resp.merchant_id.as_ref(),
+                        mandate_id,
+                        storage_scheme,
+                    )
                     .await
                     .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
                 let mandate = match mandate.mandate_type {
@@ -166,24 +178,14 @@ where
                             storage::MandateUpdate::StatusUpdate {
                                 mandate_status: storage_enums::MandateStatus::Revoked,
                             },
+                            storage_scheme,
                         )
                         .await
-                        .change_context(errors::ApiErrorResponse::MandateUpdateFailed),
-                    storage_enums::MandateType::MultiUse => state
-                        .store
-                        .update_mandate_by_merchant_id_mandate_id(
-                            &resp.merchant_id,
-                            mandate_id,
-                            storage::MandateUpdate::CaptureAmountUpdate {
-                                amount_captured: Some(
-                                    mandate.amount_captured.unwrap_or(0)
-                                        + resp.request.get_amount(),
-                                ),
-                            },
-                        )
-                        .await
-                        .change_context(errors::ApiErrorResponse::MandateUpdateFailed),
-                }?;
+                        .change_context(errors::ApiErrorResponse::MandateUpdateFailed)?,
+                    // The debit has been recorded by `reserve_mandate_debit` before the connector
+                    // was called
+                    storage_enums::MandateType::MultiUse => mandate,
+                };
                 metrics::SUBSEQUENT_MANDATE_PAYMENT.add(
                     &metrics::CONTEXT,
                     1,
@@ -260,7 +262,7 @@ where
                         }));
                         state
                             .store
-                            .insert_mandate(new_mandate_data)
+                            .insert_mandate(new_mandate_data, storage_scheme)
                             .await
                             .to_duplicate_response(errors::ApiErrorResponse::DuplicateMandate)?;
                         metrics::MANDATE_COUNT.add(
@@ -322,6 +324,104 @@ impl ForeignTryFrom<Result<types::PaymentsResponseData, types::ErrorResponse>>
     }
 }
 
+/// A debit recorded on a multi use mandate for a payment which is yet to complete
+pub struct MandateDebit {
+    mandate_id: String,
+    amount: i64,
+    frequency_period_start: Option<time::PrimitiveDateTime>,
+}
+
+/// Record the debit of the payment on its multi use mandate before the connector is called, so
+/// that concurrent payments through the mandate cannot exceed the mandate limits together
+pub async fn reserve_mandate_debit<F, FData>(
+    state: &AppState,
+    router_data: &types::RouterData<F, FData, types::PaymentsResponseData>,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> errors::RouterResult<Option<MandateDebit>>
+where
+    FData: MandateBehaviour,
+{
+    let Some(mandate_id) = router_data.request.get_mandate_id() else {
+        return Ok(None);
+    };
+    let mandate = state
+        .store
+        .find_mandate_by_merchant_id_mandate_id(
+            &router_data.merchant_id,
+            &mandate_id.mandate_id,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+
+    match mandate.mandate_type {
+        storage_enums::MandateType::SingleUse => Ok(None),
+        storage_enums::MandateType::MultiUse => {
+            let mandate_debit = MandateDebit {
+                mandate_id: mandate.mandate_id,
+                amount: router_data.request.get_amount(),
+                frequency_period_start: mandate.frequency.map(|frequency| {
+                    get_frequency_period_start(frequency, common_utils::date_time::now())
+                }),
+            };
+            state
+                .store
+                .record_mandate_debit_by_merchant_id_mandate_id(
+                    &router_data.merchant_id,
+                    &mandate_debit.mandate_id,
+                    mandate_debit.amount,
+                    mandate_debit.frequency_period_start,
+                    storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+            Ok(Some(mandate_debit))
+        }
+    }
+}
+
+/// Release the debit recorded by `reserve_mandate_debit` once the payment has failed
+pub async fn release_mandate_debit(
+    state: &AppState,
+    merchant_id: &str,
+    mandate_debit: MandateDebit,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> errors::RouterResult<()> {
+    state
+        .store
+        .release_mandate_debit_by_merchant_id_mandate_id(
+            merchant_id,
+            &mandate_debit.mandate_id,
+            mandate_debit.amount,
+            mandate_debit.frequency_period_start,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MandateUpdateFailed)?;
+    Ok(())
+}
+
+/// Get the start of the calendar period of `frequency` which contains `time`, weeks start on
+/// Monday
+pub fn get_frequency_period_start(
+    frequency: storage_enums::MandateFrequency,
+    time: time::PrimitiveDateTime,
+) -> time::PrimitiveDateTime {
+    let date = time.date();
+    let period_start_date = match frequency {
+        storage_enums::MandateFrequency::Daily => date,
+        storage_enums::MandateFrequency::Weekly => {
+            date - time::Duration::days(date.weekday().number_days_from_monday().into())
+        }
+        storage_enums::MandateFrequency::Monthly => date.replace_day(1).unwrap_or(date),
+        storage_enums::MandateFrequency::Yearly => date
+            .replace_month(time::Month::January)
+            .and_then(|date| date.replace_day(1))
+            .unwrap_or(date),
+    };
+    period_start_date.midnight()
+}
+
 pub trait MandateBehaviour {
     fn get_amount(&self) -> i64;
     fn get_setup_future_usage(&self) -> Option<diesel_models::enums::FutureUsage>;
@@ -330,3 +430,33 @@ pub trait MandateBehaviour {
     fn get_payment_method_data(&self) -> api_models::payments::PaymentMethodData;
     fn get_setup_mandate_details(&self) -> Option<&data_models::mandates::MandateData>;
 }
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_get_frequency_period_start() {
+        // A Thursday
+        let time = datetime!(2023-10-26 14:30);
+
+        assert_eq!(
+            get_frequency_period_start(storage_enums::MandateFrequency::Daily, time),
+            datetime!(2023-10-26 0:00)
+        );
+        assert_eq!(
+            get_frequency_period_start(storage_enums::MandateFrequency::Weekly, time),
+            datetime!(2023-10-23 0:00)
+        );
+        assert_eq!(
+            get_frequency_period_start(storage_enums::MandateFrequency::Monthly, time),
+            datetime!(2023-10-01 0:00)
+        );
+        assert_eq!(
+            get_frequency_period_start(storage_enums::MandateFrequency::Yearly, time),
+            datetime!(2023-01-01 0:00)
+        );
+    }
+}
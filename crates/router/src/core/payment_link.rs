@@ -1,17 +1,34 @@
 use api_models::admin as admin_types;
-use common_utils::ext_traits::AsyncExt;
-use error_stack::{IntoReport, ResultExt};
+use error_stack::{report, IntoReport, ResultExt};
+use masking::ExposeInterface;
+use router_env::{instrument, tracing};
+use scheduler::{db::process_tracker::ProcessTrackerExt, utils as pt_utils};
+use time::PrimitiveDateTime;
 
 use super::errors::{self, RouterResult, StorageErrorExt};
 use crate::{
     core::payments::helpers,
+    db::StorageInterface,
     errors::RouterResponse,
     routes::AppState,
     services,
-    types::{domain, storage::enums as storage_enums, transformers::ForeignFrom},
+    types::{
+        domain,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
     utils::OptionExt,
+    workflows::payment_link_expiry,
 };
 
+const DEFAULT_MAX_ITEMS_VISIBLE_AFTER_COLLAPSE: i8 = 3;
+const DEFAULT_FONT_FAMILY: &str = "\"Montserrat\"";
+/// The locales in which the payment link page can be rendered by the SDK
+const SUPPORTED_LOCALES: &[&str] = &[
+    "en", "en-GB", "ar", "ca", "de", "es", "fr", "fr-BE", "he", "it", "ja", "nl", "pl", "pt", "ru",
+    "sv", "zh",
+];
+
 pub async fn retrieve_payment_link(
     state: AppState,
     payment_link_id: String,
@@ -55,32 +72,43 @@ pub async fn intiate_payment_link_flow(
         "create payment link",
     )?;
 
-    let fulfillment_time = payment_intent
-        .payment_link_id
-        .as_ref()
-        .async_and_then(|pli| async move {
-            db.find_payment_link_by_payment_link_id(pli)
-                .await
-                .ok()?
-                .fulfilment_time
-                .ok_or(errors::ApiErrorResponse::PaymentNotFound)
-                .ok()
-        })
+    let payment_link = db
+        .find_payment_link_by_payment_link_id(
+            payment_intent
+                .payment_link_id
+                .as_ref()
+                .ok_or(errors::ApiErrorResponse::PaymentLinkNotFound)?,
+        )
         .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+    validate_payment_link_is_active(&payment_link, common_utils::date_time::now())?;
+
+    let fulfillment_time = payment_link
+        .fulfilment_time
         .get_required_value("fulfillment_time")
         .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
 
-    let payment_link_config = merchant_account
+    let merchant_payment_link_config = merchant_account
         .payment_link_config
-        .map(|pl_config| {
-            serde_json::from_value::<admin_types::PaymentLinkConfig>(pl_config)
-                .into_report()
-                .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                    field_name: "payment_link_config",
-                })
-        })
+        .map(parse_payment_link_config)
         .transpose()?;
 
+    let profile_payment_link_config = match payment_intent.profile_id.as_ref() {
+        Some(profile_id) => db
+            .find_business_profile_by_profile_id(profile_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                id: profile_id.to_owned(),
+            })?
+            .payment_link_config
+            .map(parse_payment_link_config)
+            .transpose()?,
+        None => None,
+    };
+
+    let payment_link_config =
+        merge_payment_link_config(profile_payment_link_config, merchant_payment_link_config);
+
     let order_details = payment_intent
         .order_details
         .get_required_value("order_details")
@@ -108,7 +136,14 @@ pub async fn intiate_payment_link_flow(
         amount: payment_intent.amount,
         currency,
         payment_id: payment_intent.payment_id,
-        merchant_name: merchant_account.merchant_name,
+        merchant_name: payment_link_config
+            .as_ref()
+            .and_then(|pl_config| pl_config.display_name.clone())
+            .or_else(|| {
+                merchant_account
+                    .merchant_name
+                    .map(|merchant_name| merchant_name.into_inner().expose())
+            }),
         order_details,
         return_url,
         expiry: fulfillment_time,
@@ -118,7 +153,16 @@ pub async fn intiate_payment_link_flow(
             .clone()
             .map(|pl_metadata| pl_metadata.merchant_logo.unwrap_or_default())
             .unwrap_or_default(),
-        max_items_visible_after_collapse: 3,
+        max_items_visible_after_collapse: payment_link_config
+            .as_ref()
+            .and_then(|pl_config| pl_config.max_items_visible_after_collapse)
+            .unwrap_or(DEFAULT_MAX_ITEMS_VISIBLE_AFTER_COLLAPSE),
+        locale: payment_link_config
+            .as_ref()
+            .and_then(|pl_config| pl_config.locale.clone()),
+        font_family: payment_link_config
+            .as_ref()
+            .and_then(|pl_config| pl_config.font_family.clone()),
     };
 
     let js_script = get_js_script(payment_details)?;
@@ -127,12 +171,453 @@ pub async fn intiate_payment_link_flow(
         js_script,
         sdk_url: state.conf.payment_link.sdk_url.clone(),
         css_script,
+        custom_template: payment_link_config.and_then(|pl_config| pl_config.custom_template),
     };
     Ok(services::ApplicationResponse::PaymenkLinkForm(Box::new(
         payment_link_data,
     )))
 }
 
+#[instrument(skip(state))]
+pub async fn list_payment_links(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    constraints: api_models::payments::PaymentLinkListConstraints,
+) -> RouterResponse<Vec<api_models::payments::RetrievePaymentLinkResponse>> {
+    let payment_links = state
+        .store
+        .list_payment_links_by_merchant_id(&merchant_account.merchant_id, constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve payment links")?;
+
+    Ok(services::ApplicationResponse::Json(
+        payment_links
+            .into_iter()
+            .map(api_models::payments::RetrievePaymentLinkResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn update_payment_link(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    payment_link_id: String,
+    req: api_models::payments::PaymentLinkUpdateRequest,
+) -> RouterResponse<api_models::payments::RetrievePaymentLinkResponse> {
+    let db = &*state.store;
+    let current_time = common_utils::date_time::now();
+    let payment_link = find_merchant_payment_link(db, &merchant_account, &payment_link_id).await?;
+    validate_payment_link_is_active(&payment_link, current_time)?;
+
+    if req.amount.map_or(false, |amount| amount <= 0) {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount must be greater than zero".to_string()
+        }))?
+    }
+    if req
+        .link_expiry
+        .map_or(false, |link_expiry| link_expiry <= current_time)
+    {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "link_expiry must be in the future".to_string()
+        }))?
+    }
+
+    if let Some(amount) = req.amount {
+        update_payment_amount(db, &merchant_account, &payment_link, amount).await?;
+    }
+
+    let payment_link = db
+        .update_payment_link(
+            payment_link,
+            storage::PaymentLinkUpdate::Update {
+                amount: req.amount,
+                fulfilment_time: req.link_expiry,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    if let Some(link_expiry) = req.link_expiry {
+        reschedule_payment_link_expiry_task(db, &payment_link, link_expiry)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reschedule payment link expiry task")?;
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        api_models::payments::RetrievePaymentLinkResponse::foreign_from(payment_link),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn cancel_payment_link(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    payment_link_id: String,
+) -> RouterResponse<api_models::payments::RetrievePaymentLinkResponse> {
+    let db = &*state.store;
+    let payment_link = find_merchant_payment_link(db, &merchant_account, &payment_link_id).await?;
+    if payment_link.status != storage_enums::PaymentLinkStatus::Active {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("Payment link is {}", payment_link.status)
+        }))?
+    }
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_link.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    if !is_payment_cancellable(payment_intent.status) {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Payment link cannot be cancelled as the payment is in {} status",
+                payment_intent.status
+            )
+        }))?
+    }
+    cancel_payment(
+        db,
+        &merchant_account,
+        payment_intent,
+        "payment link cancelled".to_string(),
+    )
+    .await?;
+
+    let payment_link = db
+        .update_payment_link(
+            payment_link,
+            storage::PaymentLinkUpdate::StatusUpdate {
+                status: storage_enums::PaymentLinkStatus::Cancelled,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    // The expiry task would also stop on finding the payment link cancelled, finishing it here
+    // keeps it from being picked up again
+    let expiry_task = db
+        .find_process_by_id(&get_payment_link_expiry_task_id(&payment_link))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve payment link expiry task from process tracker")?;
+    if let Some(expiry_task) = expiry_task {
+        expiry_task
+            .finish_with_status(db.as_scheduler(), "PAYMENT_LINK_CANCELLED".to_string())
+            .await
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to finish payment link expiry task in process tracker")?;
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        api_models::payments::RetrievePaymentLinkResponse::foreign_from(payment_link),
+    ))
+}
+
+/// Expires a payment link whose fulfilment time has been reached. The payment is cancelled if it
+/// has not been attempted yet, a link whose payment went through is marked as paid instead.
+pub async fn expire_payment_link(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    payment_link: storage::PaymentLink,
+) -> RouterResult<storage::PaymentLink> {
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_link.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let status = if is_payment_paid(payment_intent.status) {
+        storage_enums::PaymentLinkStatus::Paid
+    } else {
+        if is_payment_cancellable(payment_intent.status) {
+            cancel_payment(
+                db,
+                merchant_account,
+                payment_intent,
+                "payment link expired".to_string(),
+            )
+            .await?;
+        }
+        storage_enums::PaymentLinkStatus::Expired
+    };
+
+    db.update_payment_link(
+        payment_link,
+        storage::PaymentLinkUpdate::StatusUpdate { status },
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)
+}
+
+/// Marks the payment link of a payment as paid once the payment goes through
+pub async fn update_payment_link_status_for_payment(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<()> {
+    let payment_link_id = match payment_intent.payment_link_id.as_ref() {
+        Some(payment_link_id) if is_payment_paid(payment_intent.status) => payment_link_id,
+        _ => return Ok(()),
+    };
+    let payment_link = db
+        .find_payment_link_by_payment_link_id(payment_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+    if payment_link.status == storage_enums::PaymentLinkStatus::Paid {
+        return Ok(());
+    }
+
+    db.update_payment_link(
+        payment_link,
+        storage::PaymentLinkUpdate::StatusUpdate {
+            status: storage_enums::PaymentLinkStatus::Paid,
+        },
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+    Ok(())
+}
+
+pub fn get_payment_link_expiry_task_id(payment_link: &storage::PaymentLink) -> String {
+    pt_utils::get_process_tracker_id(
+        payment_link_expiry::PAYMENT_LINK_EXPIRY_WORKFLOW,
+        payment_link_expiry::PAYMENT_LINK_EXPIRY_TASK,
+        &payment_link.payment_link_id,
+        &payment_link.merchant_id,
+    )
+}
+
+/// Schedules the task which expires the payment link at its fulfilment time
+pub async fn add_payment_link_expiry_task(
+    db: &dyn StorageInterface,
+    payment_link: &storage::PaymentLink,
+    schedule_time: PrimitiveDateTime,
+) -> Result<(), scheduler::errors::ProcessTrackerError> {
+    let tracking_data = storage::PaymentLinkTrackingData {
+        merchant_id: payment_link.merchant_id.clone(),
+        payment_link_id: payment_link.payment_link_id.clone(),
+    };
+    let process_tracker_entry = <storage::ProcessTracker>::make_process_tracker_new(
+        get_payment_link_expiry_task_id(payment_link),
+        payment_link_expiry::PAYMENT_LINK_EXPIRY_TASK,
+        payment_link_expiry::PAYMENT_LINK_EXPIRY_WORKFLOW,
+        tracking_data,
+        schedule_time,
+    )?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+async fn reschedule_payment_link_expiry_task(
+    db: &dyn StorageInterface,
+    payment_link: &storage::PaymentLink,
+    schedule_time: PrimitiveDateTime,
+) -> Result<(), scheduler::errors::ProcessTrackerError> {
+    match db
+        .find_process_by_id(&get_payment_link_expiry_task_id(payment_link))
+        .await?
+    {
+        Some(expiry_task) => expiry_task.reset(db.as_scheduler(), schedule_time).await,
+        None => add_payment_link_expiry_task(db, payment_link, schedule_time).await,
+    }
+}
+
+async fn find_merchant_payment_link(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    payment_link_id: &str,
+) -> RouterResult<storage::PaymentLink> {
+    let payment_link = db
+        .find_payment_link_by_payment_link_id(payment_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+    if payment_link.merchant_id != merchant_account.merchant_id {
+        Err(report!(errors::ApiErrorResponse::PaymentLinkNotFound))?
+    }
+    Ok(payment_link)
+}
+
+/// A payment link can only be used or updated while it is active and its expiry has not been
+/// reached, the expiry task could still be pending right after the expiry
+fn validate_payment_link_is_active(
+    payment_link: &storage::PaymentLink,
+    current_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let is_expired = payment_link
+        .fulfilment_time
+        .map_or(false, |fulfilment_time| fulfilment_time <= current_time);
+    let status = match payment_link.status {
+        storage_enums::PaymentLinkStatus::Active if is_expired => {
+            storage_enums::PaymentLinkStatus::Expired
+        }
+        status => status,
+    };
+    if status != storage_enums::PaymentLinkStatus::Active {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("Payment link is {status}")
+        }))?
+    }
+    Ok(())
+}
+
+/// The amount can only be changed before a payment method is confirmed for the payment
+async fn update_payment_amount(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    payment_link: &storage::PaymentLink,
+    amount: i64,
+) -> RouterResult<()> {
+    let storage_scheme = merchant_account.storage_scheme;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_link.payment_id,
+            &merchant_account.merchant_id,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    if !is_payment_cancellable(payment_intent.status) {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "amount cannot be updated as the payment is in {} status",
+                payment_intent.status
+            )
+        }))?
+    }
+    let currency = payment_intent.currency.get_required_value("currency")?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            &merchant_account.merchant_id,
+            &payment_intent.active_attempt.get_id(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let attempt_status = payment_attempt.status;
+    db.update_payment_attempt_with_attempt_id(
+        payment_attempt,
+        storage::PaymentAttemptUpdate::Update {
+            amount,
+            currency,
+            status: attempt_status,
+            authentication_type: None,
+            payment_method: None,
+            payment_token: None,
+            payment_method_data: None,
+            payment_method_type: None,
+            payment_experience: None,
+            business_sub_label: None,
+            amount_to_capture: None,
+            capture_method: None,
+            updated_by: storage_scheme.to_string(),
+        },
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let intent_status = payment_intent.status;
+    db.update_payment_intent(
+        payment_intent,
+        storage::PaymentIntentUpdate::Update {
+            amount,
+            currency,
+            setup_future_usage: None,
+            status: intent_status,
+            customer_id: None,
+            shipping_address_id: None,
+            billing_address_id: None,
+            return_url: None,
+            business_country: None,
+            business_label: None,
+            description: None,
+            statement_descriptor_name: None,
+            statement_descriptor_suffix: None,
+            order_details: None,
+            metadata: None,
+            payment_confirm_source: None,
+            updated_by: storage_scheme.to_string(),
+        },
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    Ok(())
+}
+
+/// Cancels a payment which has not been sent to a connector yet, so there is nothing to void
+async fn cancel_payment(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    payment_intent: storage::PaymentIntent,
+    cancellation_reason: String,
+) -> RouterResult<()> {
+    let storage_scheme = merchant_account.storage_scheme;
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            &merchant_account.merchant_id,
+            &payment_intent.active_attempt.get_id(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    db.update_payment_attempt_with_attempt_id(
+        payment_attempt,
+        storage::PaymentAttemptUpdate::VoidUpdate {
+            status: storage_enums::AttemptStatus::Voided,
+            cancellation_reason: Some(cancellation_reason),
+            updated_by: storage_scheme.to_string(),
+        },
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    db.update_payment_intent(
+        payment_intent,
+        storage::PaymentIntentUpdate::PGStatusUpdate {
+            status: storage_enums::IntentStatus::Cancelled,
+            updated_by: storage_scheme.to_string(),
+        },
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    Ok(())
+}
+
+fn is_payment_cancellable(status: storage_enums::IntentStatus) -> bool {
+    matches!(
+        status,
+        storage_enums::IntentStatus::RequiresPaymentMethod
+            | storage_enums::IntentStatus::RequiresConfirmation
+    )
+}
+
+fn is_payment_paid(status: storage_enums::IntentStatus) -> bool {
+    matches!(
+        status,
+        storage_enums::IntentStatus::Succeeded
+            | storage_enums::IntentStatus::PartiallyCaptured
+            | storage_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | storage_enums::IntentStatus::RequiresCapture
+    )
+}
+
 /*
 The get_js_script function is used to inject dynamic value to payment_link sdk, which is unique to every payment.
 */
@@ -156,6 +641,14 @@ fn get_color_scheme_css(
         "#0C48F6".to_string(),
     );
 
+    // The font family is written into the stylesheet as is, so a value stored before it was
+    // validated is only used if it cannot break out of the declaration
+    let font_family = payment_link_config
+        .as_ref()
+        .and_then(|pl_config| pl_config.font_family.clone())
+        .filter(|font_family| is_valid_font_family(font_family))
+        .unwrap_or_else(|| DEFAULT_FONT_FAMILY.to_string());
+
     let (primary_color, primary_accent_color, secondary_color) = payment_link_config
         .and_then(|pl_config| {
             pl_config.color_scheme.map(|color| {
@@ -181,10 +674,124 @@ fn get_color_scheme_css(
       --primary-color: {primary_color};
       --primary-accent-color: {primary_accent_color};
       --secondary-color: {secondary_color};
+      --font-family: {font_family};
     }}"
     )
 }
 
+/// A font family is a comma separated list of font names, which may be quoted
+fn is_valid_font_family(font_family: &str) -> bool {
+    !font_family.trim().is_empty()
+        && font_family.chars().all(|character| {
+            character.is_ascii_alphanumeric()
+                || matches!(character, ' ' | ',' | '-' | '_' | '"' | '\'')
+        })
+}
+
+fn parse_payment_link_config(
+    payment_link_config: serde_json::Value,
+) -> RouterResult<admin_types::PaymentLinkConfig> {
+    serde_json::from_value::<admin_types::PaymentLinkConfig>(payment_link_config)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "payment_link_config",
+        })
+}
+
+/// Get the payment link configuration of the business profile, the settings which are not set on
+/// the business profile are taken from the merchant account
+fn merge_payment_link_config(
+    profile_config: Option<admin_types::PaymentLinkConfig>,
+    merchant_config: Option<admin_types::PaymentLinkConfig>,
+) -> Option<admin_types::PaymentLinkConfig> {
+    let (profile_config, merchant_config) = match (profile_config, merchant_config) {
+        (Some(profile_config), Some(merchant_config)) => (profile_config, merchant_config),
+        (profile_config, merchant_config) => return profile_config.or(merchant_config),
+    };
+
+    let color_scheme = match (profile_config.color_scheme, merchant_config.color_scheme) {
+        (Some(profile_colors), Some(merchant_colors)) => {
+            Some(admin_types::PaymentLinkColorSchema {
+                primary_color: profile_colors
+                    .primary_color
+                    .or(merchant_colors.primary_color),
+                primary_accent_color: profile_colors
+                    .primary_accent_color
+                    .or(merchant_colors.primary_accent_color),
+                secondary_color: profile_colors
+                    .secondary_color
+                    .or(merchant_colors.secondary_color),
+            })
+        }
+        (profile_colors, merchant_colors) => profile_colors.or(merchant_colors),
+    };
+
+    Some(admin_types::PaymentLinkConfig {
+        merchant_logo: profile_config
+            .merchant_logo
+            .or(merchant_config.merchant_logo),
+        color_scheme,
+        display_name: profile_config.display_name.or(merchant_config.display_name),
+        font_family: profile_config.font_family.or(merchant_config.font_family),
+        locale: profile_config.locale.or(merchant_config.locale),
+        max_items_visible_after_collapse: profile_config
+            .max_items_visible_after_collapse
+            .or(merchant_config.max_items_visible_after_collapse),
+        custom_template: profile_config
+            .custom_template
+            .or(merchant_config.custom_template),
+    })
+}
+
+/// Validates the payment link configuration of a merchant account or business profile before it
+/// is stored, so that a broken custom template is not found only when a link is opened
+pub fn validate_payment_link_config(
+    payment_link_config: &admin_types::PaymentLinkConfig,
+) -> RouterResult<()> {
+    if payment_link_config
+        .max_items_visible_after_collapse
+        .map_or(false, |max_items| max_items <= 0)
+    {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "max_items_visible_after_collapse must be greater than zero".to_string()
+        }))?
+    }
+
+    if payment_link_config
+        .font_family
+        .as_ref()
+        .map_or(false, |font_family| !is_valid_font_family(font_family))
+    {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "font_family can only contain letters, digits, spaces, commas, hyphens, \
+                      underscores and quotes"
+                .to_string()
+        }))?
+    }
+
+    if payment_link_config.locale.as_ref().map_or(false, |locale| {
+        !SUPPORTED_LOCALES.contains(&locale.as_str())
+    }) {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "locale must be one of the supported locales: {}",
+                SUPPORTED_LOCALES.join(", ")
+            )
+        }))?
+    }
+
+    if let Some(custom_template) = payment_link_config.custom_template.as_ref() {
+        tera::Tera::default()
+            .add_raw_template("payment_link", custom_template)
+            .map_err(|tera_error| {
+                report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("custom_template is not a valid template: {tera_error}")
+                })
+            })?;
+    }
+    Ok(())
+}
+
 fn validate_sdk_requirements(
     pub_key: Option<String>,
     currency: Option<api_models::enums::Currency>,
@@ -203,3 +810,77 @@ fn validate_sdk_requirements(
     })?;
     Ok((pub_key, currency, client_secret))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_payment_link_config(
+        merchant_logo: Option<&str>,
+        primary_color: Option<&str>,
+        display_name: Option<&str>,
+    ) -> admin_types::PaymentLinkConfig {
+        admin_types::PaymentLinkConfig {
+            merchant_logo: merchant_logo.map(String::from),
+            color_scheme: Some(admin_types::PaymentLinkColorSchema {
+                primary_color: primary_color.map(String::from),
+                primary_accent_color: None,
+                secondary_color: Some("#0C48F6".to_string()),
+            }),
+            display_name: display_name.map(String::from),
+            font_family: None,
+            locale: None,
+            max_items_visible_after_collapse: None,
+            custom_template: None,
+        }
+    }
+
+    #[test]
+    fn test_merge_payment_link_config() {
+        let profile_config = get_payment_link_config(None, Some("#FFFFFF"), Some("Brand"));
+        let merchant_config =
+            get_payment_link_config(Some("https://example.com/logo.png"), Some("#000000"), None);
+
+        let payment_link_config =
+            merge_payment_link_config(Some(profile_config.clone()), Some(merchant_config.clone()));
+        let expected_config = admin_types::PaymentLinkConfig {
+            merchant_logo: Some("https://example.com/logo.png".to_string()),
+            display_name: Some("Brand".to_string()),
+            ..get_payment_link_config(None, Some("#FFFFFF"), None)
+        };
+        assert_eq!(payment_link_config, Some(expected_config));
+
+        assert_eq!(
+            merge_payment_link_config(None, Some(merchant_config.clone())),
+            Some(merchant_config)
+        );
+        assert_eq!(
+            merge_payment_link_config(Some(profile_config.clone()), None),
+            Some(profile_config)
+        );
+        assert_eq!(merge_payment_link_config(None, None), None);
+    }
+
+    #[test]
+    fn test_validate_payment_link_config() {
+        let mut payment_link_config = get_payment_link_config(None, None, None);
+        payment_link_config.custom_template =
+            Some("<html>{{ payment_details_js_script }}</html>".to_string());
+        assert!(validate_payment_link_config(&payment_link_config).is_ok());
+
+        payment_link_config.custom_template = Some("<html>{{ unclosed </html>".to_string());
+        assert!(validate_payment_link_config(&payment_link_config).is_err());
+        payment_link_config.custom_template = None;
+
+        payment_link_config.font_family = Some("\"Open Sans\", sans-serif".to_string());
+        assert!(validate_payment_link_config(&payment_link_config).is_ok());
+        payment_link_config.font_family = Some("Arial; } body { display: none".to_string());
+        assert!(validate_payment_link_config(&payment_link_config).is_err());
+        payment_link_config.font_family = None;
+
+        payment_link_config.locale = Some("fr-BE".to_string());
+        assert!(validate_payment_link_config(&payment_link_config).is_ok());
+        payment_link_config.locale = Some("xx-XX".to_string());
+        assert!(validate_payment_link_config(&payment_link_config).is_err());
+    }
+}
@@ -1,4 +1,5 @@
 pub mod cards;
+pub mod surcharge;
 pub mod transformers;
 pub mod vault;
 
@@ -7,9 +7,10 @@ use api_models::{
     admin::{self, PaymentMethodsEnabled},
     enums::{self as api_enums},
     payment_methods::{
-        CardDetailsPaymentMethod, CardNetworkTypes, PaymentExperienceTypes, PaymentMethodsData,
-        RequestPaymentMethodTypes, RequiredFieldInfo, ResponsePaymentMethodIntermediate,
-        ResponsePaymentMethodTypes, ResponsePaymentMethodsEnabled,
+        CardDetailsPaymentMethod, CardNetworkTypes, InstallmentPlanTypes, PaymentExperienceTypes,
+        PaymentMethodsData, RequestPaymentMethodTypes, RequiredFieldInfo,
+        ResponsePaymentMethodIntermediate, ResponsePaymentMethodTypes,
+        ResponsePaymentMethodsEnabled,
     },
     payments::BankCodeResponse,
 };
@@ -28,6 +29,7 @@ use crate::{
     core::{
         errors::{self, StorageErrorExt},
         payment_methods::{
+            surcharge,
             transformers::{self as payment_methods},
             vault,
         },
@@ -319,9 +321,19 @@ pub async fn decode_and_decrypt_locker_data(
         .into_report()
         .change_context(errors::VaultError::ResponseDeserializationFailed)
         .attach_printable("Failed to decode hex string into bytes")?;
-    // Decrypt
-    decrypt(Some(Encryption::new(decoded_bytes.into())), key)
-        .await
+    // Decrypt, falling back to the previous merchant key since the card data stored in the
+    // locker is not re-encrypted when the merchant key is rotated
+    let encrypted_data = Encryption::new(decoded_bytes.into());
+    let decrypted_data = match decrypt(Some(encrypted_data.clone()), key).await {
+        Ok(data) => Ok(data),
+        Err(error) => match key_store.previous_key.as_ref() {
+            Some(previous_key) => {
+                decrypt(Some(encrypted_data), previous_key.get_inner().peek()).await
+            }
+            None => Err(error),
+        },
+    };
+    decrypted_data
         .change_context(errors::VaultError::FetchPaymentMethodFailed)?
         .map_or(
             Err(report!(errors::VaultError::FetchPaymentMethodFailed)),
@@ -777,6 +789,33 @@ pub fn get_banks(
     }
 }
 
+pub fn get_installment_plans(
+    state: &routes::AppState,
+    pm_type: api_enums::PaymentMethodType,
+    connectors: &[String],
+) -> Option<Vec<InstallmentPlanTypes>> {
+    let connector_installment_plans = state.conf.installment_config.0.get(&pm_type)?;
+
+    let mut installment_plans: Vec<InstallmentPlanTypes> = vec![];
+    for connector in connectors {
+        if installment_plans
+            .iter()
+            .any(|installment_plan| &installment_plan.connector == connector)
+        {
+            continue;
+        }
+        if let Some(plans) = connector_installment_plans.0.get(connector) {
+            installment_plans.push(InstallmentPlanTypes {
+                connector: connector.clone(),
+                number_of_installments: plans.number_of_installments.clone(),
+                plan_ids: plans.plan_ids.clone(),
+            });
+        }
+    }
+
+    (!installment_plans.is_empty()).then_some(installment_plans)
+}
+
 fn get_val(str: String, val: &serde_json::Value) -> Option<String> {
     str.split('.')
         .try_fold(val, |acc, x| acc.get(x))
@@ -1142,7 +1181,9 @@ pub async fn list_payment_methods(
         let mut payment_method_types = vec![];
         for payment_method_types_hm in key.1 {
             let mut payment_experience_types = vec![];
+            let mut eligible_connectors = vec![];
             for payment_experience_type in payment_method_types_hm.1 {
+                eligible_connectors.extend(payment_experience_type.1.iter().cloned());
                 payment_experience_types.push(PaymentExperienceTypes {
                     payment_experience_type: *payment_experience_type.0,
                     eligible_connectors: payment_experience_type.1.clone(),
@@ -1162,6 +1203,11 @@ pub async fn list_payment_methods(
                     .and_then(|inner_hm| inner_hm.get(payment_method_types_hm.0))
                     .cloned(),
                 surcharge_details: None,
+                installment_plans: get_installment_plans(
+                    &state,
+                    *payment_method_types_hm.0,
+                    &eligible_connectors,
+                ),
             })
         }
 
@@ -1175,7 +1221,9 @@ pub async fn list_payment_methods(
         let mut payment_method_types = vec![];
         for payment_method_types_hm in key.1 {
             let mut card_network_types = vec![];
+            let mut eligible_connectors = vec![];
             for card_network_type in payment_method_types_hm.1 {
+                eligible_connectors.extend(card_network_type.1.iter().cloned());
                 card_network_types.push(CardNetworkTypes {
                     card_network: card_network_type.0.clone(),
                     eligible_connectors: card_network_type.1.clone(),
@@ -1196,6 +1244,11 @@ pub async fn list_payment_methods(
                     .and_then(|inner_hm| inner_hm.get(payment_method_types_hm.0))
                     .cloned(),
                 surcharge_details: None,
+                installment_plans: get_installment_plans(
+                    &state,
+                    *payment_method_types_hm.0,
+                    &eligible_connectors,
+                ),
             })
         }
 
@@ -1225,6 +1278,7 @@ pub async fn list_payment_methods(
                     .and_then(|inner_hm| inner_hm.get(key.0))
                     .cloned(),
                 surcharge_details: None,
+                installment_plans: None,
             }
         })
     }
@@ -1257,6 +1311,7 @@ pub async fn list_payment_methods(
                     .and_then(|inner_hm| inner_hm.get(key.0))
                     .cloned(),
                 surcharge_details: None,
+                installment_plans: None,
             }
         })
     }
@@ -1289,6 +1344,7 @@ pub async fn list_payment_methods(
                     .and_then(|inner_hm| inner_hm.get(key.0))
                     .cloned(),
                 surcharge_details: None,
+                installment_plans: None,
             }
         })
     }
@@ -1300,6 +1356,24 @@ pub async fn list_payment_methods(
         });
     }
 
+    // Surcharges are calculated on the amount of the payment, which is known only when the
+    // payment methods are listed for a payment
+    let show_surcharge_breakup_screen = match payment_attempt.as_ref() {
+        Some(payment_attempt) => match (
+            surcharge::get_surcharge_rules(db, &merchant_account.merchant_id).await,
+            payment_attempt.currency,
+        ) {
+            (Some(surcharge_rules), Some(currency)) => surcharge::populate_surcharge_details(
+                &surcharge_rules,
+                payment_attempt.amount,
+                &currency,
+                &mut payment_method_responses,
+            )?,
+            _ => false,
+        },
+        None => false,
+    };
+
     Ok(services::ApplicationResponse::Json(
         api::PaymentMethodListResponse {
             redirect_url: merchant_account.return_url,
@@ -1315,6 +1389,9 @@ pub async fn list_payment_methods(
                             start_date: i.start_date,
                             end_date: i.end_date,
                             metadata: i.metadata,
+                            max_amount_per_transaction: i.max_amount_per_transaction,
+                            frequency: i.frequency,
+                            max_debits_per_frequency: i.max_debits_per_frequency,
                         })
                     }
                     data_models::mandates::MandateDataType::MultiUse(Some(i)) => {
@@ -1324,6 +1401,9 @@ pub async fn list_payment_methods(
                             start_date: i.start_date,
                             end_date: i.end_date,
                             metadata: i.metadata,
+                            max_amount_per_transaction: i.max_amount_per_transaction,
+                            frequency: i.frequency,
+                            max_debits_per_frequency: i.max_debits_per_frequency,
                         }))
                     }
                     data_models::mandates::MandateDataType::MultiUse(None) => {
@@ -1331,7 +1411,7 @@ pub async fn list_payment_methods(
                     }
                 },
             ),
-            show_surcharge_breakup_screen: false,
+            show_surcharge_breakup_screen,
         },
     ))
 }
@@ -0,0 +1,313 @@
+use api_models::{
+    payment_methods::{
+        ResponsePaymentMethodsEnabled, Surcharge, SurchargeDetailsResponse, SurchargeRules,
+    },
+    payments::RequestSurchargeDetails,
+};
+use common_utils::ext_traits::StringExt;
+use error_stack::ResultExt;
+use router_env::logger;
+
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        payment_methods::vault,
+        payments::helpers,
+    },
+    db::StorageInterface,
+    routes::AppState,
+    types::api::{self, enums as api_enums},
+};
+
+/// The surcharge rules configured for a merchant.
+///
+/// Configured per merchant with the key `surcharge_rules_{merchant_id}`, no surcharge is applied
+/// to the payments of the merchant when it is not configured.
+pub async fn get_surcharge_rules(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> Option<SurchargeRules> {
+    db.find_config_by_key(&format!("surcharge_rules_{merchant_id}"))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .and_then(|config| {
+            config
+                .config
+                .parse_struct("SurchargeRules")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+        })
+        .map_err(|error| logger::info!(surcharge_rules_config_error=?error))
+        .ok()
+}
+
+/// Get the network of the card used for a payment, to find the surcharge rule applicable to it.
+///
+/// The card is taken from the request, or from the locker for a saved card or a mandate, and its
+/// network is looked up from the card BIN when it is not known.
+pub async fn get_card_network(
+    state: &AppState,
+    payment_method_data: Option<&api::PaymentMethodData>,
+    token: Option<&str>,
+    is_mandate_payment: bool,
+) -> RouterResult<Option<api_enums::CardNetwork>> {
+    let card = match (payment_method_data, token) {
+        (Some(api::PaymentMethodData::Card(card)), _) => Some(card.clone()),
+        (None, Some(token)) => {
+            // The token of a mandate payment is the key of the card in the locker
+            let locker_key = if is_mandate_payment {
+                token.to_owned()
+            } else {
+                helpers::get_locker_key_from_payment_token(
+                    state,
+                    token,
+                    api_enums::PaymentMethod::Card,
+                )
+                .await?
+            };
+            match vault::Vault::get_payment_method_data_from_locker(state, &locker_key)
+                .await?
+                .0
+            {
+                Some(api::PaymentMethodData::Card(card)) => Some(card),
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+
+    let Some(card) = card else {
+        return Ok(None);
+    };
+    if card.card_network.is_some() {
+        return Ok(card.card_network);
+    }
+    Ok(state
+        .store
+        .get_card_info(&card.card_number.get_card_isin())
+        .await
+        .map_err(|error| logger::warn!(card_info_error=?error))
+        .ok()
+        .flatten()
+        .and_then(|card_info| card_info.card_network))
+}
+
+/// Calculates the surcharge for a payment using the first rule applicable to it
+pub fn get_surcharge_details(
+    surcharge_rules: &SurchargeRules,
+    amount: i64,
+    currency: &api_enums::Currency,
+    payment_method: &api_enums::PaymentMethod,
+    payment_method_type: &api_enums::PaymentMethodType,
+    card_network: Option<&api_enums::CardNetwork>,
+) -> RouterResult<Option<SurchargeDetailsResponse>> {
+    let Some(rule) = surcharge_rules.rules.iter().find(|rule| {
+        rule.is_applicable(payment_method, payment_method_type, card_network, currency)
+    }) else {
+        return Ok(None);
+    };
+
+    let surcharge_amount = match rule.surcharge {
+        Surcharge::Fixed(value) => value,
+        Surcharge::Rate(ref percentage) => percentage
+            .apply_and_ceil_result(amount)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to calculate surcharge amount by applying percentage")?,
+    };
+    let tax_on_surcharge_amount = rule
+        .tax_on_surcharge
+        .as_ref()
+        .map(|tax_on_surcharge| {
+            tax_on_surcharge
+                .apply_and_ceil_result(surcharge_amount)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to calculate tax amount on surcharge")
+        })
+        .transpose()?
+        .unwrap_or(0);
+
+    Ok(Some(SurchargeDetailsResponse {
+        surcharge: rule.surcharge.clone(),
+        tax_on_surcharge: rule.tax_on_surcharge.clone(),
+        surcharge_amount,
+        tax_on_surcharge_amount,
+        final_amount: amount + surcharge_amount + tax_on_surcharge_amount,
+    }))
+}
+
+/// Fills the surcharge details of the payment method types and card networks in the payment
+/// method list, returns whether a surcharge is applicable to any of them
+pub fn populate_surcharge_details(
+    surcharge_rules: &SurchargeRules,
+    amount: i64,
+    currency: &api_enums::Currency,
+    payment_methods: &mut [ResponsePaymentMethodsEnabled],
+) -> RouterResult<bool> {
+    let mut is_surcharge_applicable = false;
+    for payment_method_enabled in payment_methods.iter_mut() {
+        let payment_method = payment_method_enabled.payment_method;
+        for payment_method_type in payment_method_enabled.payment_method_types.iter_mut() {
+            payment_method_type.surcharge_details = get_surcharge_details(
+                surcharge_rules,
+                amount,
+                currency,
+                &payment_method,
+                &payment_method_type.payment_method_type,
+                None,
+            )?;
+            is_surcharge_applicable |= payment_method_type.surcharge_details.is_some();
+
+            for card_network_type in payment_method_type
+                .card_networks
+                .iter_mut()
+                .flat_map(|card_networks| card_networks.iter_mut())
+            {
+                card_network_type.surcharge_details = get_surcharge_details(
+                    surcharge_rules,
+                    amount,
+                    currency,
+                    &payment_method,
+                    &payment_method_type.payment_method_type,
+                    Some(&card_network_type.card_network),
+                )?;
+                is_surcharge_applicable |= card_network_type.surcharge_details.is_some();
+            }
+        }
+    }
+    Ok(is_surcharge_applicable)
+}
+
+/// Validates the surcharge sent by the client against the surcharge calculated from the rules of
+/// the merchant, the client is expected to send the surcharge shown in the payment method list
+pub fn validate_request_surcharge_details(
+    request_surcharge_details: Option<&RequestSurchargeDetails>,
+    surcharge_details: Option<&SurchargeDetailsResponse>,
+) -> RouterResult<()> {
+    match (request_surcharge_details, surcharge_details) {
+        (None, _) => Ok(()),
+        (Some(request_surcharge_details), Some(surcharge_details))
+            if request_surcharge_details.surcharge_amount == surcharge_details.surcharge_amount
+                && request_surcharge_details.tax_amount.unwrap_or(0)
+                    == surcharge_details.tax_on_surcharge_amount =>
+        {
+            Ok(())
+        }
+        (Some(_), Some(surcharge_details)) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "surcharge_details do not match the surcharge applicable to this payment, expected surcharge_amount {} and tax_amount {}",
+                surcharge_details.surcharge_amount, surcharge_details.tax_on_surcharge_amount
+            ),
+        })
+        .into(),
+        (Some(_), None) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "surcharge is not applicable to this payment".to_string(),
+        })
+        .into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use api_models::payment_methods::SurchargeRule;
+    use common_utils::types::Percentage;
+
+    use super::*;
+
+    fn rules() -> SurchargeRules {
+        SurchargeRules {
+            rules: vec![
+                SurchargeRule {
+                    payment_method: Some(api_enums::PaymentMethod::Card),
+                    payment_method_type: None,
+                    card_network: Some(api_enums::CardNetwork::AmericanExpress),
+                    currency: None,
+                    surcharge: Surcharge::Rate(Percentage::from_string("2.5".to_string()).unwrap()),
+                    tax_on_surcharge: Some(Percentage::from_string("10".to_string()).unwrap()),
+                },
+                SurchargeRule {
+                    payment_method: Some(api_enums::PaymentMethod::Card),
+                    payment_method_type: None,
+                    card_network: None,
+                    currency: Some(api_enums::Currency::USD),
+                    surcharge: Surcharge::Fixed(50),
+                    tax_on_surcharge: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_surcharge_details_from_first_applicable_rule() {
+        let surcharge_details = get_surcharge_details(
+            &rules(),
+            1000,
+            &api_enums::Currency::USD,
+            &api_enums::PaymentMethod::Card,
+            &api_enums::PaymentMethodType::Credit,
+            Some(&api_enums::CardNetwork::AmericanExpress),
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(surcharge_details.surcharge_amount, 25);
+        assert_eq!(surcharge_details.tax_on_surcharge_amount, 3);
+        assert_eq!(surcharge_details.final_amount, 1028);
+
+        let surcharge_details = get_surcharge_details(
+            &rules(),
+            1000,
+            &api_enums::Currency::USD,
+            &api_enums::PaymentMethod::Card,
+            &api_enums::PaymentMethodType::Credit,
+            Some(&api_enums::CardNetwork::Visa),
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(surcharge_details.surcharge_amount, 50);
+        assert_eq!(surcharge_details.final_amount, 1050);
+
+        let surcharge_details = get_surcharge_details(
+            &rules(),
+            1000,
+            &api_enums::Currency::EUR,
+            &api_enums::PaymentMethod::Card,
+            &api_enums::PaymentMethodType::Credit,
+            None,
+        )
+        .unwrap();
+        assert!(surcharge_details.is_none());
+    }
+
+    #[test]
+    fn test_validate_request_surcharge_details() {
+        let surcharge_details = get_surcharge_details(
+            &rules(),
+            1000,
+            &api_enums::Currency::USD,
+            &api_enums::PaymentMethod::Card,
+            &api_enums::PaymentMethodType::Debit,
+            None,
+        )
+        .unwrap();
+        let matching_request = RequestSurchargeDetails {
+            surcharge_amount: 50,
+            tax_amount: None,
+        };
+        let mismatching_request = RequestSurchargeDetails {
+            surcharge_amount: 10,
+            tax_amount: None,
+        };
+
+        assert!(validate_request_surcharge_details(None, surcharge_details.as_ref()).is_ok());
+        assert!(validate_request_surcharge_details(
+            Some(&matching_request),
+            surcharge_details.as_ref()
+        )
+        .is_ok());
+        assert!(validate_request_surcharge_details(
+            Some(&mismatching_request),
+            surcharge_details.as_ref()
+        )
+        .is_err());
+        assert!(validate_request_surcharge_details(Some(&matching_request), None).is_err());
+    }
+}
@@ -3,6 +3,7 @@ pub mod customers;
 pub mod flows;
 pub mod helpers;
 pub mod operations;
+pub mod retry;
 pub mod tokenization;
 pub mod transformers;
 pub mod types;
@@ -17,7 +18,7 @@ use api_models::{
 use common_utils::{ext_traits::AsyncExt, pii};
 use data_models::mandates::MandateData;
 use diesel_models::{ephemeral_key, fraud_check::FraudCheck};
-use error_stack::{IntoReport, ResultExt};
+use error_stack::{report, IntoReport, ResultExt};
 use futures::future::join_all;
 use helpers::ApplePayData;
 use masking::Secret;
@@ -29,8 +30,8 @@ use time;
 
 pub use self::operations::{
     PaymentApprove, PaymentCancel, PaymentCapture, PaymentConfirm, PaymentCreate,
-    PaymentMethodValidate, PaymentReject, PaymentResponse, PaymentSession, PaymentStatus,
-    PaymentUpdate,
+    PaymentIncrementalAuthorization, PaymentMethodValidate, PaymentReject, PaymentResponse,
+    PaymentSession, PaymentStatus, PaymentUpdate,
 };
 use self::{
     flows::{ConstructFlowSpecificData, Feature},
@@ -41,8 +42,9 @@ use crate::{
     configs::settings::PaymentMethodTypeTokenFilter,
     core::{
         errors::{self, CustomResult, RouterResponse, RouterResult},
+        fraud_check,
         payment_methods::PaymentMethodRetrieve,
-        utils,
+        routing, utils,
     },
     db::StorageInterface,
     logger,
@@ -139,8 +141,11 @@ where
     )
     .await?;
 
-    let schedule_time = match &connector {
-        Some(api::ConnectorCallType::Single(connector_data)) => {
+    let schedule_time = match connector
+        .as_ref()
+        .and_then(api::ConnectorCallType::first_connector)
+    {
+        Some(connector_data) => {
             if should_add_task_to_process_tracker(&payment_data) {
                 payment_sync::get_sync_process_schedule_time(
                     &*state.store,
@@ -156,7 +161,7 @@ where
                 None
             }
         }
-        _ => None,
+        None => None,
     };
 
     payment_data = tokenize_in_router_when_confirm_false(
@@ -171,38 +176,127 @@ where
     let mut external_latency = None;
     if let Some(connector_details) = connector {
         payment_data = match connector_details {
-            api::ConnectorCallType::Single(connector) => {
-                let router_data = call_connector_service(
-                    state,
-                    &merchant_account,
-                    &key_store,
-                    connector,
-                    &operation,
-                    &mut payment_data,
-                    &customer,
-                    call_connector_action,
-                    &validate_result,
-                    schedule_time,
-                    header_payload,
-                )
-                .await?;
-
-                let operation = Box::new(PaymentResponse);
-                let db = &*state.store;
-                connector_http_status_code = router_data.connector_http_status_code;
-                external_latency = router_data.external_latency;
-                //add connector http status code metrics
-                add_connector_http_status_code_metrics(connector_http_status_code);
-                operation
-                    .to_post_update_tracker()?
-                    .update_tracker(
-                        db,
-                        &validate_result.payment_id,
-                        payment_data,
-                        router_data,
-                        merchant_account.storage_scheme,
+            api::ConnectorCallType::Single(_) | api::ConnectorCallType::Retryable(_) => {
+                let mut connectors = match connector_details {
+                    api::ConnectorCallType::Retryable(connectors) => connectors,
+                    api::ConnectorCallType::Single(connector) => vec![connector],
+                    api::ConnectorCallType::Multiple(_) => vec![],
+                }
+                .into_iter();
+                let connector = connectors
+                    .next()
+                    .ok_or(errors::ApiErrorResponse::InternalServerError)
+                    .into_report()
+                    .attach_printable("Routing algorithm did not return any connector")?;
+
+                let frm_data = if is_operation_confirm(&operation) {
+                    fraud_check::get_frm_data(
+                        state,
+                        &merchant_account,
+                        &key_store,
+                        &mut payment_data,
                     )
                     .await?
+                } else {
+                    None
+                };
+
+                let frm_suggestion = match frm_data {
+                    Some(ref frm_data) if fraud_check::is_pre_authorization_check(frm_data) => {
+                        fraud_check::call_frm_before_connector_call(
+                            state,
+                            &merchant_account,
+                            frm_data,
+                            &mut payment_data,
+                        )
+                        .await?
+                    }
+                    _ => None,
+                };
+
+                if let Some(frm_suggestion) = frm_suggestion {
+                    // The payment is not sent to the connector, it is either cancelled or held
+                    // for the merchant to approve or reject it
+                    let (_, payment_data) = operation
+                        .to_update_tracker()?
+                        .update_trackers(
+                            &*state.store,
+                            payment_data.clone(),
+                            customer.clone(),
+                            validate_result.storage_scheme,
+                            None,
+                            &key_store,
+                            Some(frm_suggestion),
+                            header_payload,
+                        )
+                        .await?;
+                    payment_data
+                } else {
+                    let mut router_data = call_connector_service(
+                        state,
+                        &merchant_account,
+                        &key_store,
+                        connector,
+                        &operation,
+                        &mut payment_data,
+                        &customer,
+                        call_connector_action.clone(),
+                        &validate_result,
+                        schedule_time,
+                        header_payload,
+                    )
+                    .await?;
+
+                    // Failed authorizations are retried on the rest of the routed connectors
+                    if is_operation_confirm(&operation) {
+                        router_data = retry::do_auto_retries(
+                            state,
+                            &merchant_account,
+                            &key_store,
+                            connectors.collect(),
+                            &operation,
+                            &mut payment_data,
+                            &customer,
+                            call_connector_action,
+                            &validate_result,
+                            schedule_time,
+                            header_payload,
+                            router_data,
+                        )
+                        .await?;
+                    }
+
+                    let operation = Box::new(PaymentResponse);
+                    let db = &*state.store;
+                    connector_http_status_code = router_data.connector_http_status_code;
+                    external_latency = router_data.external_latency;
+                    //add connector http status code metrics
+                    add_connector_http_status_code_metrics(connector_http_status_code);
+                    let payment_data = operation
+                        .to_post_update_tracker()?
+                        .update_tracker(
+                            db,
+                            &validate_result.payment_id,
+                            payment_data,
+                            router_data,
+                            merchant_account.storage_scheme,
+                        )
+                        .await?;
+
+                    match frm_data {
+                        Some(ref frm_data) => {
+                            fraud_check::call_frm_after_connector_call(
+                                state,
+                                &merchant_account,
+                                &key_store,
+                                frm_data,
+                                payment_data,
+                            )
+                            .await?
+                        }
+                        None => payment_data,
+                    }
+                }
             }
 
             api::ConnectorCallType::Multiple(connectors) => {
@@ -314,6 +408,154 @@ where
     )
 }
 
+/// Captures a payment and, for a `final_capture`, releases the amount which is still authorized
+/// at the connector once the capture leaves the payment partially captured
+pub async fn payments_capture_core<Ctx: PaymentMethodRetrieve>(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: api::PaymentsCaptureRequest,
+    header_payload: HeaderPayload,
+) -> RouterResponse<api::PaymentsResponse> {
+    let payment_id = req.payment_id.clone();
+    let final_capture = req.final_capture.unwrap_or(false);
+    let merchant_connector_details = req.merchant_connector_details.clone();
+
+    let capture_response = payments_core::<api::Capture, api::PaymentsResponse, _, _, _, Ctx>(
+        state.clone(),
+        merchant_account.clone(),
+        key_store.clone(),
+        PaymentCapture,
+        req,
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+        header_payload,
+    )
+    .await?;
+
+    let intent_status = match &capture_response {
+        services::ApplicationResponse::Json(response)
+        | services::ApplicationResponse::JsonWithHeaders((response, _)) => Some(response.status),
+        _ => None,
+    };
+
+    if !final_capture || intent_status != Some(enums::IntentStatus::PartiallyCapturedAndCapturable)
+    {
+        return Ok(capture_response);
+    }
+
+    let cancel_request = api::PaymentsCancelRequest {
+        payment_id,
+        cancellation_reason: Some("Remaining authorization released on final capture".to_string()),
+        merchant_connector_details,
+    };
+
+    let cancel_response = payments_core::<api::Void, api::PaymentsResponse, _, _, _, Ctx>(
+        state,
+        merchant_account,
+        key_store,
+        PaymentCancel,
+        cancel_request,
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+        header_payload,
+    )
+    .await;
+
+    // The capture has already succeeded at the connector, so a failure to release the remaining
+    // authorization leaves the payment capturable instead of failing the capture request
+    match cancel_response {
+        Ok(cancel_response) => Ok(cancel_response),
+        Err(error) => {
+            logger::error!(release_authorization_error=?error);
+            Ok(capture_response)
+        }
+    }
+}
+
+/// Rejects a payment held for the merchant's review by a fraud check. A payment held after
+/// authorization is voided at the connector first, and is marked as failed only once the
+/// authorized amount is released.
+pub async fn payments_reject_core<Ctx: PaymentMethodRetrieve>(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: api::PaymentsRejectRequest,
+    header_payload: HeaderPayload,
+) -> RouterResponse<api::PaymentsResponse> {
+    let db = &*state.store;
+    let storage_scheme = merchant_account.storage_scheme;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &req.payment_id,
+            &merchant_account.merchant_id,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            &merchant_account.merchant_id,
+            payment_intent.active_attempt.get_id().as_str(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if helpers::is_authorization_held_for_review(&payment_intent, &payment_attempt) {
+        let cancel_request = api::PaymentsCancelRequest {
+            payment_id: req.payment_id.clone(),
+            cancellation_reason: Some("Payment rejected after fraud review".to_string()),
+            merchant_connector_details: None,
+        };
+
+        let cancel_response = payments_core::<api::Void, api::PaymentsResponse, _, _, _, Ctx>(
+            state.clone(),
+            merchant_account.clone(),
+            key_store.clone(),
+            PaymentCancel,
+            cancel_request,
+            services::AuthFlow::Merchant,
+            CallConnectorAction::Trigger,
+            header_payload,
+        )
+        .await?;
+
+        let intent_status = match &cancel_response {
+            services::ApplicationResponse::Json(response)
+            | services::ApplicationResponse::JsonWithHeaders((response, _)) => {
+                Some(response.status)
+            }
+            _ => None,
+        };
+
+        if intent_status != Some(enums::IntentStatus::Cancelled) {
+            return Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
+                current_flow: "reject".to_string(),
+                field_name: "status".to_string(),
+                current_value: intent_status
+                    .map(|status| status.to_string())
+                    .unwrap_or_default(),
+                states: enums::IntentStatus::Cancelled.to_string(),
+            }))
+            .attach_printable("Failed to void the authorization of the rejected payment");
+        }
+    }
+
+    payments_core::<api::Reject, api::PaymentsResponse, _, _, _, Ctx>(
+        state,
+        merchant_account,
+        key_store,
+        PaymentReject,
+        req,
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+        header_payload,
+    )
+    .await
+}
+
 fn is_start_pay<Op: Debug>(operation: &Op) -> bool {
     format!("{operation:?}").eq("PaymentStart")
 }
@@ -1481,6 +1723,7 @@ where
     pub surcharge_details: Option<SurchargeDetailsResponse>,
     pub frm_message: Option<FraudCheck>,
     pub payment_link_data: Option<api_models::payments::PaymentLinkResponse>,
+    pub incremental_authorizations: Vec<storage::IncrementalAuthorization>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -1560,19 +1803,24 @@ pub fn should_call_connector<Op: Debug, F: Clone>(
                     | storage_enums::IntentStatus::RequiresCustomerAction
                     | storage_enums::IntentStatus::RequiresMerchantAction
                     | storage_enums::IntentStatus::RequiresCapture
-                    | storage_enums::IntentStatus::PartiallyCaptured
+                    | storage_enums::IntentStatus::PartiallyCapturedAndCapturable
             ) && payment_data.force_sync.unwrap_or(false)
         }
-        "PaymentCancel" => matches!(
-            payment_data.payment_intent.status,
-            storage_enums::IntentStatus::RequiresCapture
-                | storage_enums::IntentStatus::PartiallyCaptured
-        ),
+        "PaymentCancel" => {
+            matches!(
+                payment_data.payment_intent.status,
+                storage_enums::IntentStatus::RequiresCapture
+                    | storage_enums::IntentStatus::PartiallyCapturedAndCapturable
+            ) || helpers::is_authorization_held_for_review(
+                &payment_data.payment_intent,
+                &payment_data.payment_attempt,
+            )
+        }
         "PaymentCapture" => {
             matches!(
                 payment_data.payment_intent.status,
                 storage_enums::IntentStatus::RequiresCapture
-                    | storage_enums::IntentStatus::PartiallyCaptured
+                    | storage_enums::IntentStatus::PartiallyCapturedAndCapturable
             ) || (matches!(
                 payment_data.payment_intent.status,
                 storage_enums::IntentStatus::Processing
@@ -1582,8 +1830,18 @@ pub fn should_call_connector<Op: Debug, F: Clone>(
             ))
         }
         "CompleteAuthorize" => true,
-        "PaymentApprove" => true,
+        // Payments held after authorization only need their status restored on approval
+        "PaymentApprove" => payment_data
+            .frm_message
+            .as_ref()
+            .map_or(true, |fraud_check| {
+                fraud_check.frm_transaction_type != storage_enums::FraudCheckType::PostFrm
+            }),
         "PaymentSession" => true,
+        "PaymentIncrementalAuthorization" => matches!(
+            payment_data.payment_intent.status,
+            storage_enums::IntentStatus::RequiresCapture
+        ),
         _ => false,
     }
 }
@@ -1751,6 +2009,45 @@ pub async fn get_filters_for_payments(
     ))
 }
 
+pub async fn list_payment_captures(
+    state: AppState,
+    merchant: domain::MerchantAccount,
+    payment_id: String,
+) -> RouterResponse<api::CaptureListResponse> {
+    let db = state.store.as_ref();
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_id,
+            &merchant.merchant_id,
+            merchant.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let captures = db
+        .find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+            &merchant.merchant_id,
+            &payment_id,
+            &payment_intent.active_attempt.get_id(),
+            merchant.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let data: Vec<api::CaptureResponse> = captures
+        .into_iter()
+        .map(ForeignFrom::foreign_from)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        api::CaptureListResponse {
+            payment_id,
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
 pub async fn add_process_sync_task(
     db: &dyn StorageInterface,
     payment_attempt: &storage::PaymentAttempt,
@@ -1851,15 +2148,18 @@ where
                 api::ConnectorCallType::Multiple(session_connectors)
             }
 
-            api::ConnectorChoice::StraightThrough(straight_through) => connector_selection(
-                state,
-                merchant_account,
-                payment_data,
-                Some(straight_through),
-            )?,
+            api::ConnectorChoice::StraightThrough(straight_through) => {
+                connector_selection(
+                    state,
+                    merchant_account,
+                    payment_data,
+                    Some(straight_through),
+                )
+                .await?
+            }
 
             api::ConnectorChoice::Decide => {
-                connector_selection(state, merchant_account, payment_data, None)?
+                connector_selection(state, merchant_account, payment_data, None).await?
             }
         })
     } else if let api::ConnectorChoice::StraightThrough(val) = connector_choice {
@@ -1874,7 +2174,7 @@ where
     Ok(connector)
 }
 
-pub fn connector_selection<F>(
+pub async fn connector_selection<F>(
     state: &AppState,
     merchant_account: &domain::MerchantAccount,
     payment_data: &mut PaymentData<F>,
@@ -1913,12 +2213,16 @@ where
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Invalid straight through routing rules format")?;
 
+    let routing_input = routing::RoutingInput::from(&*payment_data);
     let decided_connector = decide_connector(
         state,
         merchant_account,
+        payment_data.payment_intent.profile_id.as_ref(),
+        &routing_input,
         request_straight_through,
         &mut routing_data,
-    )?;
+    )
+    .await?;
 
     let encoded_algorithm = routing_data
         .algorithm
@@ -1933,9 +2237,11 @@ where
     Ok(decided_connector)
 }
 
-pub fn decide_connector(
+pub async fn decide_connector(
     state: &AppState,
     merchant_account: &domain::MerchantAccount,
+    profile_id: Option<&String>,
+    routing_input: &routing::RoutingInput,
     request_straight_through: Option<api::StraightThroughAlgorithm>,
     routing_data: &mut storage::RoutingData,
 ) -> RouterResult<api::ConnectorCallType> {
@@ -1986,9 +2292,23 @@ pub fn decide_connector(
         return Ok(api::ConnectorCallType::Single(connector_data));
     }
 
-    let routing_algorithm = merchant_account
-        .routing_algorithm
-        .clone()
+    // The business profile's routing algorithm takes precedence over the merchant account's
+    let business_profile_routing_algorithm = match profile_id {
+        Some(profile_id) => {
+            state
+                .store
+                .find_business_profile_by_profile_id(profile_id)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                    id: profile_id.to_owned(),
+                })?
+                .routing_algorithm
+        }
+        None => None,
+    };
+
+    let routing_algorithm = business_profile_routing_algorithm
+        .or_else(|| merchant_account.routing_algorithm.clone())
         .get_required_value("RoutingAlgorithm")
         .change_context(errors::ApiErrorResponse::PreconditionFailed {
             message: "no routing algorithm has been configured".to_string(),
@@ -1997,21 +2317,33 @@ pub fn decide_connector(
         .change_context(errors::ApiErrorResponse::InternalServerError) // Deserialization failed
         .attach_printable("Unable to deserialize merchant routing algorithm")?;
 
-    let connector_name = match routing_algorithm {
-        api::RoutingAlgorithm::Single(conn) => conn.to_string(),
-    };
+    let ranked_connectors = routing::perform_routing(&routing_algorithm, routing_input);
+    let connectors = ranked_connectors
+        .iter()
+        .map(|conn| {
+            api::ConnectorData::get_connector_by_name(
+                &state.conf.connectors,
+                &conn.to_string(),
+                api::GetToken::Connector,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Routing algorithm gave invalid connector")
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
 
-    let connector_data = api::ConnectorData::get_connector_by_name(
-        &state.conf.connectors,
-        &connector_name,
-        api::GetToken::Connector,
-    )
-    .change_context(errors::ApiErrorResponse::InternalServerError)
-    .attach_printable("Routing algorithm gave invalid connector")?;
+    let first_connector = connectors
+        .first()
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "routing algorithm did not return any eligible connector".to_string(),
+        })
+        .into_report()?;
 
-    routing_data.routed_through = Some(connector_name);
+    routing_data.routed_through = ranked_connectors.first().map(ToString::to_string);
 
-    Ok(api::ConnectorCallType::Single(connector_data))
+    Ok(match routing_algorithm {
+        api::RoutingAlgorithm::Single(_) => api::ConnectorCallType::Single(first_connector.clone()),
+        _ => api::ConnectorCallType::Retryable(connectors),
+    })
 }
 
 pub fn should_add_task_to_process_tracker<F: Clone>(payment_data: &PaymentData<F>) -> bool {
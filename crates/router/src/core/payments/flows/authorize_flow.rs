@@ -83,6 +83,12 @@ impl Feature<api::Authorize, types::PaymentsAuthorizeData> for types::PaymentsAu
         if self.should_proceed_with_authorize() {
             self.decide_authentication_type();
             logger::debug!(auth_type=?self.auth_type);
+            let mandate_debit = if call_connector_action == payments::CallConnectorAction::Trigger {
+                mandate::reserve_mandate_debit(state, &self, merchant_account.storage_scheme)
+                    .await?
+            } else {
+                None
+            };
             let resp = services::execute_connector_processing_step(
                 state,
                 connector_integration,
@@ -93,6 +99,29 @@ impl Feature<api::Authorize, types::PaymentsAuthorizeData> for types::PaymentsAu
             .await
             .to_payment_failed_response()?;
 
+            // The debit reserved on the mandate is released once the connector has declined the
+            // payment, it is kept when the connector could not be reached as the payment might
+            // still have been made
+            if let Some(mandate_debit) = mandate_debit {
+                if resp.response.is_err()
+                    || matches!(
+                        resp.status,
+                        diesel_models::enums::AttemptStatus::Failure
+                            | diesel_models::enums::AttemptStatus::AuthorizationFailed
+                    )
+                {
+                    mandate::release_mandate_debit(
+                        state,
+                        &resp.merchant_id,
+                        mandate_debit,
+                        merchant_account.storage_scheme,
+                    )
+                    .await
+                    .map_err(|error| logger::error!(mandate_debit_release_error=?error))
+                    .ok();
+                }
+            }
+
             metrics::PAYMENT_COUNT.add(&metrics::CONTEXT, 1, &[]); // Metrics
 
             let save_payment_result = tokenization::save_payment_method(
@@ -118,7 +147,14 @@ impl Feature<api::Authorize, types::PaymentsAuthorizeData> for types::PaymentsAu
                 }
             }?;
 
-            Ok(mandate::mandate_procedure(state, resp, maybe_customer, pm_id).await?)
+            Ok(mandate::mandate_procedure(
+                state,
+                resp,
+                maybe_customer,
+                pm_id,
+                merchant_account.storage_scheme,
+            )
+            .await?)
         } else {
             Ok(self.clone())
         }
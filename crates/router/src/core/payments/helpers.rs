@@ -22,7 +22,7 @@ use openssl::{
     symm::{decrypt_aead, Cipher},
 };
 use router_env::{instrument, logger, tracing};
-use time::Duration;
+use time::{Duration, PrimitiveDateTime};
 use uuid::Uuid;
 use x509_parser::parse_x509_certificate;
 
@@ -31,11 +31,14 @@ use super::{
     CustomerDetails, PaymentData,
 };
 use crate::{
-    configs::settings::{ConnectorRequestReferenceIdConfig, Server, TempLockerEnableConfig},
+    configs::settings::{
+        ConnectorRequestReferenceIdConfig, InstallmentConfig, Server, TempLockerEnableConfig,
+    },
     connector,
     consts::{self, BASE64_ENGINE},
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        mandate::get_frequency_period_start,
         payment_methods::{cards, vault, PaymentMethodRetrieve},
         payments,
     },
@@ -466,7 +469,11 @@ pub async fn get_token_for_recurring_mandate(
     let mandate_id = req.mandate_id.clone().get_required_value("mandate_id")?;
 
     let mandate = db
-        .find_mandate_by_merchant_id_mandate_id(&merchant_account.merchant_id, mandate_id.as_str())
+        .find_mandate_by_merchant_id_mandate_id(
+            &merchant_account.merchant_id,
+            mandate_id.as_str(),
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
 
@@ -489,6 +496,7 @@ pub async fn get_token_for_recurring_mandate(
         req.amount.get_required_value("amount")?.into(),
         req.currency.get_required_value("currency")?,
         mandate.clone(),
+        common_utils::date_time::now(),
     )?;
 
     let payment_method = db
@@ -588,6 +596,69 @@ pub fn validate_request_amount_and_amount_to_capture(
     }
 }
 
+/// Validates the installment data of a payment against the installment plans configured for the
+/// payment method type and the connectors of the payment, when they are known
+#[instrument(skip_all)]
+pub fn validate_installment_data(
+    installment_config: &InstallmentConfig,
+    installment_data: Option<&api_models::payments::InstallmentData>,
+    payment_method_type: Option<storage_enums::PaymentMethodType>,
+    connectors: Option<&Vec<api_enums::Connector>>,
+) -> RouterResult<()> {
+    let Some(installment_data) = installment_data else {
+        return Ok(());
+    };
+
+    utils::when(installment_data.number_of_installments <= 1, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "number_of_installments must be greater than 1".to_string()
+        }))
+    })?;
+
+    let installment_plans = installment_config
+        .0
+        .iter()
+        .filter(|(pm_type, _)| payment_method_type.map_or(true, |pmt| pmt == **pm_type))
+        .flat_map(|(_, connector_installment_plans)| connector_installment_plans.0.iter())
+        .filter(|(connector_name, _)| {
+            connectors.map_or(true, |connectors| {
+                connectors
+                    .iter()
+                    .any(|connector| connector.to_string() == **connector_name)
+            })
+        })
+        .map(|(_, installment_plans)| installment_plans)
+        .collect::<Vec<_>>();
+
+    utils::when(installment_plans.is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "installments are not supported for the payment method type or connector \
+                      of the payment"
+                .to_string()
+        }))
+    })?;
+
+    let is_plan_supported = installment_plans.iter().any(|installment_plans| {
+        installment_plans
+            .number_of_installments
+            .contains(&installment_data.number_of_installments)
+            && installment_data.plan_id.as_ref().map_or(true, |plan_id| {
+                installment_plans
+                    .plan_ids
+                    .as_ref()
+                    .map_or(false, |plan_ids| plan_ids.contains(plan_id))
+            })
+    });
+    utils::when(!is_plan_supported, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "an installment plan of {} installments is not supported for the payment",
+                installment_data.number_of_installments
+            )
+        }))
+    })
+}
+
 #[instrument(skip_all)]
 pub fn validate_card_data(
     payment_method_data: Option<api::PaymentMethodData>,
@@ -745,7 +816,7 @@ fn validate_new_mandate_request(
         Some(api_models::payments::MandateType::MultiUse(details)) => details,
         None => None,
     };
-    mandate_details.and_then(|md| md.start_date.zip(md.end_date)).map(|(start_date, end_date)|
+    mandate_details.as_ref().and_then(|md| md.start_date.zip(md.end_date)).map(|(start_date, end_date)|
         utils::when (start_date >= end_date, || {
         Err(report!(errors::ApiErrorResponse::PreconditionFailed {
             message: "`mandate_data.mandate_type.{multi_use|single_use}.start_date` should be greater than  \
@@ -754,6 +825,42 @@ fn validate_new_mandate_request(
         }))
     })).transpose()?;
 
+    if let Some(mandate_details) = mandate_details {
+        utils::when(
+            mandate_details
+                .max_amount_per_transaction
+                .map_or(false, |max_amount| max_amount <= 0),
+            || {
+                Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "`mandate_data.mandate_type.{multi_use|single_use}.max_amount_per_transaction` \
+                              should be greater than 0"
+                        .into()
+                }))
+            },
+        )?;
+        match (
+            mandate_details.frequency,
+            mandate_details.max_debits_per_frequency,
+        ) {
+            (Some(_), Some(max_debits)) if max_debits <= 0 => {
+                Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "`mandate_data.mandate_type.{multi_use|single_use}.max_debits_per_frequency` \
+                              should be greater than 0"
+                        .into()
+                }))?
+            }
+            (Some(_), Some(_)) | (None, None) => {}
+            (Some(_), None) | (None, Some(_)) => {
+                Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "`mandate_data.mandate_type.{multi_use|single_use}.frequency` and \
+                              `mandate_data.mandate_type.{multi_use|single_use}.max_debits_per_frequency` \
+                              should be provided together"
+                        .into()
+                }))?
+            }
+        }
+    }
+
     Ok(())
 }
 
@@ -855,7 +962,58 @@ pub fn verify_mandate_details(
     request_amount: i64,
     request_currency: api_enums::Currency,
     mandate: storage::Mandate,
+    current_time: PrimitiveDateTime,
 ) -> RouterResult<()> {
+    utils::when(
+        mandate
+            .start_date
+            .map_or(false, |start_date| current_time < start_date),
+        || {
+            Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+                reason: "mandate is not valid yet".to_string()
+            }))
+        },
+    )?;
+    utils::when(
+        mandate
+            .end_date
+            .map_or(false, |end_date| current_time > end_date),
+        || {
+            Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+                reason: "mandate has expired".to_string()
+            }))
+        },
+    )?;
+    utils::when(
+        mandate
+            .max_amount_per_transaction
+            .map_or(false, |max_amount| request_amount > max_amount),
+        || {
+            Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+                reason: "request amount is greater than the maximum amount per transaction of \
+                         the mandate"
+                    .to_string()
+            }))
+        },
+    )?;
+    if let Some((frequency, max_debits)) = mandate.frequency.zip(mandate.max_debits_per_frequency) {
+        // The debit count is only for the period in which the mandate was last debited
+        let period_start = get_frequency_period_start(frequency, current_time);
+        let debit_count = if mandate.frequency_period_start == Some(period_start) {
+            mandate.frequency_debit_count.unwrap_or(0)
+        } else {
+            0
+        };
+        utils::when(debit_count >= max_debits, || {
+            Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+                reason: format!(
+                    "maximum number of debits allowed by the mandate in the {frequency} period \
+                     has been reached"
+                )
+            }))
+        })?;
+    }
+
     match mandate.mandate_type {
         storage_enums::MandateType::SingleUse => utils::when(
             mandate
@@ -1325,6 +1483,33 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, Ctx>(
     ))
 }
 
+/// Get the key with which the payment method of a token returned in the payment method list is
+/// stored in the locker
+pub async fn get_locker_key_from_payment_token(
+    state: &AppState,
+    token: &str,
+    payment_method: storage_enums::PaymentMethod,
+) -> RouterResult<String> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let key = format!("pm_token_{token}_{payment_method}_hyperswitch");
+
+    redis_conn
+        .get_key::<Option<String>>(&key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the token from redis")?
+        .ok_or(error_stack::Report::new(
+            errors::ApiErrorResponse::UnprocessableEntity {
+                message: "Token is invalid or expired".to_owned(),
+            },
+        ))
+}
+
 pub async fn make_pm_data<'a, F: Clone, R, Ctx: PaymentMethodRetrieve>(
     operation: BoxedOperation<'a, F, R, Ctx>,
     state: &'a AppState,
@@ -1340,34 +1525,12 @@ pub async fn make_pm_data<'a, F: Clone, R, Ctx: PaymentMethodRetrieve>(
         Some(_) => token,
         None => {
             if let Some(token) = token {
-                let redis_conn = state
-                    .store
-                    .get_redis_conn()
-                    .change_context(errors::ApiErrorResponse::InternalServerError)
-                    .attach_printable("Failed to get redis connection")?;
-
-                let key = format!(
-                    "pm_token_{}_{}_hyperswitch",
-                    token,
-                    payment_data
-                        .payment_attempt
-                        .payment_method
-                        .to_owned()
-                        .get_required_value("payment_method")?,
-                );
-
-                let key = redis_conn
-                    .get_key::<Option<String>>(&key)
-                    .await
-                    .change_context(errors::ApiErrorResponse::InternalServerError)
-                    .attach_printable("Failed to fetch the token from redis")?
-                    .ok_or(error_stack::Report::new(
-                        errors::ApiErrorResponse::UnprocessableEntity {
-                            message: "Token is invalid or expired".to_owned(),
-                        },
-                    ))?;
-
-                Some(key)
+                let payment_method = payment_data
+                    .payment_attempt
+                    .payment_method
+                    .to_owned()
+                    .get_required_value("payment_method")?;
+                Some(get_locker_key_from_payment_token(state, &token, payment_method).await?)
             } else {
                 None
             }
@@ -1570,14 +1733,15 @@ pub(crate) fn validate_status_with_capture_method(
     }
     utils::when(
         status != storage_enums::IntentStatus::RequiresCapture
-            && status != storage_enums::IntentStatus::PartiallyCaptured
+            && status != storage_enums::IntentStatus::PartiallyCapturedAndCapturable
             && status != storage_enums::IntentStatus::Processing,
         || {
             Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
                 field_name: "payment.status".to_string(),
                 current_flow: "captured".to_string(),
                 current_value: status.to_string(),
-                states: "requires_capture, partially_captured, processing".to_string()
+                states: "requires_capture, partially_captured_and_capturable, processing"
+                    .to_string()
             }))
         },
     )
@@ -1598,6 +1762,108 @@ pub(crate) fn validate_amount_to_capture(
     )
 }
 
+#[instrument(skip_all)]
+pub(crate) fn validate_incremental_authorization(
+    status: storage_enums::IntentStatus,
+    capture_method: Option<storage_enums::CaptureMethod>,
+    authorized_amount: i64,
+    requested_amount: i64,
+) -> RouterResult<()> {
+    utils::when(
+        capture_method != Some(storage_enums::CaptureMethod::Manual),
+        || {
+            Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
+                field_name: "capture_method".to_string(),
+                current_flow: "incremental_authorization".to_string(),
+                current_value: capture_method
+                    .map(|capture_method| capture_method.to_string())
+                    .unwrap_or_default(),
+                states: "manual".to_string()
+            }))
+        },
+    )?;
+    utils::when(
+        status != storage_enums::IntentStatus::RequiresCapture,
+        || {
+            Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
+                field_name: "payment.status".to_string(),
+                current_flow: "incremental_authorization".to_string(),
+                current_value: status.to_string(),
+                states: "requires_capture".to_string()
+            }))
+        },
+    )?;
+    utils::when(requested_amount <= authorized_amount, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount should be greater than the currently authorized amount".to_string()
+        }))
+    })
+}
+
+/// Applies an incremental authorization accepted by the connector to the payment, the increment
+/// is added on top of the amounts currently authorized and capturable
+#[instrument(skip_all)]
+pub async fn apply_incremental_authorization_amount(
+    db: &dyn StorageInterface,
+    payment_attempt: PaymentAttempt,
+    payment_intent: PaymentIntent,
+    authorization: &storage::IncrementalAuthorization,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<(PaymentAttempt, PaymentIntent)> {
+    let additional_amount = authorization.amount - authorization.previous_amount;
+    let amount_capturable = payment_attempt.amount_capturable + additional_amount;
+    let payment_attempt = db
+        .update_payment_attempt_with_attempt_id(
+            payment_attempt,
+            storage::PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
+                amount: authorization.amount,
+                amount_capturable,
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let amount = payment_intent.amount + additional_amount;
+    let payment_intent = db
+        .update_payment_intent(
+            payment_intent,
+            storage::PaymentIntentUpdate::IncrementalAuthorizationAmountUpdate {
+                amount,
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    Ok((payment_attempt, payment_intent))
+}
+
+#[instrument(skip_all)]
+pub async fn find_capture_by_capture_id(
+    db: &dyn StorageInterface,
+    payment_attempt: &PaymentAttempt,
+    capture_id: &str,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<storage::Capture> {
+    db.find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+        &payment_attempt.merchant_id,
+        &payment_attempt.payment_id,
+        &payment_attempt.attempt_id,
+        storage_scheme,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while fetching captures of the payment attempt")?
+    .into_iter()
+    .find(|capture| capture.capture_id == capture_id)
+    .ok_or_else(|| {
+        report!(errors::ApiErrorResponse::CaptureNotFound {
+            capture_id: capture_id.to_owned()
+        })
+    })
+}
+
 #[instrument(skip_all)]
 pub(crate) fn validate_payment_method_fields_present(
     req: &api::PaymentsRequest,
@@ -2155,7 +2421,10 @@ pub fn generate_mandate(
                             .set_mandate_amount(Some(data.amount))
                             .set_mandate_currency(Some(data.currency))
                             .set_start_date(data.start_date)
-                            .set_end_date(data.end_date),
+                            .set_end_date(data.end_date)
+                            .set_max_amount_per_transaction(data.max_amount_per_transaction)
+                            .set_frequency(data.frequency)
+                            .set_max_debits_per_frequency(data.max_debits_per_frequency),
                         // .set_metadata(data.metadata),
                         // we are storing PaymentMethodData in metadata of mandate
                         None => &mut new_mandate,
@@ -2233,6 +2502,16 @@ pub(crate) fn validate_payment_status_against_not_allowed_statuses(
     })
 }
 
+/// Payments held for the merchant's review by a post authorization fraud check still hold the
+/// authorized amount at the connector
+pub(crate) fn is_authorization_held_for_review(
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> bool {
+    payment_intent.status == storage_enums::IntentStatus::RequiresMerchantAction
+        && payment_attempt.status == storage_enums::AttemptStatus::Authorized
+}
+
 #[instrument(skip_all)]
 pub(crate) fn validate_pm_or_token_given(
     payment_method: &Option<api_enums::PaymentMethod>,
@@ -2410,6 +2689,7 @@ mod tests {
             merchant_decision: None,
             payment_confirm_source: None,
             surcharge_applicable: None,
+            request_incremental_authorization: None,
             updated_by: storage_enums::MerchantStorageScheme::PostgresOnly.to_string(),
         };
         let req_cs = Some("1".to_string());
@@ -2460,6 +2740,7 @@ mod tests {
             merchant_decision: None,
             payment_confirm_source: None,
             surcharge_applicable: None,
+            request_incremental_authorization: None,
             updated_by: storage_enums::MerchantStorageScheme::PostgresOnly.to_string(),
         };
         let req_cs = Some("1".to_string());
@@ -2510,6 +2791,7 @@ mod tests {
             merchant_decision: None,
             payment_confirm_source: None,
             surcharge_applicable: None,
+            request_incremental_authorization: None,
             updated_by: storage_enums::MerchantStorageScheme::PostgresOnly.to_string(),
         };
         let req_cs = Some("1".to_string());
@@ -2521,6 +2803,133 @@ mod tests {
         )
         .is_err())
     }
+
+    #[test]
+    fn test_validate_incremental_authorization() {
+        assert!(validate_incremental_authorization(
+            storage_enums::IntentStatus::RequiresCapture,
+            Some(storage_enums::CaptureMethod::Manual),
+            1000,
+            1500,
+        )
+        .is_ok());
+        // the new amount has to be greater than the authorized amount
+        assert!(validate_incremental_authorization(
+            storage_enums::IntentStatus::RequiresCapture,
+            Some(storage_enums::CaptureMethod::Manual),
+            1000,
+            1000,
+        )
+        .is_err());
+        assert!(validate_incremental_authorization(
+            storage_enums::IntentStatus::RequiresCapture,
+            Some(storage_enums::CaptureMethod::Automatic),
+            1000,
+            1500,
+        )
+        .is_err());
+        assert!(validate_incremental_authorization(
+            storage_enums::IntentStatus::PartiallyCaptured,
+            Some(storage_enums::CaptureMethod::Manual),
+            1000,
+            1500,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_status_with_capture_method() {
+        assert!(validate_status_with_capture_method(
+            storage_enums::IntentStatus::PartiallyCapturedAndCapturable,
+            storage_enums::CaptureMethod::ManualMultiple,
+        )
+        .is_ok());
+        // the remaining authorization of a final partial capture has already been released
+        assert!(validate_status_with_capture_method(
+            storage_enums::IntentStatus::PartiallyCaptured,
+            storage_enums::CaptureMethod::ManualMultiple,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_installment_data() {
+        use std::collections::HashMap;
+
+        use api_models::payments::InstallmentData;
+
+        use crate::configs::settings::{ConnectorInstallmentPlans, InstallmentPlans};
+
+        let installment_config = InstallmentConfig(HashMap::from([(
+            storage_enums::PaymentMethodType::Credit,
+            ConnectorInstallmentPlans(HashMap::from([(
+                "adyen".to_string(),
+                InstallmentPlans {
+                    number_of_installments: vec![3, 6],
+                    plan_ids: Some(vec!["regular".to_string()]),
+                },
+            )])),
+        )]));
+        let installment_data = |number_of_installments, plan_id: Option<&str>| InstallmentData {
+            number_of_installments,
+            plan_id: plan_id.map(String::from),
+            interest_bearer: None,
+        };
+        let credit = Some(storage_enums::PaymentMethodType::Credit);
+        let adyen = vec![api_enums::Connector::Adyen];
+        let stripe = vec![api_enums::Connector::Stripe];
+
+        assert!(validate_installment_data(&installment_config, None, credit, None).is_ok());
+        assert!(validate_installment_data(
+            &installment_config,
+            Some(&installment_data(3, Some("regular"))),
+            credit,
+            Some(&adyen),
+        )
+        .is_ok());
+        assert!(validate_installment_data(
+            &installment_config,
+            Some(&installment_data(6, None)),
+            None,
+            None,
+        )
+        .is_ok());
+        assert!(validate_installment_data(
+            &installment_config,
+            Some(&installment_data(1, None)),
+            credit,
+            Some(&adyen),
+        )
+        .is_err());
+        assert!(validate_installment_data(
+            &installment_config,
+            Some(&installment_data(4, None)),
+            credit,
+            Some(&adyen),
+        )
+        .is_err());
+        assert!(validate_installment_data(
+            &installment_config,
+            Some(&installment_data(3, Some("revolving"))),
+            credit,
+            Some(&adyen),
+        )
+        .is_err());
+        assert!(validate_installment_data(
+            &installment_config,
+            Some(&installment_data(3, None)),
+            credit,
+            Some(&stripe),
+        )
+        .is_err());
+        assert!(validate_installment_data(
+            &installment_config,
+            Some(&installment_data(3, None)),
+            Some(storage_enums::PaymentMethodType::Debit),
+            None,
+        )
+        .is_err());
+    }
 }
 
 // This function will be removed after moving this functionality to server_wrap and using cache instead of config
@@ -2750,6 +3159,7 @@ pub fn get_attempt_type(
                     | enums::AttemptStatus::Pending
                     | enums::AttemptStatus::ConfirmationAwaited
                     | enums::AttemptStatus::PartialCharged
+                    | enums::AttemptStatus::PartialChargedAndChargeable
                     | enums::AttemptStatus::Voided
                     | enums::AttemptStatus::AutoRefunded
                     | enums::AttemptStatus::PaymentMethodAwaited
@@ -2810,6 +3220,7 @@ pub fn get_attempt_type(
         enums::IntentStatus::Cancelled
         | enums::IntentStatus::RequiresCapture
         | enums::IntentStatus::PartiallyCaptured
+        | enums::IntentStatus::PartiallyCapturedAndCapturable
         | enums::IntentStatus::Processing
         | enums::IntentStatus::Succeeded => {
             Err(report!(errors::ApiErrorResponse::PreconditionFailed {
@@ -2904,6 +3315,8 @@ impl AttemptType {
             connector_response_reference_id: None,
             amount_capturable: old_payment_attempt.amount,
             updated_by: storage_scheme.to_string(),
+            // If the installment plan is passed in Create call, it needs to be retained for the new attempt, it could be overridden from the request.
+            installment_data: old_payment_attempt.installment_data,
         }
     }
 
@@ -3040,6 +3453,7 @@ pub fn is_manual_retry_allowed(
             | enums::AttemptStatus::Pending
             | enums::AttemptStatus::ConfirmationAwaited
             | enums::AttemptStatus::PartialCharged
+            | enums::AttemptStatus::PartialChargedAndChargeable
             | enums::AttemptStatus::Voided
             | enums::AttemptStatus::AutoRefunded
             | enums::AttemptStatus::PaymentMethodAwaited
@@ -3059,6 +3473,7 @@ pub fn is_manual_retry_allowed(
         enums::IntentStatus::Cancelled
         | enums::IntentStatus::RequiresCapture
         | enums::IntentStatus::PartiallyCaptured
+        | enums::IntentStatus::PartiallyCapturedAndCapturable
         | enums::IntentStatus::Processing
         | enums::IntentStatus::Succeeded => Some(false),
 
@@ -4,6 +4,7 @@ pub mod payment_capture;
 pub mod payment_complete_authorize;
 pub mod payment_confirm;
 pub mod payment_create;
+pub mod payment_incremental_authorization;
 pub mod payment_method_validate;
 pub mod payment_reject;
 pub mod payment_response;
@@ -20,10 +21,11 @@ use router_env::{instrument, tracing};
 pub use self::{
     payment_approve::PaymentApprove, payment_cancel::PaymentCancel,
     payment_capture::PaymentCapture, payment_confirm::PaymentConfirm,
-    payment_create::PaymentCreate, payment_method_validate::PaymentMethodValidate,
-    payment_reject::PaymentReject, payment_response::PaymentResponse,
-    payment_session::PaymentSession, payment_start::PaymentStart, payment_status::PaymentStatus,
-    payment_update::PaymentUpdate,
+    payment_create::PaymentCreate,
+    payment_incremental_authorization::PaymentIncrementalAuthorization,
+    payment_method_validate::PaymentMethodValidate, payment_reject::PaymentReject,
+    payment_response::PaymentResponse, payment_session::PaymentSession,
+    payment_start::PaymentStart, payment_status::PaymentStatus, payment_update::PaymentUpdate,
 };
 use super::{helpers, CustomerDetails, PaymentData};
 use crate::{
@@ -412,3 +414,63 @@ where
         helpers::get_connector_default(state, None).await
     }
 }
+
+#[async_trait]
+impl<
+        F: Clone + Send,
+        Ctx: PaymentMethodRetrieve,
+        Op: Send + Sync + Operation<F, api::PaymentsIncrementalAuthorizationRequest, Ctx>,
+    > Domain<F, api::PaymentsIncrementalAuthorizationRequest, Ctx> for Op
+where
+    for<'a> &'a Op: Operation<F, api::PaymentsIncrementalAuthorizationRequest, Ctx>,
+{
+    #[instrument(skip_all)]
+    async fn get_or_create_customer_details<'a>(
+        &'a self,
+        db: &dyn StorageInterface,
+        payment_data: &mut PaymentData<F>,
+        _request: Option<CustomerDetails>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<
+        (
+            BoxedOperation<'a, F, api::PaymentsIncrementalAuthorizationRequest, Ctx>,
+            Option<domain::Customer>,
+        ),
+        errors::StorageError,
+    > {
+        Ok((
+            Box::new(self),
+            helpers::get_customer_from_details(
+                db,
+                payment_data.payment_intent.customer_id.clone(),
+                &merchant_key_store.merchant_id,
+                payment_data,
+                merchant_key_store,
+            )
+            .await?,
+        ))
+    }
+    #[instrument(skip_all)]
+    async fn make_pm_data<'a>(
+        &'a self,
+        _state: &'a AppState,
+        _payment_data: &mut PaymentData<F>,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> RouterResult<(
+        BoxedOperation<'a, F, api::PaymentsIncrementalAuthorizationRequest, Ctx>,
+        Option<api::PaymentMethodData>,
+    )> {
+        Ok((Box::new(self), None))
+    }
+
+    async fn get_connector<'a>(
+        &'a self,
+        _merchant_account: &domain::MerchantAccount,
+        state: &AppState,
+        _request: &api::PaymentsIncrementalAuthorizationRequest,
+        _payment_intent: &storage::PaymentIntent,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<api::ConnectorChoice, errors::ApiErrorResponse> {
+        helpers::get_connector_default(state, None).await
+    }
+}
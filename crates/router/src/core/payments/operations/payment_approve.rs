@@ -254,6 +254,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 surcharge_details: None,
                 frm_message: frm_response.ok(),
                 payment_link_data: None,
+                incremental_authorizations: vec![],
             },
             Some(CustomerDetails {
                 customer_id: request.customer_id.clone(),
@@ -375,6 +376,28 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
+        // A payment held by a post authorization fraud check is already authorized with the
+        // connector, so approving it only makes it capturable again
+        let is_post_frm = payment_data
+            .frm_message
+            .as_ref()
+            .map_or(false, |fraud_check| {
+                fraud_check.frm_transaction_type == storage_enums::FraudCheckType::PostFrm
+            });
+        if is_post_frm {
+            payment_data.payment_intent = db
+                .update_payment_intent(
+                    payment_data.payment_intent,
+                    storage::PaymentIntentUpdate::PGStatusUpdate {
+                        status: storage_enums::IntentStatus::RequiresCapture,
+                        updated_by: storage_scheme.to_string(),
+                    },
+                    storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+        }
+
         Ok((Box::new(self), payment_data))
     }
 }
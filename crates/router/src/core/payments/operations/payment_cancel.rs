@@ -67,7 +67,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 enums::IntentStatus::Succeeded,
                 enums::IntentStatus::Cancelled,
                 enums::IntentStatus::Processing,
-                enums::IntentStatus::RequiresMerchantAction,
+                enums::IntentStatus::PartiallyCaptured,
             ],
             "cancel",
         )?;
@@ -82,6 +82,16 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
+        // Payments held for the merchant's review can only be cancelled once they are authorized,
+        // which voids the authorization when the merchant rejects the payment
+        if !helpers::is_authorization_held_for_review(&payment_intent, &payment_attempt) {
+            helpers::validate_payment_status_against_not_allowed_statuses(
+                &payment_intent.status,
+                &[enums::IntentStatus::RequiresMerchantAction],
+                "cancel",
+            )?;
+        }
+
         let shipping_address = helpers::create_or_find_address_for_payment_by_request(
             db,
             None,
@@ -174,6 +184,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 surcharge_details: None,
                 frm_message: None,
                 payment_link_data: None,
+                incremental_authorizations: vec![],
             },
             None,
         ))
@@ -203,16 +214,25 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
         F: 'b + Send,
     {
         let cancellation_reason = payment_data.payment_attempt.cancellation_reason.clone();
-        let (intent_status_update, attempt_status_update) =
-            if payment_data.payment_intent.status != enums::IntentStatus::RequiresCapture {
-                let payment_intent_update = storage::PaymentIntentUpdate::PGStatusUpdate {
-                    status: enums::IntentStatus::Cancelled,
-                    updated_by: storage_scheme.to_string(),
-                };
-                (Some(payment_intent_update), enums::AttemptStatus::Voided)
-            } else {
-                (None, enums::AttemptStatus::VoidInitiated)
+        // Payments which still hold an authorization have to be voided at the connector, this
+        // also releases the uncaptured amount of partially captured payments
+        let holds_authorization = matches!(
+            payment_data.payment_intent.status,
+            enums::IntentStatus::RequiresCapture
+                | enums::IntentStatus::PartiallyCapturedAndCapturable
+        ) || helpers::is_authorization_held_for_review(
+            &payment_data.payment_intent,
+            &payment_data.payment_attempt,
+        );
+        let (intent_status_update, attempt_status_update) = if !holds_authorization {
+            let payment_intent_update = storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: enums::IntentStatus::Cancelled,
+                updated_by: storage_scheme.to_string(),
             };
+            (Some(payment_intent_update), enums::AttemptStatus::Voided)
+        } else {
+            (None, enums::AttemptStatus::VoidInitiated)
+        };
 
         if let Some(payment_intent_update) = intent_status_update {
             payment_data.payment_intent = db
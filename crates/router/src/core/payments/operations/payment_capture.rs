@@ -236,6 +236,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 surcharge_details: None,
                 frm_message: None,
                 payment_link_data: None,
+                incremental_authorizations: vec![],
             },
             None,
         ))
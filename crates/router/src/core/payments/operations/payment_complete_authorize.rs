@@ -249,6 +249,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 surcharge_details: None,
                 frm_message: None,
                 payment_link_data: None,
+                incremental_authorizations: vec![],
             },
             Some(CustomerDetails {
                 customer_id: request.customer_id.clone(),
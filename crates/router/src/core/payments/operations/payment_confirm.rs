@@ -12,7 +12,7 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
-        payment_methods::PaymentMethodRetrieve,
+        payment_methods::{surcharge, PaymentMethodRetrieve},
         payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
     },
     db::StorageInterface,
@@ -329,24 +329,77 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             .clone()
             .or(payment_attempt.business_sub_label);
 
+        helpers::validate_installment_data(
+            &state.conf.installment_config,
+            request.installment_data.as_ref(),
+            payment_attempt.payment_method_type,
+            request.connector.as_ref(),
+        )?;
+        payment_attempt.installment_data = request
+            .get_installment_data_as_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode installment data")?
+            .or(payment_attempt.installment_data);
+
         // The operation merges mandate data from both request and payment_attempt
         setup_mandate = setup_mandate.map(|mut sm| {
             sm.mandate_type = payment_attempt.mandate_details.clone().or(sm.mandate_type);
             sm
         });
 
-        // populate payment_data.surcharge_details from request
-        let surcharge_details = request.surcharge_details.map(|surcharge_details| {
-            payment_methods::SurchargeDetailsResponse {
-                surcharge: payment_methods::Surcharge::Fixed(surcharge_details.surcharge_amount),
-                tax_on_surcharge: None,
-                surcharge_amount: surcharge_details.surcharge_amount,
-                tax_on_surcharge_amount: surcharge_details.tax_amount.unwrap_or(0),
-                final_amount: payment_attempt.amount
-                    + surcharge_details.surcharge_amount
-                    + surcharge_details.tax_amount.unwrap_or(0),
+        // The surcharge of the payment is calculated from the surcharge rules of the merchant when
+        // they are configured, and the surcharge sent by the client is validated against it
+        let surcharge_rules = surcharge::get_surcharge_rules(db, merchant_id).await;
+        let surcharge_details = match surcharge_rules {
+            Some(surcharge_rules) => {
+                // The surcharge rules cannot be evaluated without the payment method type, so it
+                // is required rather than accepting the surcharge sent by the client as is
+                let payment_method = payment_attempt
+                    .payment_method
+                    .get_required_value("payment_method")?;
+                let payment_method_type = payment_attempt
+                    .payment_method_type
+                    .get_required_value("payment_method_type")?;
+                let card_network = match payment_method {
+                    storage_enums::PaymentMethod::Card => {
+                        surcharge::get_card_network(
+                            state,
+                            request.payment_method_data.as_ref(),
+                            token.as_deref(),
+                            request.mandate_id.is_some(),
+                        )
+                        .await?
+                    }
+                    _ => None,
+                };
+                let surcharge_details = surcharge::get_surcharge_details(
+                    &surcharge_rules,
+                    payment_attempt.amount,
+                    &currency,
+                    &payment_method,
+                    &payment_method_type,
+                    card_network.as_ref(),
+                )?;
+                surcharge::validate_request_surcharge_details(
+                    request.surcharge_details.as_ref(),
+                    surcharge_details.as_ref(),
+                )?;
+                surcharge_details
             }
-        });
+            None => request.surcharge_details.map(|surcharge_details| {
+                payment_methods::SurchargeDetailsResponse {
+                    surcharge: payment_methods::Surcharge::Fixed(
+                        surcharge_details.surcharge_amount,
+                    ),
+                    tax_on_surcharge: None,
+                    surcharge_amount: surcharge_details.surcharge_amount,
+                    tax_on_surcharge_amount: surcharge_details.tax_amount.unwrap_or(0),
+                    final_amount: payment_attempt.amount
+                        + surcharge_details.surcharge_amount
+                        + surcharge_details.tax_amount.unwrap_or(0),
+                }
+            }),
+        };
 
         Ok((
             Box::new(self),
@@ -384,6 +437,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 surcharge_details,
                 frm_message: None,
                 payment_link_data: None,
+                incremental_authorizations: vec![],
             },
             Some(customer_details),
         ))
@@ -535,6 +589,7 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
             .attach_printable("Failed to encode additional pm data")?;
 
         let business_sub_label = payment_data.payment_attempt.business_sub_label.clone();
+        let installment_data = payment_data.payment_attempt.installment_data.clone();
         let authentication_type = payment_data.payment_attempt.authentication_type;
 
         let (shipping_address, billing_address) = (
@@ -591,6 +646,7 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                     amount_capturable: Some(authorized_amount),
                     surcharge_amount,
                     tax_amount,
+                    installment_data,
                     updated_by: storage_scheme.to_string(),
                 },
                 storage_scheme,
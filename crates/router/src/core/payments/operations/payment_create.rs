@@ -14,6 +14,7 @@ use crate::{
     consts,
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        payment_link,
         payment_methods::PaymentMethodRetrieve,
         payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
         utils::{self as core_utils},
@@ -210,7 +211,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             .as_ref()
             .async_and_then(|mandate_id| async {
                 let mandate = db
-                    .find_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id)
+                    .find_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id, storage_scheme)
                     .await
                     .to_not_found_response(errors::ApiErrorResponse::MandateNotFound);
                 Some(mandate.and_then(|mandate_obj| {
@@ -312,6 +313,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 surcharge_details: None,
                 frm_message: None,
                 payment_link_data,
+                incremental_authorizations: vec![],
             },
             Some(customer_details),
         ))
@@ -593,6 +595,16 @@ impl PaymentCreate {
             .transpose()
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to encode additional pm data")?;
+        helpers::validate_installment_data(
+            &state.conf.installment_config,
+            request.installment_data.as_ref(),
+            payment_method_type,
+            request.connector.as_ref(),
+        )?;
+        let installment_data = request
+            .get_installment_data_as_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode installment data")?;
         let attempt_id = if core_utils::is_merchant_enabled_for_payment_id_as_connector_request_id(
             &state.conf,
             merchant_id,
@@ -629,6 +641,7 @@ impl PaymentCreate {
                 .mandate_data
                 .as_ref()
                 .and_then(|inner| inner.mandate_type.clone().map(Into::into)),
+            installment_data,
             ..storage::PaymentAttemptNew::default()
         })
     }
@@ -721,6 +734,7 @@ impl PaymentCreate {
             payment_link_id,
             payment_confirm_source: None,
             surcharge_applicable: None,
+            request_incremental_authorization: request.request_incremental_authorization,
             updated_by: merchant_account.storage_scheme.to_string(),
         })
     }
@@ -811,6 +825,7 @@ async fn create_payment_link(
         created_at,
         last_modified_at,
         fulfilment_time: payment_link_object.link_expiry,
+        status: enums::PaymentLinkStatus::Active,
     };
     let payment_link_db = db
         .insert_payment_link(payment_link_req)
@@ -819,6 +834,13 @@ async fn create_payment_link(
             message: "payment link already exists!".to_string(),
         })?;
 
+    if let Some(link_expiry) = payment_link_db.fulfilment_time {
+        payment_link::add_payment_link_expiry_task(db, &payment_link_db, link_expiry)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to schedule payment link expiry task")?;
+    }
+
     Ok(Some(api_models::payments::PaymentLinkResponse {
         link: payment_link_db.link_to_pay,
         payment_link_id: payment_link_db.payment_link_id,
@@ -0,0 +1,258 @@
+use std::marker::PhantomData;
+
+use api_models::enums::FrmSuggestion;
+use async_trait::async_trait;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payment_methods::PaymentMethodRetrieve,
+        payments::{self, helpers, operations},
+    },
+    db::StorageInterface,
+    routes::AppState,
+    services,
+    types::{
+        api::{self, PaymentIdTypeExt},
+        domain,
+        storage::{self, enums, payment_attempt::PaymentAttemptExt},
+    },
+    utils::{self, OptionExt},
+};
+
+#[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
+#[operation(ops = "all", flow = "incrementalauthorization")]
+pub struct PaymentIncrementalAuthorization;
+
+#[async_trait]
+impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
+    GetTracker<F, payments::PaymentData<F>, api::PaymentsIncrementalAuthorizationRequest, Ctx>
+    for PaymentIncrementalAuthorization
+{
+    #[instrument(skip_all)]
+    async fn get_trackers<'a>(
+        &'a self,
+        state: &'a AppState,
+        payment_id: &api::PaymentIdType,
+        request: &api::PaymentsIncrementalAuthorizationRequest,
+        _mandate_type: Option<api::MandateTransactionType>,
+        merchant_account: &domain::MerchantAccount,
+        key_store: &domain::MerchantKeyStore,
+        _auth_flow: services::AuthFlow,
+    ) -> RouterResult<(
+        BoxedOperation<'a, F, api::PaymentsIncrementalAuthorizationRequest, Ctx>,
+        payments::PaymentData<F>,
+        Option<payments::CustomerDetails>,
+    )> {
+        let db = &*state.store;
+        let merchant_id = &merchant_account.merchant_id;
+        let storage_scheme = merchant_account.storage_scheme;
+
+        let payment_id = payment_id
+            .get_payment_intent_id()
+            .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(&payment_id, merchant_id, storage_scheme)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                payment_intent.payment_id.as_str(),
+                merchant_id,
+                payment_intent.active_attempt.get_id().as_str(),
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        helpers::validate_incremental_authorization(
+            payment_intent.status,
+            payment_attempt.capture_method,
+            payment_attempt.amount,
+            request.amount,
+        )?;
+        utils::when(
+            payment_intent.request_incremental_authorization != Some(true),
+            || {
+                Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Incremental authorization was not requested for this payment"
+                        .to_string(),
+                }))
+            },
+        )?;
+
+        let connector_response = db
+            .find_connector_response_by_payment_id_merchant_id_attempt_id(
+                &payment_attempt.payment_id,
+                &payment_attempt.merchant_id,
+                &payment_attempt.attempt_id,
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let mut incremental_authorizations = db
+            .find_all_incremental_authorizations_by_merchant_id_payment_id_attempt_id(
+                merchant_id,
+                &payment_id,
+                &payment_attempt.attempt_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        // Increments are applied on top of the amount authorized so far, so a new increment is
+        // only accepted once the connector has resolved the one it is processing
+        utils::when(
+            incremental_authorizations.iter().any(|authorization| {
+                authorization.status == enums::AuthorizationStatus::Processing
+            }),
+            || {
+                Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message:
+                        "An incremental authorization is already being processed for this payment"
+                            .to_string(),
+                }))
+            },
+        )?;
+
+        // The latest increment is the one sent to the connector, it is updated with the
+        // connector response in the post update tracker
+        let authorization = db
+            .insert_incremental_authorization(payment_attempt.make_new_incremental_authorization(
+                request.amount,
+                request.reason.clone(),
+                incremental_authorizations.len(),
+            ))
+            .await
+            .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
+                payment_id: payment_id.clone(),
+            })?;
+        incremental_authorizations.push(authorization);
+
+        let currency = payment_attempt.currency.get_required_value("currency")?;
+        let amount = payment_attempt.amount.into();
+
+        let shipping_address = helpers::create_or_find_address_for_payment_by_request(
+            db,
+            None,
+            payment_intent.shipping_address_id.as_deref(),
+            merchant_id,
+            payment_intent.customer_id.as_ref(),
+            key_store,
+            &payment_intent.payment_id,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+
+        let billing_address = helpers::create_or_find_address_for_payment_by_request(
+            db,
+            None,
+            payment_intent.billing_address_id.as_deref(),
+            merchant_id,
+            payment_intent.customer_id.as_ref(),
+            key_store,
+            &payment_intent.payment_id,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+
+        Ok((
+            Box::new(self),
+            payments::PaymentData {
+                flow: PhantomData,
+                payment_intent,
+                payment_attempt,
+                currency,
+                force_sync: None,
+                amount,
+                email: None,
+                mandate_id: None,
+                mandate_connector: None,
+                setup_mandate: None,
+                token: None,
+                address: payments::PaymentAddress {
+                    shipping: shipping_address.as_ref().map(|a| a.into()),
+                    billing: billing_address.as_ref().map(|a| a.into()),
+                },
+                confirm: None,
+                payment_method_data: None,
+                refunds: vec![],
+                disputes: vec![],
+                attempts: None,
+                connector_response,
+                sessions_token: vec![],
+                card_cvc: None,
+                creds_identifier: None,
+                pm_token: None,
+                connector_customer_id: None,
+                recurring_mandate_payment_data: None,
+                ephemeral_key: None,
+                multiple_capture_data: None,
+                redirect_response: None,
+                surcharge_details: None,
+                frm_message: None,
+                payment_link_data: None,
+                incremental_authorizations,
+            },
+            None,
+        ))
+    }
+}
+
+#[async_trait]
+impl<F: Clone, Ctx: PaymentMethodRetrieve>
+    UpdateTracker<F, payments::PaymentData<F>, api::PaymentsIncrementalAuthorizationRequest, Ctx>
+    for PaymentIncrementalAuthorization
+{
+    #[instrument(skip_all)]
+    async fn update_trackers<'b>(
+        &'b self,
+        _db: &dyn StorageInterface,
+        payment_data: payments::PaymentData<F>,
+        _customer: Option<domain::Customer>,
+        _storage_scheme: enums::MerchantStorageScheme,
+        _updated_customer: Option<storage::CustomerUpdate>,
+        _mechant_key_store: &domain::MerchantKeyStore,
+        _frm_suggestion: Option<FrmSuggestion>,
+        _header_payload: api::HeaderPayload,
+    ) -> RouterResult<(
+        BoxedOperation<'b, F, api::PaymentsIncrementalAuthorizationRequest, Ctx>,
+        payments::PaymentData<F>,
+    )>
+    where
+        F: 'b + Send,
+    {
+        Ok((Box::new(self), payment_data))
+    }
+}
+
+impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
+    ValidateRequest<F, api::PaymentsIncrementalAuthorizationRequest, Ctx>
+    for PaymentIncrementalAuthorization
+{
+    #[instrument(skip_all)]
+    fn validate_request<'a, 'b>(
+        &'b self,
+        request: &api::PaymentsIncrementalAuthorizationRequest,
+        merchant_account: &'a domain::MerchantAccount,
+    ) -> RouterResult<(
+        BoxedOperation<'b, F, api::PaymentsIncrementalAuthorizationRequest, Ctx>,
+        operations::ValidateResult<'a>,
+    )> {
+        Ok((
+            Box::new(self),
+            operations::ValidateResult {
+                merchant_id: &merchant_account.merchant_id,
+                payment_id: api::PaymentIdType::PaymentIntentId(request.payment_id.to_owned()),
+                mandate_type: None,
+                storage_scheme: merchant_account.storage_scheme,
+                requeue: false,
+            },
+        ))
+    }
+}
@@ -200,6 +200,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 surcharge_details: None,
                 frm_message: None,
                 payment_link_data: None,
+                incremental_authorizations: vec![],
             },
             Some(payments::CustomerDetails {
                 customer_id: request.customer_id.clone(),
@@ -402,6 +403,7 @@ impl PaymentMethodValidate {
             merchant_decision: Default::default(),
             payment_confirm_source: Default::default(),
             surcharge_applicable: Default::default(),
+            request_incremental_authorization: Default::default(),
             payment_link_id: Default::default(),
             updated_by: storage_scheme.to_string(),
         }
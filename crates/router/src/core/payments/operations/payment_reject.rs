@@ -160,6 +160,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 surcharge_details: None,
                 frm_message: frm_response.ok(),
                 payment_link_data: None,
+                incremental_authorizations: vec![],
             },
             None,
         ))
@@ -203,8 +204,13 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                         Some(fraud_check.frm_reason.map(|reason| reason.to_string())),
                     )
                 });
+        // The attempt of a payment which was voided before it was rejected remains voided
+        let attempt_status = match payment_data.payment_attempt.status {
+            enums::AttemptStatus::Voided => enums::AttemptStatus::Voided,
+            _ => enums::AttemptStatus::Failure,
+        };
         let attempt_status_update = storage::PaymentAttemptUpdate::RejectUpdate {
-            status: enums::AttemptStatus::Failure,
+            status: attempt_status,
             error_code,
             error_message,
             updated_by: storage_scheme.to_string(),
@@ -1,18 +1,18 @@
 use std::collections::HashMap;
 
 use async_trait::async_trait;
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
 use futures::FutureExt;
 use router_derive;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 
 use super::{Operation, PostUpdateTracker};
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
-        mandate,
+        mandate, payment_link,
         payment_methods::PaymentMethodRetrieve,
-        payments::{types::MultipleCaptureData, PaymentData},
+        payments::{helpers, types::MultipleCaptureData, PaymentData},
         utils as core_utils,
     },
     db::StorageInterface,
@@ -33,7 +33,7 @@ use crate::{
 #[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
 #[operation(
     ops = "post_tracker",
-    flow = "syncdata,authorizedata,canceldata,capturedata,completeauthorizedata,approvedata,rejectdata,setupmandatedata,sessiondata"
+    flow = "syncdata,authorizedata,canceldata,capturedata,completeauthorizedata,approvedata,rejectdata,setupmandatedata,sessiondata,incrementalauthorizationdata"
 )]
 pub struct PaymentResponse;
 
@@ -154,13 +154,25 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCancelData> f
         db: &dyn StorageInterface,
         payment_id: &api::PaymentIdType,
         mut payment_data: PaymentData<F>,
-        router_data: types::RouterData<F, types::PaymentsCancelData, types::PaymentsResponseData>,
+        mut router_data: types::RouterData<
+            F,
+            types::PaymentsCancelData,
+            types::PaymentsResponseData,
+        >,
 
         storage_scheme: enums::MerchantStorageScheme,
     ) -> RouterResult<PaymentData<F>>
     where
         F: 'b + Send,
     {
+        // Voiding a partially captured payment only releases the uncaptured amount, the payment
+        // then ends up in its final partially captured state instead of being cancelled
+        if router_data.status == enums::AttemptStatus::Voided
+            && payment_data.payment_intent.amount_captured > Some(0)
+        {
+            router_data.status = enums::AttemptStatus::PartialCharged;
+        }
+
         payment_data = payment_response_update_tracker(
             db,
             payment_id,
@@ -288,6 +300,89 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::CompleteAuthorizeData
     }
 }
 
+#[async_trait]
+impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsIncrementalAuthorizationData>
+    for PaymentResponse
+{
+    async fn update_tracker<'b>(
+        &'b self,
+        db: &dyn StorageInterface,
+        _payment_id: &api::PaymentIdType,
+        mut payment_data: PaymentData<F>,
+        router_data: types::RouterData<
+            F,
+            types::PaymentsIncrementalAuthorizationData,
+            types::PaymentsResponseData,
+        >,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> RouterResult<PaymentData<F>>
+    where
+        F: 'b + Send,
+    {
+        let authorization = payment_data
+            .incremental_authorizations
+            .pop()
+            .ok_or_else(|| report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Missing incremental authorization in payment data")?;
+
+        let authorization = db
+            .update_incremental_authorization(
+                authorization,
+                get_incremental_authorization_update(router_data.response)?,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        // A declined increment leaves the previously authorized amount untouched, so the
+        // payment itself is only updated once the connector has accepted the new amount
+        if authorization.status == enums::AuthorizationStatus::Success {
+            (payment_data.payment_attempt, payment_data.payment_intent) =
+                helpers::apply_incremental_authorization_amount(
+                    db,
+                    payment_data.payment_attempt.clone(),
+                    payment_data.payment_intent.clone(),
+                    &authorization,
+                    storage_scheme,
+                )
+                .await?;
+            payment_data.amount = authorization.amount.into();
+        }
+
+        payment_data.incremental_authorizations.push(authorization);
+
+        Ok(payment_data)
+    }
+}
+
+fn get_incremental_authorization_update(
+    response: Result<types::PaymentsResponseData, types::ErrorResponse>,
+) -> RouterResult<storage::IncrementalAuthorizationUpdate> {
+    match response {
+        Err(err) => Ok(storage::IncrementalAuthorizationUpdate::StatusUpdate {
+            status: match err.status_code {
+                500..=511 => enums::AuthorizationStatus::Unresolved,
+                _ => enums::AuthorizationStatus::Failure,
+            },
+            error_code: Some(err.code),
+            error_message: Some(err.reason.unwrap_or(err.message)),
+            connector_authorization_id: None,
+        }),
+        Ok(types::PaymentsResponseData::IncrementalAuthorizationResponse {
+            status,
+            connector_authorization_id,
+            error_code,
+            error_message,
+        }) => Ok(storage::IncrementalAuthorizationUpdate::StatusUpdate {
+            status,
+            error_code,
+            error_message,
+            connector_authorization_id,
+        }),
+        Ok(_) => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response received for incremental authorization"),
+    }
+}
+
 #[instrument(skip_all)]
 async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     db: &dyn StorageInterface,
@@ -527,6 +622,9 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
             types::PaymentsResponseData::TokenizationResponse { .. } => (None, None, None),
             types::PaymentsResponseData::ConnectorCustomerResponse { .. } => (None, None, None),
             types::PaymentsResponseData::ThreeDSEnrollmentResponse { .. } => (None, None, None),
+            types::PaymentsResponseData::IncrementalAuthorizationResponse { .. } => {
+                (None, None, None)
+            }
             types::PaymentsResponseData::MultipleCaptureResponse {
                 capture_sync_response_list,
             } => match payment_data.multiple_capture_data {
@@ -643,11 +741,19 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
         router_data.merchant_id,
         payment_data.mandate_id.clone(),
         router_data.response.clone(),
+        storage_scheme,
     );
 
     let (payment_intent, _) = futures::try_join!(payment_intent_fut, mandate_update_fut)?;
     payment_data.payment_intent = payment_intent;
 
+    // The payment has already gone through, failing to update the status of its payment link
+    // should not fail the payment
+    payment_link::update_payment_link_status_for_payment(db, &payment_data.payment_intent)
+        .await
+        .map_err(|error| logger::error!(payment_link_status_update_error=?error))
+        .ok();
+
     Ok(payment_data)
 }
 
@@ -333,13 +333,32 @@ async fn get_tracker_for_sync<
         })?;
 
     let disputes = db
-        .find_disputes_by_merchant_id_payment_id(&merchant_account.merchant_id, &payment_id_str)
+        .find_disputes_by_merchant_id_payment_id(
+            &merchant_account.merchant_id,
+            &payment_id_str,
+            merchant_account.storage_scheme,
+        )
         .await
         .change_context(errors::ApiErrorResponse::PaymentNotFound)
         .attach_printable_lazy(|| {
             format!("Error while retrieving dispute list for, merchant_id: {}, payment_id: {payment_id_str}", &merchant_account.merchant_id)
         })?;
 
+    let incremental_authorizations = db
+        .find_all_incremental_authorizations_by_merchant_id_payment_id_attempt_id(
+            &merchant_account.merchant_id,
+            &payment_id_str,
+            &payment_attempt.attempt_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)
+        .attach_printable_lazy(|| {
+            format!(
+                "Error while retrieving incremental authorizations for, merchant_id: {}, payment_id: {payment_id_str}",
+                &merchant_account.merchant_id
+            )
+        })?;
+
     let frm_response = db
         .find_fraud_check_by_payment_id(payment_id_str.to_string(), merchant_account.merchant_id.clone())
         .await
@@ -412,6 +431,7 @@ async fn get_tracker_for_sync<
             payment_link_data: None,
             surcharge_details: None,
             frm_message: frm_response.ok(),
+            incremental_authorizations,
         },
         None,
     ))
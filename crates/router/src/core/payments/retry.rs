@@ -0,0 +1,370 @@
+use std::collections::HashSet;
+
+use common_utils::ext_traits::StringExt;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+use serde::Deserialize;
+
+use super::{
+    call_connector_service,
+    flows::{ConstructFlowSpecificData, Feature},
+    operations::{BoxedOperation, ValidateResult},
+    CallConnectorAction, PaymentCreate, PaymentData,
+};
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payment_methods::PaymentMethodRetrieve,
+    },
+    db::StorageInterface,
+    logger,
+    routes::{metrics, AppState},
+    services,
+    types::{
+        self, api, domain,
+        storage::{self, enums as storage_enums},
+    },
+    utils,
+};
+
+/// Connector error codes which should, or should not, be retried on the next connector.
+///
+/// Stored as a config with the key `auto_retry_error_codes_{connector}`, for example
+///
+/// ```json
+/// {
+///     "retryable": ["processing_error", "issuer_unavailable"],
+///     "non_retryable": ["card_declined", "insufficient_funds"]
+/// }
+/// ```
+///
+/// Errors whose codes are not present in either of the lists are retried only when the
+/// connector responded with a 5xx status code or did not respond in time.
+#[derive(Debug, Default, Deserialize)]
+pub struct ErrorCodeRetryMapping {
+    #[serde(default)]
+    pub retryable: HashSet<String>,
+    #[serde(default)]
+    pub non_retryable: HashSet<String>,
+}
+
+impl ErrorCodeRetryMapping {
+    pub fn is_retryable(&self, error_response: &types::ErrorResponse) -> bool {
+        if self.non_retryable.contains(&error_response.code) {
+            false
+        } else {
+            self.retryable.contains(&error_response.code) || error_response.status_code >= 500
+        }
+    }
+}
+
+/// Retries a failed authorization on the remaining connectors returned by routing, until one of
+/// them succeeds, an error which cannot be retried is returned, or the maximum number of
+/// attempts configured for the business profile is reached.
+///
+/// Every retry is made on a new payment attempt, after marking the previous one as failed.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub async fn do_auto_retries<F, FData, ApiRequest, Ctx>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    remaining_connectors: Vec<api::ConnectorData>,
+    operation: &BoxedOperation<'_, F, ApiRequest, Ctx>,
+    payment_data: &mut PaymentData<F>,
+    customer: &Option<domain::Customer>,
+    call_connector_action: CallConnectorAction,
+    validate_result: &ValidateResult<'_>,
+    schedule_time: Option<time::PrimitiveDateTime>,
+    header_payload: api_models::payments::HeaderPayload,
+    mut router_data: types::RouterData<F, FData, types::PaymentsResponseData>,
+) -> RouterResult<types::RouterData<F, FData, types::PaymentsResponseData>>
+where
+    F: Send + Clone + Sync,
+    FData: Send + Sync,
+
+    // To create connector flow specific interface data
+    PaymentData<F>: ConstructFlowSpecificData<F, FData, types::PaymentsResponseData>,
+    types::RouterData<F, FData, types::PaymentsResponseData>: Feature<F, FData> + Send,
+    Ctx: PaymentMethodRetrieve,
+
+    // To construct connector flow specific api
+    dyn api::Connector: services::api::ConnectorIntegration<F, FData, types::PaymentsResponseData>,
+{
+    let db = &*state.store;
+    let max_attempts = get_max_payment_attempts(db, &payment_data.payment_intent).await;
+    let mut attempts_made = 1;
+
+    for connector in remaining_connectors {
+        let error_response = match router_data.response {
+            Err(ref error_response) => error_response.clone(),
+            Ok(_) => break,
+        };
+
+        if attempts_made >= max_attempts {
+            metrics::AUTO_RETRY_EXHAUSTED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::request::add_attributes(
+                    "merchant_id",
+                    merchant_account.merchant_id.clone(),
+                )],
+            );
+            break;
+        }
+
+        let is_retryable = get_error_code_retry_mapping(db, &router_data.connector)
+            .await
+            .is_retryable(&error_response);
+        if !is_retryable {
+            break;
+        }
+
+        metrics::AUTO_RETRY_PAYMENT_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &[
+                metrics::request::add_attributes(
+                    "merchant_id",
+                    merchant_account.merchant_id.clone(),
+                ),
+                metrics::request::add_attributes("connector", connector.connector_name.to_string()),
+            ],
+        );
+
+        modify_trackers(
+            db,
+            &connector,
+            payment_data,
+            &error_response,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+
+        router_data = call_connector_service(
+            state,
+            merchant_account,
+            key_store,
+            connector,
+            operation,
+            payment_data,
+            customer,
+            call_connector_action.clone(),
+            validate_result,
+            schedule_time,
+            header_payload,
+        )
+        .await?;
+
+        attempts_made += 1;
+    }
+
+    Ok(router_data)
+}
+
+/// Marks the active attempt as failed and creates a new attempt routed through `connector`,
+/// which becomes the active attempt of the payment
+#[instrument(skip_all)]
+async fn modify_trackers<F>(
+    db: &dyn StorageInterface,
+    connector: &api::ConnectorData,
+    payment_data: &mut PaymentData<F>,
+    error_response: &types::ErrorResponse,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<()>
+where
+    F: Send + Clone + Sync,
+{
+    let failed_payment_attempt = db
+        .update_payment_attempt_with_attempt_id(
+            payment_data.payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::ErrorUpdate {
+                connector: None,
+                status: storage_enums::AttemptStatus::Failure,
+                error_code: Some(Some(error_response.code.clone())),
+                error_message: Some(Some(error_response.message.clone())),
+                error_reason: Some(error_response.reason.clone()),
+                amount_capturable: Some(0),
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let new_attempt_count = payment_data.payment_intent.attempt_count + 1;
+    let new_payment_attempt = db
+        .insert_payment_attempt(
+            make_new_payment_attempt(
+                connector.connector_name.to_string(),
+                failed_payment_attempt,
+                new_attempt_count,
+                storage_scheme,
+            ),
+            storage_scheme,
+        )
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
+            payment_id: payment_data.payment_intent.payment_id.clone(),
+        })?;
+
+    let connector_response = db
+        .insert_connector_response(
+            PaymentCreate::make_connector_response(&new_payment_attempt),
+            storage_scheme,
+        )
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
+            payment_id: payment_data.payment_intent.payment_id.clone(),
+        })?;
+
+    let payment_intent = db
+        .update_payment_intent(
+            payment_data.payment_intent.clone(),
+            storage::PaymentIntentUpdate::PaymentAttemptAndAttemptCountUpdate {
+                active_attempt_id: new_payment_attempt.attempt_id.clone(),
+                attempt_count: new_attempt_count,
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    logger::info!(
+        "auto_retry payment for {} with attempt_id {} through {}",
+        payment_intent.payment_id,
+        new_payment_attempt.attempt_id,
+        connector.connector_name
+    );
+
+    payment_data.payment_intent = payment_intent;
+    payment_data.payment_attempt = new_payment_attempt;
+    payment_data.connector_response = connector_response;
+
+    Ok(())
+}
+
+/// Creates a new attempt from the failed one, retaining the payment method details so that the
+/// payment can be confirmed again without any input from the customer
+fn make_new_payment_attempt(
+    connector: String,
+    old_payment_attempt: storage::PaymentAttempt,
+    new_attempt_count: i16,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> storage::PaymentAttemptNew {
+    let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
+
+    storage::PaymentAttemptNew {
+        attempt_id: utils::get_payment_attempt_id(
+            &old_payment_attempt.payment_id,
+            new_attempt_count,
+        ),
+        payment_id: old_payment_attempt.payment_id,
+        merchant_id: old_payment_attempt.merchant_id,
+        status: storage_enums::AttemptStatus::Started,
+        amount: old_payment_attempt.amount,
+        currency: old_payment_attempt.currency,
+        save_to_locker: old_payment_attempt.save_to_locker,
+        connector: Some(connector),
+        error_message: None,
+        offer_amount: old_payment_attempt.offer_amount,
+        surcharge_amount: old_payment_attempt.surcharge_amount,
+        tax_amount: old_payment_attempt.tax_amount,
+        payment_method_id: old_payment_attempt.payment_method_id,
+        payment_method: old_payment_attempt.payment_method,
+        capture_method: old_payment_attempt.capture_method,
+        capture_on: old_payment_attempt.capture_on,
+        confirm: old_payment_attempt.confirm,
+        authentication_type: old_payment_attempt.authentication_type,
+        created_at,
+        modified_at,
+        last_synced,
+        cancellation_reason: None,
+        amount_to_capture: old_payment_attempt.amount_to_capture,
+        mandate_id: old_payment_attempt.mandate_id,
+        browser_info: old_payment_attempt.browser_info,
+        payment_token: old_payment_attempt.payment_token,
+        error_code: None,
+        connector_metadata: None,
+        payment_experience: old_payment_attempt.payment_experience,
+        payment_method_type: old_payment_attempt.payment_method_type,
+        payment_method_data: old_payment_attempt.payment_method_data,
+        business_sub_label: old_payment_attempt.business_sub_label,
+        straight_through_algorithm: old_payment_attempt.straight_through_algorithm,
+        preprocessing_step_id: None,
+        mandate_details: old_payment_attempt.mandate_details,
+        error_reason: None,
+        connector_response_reference_id: None,
+        multiple_capture_count: None,
+        amount_capturable: old_payment_attempt.amount,
+        updated_by: storage_scheme.to_string(),
+        installment_data: old_payment_attempt.installment_data,
+    }
+}
+
+/// The maximum number of attempts, including the first one, which can be made for a payment.
+///
+/// Configured per business profile with the key `max_payment_attempts_{profile_id}`, automatic
+/// retries are disabled when it is not configured.
+async fn get_max_payment_attempts(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+) -> i32 {
+    let max_payment_attempts = match payment_intent.profile_id {
+        Some(ref profile_id) => db
+            .find_config_by_key(&format!("max_payment_attempts_{profile_id}"))
+            .await
+            .map_err(|error| logger::info!(max_payment_attempts_config_error=?error))
+            .ok()
+            .and_then(|config| config.config.parse::<i32>().ok()),
+        None => None,
+    };
+
+    max_payment_attempts.unwrap_or(1)
+}
+
+async fn get_error_code_retry_mapping(
+    db: &dyn StorageInterface,
+    connector: &str,
+) -> ErrorCodeRetryMapping {
+    db.find_config_by_key(&format!("auto_retry_error_codes_{connector}"))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .and_then(|config| {
+            config
+                .config
+                .parse_struct("ErrorCodeRetryMapping")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+        })
+        .map_err(|error| logger::info!(auto_retry_error_codes_config_error=?error))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn error_response(code: &str, status_code: u16) -> types::ErrorResponse {
+        types::ErrorResponse {
+            code: code.to_string(),
+            message: "error".to_string(),
+            reason: None,
+            status_code,
+        }
+    }
+
+    #[test]
+    fn test_error_code_retry_mapping() {
+        let mapping: ErrorCodeRetryMapping = serde_json::from_str(
+            r#"{ "retryable": ["issuer_unavailable"], "non_retryable": ["fraudulent"] }"#,
+        )
+        .unwrap();
+
+        assert!(mapping.is_retryable(&error_response("issuer_unavailable", 402)));
+        assert!(mapping.is_retryable(&error_response("TIMEOUT", 504)));
+        assert!(!mapping.is_retryable(&error_response("fraudulent", 500)));
+        assert!(!mapping.is_retryable(&error_response("card_declined", 402)));
+    }
+}
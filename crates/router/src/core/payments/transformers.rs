@@ -404,6 +404,19 @@ where
             .collect()
     });
 
+    let incremental_authorizations_response = if payment_data.incremental_authorizations.is_empty()
+    {
+        None
+    } else {
+        Some(
+            payment_data
+                .incremental_authorizations
+                .into_iter()
+                .map(ForeignInto::foreign_into)
+                .collect(),
+        )
+    };
+
     let merchant_id = payment_attempt.merchant_id.to_owned();
     let payment_method_type = payment_attempt
         .payment_method_type
@@ -431,6 +444,14 @@ where
                 surcharge_amount,
                 tax_amount: payment_attempt.tax_amount,
             });
+    let installment_data: Option<api_models::payments::InstallmentData> = payment_attempt
+        .installment_data
+        .clone()
+        .map(|data| data.parse_value("InstallmentData"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "installment_data",
+        })?;
     let merchant_decision = payment_intent.merchant_decision.to_owned();
     let frm_message = payment_data.frm_message.map(FrmMessage::foreign_from);
 
@@ -612,6 +633,10 @@ where
                                             start_date: i.start_date,
                                             end_date: i.end_date,
                                             metadata: i.metadata,
+                                            max_amount_per_transaction: i
+                                                .max_amount_per_transaction,
+                                            frequency: i.frequency,
+                                            max_debits_per_frequency: i.max_debits_per_frequency,
                                         }))
                                     }
                                     data_models::mandates::MandateDataType::SingleUse(i) => {
@@ -622,6 +647,11 @@ where
                                                 start_date: i.start_date,
                                                 end_date: i.end_date,
                                                 metadata: i.metadata,
+                                                max_amount_per_transaction: i
+                                                    .max_amount_per_transaction,
+                                                frequency: i.frequency,
+                                                max_debits_per_frequency: i
+                                                    .max_debits_per_frequency,
                                             },
                                         )
                                     }
@@ -691,6 +721,8 @@ where
                         .set_payment_link(payment_link_data)
                         .set_profile_id(payment_intent.profile_id)
                         .set_attempt_count(payment_intent.attempt_count)
+                        .set_installment_data(installment_data)
+                        .set_incremental_authorizations(incremental_authorizations_response)
                         .to_owned(),
                     headers,
                 ))
@@ -751,6 +783,8 @@ where
                 attempt_count: payment_intent.attempt_count,
                 payment_link: payment_link_data,
                 surcharge_details,
+                installment_data,
+                incremental_authorizations: incremental_authorizations_response,
                 ..Default::default()
             },
             headers,
@@ -954,6 +988,14 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "browser_info",
             })?;
+        let installment_data: Option<api_models::payments::InstallmentData> = attempt
+            .installment_data
+            .clone()
+            .map(|data| data.parse_value("InstallmentData"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "installment_data",
+            })?;
 
         let order_category = additional_data
             .payment_data
@@ -1004,6 +1046,13 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             payment_data.creds_identifier.as_deref(),
         ));
 
+        // Only an authorization which is captured later can be raised through incremental authorizations
+        let request_incremental_authorization = payment_data
+            .payment_intent
+            .request_incremental_authorization
+            .unwrap_or(false)
+            && payment_data.payment_attempt.capture_method == Some(enums::CaptureMethod::Manual);
+
         // payment_method_data is not required during recurring mandate payment, in such case keep default PaymentMethodData as MandatePayment
         let payment_method_data = payment_data.payment_method_data.or_else(|| {
             if payment_data.mandate_id.is_some() {
@@ -1038,6 +1087,8 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             complete_authorize_url,
             customer_id: None,
             surcharge_details: payment_data.surcharge_details,
+            installment_data,
+            request_incremental_authorization,
         })
     }
 }
@@ -1160,8 +1211,16 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCancelDa
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "browser_info",
             })?;
+        // Only the uncaptured part of the authorization is released for partially captured payments
+        let amount = if payment_data.payment_intent.status
+            == enums::IntentStatus::PartiallyCapturedAndCapturable
+        {
+            payment_data.payment_attempt.amount_capturable
+        } else {
+            payment_data.amount.into()
+        };
         Ok(Self {
-            amount: Some(payment_data.amount.into()),
+            amount: Some(amount),
             currency: Some(payment_data.currency),
             connector_transaction_id: connector
                 .connector
@@ -1186,6 +1245,36 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsApproveD
     }
 }
 
+impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>>
+    for types::PaymentsIncrementalAuthorizationData
+{
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn try_from(additional_data: PaymentAdditionalData<'_, F>) -> Result<Self, Self::Error> {
+        let payment_data = additional_data.payment_data;
+        let connector = api::ConnectorData::get_connector_by_name(
+            &additional_data.state.conf.connectors,
+            &additional_data.connector_name,
+            api::GetToken::Connector,
+        )?;
+        let authorization = payment_data
+            .incremental_authorizations
+            .last()
+            .get_required_value("incremental_authorization")?;
+        Ok(Self {
+            total_amount: authorization.amount,
+            additional_amount: authorization.amount - authorization.previous_amount,
+            currency: payment_data.currency,
+            reason: authorization.reason.clone(),
+            connector_transaction_id: connector
+                .connector
+                .connector_transaction_id(payment_data.payment_attempt.clone())?
+                .ok_or(errors::ApiErrorResponse::ResourceIdNotFound)?,
+            connector_meta: payment_data.payment_attempt.connector_metadata,
+        })
+    }
+}
+
 impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsRejectData {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
 
@@ -5,6 +5,8 @@ use api_models::enums as api_enums;
 use common_utils::{crypto::Encryptable, ext_traits::ValueExt};
 use diesel_models::enums as storage_enums;
 use error_stack::{report, ResultExt};
+#[cfg(feature = "olap")]
+use futures::future::join_all;
 use router_env::{instrument, tracing};
 use serde_json;
 
@@ -15,12 +17,14 @@ use crate::{
         payments::{self, helpers as payment_helpers},
         utils as core_utils,
     },
+    logger,
     routes::AppState,
     services,
     types::{
         self,
         api::{self, payouts},
         domain, storage,
+        transformers::ForeignFrom,
     },
     utils::{self, OptionExt},
 };
@@ -184,7 +188,12 @@ pub async fn payouts_update_core(
     let payout_id = req.payout_id.clone().get_required_value("payout_id")?;
     let merchant_id = &merchant_account.merchant_id;
     payout_data.payouts = db
-        .update_payout_by_merchant_id_payout_id(merchant_id, &payout_id, updated_payouts)
+        .update_payout_by_merchant_id_payout_id(
+            merchant_id,
+            &payout_id,
+            updated_payouts,
+            merchant_account.storage_scheme,
+        )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Error updating payouts")?;
@@ -219,6 +228,7 @@ pub async fn payouts_update_core(
                     merchant_id,
                     &payout_id,
                     update_payout_attempt,
+                    merchant_account.storage_scheme,
                 )
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -316,10 +326,12 @@ pub async fn payouts_cancel_core(
                 &merchant_account.merchant_id,
                 &payout_attempt.payout_id,
                 updated_payout_attempt,
+                merchant_account.storage_scheme,
             )
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Error updating payout_attempt in db")?;
+        trigger_payouts_webhook(&state, &merchant_account, &payout_data).await;
 
     // Trigger connector's cancellation
     } else {
@@ -403,6 +415,7 @@ pub async fn payouts_fulfill_core(
             &payout_attempt.merchant_id,
             &payout_attempt.payout_id,
             Some(&payout_data.payouts.payout_type),
+            merchant_account.storage_scheme,
         )
         .await?
         .get_required_value("payout_method_data")?,
@@ -435,6 +448,132 @@ pub async fn payouts_fulfill_core(
     .await
 }
 
+#[cfg(all(feature = "olap", feature = "payouts"))]
+#[instrument(skip_all)]
+pub async fn payouts_list_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    constraints: payouts::PayoutListConstraints,
+) -> RouterResponse<payouts::PayoutListResponse> {
+    validator::validate_payout_list_request(&constraints)?;
+    let merchant_id = &merchant_account.merchant_id;
+    let db = state.store.as_ref();
+    let payouts = db
+        .filter_payouts_by_constraints(merchant_id, &constraints, merchant_account.storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+
+    let collected_futures = payouts.into_iter().map(|payout| async {
+        match db
+            .find_payout_attempt_by_merchant_id_payout_id(
+                merchant_id,
+                &payout.payout_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+        {
+            Ok(payout_attempt) => Some(Ok((payout, payout_attempt))),
+            Err(error) => {
+                if error.current_context().is_db_not_found() {
+                    logger::warn!(
+                        ?error,
+                        "payout_attempt missing for payout_id : {}",
+                        payout.payout_id,
+                    );
+                    return None;
+                }
+                Some(Err(error))
+            }
+        }
+    });
+
+    let data: Vec<payouts::PayoutCreateResponse> = join_all(collected_futures)
+        .await
+        .into_iter()
+        .flatten()
+        .collect::<Result<Vec<(storage::Payouts, storage::PayoutAttempt)>, _>>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .into_iter()
+        .map(ForeignFrom::foreign_from)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutListResponse {
+            size: data.len(),
+            total_count: None,
+            data,
+        },
+    ))
+}
+
+#[cfg(all(feature = "olap", feature = "payouts"))]
+#[instrument(skip_all)]
+pub async fn payouts_filtered_list_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    constraints: payouts::PayoutListFilterConstraints,
+) -> RouterResponse<payouts::PayoutListResponse> {
+    validator::validate_payout_list_request_for_joins(constraints.limit)?;
+    let db = state.store.as_ref();
+    let data: Vec<payouts::PayoutCreateResponse> = db
+        .filter_payouts_and_attempts(
+            &merchant_account.merchant_id,
+            &constraints,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?
+        .into_iter()
+        .map(ForeignFrom::foreign_from)
+        .collect();
+
+    let total_count = db
+        .get_total_count_of_filtered_payouts(
+            &merchant_account.merchant_id,
+            &constraints,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutListResponse {
+            size: data.len(),
+            total_count: Some(total_count),
+            data,
+        },
+    ))
+}
+
+#[cfg(all(feature = "olap", feature = "payouts"))]
+#[instrument(skip_all)]
+pub async fn payouts_list_available_filters_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    time_range: api_models::payments::TimeRange,
+) -> RouterResponse<payouts::PayoutListFilters> {
+    let db = state.store.as_ref();
+    let payouts = db
+        .filter_payouts_by_time_range_constraints(
+            &merchant_account.merchant_id,
+            &time_range,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+
+    let filters = db
+        .get_filters_for_payouts(
+            payouts.as_slice(),
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+
+    Ok(services::ApplicationResponse::Json(filters))
+}
+
 // ********************************************** HELPERS **********************************************
 #[cfg(feature = "payouts")]
 pub async fn call_connector_payout(
@@ -458,6 +597,7 @@ pub async fn call_connector_payout(
                 &payout_attempt.merchant_id,
                 &payout_attempt.payout_id,
                 Some(&payouts.payout_type),
+                merchant_account.storage_scheme,
             )
             .await?
             .get_required_value("payout_method_data")?,
@@ -705,10 +845,12 @@ pub async fn check_payout_eligibility(
                     merchant_id,
                     payout_id,
                     updated_payout_attempt,
+                    merchant_account.storage_scheme,
                 )
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payout_attempt in db")?;
+            trigger_payouts_webhook(state, merchant_account, payout_data).await;
             if helpers::is_payout_err_state(status) {
                 return Err(report!(errors::ApiErrorResponse::PayoutFailed {
                     data: Some(
@@ -732,10 +874,12 @@ pub async fn check_payout_eligibility(
                     merchant_id,
                     payout_id,
                     updated_payout_attempt,
+                    merchant_account.storage_scheme,
                 )
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payout_attempt in db")?;
+            trigger_payouts_webhook(state, merchant_account, payout_data).await;
         }
     };
 
@@ -811,10 +955,12 @@ pub async fn create_payout(
                     merchant_id,
                     payout_id,
                     updated_payout_attempt,
+                    merchant_account.storage_scheme,
                 )
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payout_attempt in db")?;
+            trigger_payouts_webhook(state, merchant_account, payout_data).await;
             if helpers::is_payout_err_state(status) {
                 return Err(report!(errors::ApiErrorResponse::PayoutFailed {
                     data: Some(
@@ -838,10 +984,12 @@ pub async fn create_payout(
                     merchant_id,
                     payout_id,
                     updated_payout_attempt,
+                    merchant_account.storage_scheme,
                 )
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payout_attempt in db")?;
+            trigger_payouts_webhook(state, merchant_account, payout_data).await;
         }
     };
 
@@ -910,10 +1058,12 @@ pub async fn cancel_payout(
                     merchant_id,
                     payout_id,
                     updated_payout_attempt,
+                    merchant_account.storage_scheme,
                 )
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Error updating payout_attempt in db")?
+                .attach_printable("Error updating payout_attempt in db")?;
+            trigger_payouts_webhook(state, merchant_account, payout_data).await;
         }
         Err(err) => {
             let updated_payouts_create =
@@ -930,10 +1080,12 @@ pub async fn cancel_payout(
                     merchant_id,
                     payout_id,
                     updated_payouts_create,
+                    merchant_account.storage_scheme,
                 )
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Error updating payout_attempt in db")?
+                .attach_printable("Error updating payout_attempt in db")?;
+            trigger_payouts_webhook(state, merchant_account, payout_data).await;
         }
     };
 
@@ -1015,10 +1167,12 @@ pub async fn fulfill_payout(
                     merchant_id,
                     payout_id,
                     updated_payouts,
+                    merchant_account.storage_scheme,
                 )
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payout_attempt in db")?;
+            trigger_payouts_webhook(state, merchant_account, payout_data).await;
             if helpers::is_payout_err_state(status) {
                 return Err(report!(errors::ApiErrorResponse::PayoutFailed {
                     data: Some(
@@ -1041,16 +1195,32 @@ pub async fn fulfill_payout(
                     merchant_id,
                     payout_id,
                     updated_payouts,
+                    merchant_account.storage_scheme,
                 )
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Error updating payout_attempt in db")?
+                .attach_printable("Error updating payout_attempt in db")?;
+            trigger_payouts_webhook(state, merchant_account, payout_data).await;
         }
     };
 
     Ok(payout_data.clone())
 }
 
+/// Sends the outgoing webhook for the current status of the payout. Failures are only logged, so
+/// that they do not fail the payout operation which has already been recorded.
+#[cfg(feature = "payouts")]
+async fn trigger_payouts_webhook(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &PayoutData,
+) {
+    utils::trigger_payouts_webhook(merchant_account, payout_data, state)
+        .await
+        .map_err(|error| logger::warn!(payouts_outgoing_webhook_error=?error))
+        .ok();
+}
+
 #[cfg(feature = "payouts")]
 pub async fn response_handler(
     _state: &AppState,
@@ -1213,7 +1383,7 @@ pub async fn payout_create_db_entries(
         .set_payout_method_id(payout_method_id)
         .to_owned();
     let payouts = db
-        .insert_payout(payouts_req)
+        .insert_payout(payouts_req, merchant_account.storage_scheme)
         .await
         .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayout {
             payout_id: payout_id.to_owned(),
@@ -1248,7 +1418,7 @@ pub async fn payout_create_db_entries(
         .set_profile_id(req.profile_id.to_owned())
         .to_owned();
     let payout_attempt = db
-        .insert_payout_attempt(payout_attempt_req)
+        .insert_payout_attempt(payout_attempt_req, merchant_account.storage_scheme)
         .await
         .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayout {
             payout_id: payout_id.to_owned(),
@@ -1286,12 +1456,20 @@ pub async fn make_payout_data(
     };
 
     let payouts = db
-        .find_payout_by_merchant_id_payout_id(merchant_id, &payout_id)
+        .find_payout_by_merchant_id_payout_id(
+            merchant_id,
+            &payout_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
 
     let payout_attempt = db
-        .find_payout_attempt_by_merchant_id_payout_id(merchant_id, &payout_id)
+        .find_payout_attempt_by_merchant_id_payout_id(
+            merchant_id,
+            &payout_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
 
@@ -1325,3 +1503,37 @@ pub async fn make_payout_data(
         merchant_connector_account: None,
     })
 }
+
+#[cfg(feature = "payouts")]
+impl ForeignFrom<(storage::Payouts, storage::PayoutAttempt)> for payouts::PayoutCreateResponse {
+    fn foreign_from(item: (storage::Payouts, storage::PayoutAttempt)) -> Self {
+        let (payout, payout_attempt) = item;
+        Self {
+            payout_id: payout.payout_id,
+            merchant_id: payout.merchant_id,
+            amount: payout.amount,
+            currency: payout.destination_currency,
+            connector: Some(payout_attempt.connector),
+            payout_type: payout.payout_type,
+            billing: None,
+            customer_id: payout.customer_id,
+            auto_fulfill: payout.auto_fulfill,
+            email: None,
+            name: None,
+            phone: None,
+            phone_country_code: None,
+            client_secret: None,
+            return_url: payout.return_url,
+            business_country: payout_attempt.business_country,
+            business_label: payout_attempt.business_label,
+            description: payout.description,
+            entity_type: payout.entity_type,
+            recurring: payout.recurring,
+            metadata: payout.metadata,
+            status: payout_attempt.status,
+            error_message: payout_attempt.error_message,
+            error_code: payout_attempt.error_code,
+            profile_id: payout_attempt.profile_id,
+        }
+    }
+}
@@ -36,6 +36,7 @@ pub async fn make_payout_method_data<'a>(
     merchant_id: &str,
     payout_id: &str,
     payout_type: Option<&api_enums::PayoutType>,
+    storage_scheme: storage::enums::MerchantStorageScheme,
 ) -> RouterResult<Option<api::PayoutMethodData>> {
     let db = &*state.store;
     let hyperswitch_token = if let Some(payout_token) = payout_token {
@@ -106,6 +107,7 @@ pub async fn make_payout_method_data<'a>(
                     merchant_id,
                     payout_id,
                     payout_update,
+                    storage_scheme,
                 )
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -204,6 +206,7 @@ pub async fn save_payout_data_to_locker(
         &merchant_account.merchant_id,
         &payout_attempt.payout_id,
         updated_payout,
+        merchant_account.storage_scheme,
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -440,6 +443,35 @@ pub fn is_payout_terminal_state(status: api_enums::PayoutStatus) -> bool {
     )
 }
 
+/// Checks whether a payout can move from `current_status` to `new_status`. Payouts only move
+/// forward, and the only status change out of a terminal status is the reversal of a successful
+/// payout.
+pub fn is_payout_status_transition_allowed(
+    current_status: api_enums::PayoutStatus,
+    new_status: api_enums::PayoutStatus,
+) -> bool {
+    fn get_progress(status: api_enums::PayoutStatus) -> u8 {
+        match status {
+            api_enums::PayoutStatus::RequiresCreation
+            | api_enums::PayoutStatus::RequiresPayoutMethodData => 0,
+            api_enums::PayoutStatus::RequiresFulfillment => 1,
+            api_enums::PayoutStatus::Pending => 2,
+            api_enums::PayoutStatus::Success
+            | api_enums::PayoutStatus::Failed
+            | api_enums::PayoutStatus::Cancelled
+            | api_enums::PayoutStatus::Ineligible
+            | api_enums::PayoutStatus::Reversed => 3,
+        }
+    }
+
+    if is_payout_terminal_state(current_status) {
+        current_status == api_enums::PayoutStatus::Success
+            && new_status == api_enums::PayoutStatus::Reversed
+    } else {
+        get_progress(new_status) > get_progress(current_status)
+    }
+}
+
 pub fn is_payout_err_state(status: api_enums::PayoutStatus) -> bool {
     matches!(
         status,
@@ -20,9 +20,10 @@ pub async fn validate_uniqueness_of_payout_id_against_merchant_id(
     db: &dyn StorageInterface,
     payout_id: &str,
     merchant_id: &str,
+    storage_scheme: storage::enums::MerchantStorageScheme,
 ) -> RouterResult<Option<storage::Payouts>> {
     let payout = db
-        .find_payout_by_merchant_id_payout_id(merchant_id, payout_id)
+        .find_payout_by_merchant_id_payout_id(merchant_id, payout_id, storage_scheme)
         .await;
 
     logger::debug!(?payout);
@@ -73,18 +74,23 @@ pub async fn validate_create_request(
     // Payout ID
     let db: &dyn StorageInterface = &*state.store;
     let payout_id = core_utils::get_or_generate_uuid("payout_id", req.payout_id.as_ref())?;
-    match validate_uniqueness_of_payout_id_against_merchant_id(db, &payout_id, merchant_id)
-        .await
-        .change_context(errors::ApiErrorResponse::DuplicatePayout {
-            payout_id: payout_id.to_owned(),
-        })
-        .attach_printable_lazy(|| {
-            format!(
-                "Unique violation while checking payout_id: {} against merchant_id: {}",
-                payout_id.to_owned(),
-                merchant_id
-            )
-        })? {
+    match validate_uniqueness_of_payout_id_against_merchant_id(
+        db,
+        &payout_id,
+        merchant_id,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::DuplicatePayout {
+        payout_id: payout_id.to_owned(),
+    })
+    .attach_printable_lazy(|| {
+        format!(
+            "Unique violation while checking payout_id: {} against merchant_id: {}",
+            payout_id.to_owned(),
+            merchant_id
+        )
+    })? {
         Some(_) => Err(report!(errors::ApiErrorResponse::DuplicatePayout {
             payout_id: payout_id.to_owned()
         })),
@@ -103,6 +109,7 @@ pub async fn validate_create_request(
                 &merchant_account.merchant_id,
                 payout_id.as_ref(),
                 req.payout_type.as_ref(),
+                merchant_account.storage_scheme,
             )
             .await?
         }
@@ -111,3 +118,38 @@ pub async fn validate_create_request(
 
     Ok((payout_id, payout_method_data))
 }
+
+#[cfg(all(feature = "olap", feature = "payouts"))]
+pub(super) fn validate_payout_list_request(
+    req: &payouts::PayoutListConstraints,
+) -> RouterResult<()> {
+    use common_utils::consts::PAYOUTS_LIST_MAX_LIMIT_GET;
+
+    utils::when(
+        req.limit > PAYOUTS_LIST_MAX_LIMIT_GET || req.limit < 1,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "limit should be in between 1 and {}",
+                    PAYOUTS_LIST_MAX_LIMIT_GET
+                ),
+            })
+        },
+    )?;
+    Ok(())
+}
+
+#[cfg(all(feature = "olap", feature = "payouts"))]
+pub(super) fn validate_payout_list_request_for_joins(limit: u32) -> RouterResult<()> {
+    use common_utils::consts::PAYOUTS_LIST_MAX_LIMIT_POST;
+
+    utils::when(!(1..=PAYOUTS_LIST_MAX_LIMIT_POST).contains(&limit), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "limit should be in between 1 and {}",
+                PAYOUTS_LIST_MAX_LIMIT_POST
+            ),
+        })
+    })?;
+    Ok(())
+}
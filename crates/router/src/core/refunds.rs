@@ -36,7 +36,7 @@ pub async fn refund_create_core(
     req: refunds::RefundRequest,
 ) -> RouterResponse<refunds::RefundResponse> {
     let db = &*state.store;
-    let (merchant_id, payment_intent, payment_attempt, amount);
+    let (merchant_id, payment_intent, payment_attempt, capture, amount);
 
     merchant_id = &merchant_account.merchant_id;
 
@@ -57,14 +57,51 @@ pub async fn refund_create_core(
         },
     )?;
 
-    // Amount is not passed in request refer from payment intent.
-    amount = req.amount.unwrap_or(
-        payment_intent
+    payment_attempt = db
+        .find_payment_attempt_last_successful_attempt_by_payment_id_merchant_id(
+            &req.payment_id,
+            merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::SuccessfulPaymentNotFound)?;
+
+    capture = req
+        .capture_id
+        .as_deref()
+        .async_map(|capture_id| {
+            payments::helpers::find_capture_by_capture_id(
+                db,
+                &payment_attempt,
+                capture_id,
+                merchant_account.storage_scheme,
+            )
+        })
+        .await
+        .transpose()?;
+
+    if let Some(capture) = capture.as_ref() {
+        validator::validate_capture_refund_support(&payment_attempt)?;
+        utils::when(capture.status != enums::CaptureStatus::Charged, || {
+            Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
+                field_name: "capture.status".to_string(),
+                current_flow: "refund".to_string(),
+                current_value: capture.status.to_string(),
+                states: "charged".to_string()
+            }))
+        })?;
+    }
+
+    // Amount is not passed in request refer from the capture or the payment intent.
+    amount = match (req.amount, capture.as_ref()) {
+        (Some(amount), _) => amount,
+        (None, Some(capture)) => capture.amount,
+        (None, None) => payment_intent
             .amount_captured
             .ok_or(errors::ApiErrorResponse::InternalServerError)
             .into_report()
             .attach_printable("amount captured is none in a successful payment")?,
-    );
+    };
 
     //[#299]: Can we change the flow based on some workflow idea
     utils::when(amount <= 0, || {
@@ -75,15 +112,6 @@ pub async fn refund_create_core(
         .attach_printable("amount less than or equal to zero"))
     })?;
 
-    payment_attempt = db
-        .find_payment_attempt_last_successful_attempt_by_payment_id_merchant_id(
-            &req.payment_id,
-            merchant_id,
-            merchant_account.storage_scheme,
-        )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::SuccessfulPaymentNotFound)?;
-
     let creds_identifier = req
         .merchant_connector_details
         .as_ref()
@@ -107,6 +135,7 @@ pub async fn refund_create_core(
         &key_store,
         &payment_attempt,
         &payment_intent,
+        capture,
         amount,
         req,
         creds_identifier,
@@ -514,6 +543,7 @@ pub async fn validate_and_create_refund(
     key_store: &domain::MerchantKeyStore,
     payment_attempt: &storage::PaymentAttempt,
     payment_intent: &storage::PaymentIntent,
+    capture: Option<storage::Capture>,
     refund_amount: i64,
     req: refunds::RefundRequest,
     creds_identifier: Option<String>,
@@ -570,6 +600,11 @@ pub async fn validate_and_create_refund(
     validator::validate_refund_amount(payment_attempt.amount, &all_refunds, refund_amount)
         .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)?;
 
+    if let Some(capture) = capture.as_ref() {
+        validator::validate_refund_amount_against_capture(capture, &all_refunds, refund_amount)
+            .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)?;
+    }
+
     validator::validate_maximum_refund_against_payment_attempt(
         &all_refunds,
         state.conf.refund.max_attempts,
@@ -602,6 +637,7 @@ pub async fn validate_and_create_refund(
         .set_description(req.reason.clone())
         .set_attempt_id(payment_attempt.attempt_id.clone())
         .set_refund_reason(req.reason)
+        .set_capture_id(capture.map(|capture| capture.capture_id))
         .to_owned();
 
     refund = db
@@ -710,6 +746,7 @@ impl ForeignFrom<storage::Refund> for api::RefundResponse {
             created_at: Some(refund.created_at),
             updated_at: Some(refund.updated_at),
             connector: refund.connector,
+            capture_id: refund.capture_id,
         }
     }
 }
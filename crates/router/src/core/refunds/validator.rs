@@ -67,6 +67,41 @@ pub fn validate_refund_amount(
     )
 }
 
+#[instrument(skip_all)]
+pub fn validate_refund_amount_against_capture(
+    capture: &storage::Capture,
+    all_refunds: &[storage::Refund],
+    refund_amount: i64,
+) -> CustomResult<(), RefundValidationError> {
+    let capture_refunds: Vec<storage::Refund> = all_refunds
+        .iter()
+        .filter(|refund| refund.capture_id.as_deref() == Some(capture.capture_id.as_str()))
+        .cloned()
+        .collect();
+
+    validate_refund_amount(capture.amount, &capture_refunds, refund_amount)
+}
+
+/// Refunds against a capture are only possible through the connectors whose refund request
+/// carries the connector reference of the capture
+pub fn validate_capture_refund_support(
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    let connector = payment_attempt
+        .connector
+        .clone()
+        .get_required_value("connector")?;
+
+    utils::when(
+        connector != api_models::enums::Connector::Adyen.to_string(),
+        || {
+            Err(report!(errors::ApiErrorResponse::NotSupported {
+                message: format!("Refunds against a capture are not supported by {connector}"),
+            }))
+        },
+    )
+}
+
 #[instrument(skip_all)]
 pub fn validate_payment_order_age(
     created_at: &PrimitiveDateTime,
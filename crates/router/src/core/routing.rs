@@ -0,0 +1,238 @@
+use api_models::routing::{
+    AmountRange, ConnectorVolumeSplit, RoutingCondition, RoutingRuleOutput, RoutingRules,
+};
+use error_stack::{IntoReport, ResultExt};
+use masking::PeekInterface;
+use rand::distributions::{Distribution, WeightedIndex};
+
+use super::errors::{self, RouterResult};
+use crate::{
+    core::payments::PaymentData,
+    types::{api, storage::enums as storage_enums},
+    utils::ValueExt,
+};
+
+/// The attributes of a payment which routing rules can be evaluated against
+#[derive(Clone, Debug, Default)]
+pub struct RoutingInput {
+    pub amount: i64,
+    pub currency: Option<storage_enums::Currency>,
+    pub card_network: Option<api_models::enums::CardNetwork>,
+    pub payment_method: Option<storage_enums::PaymentMethod>,
+    pub payment_method_type: Option<storage_enums::PaymentMethodType>,
+    pub billing_country: Option<api_models::enums::CountryAlpha2>,
+    pub metadata: Option<serde_json::Value>,
+}
+
+impl<F: Clone> From<&PaymentData<F>> for RoutingInput {
+    fn from(payment_data: &PaymentData<F>) -> Self {
+        let card_network = match payment_data.payment_method_data {
+            Some(api::PaymentMethodData::Card(ref card)) => card.card_network.clone(),
+            _ => None,
+        };
+
+        Self {
+            amount: payment_data.amount.into(),
+            currency: Some(payment_data.currency),
+            card_network,
+            payment_method: payment_data.payment_attempt.payment_method,
+            payment_method_type: payment_data.payment_attempt.payment_method_type,
+            billing_country: payment_data
+                .address
+                .billing
+                .as_ref()
+                .and_then(|billing| billing.address.as_ref())
+                .and_then(|address| address.country),
+            metadata: payment_data
+                .payment_intent
+                .metadata
+                .as_ref()
+                .map(|metadata| metadata.peek().clone()),
+        }
+    }
+}
+
+/// Parses and validates a routing algorithm configured on a merchant account or business profile
+pub fn validate_routing_algorithm(
+    routing_algorithm: &serde_json::Value,
+) -> RouterResult<api::RoutingAlgorithm> {
+    let algorithm: api::RoutingAlgorithm = routing_algorithm
+        .clone()
+        .parse_value("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "routing_algorithm",
+        })
+        .attach_printable("Invalid routing algorithm given")?;
+
+    algorithm
+        .validate()
+        .map_err(|err| errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid routing algorithm: {err}"),
+        })
+        .into_report()?;
+
+    Ok(algorithm)
+}
+
+/// Evaluates the routing algorithm against the payment and returns the eligible connectors,
+/// ranked in the order in which they should be tried
+pub fn perform_routing(
+    algorithm: &api::RoutingAlgorithm,
+    input: &RoutingInput,
+) -> Vec<api_models::enums::RoutableConnectors> {
+    match algorithm {
+        api::RoutingAlgorithm::Single(connector) => vec![*connector],
+        api::RoutingAlgorithm::Priority(connectors) => connectors.clone(),
+        api::RoutingAlgorithm::VolumeSplit(splits) => rank_volume_split(splits),
+        api::RoutingAlgorithm::Advanced(rules) => evaluate_rules(rules, input),
+    }
+}
+
+fn evaluate_rules(
+    rules: &RoutingRules,
+    input: &RoutingInput,
+) -> Vec<api_models::enums::RoutableConnectors> {
+    let output = rules
+        .rules
+        .iter()
+        .find(|rule| {
+            rule.conditions
+                .iter()
+                .all(|condition| condition_matches(condition, input))
+        })
+        .map(|rule| &rule.connectors)
+        .unwrap_or(&rules.default_selection);
+
+    match output {
+        RoutingRuleOutput::Priority(connectors) => connectors.clone(),
+        RoutingRuleOutput::VolumeSplit(splits) => rank_volume_split(splits),
+    }
+}
+
+fn condition_matches(condition: &RoutingCondition, input: &RoutingInput) -> bool {
+    match condition {
+        RoutingCondition::Amount(AmountRange { min, max }) => {
+            min.map_or(true, |min| input.amount >= min)
+                && max.map_or(true, |max| input.amount <= max)
+        }
+        RoutingCondition::Currency(currencies) => input
+            .currency
+            .map_or(false, |currency| currencies.contains(&currency)),
+        RoutingCondition::CardNetwork(networks) => input
+            .card_network
+            .as_ref()
+            .map_or(false, |network| networks.contains(network)),
+        RoutingCondition::PaymentMethod(payment_methods) => input
+            .payment_method
+            .map_or(false, |pm| payment_methods.contains(&pm)),
+        RoutingCondition::PaymentMethodType(payment_method_types) => input
+            .payment_method_type
+            .map_or(false, |pmt| payment_method_types.contains(&pmt)),
+        RoutingCondition::BillingCountry(countries) => input
+            .billing_country
+            .map_or(false, |country| countries.contains(&country)),
+        RoutingCondition::Metadata { key, value } => input
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(key))
+            .and_then(|metadata_value| metadata_value.as_str())
+            .map_or(false, |metadata_value| metadata_value == value),
+    }
+}
+
+/// Picks connectors one after another, weighted by their split, so that the first connector
+/// follows the configured distribution and the rest act as fallbacks
+fn rank_volume_split(
+    splits: &[ConnectorVolumeSplit],
+) -> Vec<api_models::enums::RoutableConnectors> {
+    let mut remaining = splits.to_vec();
+    let mut ranked = Vec::with_capacity(remaining.len());
+    let mut rng = rand::thread_rng();
+
+    while !remaining.is_empty() {
+        // `WeightedIndex` fails only when every remaining weight is zero
+        let index = WeightedIndex::new(remaining.iter().map(|split| split.split))
+            .map(|distribution| distribution.sample(&mut rng))
+            .unwrap_or(0);
+        ranked.push(remaining.remove(index).connector);
+    }
+
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use api_models::enums::RoutableConnectors;
+
+    use super::*;
+
+    fn rules() -> RoutingRules {
+        serde_json::from_value(serde_json::json!({
+            "rules": [
+                {
+                    "name": "high_value_usd",
+                    "conditions": [
+                        { "field": "amount", "value": { "min": 10000, "max": null } },
+                        { "field": "currency", "value": ["USD"] }
+                    ],
+                    "connectors": { "type": "priority", "data": ["adyen", "stripe"] }
+                },
+                {
+                    "name": "vip",
+                    "conditions": [
+                        { "field": "metadata", "value": { "key": "tier", "value": "vip" } }
+                    ],
+                    "connectors": { "type": "priority", "data": ["checkout"] }
+                }
+            ],
+            "default_selection": { "type": "priority", "data": ["stripe"] }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_first_matching_rule_is_selected() {
+        let input = RoutingInput {
+            amount: 15000,
+            currency: Some(storage_enums::Currency::USD),
+            metadata: Some(serde_json::json!({ "tier": "vip" })),
+            ..Default::default()
+        };
+        assert_eq!(
+            evaluate_rules(&rules(), &input),
+            vec![RoutableConnectors::Adyen, RoutableConnectors::Stripe]
+        );
+    }
+
+    #[test]
+    fn test_default_selection_when_no_rule_matches() {
+        let input = RoutingInput {
+            amount: 500,
+            currency: Some(storage_enums::Currency::USD),
+            ..Default::default()
+        };
+        assert_eq!(
+            evaluate_rules(&rules(), &input),
+            vec![RoutableConnectors::Stripe]
+        );
+    }
+
+    #[test]
+    fn test_volume_split_ranks_every_connector() {
+        let splits = vec![
+            ConnectorVolumeSplit {
+                connector: RoutableConnectors::Adyen,
+                split: 100,
+            },
+            ConnectorVolumeSplit {
+                connector: RoutableConnectors::Stripe,
+                split: 0,
+            },
+        ];
+        assert_eq!(
+            rank_volume_split(&splits),
+            vec![RoutableConnectors::Adyen, RoutableConnectors::Stripe]
+        );
+    }
+}
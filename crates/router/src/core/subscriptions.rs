@@ -0,0 +1,340 @@
+use error_stack::{report, IntoReport, ResultExt};
+use router_env::{instrument, tracing};
+use scheduler::{db::process_tracker::ProcessTrackerExt, utils as pt_utils};
+use time::PrimitiveDateTime;
+
+use super::errors::{self, RouterResponse, RouterResult, StorageErrorExt};
+use crate::{
+    consts,
+    db::StorageInterface,
+    routes::AppState,
+    services,
+    types::{
+        api::subscriptions,
+        domain,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+    utils::generate_id,
+    workflows::subscription_billing,
+};
+
+#[instrument(skip(state))]
+pub async fn create_subscription(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: subscriptions::SubscriptionCreateRequest,
+) -> RouterResponse<subscriptions::SubscriptionResponse> {
+    let db = state.store.as_ref();
+    let now = common_utils::date_time::now();
+
+    if req.amount <= 0 {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount must be greater than zero".to_string()
+        }))?
+    }
+    if req.interval_count == 0 {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "interval_count must be greater than zero".to_string()
+        }))?
+    }
+    if [req.trial_end, req.billing_anchor]
+        .into_iter()
+        .flatten()
+        .any(|time| time < now)
+    {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "trial_end and billing_anchor must not be in the past".to_string()
+        }))?
+    }
+
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(
+            &merchant_account.merchant_id,
+            &req.mandate_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+    validate_mandate_for_subscription(&mandate, &req)?;
+
+    // The customer is billed for the first time once the trial ends, the following billing cycles
+    // are counted from that time
+    let billing_anchor = req.trial_end.or(req.billing_anchor).unwrap_or(now);
+    let status = if req.trial_end.is_some() {
+        storage_enums::SubscriptionStatus::Trialing
+    } else {
+        storage_enums::SubscriptionStatus::Active
+    };
+
+    let subscription_new = storage::SubscriptionNew {
+        subscription_id: generate_id(consts::ID_LENGTH, "sub"),
+        merchant_id: merchant_account.merchant_id.clone(),
+        customer_id: req.customer_id,
+        mandate_id: req.mandate_id,
+        profile_id: req.profile_id,
+        status,
+        amount: req.amount,
+        currency: req.currency,
+        billing_interval: req.interval,
+        interval_count: req.interval_count.into(),
+        trial_end: req.trial_end,
+        billing_anchor,
+        next_billing_at: Some(billing_anchor),
+        description: req.description,
+        metadata: req.metadata,
+    };
+
+    let subscription = db
+        .insert_subscription(subscription_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert subscription")?;
+
+    add_subscription_billing_task(db, &subscription, billing_anchor)
+        .await
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add subscription billing task to process tracker")?;
+
+    Ok(services::ApplicationResponse::Json(
+        subscriptions::SubscriptionResponse::foreign_from(subscription),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_subscription(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: subscriptions::SubscriptionId,
+) -> RouterResponse<subscriptions::SubscriptionResponse> {
+    let subscription = state
+        .store
+        .find_subscription_by_merchant_id_subscription_id(
+            &merchant_account.merchant_id,
+            &req.subscription_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::SubscriptionNotFound)?;
+
+    Ok(services::ApplicationResponse::Json(
+        subscriptions::SubscriptionResponse::foreign_from(subscription),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn cancel_subscription(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: subscriptions::SubscriptionId,
+) -> RouterResponse<subscriptions::SubscriptionResponse> {
+    let db = state.store.as_ref();
+    let subscription = db
+        .find_subscription_by_merchant_id_subscription_id(
+            &merchant_account.merchant_id,
+            &req.subscription_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::SubscriptionNotFound)?;
+
+    if subscription.status == storage_enums::SubscriptionStatus::Cancelled {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Subscription has already been cancelled".to_string()
+        }))?
+    }
+
+    let subscription = db
+        .update_subscription_by_merchant_id_subscription_id(
+            &merchant_account.merchant_id,
+            &req.subscription_id,
+            storage::SubscriptionUpdate::CancelUpdate,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::SubscriptionNotFound)?;
+
+    // The billing task would also stop on finding the subscription cancelled, finishing it here
+    // keeps it from being picked up again
+    let task_id = get_subscription_billing_task_id(&subscription);
+    let billing_task = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve subscription billing task from process tracker")?;
+    if let Some(billing_task) = billing_task {
+        billing_task
+            .finish_with_status(db.as_scheduler(), "SUBSCRIPTION_CANCELLED".to_string())
+            .await
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to finish subscription billing task in process tracker")?;
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        subscriptions::SubscriptionResponse::foreign_from(subscription),
+    ))
+}
+
+/// The customer is billed through the mandate without being present, so it has to be a multi-use
+/// mandate of the same customer which allows charging the subscription amount
+fn validate_mandate_for_subscription(
+    mandate: &storage::Mandate,
+    req: &subscriptions::SubscriptionCreateRequest,
+) -> RouterResult<()> {
+    let validation_error = |reason: &str| {
+        Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+            reason: reason.to_string()
+        }))
+    };
+
+    if mandate.customer_id != req.customer_id {
+        return validation_error("mandate does not belong to the customer");
+    }
+    if mandate.mandate_status != storage_enums::MandateStatus::Active {
+        return validation_error("mandate is not active");
+    }
+    if mandate.mandate_type != storage_enums::MandateType::MultiUse {
+        return validation_error("mandate is not a multi use mandate");
+    }
+    if mandate
+        .mandate_currency
+        .map_or(false, |currency| currency != req.currency)
+    {
+        return validation_error("currency does not match the currency of the mandate");
+    }
+    if mandate
+        .mandate_amount
+        .map_or(false, |mandate_amount| req.amount > mandate_amount)
+    {
+        return validation_error("amount exceeds the amount allowed by the mandate");
+    }
+    Ok(())
+}
+
+pub fn get_subscription_billing_task_id(subscription: &storage::Subscription) -> String {
+    pt_utils::get_process_tracker_id(
+        subscription_billing::SUBSCRIPTION_BILLING_WORKFLOW,
+        subscription_billing::SUBSCRIPTION_BILLING_TASK,
+        &subscription.subscription_id,
+        &subscription.merchant_id,
+    )
+}
+
+/// Schedules the task which bills the subscription, the task is rescheduled for the next billing
+/// cycle by the `SubscriptionBillingWorkflow` after every successful renewal
+async fn add_subscription_billing_task(
+    db: &dyn StorageInterface,
+    subscription: &storage::Subscription,
+    schedule_time: PrimitiveDateTime,
+) -> Result<(), scheduler::errors::ProcessTrackerError> {
+    let tracking_data = storage::SubscriptionTrackingData {
+        merchant_id: subscription.merchant_id.clone(),
+        subscription_id: subscription.subscription_id.clone(),
+    };
+    let process_tracker_entry = <storage::ProcessTracker>::make_process_tracker_new(
+        get_subscription_billing_task_id(subscription),
+        subscription_billing::SUBSCRIPTION_BILLING_TASK,
+        subscription_billing::SUBSCRIPTION_BILLING_WORKFLOW,
+        tracking_data,
+        schedule_time,
+    )?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Get the start of the first billing cycle which begins after `after`
+///
+/// Billing cycles are counted from the billing anchor so that clamping the day of the month (for
+/// example from the 31st to the 30th) in one cycle does not shift the following cycles.
+pub fn get_next_billing_time(
+    subscription: &storage::Subscription,
+    after: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    let mut cycle: i32 = 1;
+    loop {
+        let billing_time = add_billing_intervals(
+            subscription.billing_anchor,
+            subscription.billing_interval,
+            subscription.interval_count.checked_mul(cycle)?,
+        )?;
+        if billing_time > after {
+            return Some(billing_time);
+        }
+        cycle = cycle.checked_add(1)?;
+    }
+}
+
+fn add_billing_intervals(
+    time: PrimitiveDateTime,
+    interval: storage_enums::SubscriptionInterval,
+    count: i32,
+) -> Option<PrimitiveDateTime> {
+    match interval {
+        storage_enums::SubscriptionInterval::Day => {
+            time.checked_add(time::Duration::days(count.into()))
+        }
+        storage_enums::SubscriptionInterval::Week => {
+            time.checked_add(time::Duration::weeks(count.into()))
+        }
+        storage_enums::SubscriptionInterval::Month => add_months(time, count),
+        storage_enums::SubscriptionInterval::Year => add_months(time, count.checked_mul(12)?),
+    }
+}
+
+/// Adds calendar months, clamping the day to the last day of the resulting month
+fn add_months(time: PrimitiveDateTime, months: i32) -> Option<PrimitiveDateTime> {
+    let date = time.date();
+    let total_months = date
+        .year()
+        .checked_mul(12)?
+        .checked_add(i32::from(u8::from(date.month())) - 1)?
+        .checked_add(months)?;
+    let year = total_months.div_euclid(12);
+    let month = time::Month::try_from(u8::try_from(total_months.rem_euclid(12) + 1).ok()?).ok()?;
+    let day = date.day().min(time::util::days_in_year_month(year, month));
+    let date = time::Date::from_calendar_date(year, month, day).ok()?;
+    Some(PrimitiveDateTime::new(date, time.time()))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_add_billing_intervals() {
+        let time = datetime!(2024-01-31 10:00);
+        let month = storage_enums::SubscriptionInterval::Month;
+
+        assert_eq!(
+            add_billing_intervals(time, storage_enums::SubscriptionInterval::Day, 2),
+            Some(datetime!(2024-02-02 10:00))
+        );
+        assert_eq!(
+            add_billing_intervals(time, storage_enums::SubscriptionInterval::Week, 1),
+            Some(datetime!(2024-02-07 10:00))
+        );
+        assert_eq!(
+            add_billing_intervals(time, month, 1),
+            Some(datetime!(2024-02-29 10:00))
+        );
+        assert_eq!(
+            add_billing_intervals(time, month, 2),
+            Some(datetime!(2024-03-31 10:00))
+        );
+        assert_eq!(
+            add_billing_intervals(time, month, 11),
+            Some(datetime!(2024-12-31 10:00))
+        );
+        assert_eq!(
+            add_billing_intervals(
+                datetime!(2024-02-29 10:00),
+                storage_enums::SubscriptionInterval::Year,
+                1
+            ),
+            Some(datetime!(2025-02-28 10:00))
+        );
+    }
+}
@@ -278,6 +278,20 @@ pub async fn construct_refund_router_data<'a, F>(
             field_name: "browser_info",
         })?;
 
+    let connector_capture_id = match refund.capture_id.as_deref() {
+        Some(capture_id) => {
+            helpers::find_capture_by_capture_id(
+                &*state.store,
+                payment_attempt,
+                capture_id,
+                merchant_account.storage_scheme,
+            )
+            .await?
+            .connector_capture_id
+        }
+        None => None,
+    };
+
     let router_data = types::RouterData {
         flow: PhantomData,
         merchant_id: merchant_account.merchant_id.clone(),
@@ -307,6 +321,7 @@ pub async fn construct_refund_router_data<'a, F>(
             reason: refund.refund_reason.clone(),
             connector_refund_id: refund.connector_refund_id.clone(),
             browser_info,
+            connector_capture_id,
         },
 
         response: Ok(types::RefundsResponseData {
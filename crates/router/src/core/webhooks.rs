@@ -1,5 +1,7 @@
 pub mod types;
 pub mod utils;
+#[cfg(feature = "olap")]
+pub mod webhook_events;
 
 use std::str::FromStr;
 
@@ -11,6 +13,7 @@ use common_utils::errors::ReportSwitchExt;
 use error_stack::{report, IntoReport, ResultExt};
 use masking::ExposeInterface;
 use router_env::{instrument, tracing};
+use scheduler::{db::process_tracker::ProcessTrackerExt, errors as sch_errors, utils as pt_utils};
 
 use super::{errors::StorageErrorExt, metrics};
 #[cfg(feature = "stripe")]
@@ -18,7 +21,7 @@ use crate::compatibility::stripe::webhooks as stripe_webhooks;
 use crate::{
     consts,
     core::{
-        api_locking,
+        api_locking, disputes,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse},
         payment_methods::PaymentMethodRetrieve,
         payments, refunds,
@@ -35,9 +38,12 @@ use crate::{
         transformers::{ForeignInto, ForeignTryInto},
     },
     utils::{self as helper_utils, generate_id, Encode, OptionExt, ValueExt},
+    workflows::outgoing_webhook_retry,
 };
 
 const OUTGOING_WEBHOOK_TIMEOUT_SECS: u64 = 5;
+/// Response bodies returned by the merchant server are stored up to this length (in bytes)
+const WEBHOOK_RESPONSE_BODY_MAX_LENGTH: usize = 4096;
 const MERCHANT_ID: &str = "merchant_id";
 
 pub async fn payments_incoming_webhook_flow<
@@ -55,7 +61,7 @@ pub async fn payments_incoming_webhook_flow<
     } else {
         payments::CallConnectorAction::Trigger
     };
-    let payments_response = match webhook_details.object_reference_id {
+    let (payments_response, previous_captures) = match webhook_details.object_reference_id {
         api_models::webhooks::ObjectReferenceId::PaymentId(id) => {
             let payment_id = get_payment_id(
                 state.store.as_ref(),
@@ -65,6 +71,16 @@ pub async fn payments_incoming_webhook_flow<
             )
             .await?;
 
+            // Captures are compared before and after the sync to find the capture updated by this webhook
+            let previous_captures = get_captures_of_active_attempt(
+                state.store.as_ref(),
+                &merchant_account,
+                &payment_id,
+            )
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to fetch captures of the payment"))
+            .unwrap_or_default();
+
             let lock_action = api_locking::LockAction::Hold {
                 input: super::api_locking::LockingInput {
                     unique_locking_key: payment_id,
@@ -105,7 +121,7 @@ pub async fn payments_incoming_webhook_flow<
                 .free_lock_action(&state, merchant_account.merchant_id.to_owned())
                 .await?;
 
-            match response {
+            let response = match response {
                 Ok(value) => value,
                 Err(err)
                     if matches!(
@@ -129,7 +145,9 @@ pub async fn payments_incoming_webhook_flow<
                     return Ok(WebhookResponseTracker::NoEffect);
                 }
                 error @ Err(_) => error?,
-            }
+            };
+
+            (response, previous_captures)
         }
         _ => Err(errors::ApiErrorResponse::WebhookProcessingFailure)
             .into_report()
@@ -139,7 +157,7 @@ pub async fn payments_incoming_webhook_flow<
     };
 
     match payments_response {
-        services::ApplicationResponse::JsonWithHeaders((payments_response, _)) => {
+        services::ApplicationResponse::JsonWithHeaders((mut payments_response, _)) => {
             let payment_id = payments_response
                 .payment_id
                 .clone()
@@ -149,13 +167,33 @@ pub async fn payments_incoming_webhook_flow<
 
             let status = payments_response.status;
 
+            // Captures are only recorded for payments captured through multiple partial captures
+            let updated_capture = if previous_captures.is_empty() {
+                None
+            } else {
+                get_captures_of_active_attempt(state.store.as_ref(), &merchant_account, &payment_id)
+                    .await
+                    .map_err(|error| {
+                        logger::error!(?error, "Failed to fetch captures of the payment")
+                    })
+                    .ok()
+                    .and_then(|captures| get_updated_capture(&previous_captures, captures))
+            };
+
             let event_type: Option<enums::EventType> = payments_response.status.foreign_into();
+            let event_type = event_type.or_else(|| {
+                updated_capture
+                    .is_some()
+                    .then_some(enums::EventType::PaymentCaptured)
+            });
+            payments_response.updated_capture = updated_capture.map(ForeignInto::foreign_into);
 
             // If event is NOT an UnsupportedEvent, trigger Outgoing Webhook
             if let Some(outgoing_event_type) = event_type {
                 create_event_and_trigger_outgoing_webhook::<W>(
                     state,
                     merchant_account,
+                    payments_response.profile_id.clone(),
                     outgoing_event_type,
                     enums::EventClass::Payments,
                     None,
@@ -269,6 +307,7 @@ pub async fn refunds_incoming_webhook_flow<W: types::OutgoingWebhookType>(
         create_event_and_trigger_outgoing_webhook::<W>(
             state,
             merchant_account,
+            updated_refund.profile_id.clone(),
             outgoing_event_type,
             enums::EventClass::Refunds,
             None,
@@ -329,6 +368,7 @@ pub async fn get_or_update_dispute_object(
     option_dispute: Option<diesel_models::dispute::Dispute>,
     dispute_details: api::disputes::DisputePayload,
     merchant_id: &str,
+    storage_scheme: enums::MerchantStorageScheme,
     payment_attempt: &data_models::payments::payment_attempt::PaymentAttempt,
     event_type: api_models::webhooks::IncomingWebhookEvent,
     connector_name: &str,
@@ -364,7 +404,7 @@ pub async fn get_or_update_dispute_object(
             };
             state
                 .store
-                .insert_dispute(new_dispute.clone())
+                .insert_dispute(new_dispute.clone(), storage_scheme)
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
         }
@@ -393,7 +433,7 @@ pub async fn get_or_update_dispute_object(
                 challenge_required_by: dispute_details.challenge_required_by,
                 connector_updated_at: dispute_details.updated_at,
             };
-            db.update_dispute(dispute, update_dispute)
+            db.update_dispute(dispute, update_dispute, storage_scheme)
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
         }
@@ -416,6 +456,7 @@ pub async fn mandates_incoming_webhook_flow<W: types::OutgoingWebhookType>(
                 .find_mandate_by_merchant_id_mandate_id(
                     &merchant_account.merchant_id,
                     mandate_id.as_str(),
+                    merchant_account.storage_scheme,
                 )
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?,
@@ -425,6 +466,7 @@ pub async fn mandates_incoming_webhook_flow<W: types::OutgoingWebhookType>(
                 .find_mandate_by_merchant_id_connector_mandate_id(
                     &merchant_account.merchant_id,
                     connector_mandate_id.as_str(),
+                    merchant_account.storage_scheme,
                 )
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?,
@@ -442,6 +484,7 @@ pub async fn mandates_incoming_webhook_flow<W: types::OutgoingWebhookType>(
                 &merchant_account.merchant_id,
                 &mandate.mandate_id,
                 storage::MandateUpdate::StatusUpdate { mandate_status },
+                merchant_account.storage_scheme,
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
@@ -454,6 +497,7 @@ pub async fn mandates_incoming_webhook_flow<W: types::OutgoingWebhookType>(
             create_event_and_trigger_outgoing_webhook::<W>(
                 state,
                 merchant_account,
+                None,
                 outgoing_event_type,
                 enums::EventClass::Mandates,
                 None,
@@ -473,6 +517,209 @@ pub async fn mandates_incoming_webhook_flow<W: types::OutgoingWebhookType>(
     }
 }
 
+#[instrument(skip_all)]
+pub async fn incremental_authorization_incoming_webhook_flow(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    webhook_details: api::IncomingWebhookDetails,
+    source_verified: bool,
+    event_type: api_models::webhooks::IncomingWebhookEvent,
+) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
+    if source_verified {
+        let db = &*state.store;
+        let storage_scheme = merchant_account.storage_scheme;
+        let payment_attempt = get_payment_attempt_from_object_reference_id(
+            &state,
+            webhook_details.object_reference_id,
+            &merchant_account,
+        )
+        .await?;
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &payment_attempt.payment_id,
+                &merchant_account.merchant_id,
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+
+        // Only one increment can be processed by the connector at a time, so the outcome
+        // belongs to the increment which is still being processed
+        let authorization = db
+            .find_all_incremental_authorizations_by_merchant_id_payment_id_attempt_id(
+                &merchant_account.merchant_id,
+                &payment_attempt.payment_id,
+                &payment_attempt.attempt_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?
+            .into_iter()
+            .find(|authorization| authorization.status == enums::AuthorizationStatus::Processing);
+        let Some(authorization) = authorization else {
+            logger::info!(
+                payment_id = %payment_attempt.payment_id,
+                "No incremental authorization is being processed, ignoring the webhook"
+            );
+            return Ok(WebhookResponseTracker::Payment {
+                payment_id: payment_intent.payment_id,
+                status: payment_intent.status,
+            });
+        };
+
+        let status = event_type
+            .foreign_try_into()
+            .into_report()
+            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .attach_printable("event type to authorization status mapping failed")?;
+        let authorization = db
+            .update_incremental_authorization(
+                authorization,
+                storage::IncrementalAuthorizationUpdate::StatusUpdate {
+                    status,
+                    error_code: None,
+                    error_message: None,
+                    connector_authorization_id: None,
+                },
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+
+        let payment_intent = if authorization.status == enums::AuthorizationStatus::Success {
+            let (_, payment_intent) = payments::helpers::apply_incremental_authorization_amount(
+                db,
+                payment_attempt,
+                payment_intent,
+                &authorization,
+                storage_scheme,
+            )
+            .await?;
+            payment_intent
+        } else {
+            payment_intent
+        };
+
+        Ok(WebhookResponseTracker::Payment {
+            payment_id: payment_intent.payment_id,
+            status: payment_intent.status,
+        })
+    } else {
+        logger::error!("Webhook source verification failed for incremental authorization flow");
+        Err(errors::ApiErrorResponse::WebhookAuthenticationFailed).into_report()
+    }
+}
+
+#[cfg(feature = "payouts")]
+#[instrument(skip_all)]
+pub async fn payouts_incoming_webhook_flow<W: types::OutgoingWebhookType>(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    webhook_details: api::IncomingWebhookDetails,
+    source_verified: bool,
+    event_type: api_models::webhooks::IncomingWebhookEvent,
+) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
+    if source_verified {
+        let db = &*state.store;
+        let payout_attempt = match webhook_details.object_reference_id {
+            webhooks::ObjectReferenceId::PayoutId(webhooks::PayoutIdType::PayoutId(payout_id)) => {
+                db.find_payout_attempt_by_merchant_id_payout_id(
+                    &merchant_account.merchant_id,
+                    &payout_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?
+            }
+            webhooks::ObjectReferenceId::PayoutId(webhooks::PayoutIdType::ConnectorPayoutId(
+                connector_payout_id,
+            )) => db
+                .find_payout_attempt_by_merchant_id_connector_payout_id(
+                    &merchant_account.merchant_id,
+                    &connector_payout_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?,
+            _ => Err(errors::ApiErrorResponse::WebhookProcessingFailure)
+                .into_report()
+                .attach_printable("received a non-payout id for retrieving payout")?,
+        };
+        let status = event_type
+            .foreign_try_into()
+            .into_report()
+            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .attach_printable("event type to payout status mapping failed")?;
+
+        // Webhooks for a status the payout has already moved to or past, which can be received
+        // late or more than once, are acknowledged without updating the attempt or notifying the
+        // merchant again
+        if !super::payouts::helpers::is_payout_status_transition_allowed(
+            payout_attempt.status,
+            status,
+        ) {
+            logger::info!(
+                current_status = ?payout_attempt.status,
+                webhook_status = ?status,
+                "Ignoring payout webhook which does not move the payout forward"
+            );
+            return Ok(WebhookResponseTracker::Payout {
+                payout_id: payout_attempt.payout_id,
+                status: payout_attempt.status,
+            });
+        }
+
+        let updated_payout_attempt = db
+            .update_payout_attempt_by_merchant_id_payout_id(
+                &merchant_account.merchant_id,
+                &payout_attempt.payout_id,
+                storage::PayoutAttemptUpdate::StatusUpdate {
+                    connector_payout_id: payout_attempt.connector_payout_id.clone(),
+                    status,
+                    error_message: None,
+                    error_code: None,
+                    is_eligible: payout_attempt.is_eligible,
+                    last_modified_at: Some(common_utils::date_time::now()),
+                },
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)
+            .attach_printable("Failed to update payout attempt with webhook status")?;
+        let payout = db
+            .find_payout_by_merchant_id_payout_id(
+                &merchant_account.merchant_id,
+                &updated_payout_attempt.payout_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+
+        let event_type: Option<enums::EventType> = updated_payout_attempt.status.foreign_into();
+        if let Some(outgoing_event_type) = event_type {
+            let payout_response: api::PayoutCreateResponse =
+                (payout, updated_payout_attempt.clone()).foreign_into();
+            create_event_and_trigger_outgoing_webhook::<W>(
+                state,
+                merchant_account,
+                updated_payout_attempt.profile_id.clone(),
+                outgoing_event_type,
+                enums::EventClass::Payouts,
+                None,
+                updated_payout_attempt.payout_id.clone(),
+                enums::EventObjectType::PayoutDetails,
+                api::OutgoingWebhookContent::PayoutDetails(Box::new(payout_response)),
+            )
+            .await?;
+        }
+        Ok(WebhookResponseTracker::Payout {
+            payout_id: updated_payout_attempt.payout_id,
+            status: updated_payout_attempt.status,
+        })
+    } else {
+        logger::error!("Webhook source verification failed for payouts webhook flow");
+        Err(errors::ApiErrorResponse::WebhookAuthenticationFailed).into_report()
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn disputes_incoming_webhook_flow<W: types::OutgoingWebhookType>(
     state: AppState,
@@ -498,6 +745,7 @@ pub async fn disputes_incoming_webhook_flow<W: types::OutgoingWebhookType>(
                 &merchant_account.merchant_id,
                 &payment_attempt.payment_id,
                 &dispute_details.connector_dispute_id,
+                merchant_account.storage_scheme,
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
@@ -506,17 +754,23 @@ pub async fn disputes_incoming_webhook_flow<W: types::OutgoingWebhookType>(
             option_dispute,
             dispute_details,
             &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
             &payment_attempt,
             event_type,
             connector.id(),
         )
         .await?;
+        disputes::add_dispute_deadline_task(db, &dispute_object)
+            .await
+            .map_err(|error| logger::error!(dispute_deadline_task_error=?error))
+            .ok();
         let disputes_response = Box::new(dispute_object.clone().foreign_into());
         let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
 
         create_event_and_trigger_outgoing_webhook::<W>(
             state,
             merchant_account,
+            dispute_object.profile_id.clone(),
             event_type,
             enums::EventClass::Disputes,
             None,
@@ -593,6 +847,7 @@ async fn bank_transfer_webhook_flow<W: types::OutgoingWebhookType, Ctx: PaymentM
                 create_event_and_trigger_outgoing_webhook::<W>(
                     state,
                     merchant_account,
+                    payments_response.profile_id.clone(),
                     outgoing_event_type,
                     enums::EventClass::Payments,
                     None,
@@ -617,6 +872,39 @@ async fn bank_transfer_webhook_flow<W: types::OutgoingWebhookType, Ctx: PaymentM
 pub async fn create_event_and_trigger_appropriate_outgoing_webhook(
     state: AppState,
     merchant_account: domain::MerchantAccount,
+    business_profile_id: Option<String>,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    intent_reference_id: Option<String>,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let event_id = format!("{primary_object_id}_{}", event_type);
+    create_event_with_id_and_trigger_appropriate_outgoing_webhook(
+        state,
+        merchant_account,
+        business_profile_id,
+        event_id,
+        event_type,
+        event_class,
+        intent_reference_id,
+        primary_object_id,
+        primary_object_type,
+        content,
+    )
+    .await
+}
+
+/// Used for events which can occur more than once for the same object, the merchant is notified
+/// only once for a given `event_id`
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub async fn create_event_with_id_and_trigger_appropriate_outgoing_webhook(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    business_profile_id: Option<String>,
+    event_id: String,
     event_type: enums::EventType,
     event_class: enums::EventClass,
     intent_reference_id: Option<String>,
@@ -627,22 +915,13 @@ pub async fn create_event_and_trigger_appropriate_outgoing_webhook(
     match merchant_account.get_compatible_connector() {
         #[cfg(feature = "stripe")]
         Some(api_models::enums::Connector::Stripe) => {
-            create_event_and_trigger_outgoing_webhook::<stripe_webhooks::StripeOutgoingWebhook>(
-                state.clone(),
-                merchant_account,
-                event_type,
-                event_class,
-                intent_reference_id,
-                primary_object_id,
-                primary_object_type,
-                content,
-            )
-            .await
-        }
-        _ => {
-            create_event_and_trigger_outgoing_webhook::<api_models::webhooks::OutgoingWebhook>(
+            create_event_with_id_and_trigger_outgoing_webhook::<
+                stripe_webhooks::StripeOutgoingWebhook,
+            >(
                 state.clone(),
                 merchant_account,
+                business_profile_id,
+                event_id,
                 event_type,
                 event_class,
                 intent_reference_id,
@@ -652,6 +931,21 @@ pub async fn create_event_and_trigger_appropriate_outgoing_webhook(
             )
             .await
         }
+        _ => create_event_with_id_and_trigger_outgoing_webhook::<
+            api_models::webhooks::OutgoingWebhook,
+        >(
+            state.clone(),
+            merchant_account,
+            business_profile_id,
+            event_id,
+            event_type,
+            event_class,
+            intent_reference_id,
+            primary_object_id,
+            primary_object_type,
+            content,
+        )
+        .await,
     }
 }
 
@@ -660,6 +954,7 @@ pub async fn create_event_and_trigger_appropriate_outgoing_webhook(
 pub async fn create_event_and_trigger_outgoing_webhook<W: types::OutgoingWebhookType>(
     state: AppState,
     merchant_account: domain::MerchantAccount,
+    business_profile_id: Option<String>,
     event_type: enums::EventType,
     event_class: enums::EventClass,
     intent_reference_id: Option<String>,
@@ -668,6 +963,34 @@ pub async fn create_event_and_trigger_outgoing_webhook<W: types::OutgoingWebhook
     content: api::OutgoingWebhookContent,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
     let event_id = format!("{primary_object_id}_{}", event_type);
+    create_event_with_id_and_trigger_outgoing_webhook::<W>(
+        state,
+        merchant_account,
+        business_profile_id,
+        event_id,
+        event_type,
+        event_class,
+        intent_reference_id,
+        primary_object_id,
+        primary_object_type,
+        content,
+    )
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn create_event_with_id_and_trigger_outgoing_webhook<W: types::OutgoingWebhookType>(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    business_profile_id: Option<String>,
+    event_id: String,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    intent_reference_id: Option<String>,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+) -> CustomResult<(), errors::ApiErrorResponse> {
     let new_event = storage::EventNew {
         event_id: event_id.clone(),
         event_type,
@@ -676,14 +999,25 @@ pub async fn create_event_and_trigger_outgoing_webhook<W: types::OutgoingWebhook
         intent_reference_id,
         primary_object_id,
         primary_object_type,
+        merchant_id: Some(merchant_account.merchant_id.clone()),
+        business_profile_id,
+        request: None,
     };
 
-    let event_insert_result = state.store.insert_event(new_event).await;
+    let event_insert_result = state
+        .store
+        .insert_event(new_event, merchant_account.storage_scheme)
+        .await;
 
     let event = match event_insert_result {
         Ok(event) => Ok(event),
         Err(error) => {
-            if error.current_context().is_db_unique_violation() {
+            if error.current_context().is_db_unique_violation()
+                || matches!(
+                    error.current_context(),
+                    errors::StorageError::DuplicateValue { .. }
+                )
+            {
                 logger::info!("Merchant already notified about the event {event_id}");
                 return Ok(());
             } else {
@@ -724,21 +1058,7 @@ pub async fn trigger_webhook_to_merchant<W: types::OutgoingWebhookType>(
     webhook: api::OutgoingWebhook,
     state: &AppState,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
-    let webhook_details_json = merchant_account
-        .webhook_details
-        .get_required_value("webhook_details")
-        .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
-
-    let webhook_details: api::WebhookDetails =
-        webhook_details_json
-            .parse_value("WebhookDetails")
-            .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
-
-    let webhook_url = webhook_details
-        .webhook_url
-        .get_required_value("webhook_url")
-        .change_context(errors::WebhooksFlowError::MerchantWebhookURLNotConfigured)
-        .map(ExposeInterface::expose)?;
+    let webhook_url = get_webhook_url(&merchant_account)?;
 
     let outgoing_webhook_event_id = webhook.event_id.clone();
 
@@ -763,62 +1083,186 @@ pub async fn trigger_webhook_to_merchant<W: types::OutgoingWebhookType>(
         W::add_webhook_header(&mut header, signature)
     }
 
-    let request = services::RequestBuilder::new()
+    let request_content = api_models::webhook_events::OutgoingWebhookRequestContent {
+        body: router_types::RequestBody::get_inner_value(transformed_outgoing_webhook_string),
+        headers: header
+            .into_iter()
+            .map(|(name, value)| (name, value.into_inner().into()))
+            .collect(),
+    };
+
+    // Retries deliver the request stored in the event, so they are only scheduled once the
+    // request has been stored
+    let is_request_stored =
+        match Encode::<api_models::webhook_events::OutgoingWebhookRequestContent>::encode_to_value(
+            &request_content,
+        ) {
+            Ok(request) => state
+                .store
+                .update_event(
+                    outgoing_webhook_event_id.clone(),
+                    storage::EventUpdate::UpdateRequest {
+                        request: request.into(),
+                    },
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .map_err(|error| logger::error!(outgoing_webhook_request_storage_error=?error))
+                .is_ok(),
+            Err(error) => {
+                logger::error!(outgoing_webhook_request_encoding_error=?error);
+                false
+            }
+        };
+
+    let delivery_result = deliver_webhook_to_merchant(
+        state,
+        &webhook_url,
+        &merchant_account.merchant_id,
+        &outgoing_webhook_event_id,
+        &request_content,
+        enums::WebhookDeliveryAttempt::InitialAttempt,
+        merchant_account.storage_scheme,
+    )
+    .await;
+
+    if delivery_result.is_err() && is_request_stored {
+        add_outgoing_webhook_retry_task(
+            state,
+            &merchant_account.merchant_id,
+            storage::OutgoingWebhookTrackingData {
+                event_id: outgoing_webhook_event_id,
+            },
+        )
+        .await
+        .map_err(|error| logger::error!(outgoing_webhook_retry_scheduling_error=?error))
+        .ok();
+    }
+
+    delivery_result
+}
+
+pub fn get_webhook_url(
+    merchant_account: &domain::MerchantAccount,
+) -> CustomResult<String, errors::WebhooksFlowError> {
+    let webhook_details_json = merchant_account
+        .webhook_details
+        .clone()
+        .get_required_value("webhook_details")
+        .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
+
+    let webhook_details: api::WebhookDetails =
+        webhook_details_json
+            .parse_value("WebhookDetails")
+            .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
+
+    webhook_details
+        .webhook_url
+        .get_required_value("webhook_url")
+        .change_context(errors::WebhooksFlowError::MerchantWebhookURLNotConfigured)
+        .map(ExposeInterface::expose)
+}
+
+/// Sends the webhook to the merchant, records the delivery attempt and marks the event as
+/// notified once the merchant has received it
+pub async fn deliver_webhook_to_merchant(
+    state: &AppState,
+    webhook_url: &str,
+    merchant_id: &str,
+    event_id: &str,
+    request_content: &api_models::webhook_events::OutgoingWebhookRequestContent,
+    delivery_attempt: enums::WebhookDeliveryAttempt,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let mut request = services::RequestBuilder::new()
         .method(services::Method::Post)
-        .url(&webhook_url)
+        .url(webhook_url)
         .attach_default_headers()
-        .headers(header)
-        .body(Some(transformed_outgoing_webhook_string))
+        .headers(
+            request_content
+                .headers
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone().expose().into()))
+                .collect(),
+        )
         .build();
+    request.set_body(request_content.body.clone().expose());
 
+    let delivery_started_at = std::time::Instant::now();
     let response = state
         .api_client
         .send_request(state, request, Some(OUTGOING_WEBHOOK_TIMEOUT_SECS), false)
         .await;
+    let latency_ms = i64::try_from(delivery_started_at.elapsed().as_millis()).ok();
 
     metrics::WEBHOOK_OUTGOING_COUNT.add(
         &metrics::CONTEXT,
         1,
-        &[metrics::KeyValue::new(
-            MERCHANT_ID,
-            merchant_account.merchant_id.clone(),
-        )],
+        &[metrics::KeyValue::new(MERCHANT_ID, merchant_id.to_string())],
     );
     logger::debug!(outgoing_webhook_response=?response);
 
     match response {
         Err(e) => {
-            // [#217]: Schedule webhook for retry.
+            record_webhook_delivery_attempt(
+                state,
+                storage::WebhookDeliveryAttemptNew {
+                    attempt_id: generate_id(consts::ID_LENGTH, "whda"),
+                    event_id: event_id.to_string(),
+                    merchant_id: merchant_id.to_string(),
+                    delivery_attempt,
+                    is_success: false,
+                    response_status_code: None,
+                    response_body: None,
+                    error_message: Some(e.current_context().to_string()),
+                    latency_ms,
+                },
+            )
+            .await;
             Err(e).change_context(errors::WebhooksFlowError::CallToMerchantFailed)?;
         }
         Ok(res) => {
-            if res.status().is_success() {
+            let status_code = res.status();
+            let response_body = res.text().await.ok().map(|mut response_body| {
+                truncate_response_body(&mut response_body);
+                response_body
+            });
+            record_webhook_delivery_attempt(
+                state,
+                storage::WebhookDeliveryAttemptNew {
+                    attempt_id: generate_id(consts::ID_LENGTH, "whda"),
+                    event_id: event_id.to_string(),
+                    merchant_id: merchant_id.to_string(),
+                    delivery_attempt,
+                    is_success: status_code.is_success(),
+                    response_status_code: i16::try_from(status_code.as_u16()).ok(),
+                    response_body,
+                    error_message: None,
+                    latency_ms,
+                },
+            )
+            .await;
+
+            if status_code.is_success() {
                 metrics::WEBHOOK_OUTGOING_RECEIVED_COUNT.add(
                     &metrics::CONTEXT,
                     1,
-                    &[metrics::KeyValue::new(
-                        MERCHANT_ID,
-                        merchant_account.merchant_id.clone(),
-                    )],
+                    &[metrics::KeyValue::new(MERCHANT_ID, merchant_id.to_string())],
                 );
                 let update_event = storage::EventUpdate::UpdateWebhookNotified {
                     is_webhook_notified: Some(true),
                 };
                 state
                     .store
-                    .update_event(outgoing_webhook_event_id, update_event)
+                    .update_event(event_id.to_string(), update_event, storage_scheme)
                     .await
                     .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)?;
             } else {
                 metrics::WEBHOOK_OUTGOING_NOT_RECEIVED_COUNT.add(
                     &metrics::CONTEXT,
                     1,
-                    &[metrics::KeyValue::new(
-                        MERCHANT_ID,
-                        merchant_account.merchant_id.clone(),
-                    )],
+                    &[metrics::KeyValue::new(MERCHANT_ID, merchant_id.to_string())],
                 );
-                // [#217]: Schedule webhook for retry.
                 Err(errors::WebhooksFlowError::NotReceivedByMerchant).into_report()?;
             }
         }
@@ -827,6 +1271,74 @@ pub async fn trigger_webhook_to_merchant<W: types::OutgoingWebhookType>(
     Ok(())
 }
 
+/// The delivery attempt is only recorded for debugging purposes, so a failure to store it must
+/// not affect the delivery of the webhook
+async fn record_webhook_delivery_attempt(
+    state: &AppState,
+    delivery_attempt: storage::WebhookDeliveryAttemptNew,
+) {
+    let event_id = delivery_attempt.event_id.clone();
+    state
+        .store
+        .insert_webhook_delivery_attempt(delivery_attempt)
+        .await
+        .map_err(|error| {
+            logger::error!(
+                event_id = %event_id,
+                webhook_delivery_attempt_insertion_error=?error
+            )
+        })
+        .ok();
+}
+
+fn truncate_response_body(response_body: &mut String) {
+    if response_body.len() > WEBHOOK_RESPONSE_BODY_MAX_LENGTH {
+        let boundary = (0..=WEBHOOK_RESPONSE_BODY_MAX_LENGTH)
+            .rev()
+            .find(|index| response_body.is_char_boundary(*index))
+            .unwrap_or(0);
+        response_body.truncate(boundary);
+    }
+}
+
+/// Schedules the first retry of a webhook which could not be delivered to the merchant, the
+/// subsequent retries are scheduled by the `OutgoingWebhookRetryWorkflow`
+async fn add_outgoing_webhook_retry_task(
+    state: &AppState,
+    merchant_id: &str,
+    tracking_data: storage::OutgoingWebhookTrackingData,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let schedule_time = match outgoing_webhook_retry::get_outgoing_webhook_retry_schedule_time(
+        &state.conf.webhooks.outgoing_retry,
+        0,
+    ) {
+        Some(schedule_time) => schedule_time,
+        // Retries are disabled
+        None => return Ok(()),
+    };
+
+    let runner = outgoing_webhook_retry::OUTGOING_WEBHOOK_RETRY_WORKFLOW;
+    let task = outgoing_webhook_retry::OUTGOING_WEBHOOK_RETRY_TASK;
+    let process_tracker_id =
+        pt_utils::get_process_tracker_id(runner, task, &tracking_data.event_id, merchant_id);
+    let process_tracker_entry = <storage::ProcessTracker>::make_process_tracker_new(
+        process_tracker_id,
+        task,
+        runner,
+        tracking_data,
+        schedule_time,
+    )?;
+
+    state.store.insert_process(process_tracker_entry).await?;
+
+    metrics::WEBHOOK_OUTGOING_RETRY_SCHEDULED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::KeyValue::new(MERCHANT_ID, merchant_id.to_string())],
+    );
+    Ok(())
+}
+
 pub async fn webhooks_wrapper<W: types::OutgoingWebhookType, Ctx: PaymentMethodRetrieve>(
     state: AppState,
     req: &actix_web::HttpRequest,
@@ -1104,6 +1616,29 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType, Ctx: PaymentMethodRetr
             .await
             .attach_printable("Incoming webhook flow for mandates failed")?,
 
+            #[cfg(feature = "payouts")]
+            api::WebhookFlow::Payout => payouts_incoming_webhook_flow::<W>(
+                state.clone(),
+                merchant_account,
+                webhook_details,
+                source_verified,
+                event_type,
+            )
+            .await
+            .attach_printable("Incoming webhook flow for payouts failed")?,
+
+            api::WebhookFlow::IncrementalAuthorization => {
+                incremental_authorization_incoming_webhook_flow(
+                    state.clone(),
+                    merchant_account,
+                    webhook_details,
+                    source_verified,
+                    event_type,
+                )
+                .await
+                .attach_printable("Incoming webhook flow for incremental authorizations failed")?
+            }
+
             _ => Err(errors::ApiErrorResponse::InternalServerError)
                 .into_report()
                 .attach_printable("Unsupported Flow Type received in incoming webhooks")?,
@@ -1129,6 +1664,41 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType, Ctx: PaymentMethodRetr
 }
 
 #[inline]
+async fn get_captures_of_active_attempt(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    payment_id: &str,
+) -> CustomResult<Vec<storage::Capture>, errors::ApiErrorResponse> {
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    db.find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+        &merchant_account.merchant_id,
+        payment_id,
+        &payment_intent.active_attempt.get_id(),
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+}
+
+/// Returns the most recently modified capture which is not present as is in `previous_captures`
+fn get_updated_capture(
+    previous_captures: &[storage::Capture],
+    current_captures: Vec<storage::Capture>,
+) -> Option<storage::Capture> {
+    current_captures
+        .into_iter()
+        .filter(|capture| !previous_captures.contains(capture))
+        .max_by_key(|capture| capture.modified_at)
+}
+
 pub async fn get_payment_id(
     db: &dyn StorageInterface,
     payment_id: &api::PaymentIdType,
@@ -1247,3 +1817,51 @@ async fn fetch_mca_and_connector(
         Ok((mca, connector))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_capture(capture_id: &str, status: enums::CaptureStatus) -> storage::Capture {
+        let now = common_utils::date_time::now();
+        storage::Capture {
+            capture_id: capture_id.to_string(),
+            payment_id: "pay_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            status,
+            amount: 100,
+            currency: Some(enums::Currency::USD),
+            connector: "adyen".to_string(),
+            error_message: None,
+            error_code: None,
+            error_reason: None,
+            tax_amount: None,
+            created_at: now,
+            modified_at: now,
+            authorized_attempt_id: "pay_1_1".to_string(),
+            connector_capture_id: None,
+            capture_sequence: 1,
+            connector_response_reference_id: None,
+        }
+    }
+
+    #[test]
+    fn test_get_updated_capture() {
+        let previous_captures = vec![
+            make_capture("pay_1_1_1", enums::CaptureStatus::Pending),
+            make_capture("pay_1_1_2", enums::CaptureStatus::Pending),
+        ];
+
+        // nothing changed during the sync
+        assert_eq!(
+            get_updated_capture(&previous_captures, previous_captures.clone()),
+            None
+        );
+
+        let mut current_captures = previous_captures.clone();
+        current_captures[1].status = enums::CaptureStatus::Charged;
+        let updated_capture = get_updated_capture(&previous_captures, current_captures)
+            .map(|capture| capture.capture_id);
+        assert_eq!(updated_capture.as_deref(), Some("pay_1_1_2"));
+    }
+}
@@ -0,0 +1,181 @@
+use api_models::webhook_events;
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use super::{deliver_webhook_to_merchant, get_webhook_url};
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::AppState,
+    services,
+    types::{
+        domain,
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+    utils::{self, ValueExt},
+};
+
+#[instrument(skip(state))]
+pub async fn list_events(
+    state: AppState,
+    merchant_id: String,
+    mut constraints: webhook_events::EventListConstraints,
+) -> RouterResponse<Vec<webhook_events::EventListItemResponse>> {
+    utils::when(constraints.limit.map_or(false, |limit| limit <= 0), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "limit must be greater than zero".to_string(),
+        })
+        .into_report()
+    })?;
+    constraints.limit = Some(
+        constraints
+            .limit
+            .map_or(consts::MAX_EVENT_LIST_LIMIT, |limit| {
+                limit.min(consts::MAX_EVENT_LIST_LIMIT)
+            }),
+    );
+
+    let events = state
+        .store
+        .list_events_by_merchant_id(&merchant_id, constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list events")?;
+
+    Ok(services::ApplicationResponse::Json(
+        events
+            .into_iter()
+            .map(webhook_events::EventListItemResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_event(
+    state: AppState,
+    merchant_id: String,
+    event_id: String,
+) -> RouterResponse<webhook_events::EventRetrieveResponse> {
+    let merchant_account = find_merchant_account(&state, &merchant_id).await?;
+    let event = find_event(&state, &merchant_account, &event_id).await?;
+    let event_response = get_event_retrieve_response(&state, event).await?;
+
+    Ok(services::ApplicationResponse::Json(event_response))
+}
+
+/// Sends the webhook of the event to the merchant again, using the same request that was sent
+/// in the first attempt. The outcome of the delivery is available in the delivery attempts of
+/// the returned event.
+#[instrument(skip(state))]
+pub async fn redeliver_event(
+    state: AppState,
+    merchant_id: String,
+    event_id: String,
+) -> RouterResponse<webhook_events::EventRetrieveResponse> {
+    let merchant_account = find_merchant_account(&state, &merchant_id).await?;
+    let event = find_event(&state, &merchant_account, &event_id).await?;
+
+    let request_content =
+        get_request_content(&event)?.ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The webhook request of this event was not stored and cannot be re-sent"
+                .to_string(),
+        })?;
+
+    let webhook_url = get_webhook_url(&merchant_account).change_context(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "Webhook URL is not configured for the merchant".to_string(),
+        },
+    )?;
+
+    // The outcome of the delivery is recorded as a delivery attempt of the event
+    deliver_webhook_to_merchant(
+        &state,
+        &webhook_url,
+        &merchant_id,
+        &event.event_id,
+        &request_content,
+        enums::WebhookDeliveryAttempt::ManualRetry,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .map_err(|error| logger::warn!(manual_webhook_redelivery_error=?error))
+    .ok();
+
+    let event = find_event(&state, &merchant_account, &event.event_id).await?;
+    let event_response = get_event_retrieve_response(&state, event).await?;
+
+    Ok(services::ApplicationResponse::Json(event_response))
+}
+
+async fn find_merchant_account(
+    state: &AppState,
+    merchant_id: &str,
+) -> RouterResult<domain::MerchantAccount> {
+    let db = &*state.store;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    db.find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+}
+
+async fn find_event(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    event_id: &str,
+) -> RouterResult<storage::Event> {
+    state
+        .store
+        .find_event_by_merchant_id_event_id(
+            &merchant_account.merchant_id,
+            event_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Event with id {event_id} not found"),
+        })
+}
+
+fn get_request_content(
+    event: &storage::Event,
+) -> RouterResult<Option<webhook_events::OutgoingWebhookRequestContent>> {
+    event
+        .request
+        .clone()
+        .map(|request| {
+            masking::ExposeInterface::expose(request)
+                .parse_value("OutgoingWebhookRequestContent")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse the stored webhook request")
+        })
+        .transpose()
+}
+
+async fn get_event_retrieve_response(
+    state: &AppState,
+    event: storage::Event,
+) -> RouterResult<webhook_events::EventRetrieveResponse> {
+    let request = get_request_content(&event)?;
+    let merchant_id = event.merchant_id.clone().unwrap_or_default();
+
+    let delivery_attempts = state
+        .store
+        .find_webhook_delivery_attempts_by_merchant_id_event_id(&merchant_id, &event.event_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the delivery attempts of the event")?;
+
+    Ok(webhook_events::EventRetrieveResponse {
+        event_information: webhook_events::EventListItemResponse::foreign_from(event),
+        request,
+        delivery_attempts: delivery_attempts
+            .into_iter()
+            .map(webhook_events::WebhookDeliveryAttemptResponse::foreign_from)
+            .collect(),
+    })
+}
@@ -12,6 +12,7 @@ pub mod ephemeral_key;
 pub mod events;
 pub mod file;
 pub mod fraud_check;
+pub mod incremental_authorization;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -23,6 +24,8 @@ pub mod payout_attempt;
 pub mod payouts;
 pub mod refund;
 pub mod reverse_lookup;
+pub mod subscription;
+pub mod webhook_delivery_attempt;
 
 use data_models::payments::{
     payment_attempt::PaymentAttemptInterface, payment_intent::PaymentIntentInterface,
@@ -56,6 +59,7 @@ pub trait StorageInterface:
     + events::EventInterface
     + file::FileMetadataInterface
     + fraud_check::FraudCheckInterface
+    + incremental_authorization::IncrementalAuthorizationInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
     + merchant_account::MerchantAccountInterface
@@ -75,6 +79,8 @@ pub trait StorageInterface:
     + payment_link::PaymentLinkInterface
     + RedisConnInterface
     + business_profile::BusinessProfileInterface
+    + subscription::SubscriptionInterface
+    + webhook_delivery_attempt::WebhookDeliveryAttemptInterface
     + 'static
 {
     fn get_scheduler_db(&self) -> Box<dyn scheduler::SchedulerInterface>;
@@ -8,7 +8,7 @@ use crate::{
     types::{
         domain::{
             self,
-            behaviour::{Conversion, ReverseConversion},
+            behaviour::{Conversion, KeyStoreReverseConversion},
         },
         storage as storage_types,
     },
@@ -72,6 +72,13 @@ where
         address: storage_types::AddressUpdate,
         key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Address>, errors::StorageError>;
+    async fn list_addresses_by_merchant_id_after_address_id(
+        &self,
+        merchant_id: &str,
+        last_address_id: &str,
+        limit: i64,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Address>, errors::StorageError>;
 }
 
 #[cfg(not(feature = "kv_store"))]
@@ -88,7 +95,7 @@ mod storage {
         types::{
             domain::{
                 self,
-                behaviour::{Conversion, ReverseConversion},
+                behaviour::{Conversion, KeyStoreReverseConversion},
             },
             storage::{self as storage_types, enums::MerchantStorageScheme},
         },
@@ -107,7 +114,7 @@ mod storage {
                 .into_report()
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -134,7 +141,7 @@ mod storage {
             .into_report()
             .async_and_then(|address| async {
                 address
-                    .convert(key_store.key.get_inner())
+                    .convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -155,7 +162,7 @@ mod storage {
                 .into_report()
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -181,7 +188,7 @@ mod storage {
                 .into_report()
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -206,7 +213,7 @@ mod storage {
                 .into_report()
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -229,7 +236,7 @@ mod storage {
                 .into_report()
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -258,7 +265,39 @@ mod storage {
                 for address in addresses.into_iter() {
                     output.push(
                         address
-                            .convert(key_store.key.get_inner())
+                            .convert_with_key_store(key_store)
+                            .await
+                            .change_context(errors::StorageError::DecryptionError)?,
+                    )
+                }
+                Ok(output)
+            })
+            .await
+        }
+
+        async fn list_addresses_by_merchant_id_after_address_id(
+            &self,
+            merchant_id: &str,
+            last_address_id: &str,
+            limit: i64,
+            key_store: &domain::MerchantKeyStore,
+        ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Address::list_by_merchant_id_after_address_id(
+                &conn,
+                merchant_id,
+                last_address_id,
+                limit,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+            .async_and_then(|addresses| async {
+                let mut output = Vec::with_capacity(addresses.len());
+                for address in addresses.into_iter() {
+                    output.push(
+                        address
+                            .convert_with_key_store(key_store)
                             .await
                             .change_context(errors::StorageError::DecryptionError)?,
                     )
@@ -287,7 +326,7 @@ mod storage {
         types::{
             domain::{
                 self,
-                behaviour::{Conversion, ReverseConversion},
+                behaviour::{Conversion, KeyStoreReverseConversion},
             },
             storage::{self as storage_types, kv},
         },
@@ -307,7 +346,7 @@ mod storage {
                 .into_report()
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -355,7 +394,7 @@ mod storage {
                 }
             }?;
             address
-                .convert(key_store.key.get_inner())
+                .convert_with_key_store(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError)
         }
@@ -374,7 +413,7 @@ mod storage {
                 .into_report()
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -402,7 +441,7 @@ mod storage {
                         .into_report()
                         .async_and_then(|address| async {
                             address
-                                .convert(key_store.key.get_inner())
+                                .convert_with_key_store(key_store)
                                 .await
                                 .change_context(errors::StorageError::DecryptionError)
                         })
@@ -442,7 +481,7 @@ mod storage {
                     .change_context(errors::StorageError::KVError)?;
 
                     updated_address
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 }
@@ -472,7 +511,7 @@ mod storage {
                         .into_report()
                         .async_and_then(|address| async {
                             address
-                                .convert(key_store.key.get_inner())
+                                .convert_with_key_store(key_store)
                                 .await
                                 .change_context(errors::StorageError::DecryptionError)
                         })
@@ -528,7 +567,7 @@ mod storage {
                         })
                         .into_report(),
                         Ok(HsetnxReply::KeySet) => Ok(created_address
-                            .convert(key_store.key.get_inner())
+                            .convert_with_key_store(key_store)
                             .await
                             .change_context(errors::StorageError::DecryptionError)?),
                         Err(er) => Err(er).change_context(errors::StorageError::KVError),
@@ -553,7 +592,7 @@ mod storage {
                 .into_report()
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -582,7 +621,39 @@ mod storage {
                 for address in addresses.into_iter() {
                     output.push(
                         address
-                            .convert(key_store.key.get_inner())
+                            .convert_with_key_store(key_store)
+                            .await
+                            .change_context(errors::StorageError::DecryptionError)?,
+                    )
+                }
+                Ok(output)
+            })
+            .await
+        }
+
+        async fn list_addresses_by_merchant_id_after_address_id(
+            &self,
+            merchant_id: &str,
+            last_address_id: &str,
+            limit: i64,
+            key_store: &domain::MerchantKeyStore,
+        ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Address::list_by_merchant_id_after_address_id(
+                &conn,
+                merchant_id,
+                last_address_id,
+                limit,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+            .async_and_then(|addresses| async {
+                let mut output = Vec::with_capacity(addresses.len());
+                for address in addresses.into_iter() {
+                    output.push(
+                        address
+                            .convert_with_key_store(key_store)
                             .await
                             .change_context(errors::StorageError::DecryptionError)?,
                     )
@@ -610,7 +681,7 @@ impl AddressInterface for MockDb {
         {
             Some(address) => address
                 .clone()
-                .convert(key_store.key.get_inner())
+                .convert_with_key_store(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError),
             None => {
@@ -638,7 +709,7 @@ impl AddressInterface for MockDb {
         {
             Some(address) => address
                 .clone()
-                .convert(key_store.key.get_inner())
+                .convert_with_key_store(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError),
             None => {
@@ -669,7 +740,7 @@ impl AddressInterface for MockDb {
                 address_updated
             }) {
             Some(address_updated) => address_updated
-                .convert(key_store.key.get_inner())
+                .convert_with_key_store(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError),
             None => Err(errors::StorageError::ValueNotFound(
@@ -700,7 +771,7 @@ impl AddressInterface for MockDb {
                 address_updated
             }) {
             Some(address_updated) => address_updated
-                .convert(key_store.key.get_inner())
+                .convert_with_key_store(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError),
             None => Err(errors::StorageError::ValueNotFound(
@@ -726,7 +797,7 @@ impl AddressInterface for MockDb {
         addresses.push(address.clone());
 
         address
-            .convert(key_store.key.get_inner())
+            .convert_with_key_store(key_store)
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -745,7 +816,7 @@ impl AddressInterface for MockDb {
         addresses.push(address.clone());
 
         address
-            .convert(key_store.key.get_inner())
+            .convert_with_key_store(key_store)
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -773,7 +844,7 @@ impl AddressInterface for MockDb {
             }) {
             Some(address) => {
                 let address: domain::Address = address
-                    .convert(key_store.key.get_inner())
+                    .convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)?;
                 Ok(vec![address])
@@ -783,4 +854,38 @@ impl AddressInterface for MockDb {
             }
         }
     }
+
+    async fn list_addresses_by_merchant_id_after_address_id(
+        &self,
+        merchant_id: &str,
+        last_address_id: &str,
+        limit: i64,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+        let mut addresses = self
+            .addresses
+            .lock()
+            .await
+            .iter()
+            .filter(|address| {
+                address.merchant_id == merchant_id && address.address_id.as_str() > last_address_id
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        addresses.sort_by(|a, b| a.address_id.cmp(&b.address_id));
+
+        let mut output = Vec::with_capacity(addresses.len());
+        for address in addresses
+            .into_iter()
+            .take(usize::try_from(limit).unwrap_or(usize::MAX))
+        {
+            output.push(
+                address
+                    .convert_with_key_store(key_store)
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            )
+        }
+        Ok(output)
+    }
 }
@@ -32,14 +32,17 @@ pub trait CaptureInterface {
 
 #[cfg(feature = "kv_store")]
 mod storage {
-    use error_stack::IntoReport;
+    use error_stack::{IntoReport, ResultExt};
+    use redis_interface::HsetnxReply;
+    use storage_impl::redis::kv_store::{kv_wrapper, KvOperation};
 
     use super::CaptureInterface;
     use crate::{
         connection,
         core::errors::{self, CustomResult},
         services::Store,
-        types::storage::{capture::*, enums},
+        types::storage::{capture::*, enums, kv},
+        utils::{self, db_utils},
     };
 
     #[async_trait::async_trait]
@@ -47,33 +50,115 @@ mod storage {
         async fn insert_capture(
             &self,
             capture: CaptureNew,
-            _storage_scheme: enums::MerchantStorageScheme,
+            storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<Capture, errors::StorageError> {
-            let db_call = || async {
-                let conn = connection::pg_connection_write(self).await?;
-                capture
-                    .insert(&conn)
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    capture
+                        .insert(&conn)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("mid_{}_pid_{}", capture.merchant_id, capture.payment_id);
+                    let field = format!("capture_{}", capture.capture_id);
+
+                    let created_capture = Capture {
+                        capture_id: capture.capture_id.clone(),
+                        payment_id: capture.payment_id.clone(),
+                        merchant_id: capture.merchant_id.clone(),
+                        status: capture.status,
+                        amount: capture.amount,
+                        currency: capture.currency,
+                        connector: capture.connector.clone(),
+                        error_message: capture.error_message.clone(),
+                        error_code: capture.error_code.clone(),
+                        error_reason: capture.error_reason.clone(),
+                        tax_amount: capture.tax_amount,
+                        created_at: capture.created_at,
+                        modified_at: capture.modified_at,
+                        authorized_attempt_id: capture.authorized_attempt_id.clone(),
+                        connector_capture_id: capture.connector_capture_id.clone(),
+                        capture_sequence: capture.capture_sequence,
+                        connector_response_reference_id: capture
+                            .connector_response_reference_id
+                            .clone(),
+                    };
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Insert {
+                            insertable: kv::Insertable::Capture(capture),
+                        },
+                    };
+
+                    match kv_wrapper::<Capture, _, _>(
+                        self,
+                        KvOperation::<Capture>::HSetNx(&field, &created_capture, redis_entry),
+                        &key,
+                    )
                     .await
-                    .map_err(Into::into)
-                    .into_report()
-            };
-            db_call().await
+                    .change_context(errors::StorageError::KVError)?
+                    .try_into_hsetnx()
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "capture",
+                            key: Some(created_capture.capture_id),
+                        })
+                        .into_report(),
+                        Ok(HsetnxReply::KeySet) => Ok(created_capture),
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }
         }
 
         async fn update_capture_with_capture_id(
             &self,
             this: Capture,
             capture: CaptureUpdate,
-            _storage_scheme: enums::MerchantStorageScheme,
+            storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<Capture, errors::StorageError> {
-            let db_call = || async {
-                let conn = connection::pg_connection_write(self).await?;
-                this.update_with_capture_id(&conn, capture)
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    this.update_with_capture_id(&conn, capture)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("mid_{}_pid_{}", this.merchant_id, this.payment_id);
+                    let field = format!("capture_{}", this.capture_id);
+
+                    let updated_capture = capture.clone().apply_changeset(this.clone());
+                    let redis_value =
+                        utils::Encode::<Capture>::encode_to_string_of_json(&updated_capture)
+                            .change_context(errors::StorageError::SerializationFailed)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: kv::Updateable::CaptureUpdate(kv::CaptureUpdateMems {
+                                orig: this,
+                                update_data: capture,
+                            }),
+                        },
+                    };
+
+                    kv_wrapper::<(), _, _>(
+                        self,
+                        KvOperation::Hset::<Capture>((&field, redis_value), redis_entry),
+                        &key,
+                    )
                     .await
-                    .map_err(Into::into)
-                    .into_report()
-            };
-            db_call().await
+                    .change_context(errors::StorageError::KVError)?
+                    .try_into_hset()
+                    .change_context(errors::StorageError::KVError)?;
+
+                    Ok(updated_capture)
+                }
+            }
         }
 
         async fn find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
@@ -81,9 +166,9 @@ mod storage {
             merchant_id: &str,
             payment_id: &str,
             authorized_attempt_id: &str,
-            _storage_scheme: enums::MerchantStorageScheme,
+            storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<Vec<Capture>, errors::StorageError> {
-            let db_call = || async {
+            let database_call = || async {
                 let conn = connection::pg_connection_read(self).await?;
                 Capture::find_all_by_merchant_id_payment_id_authorized_attempt_id(
                     merchant_id,
@@ -95,7 +180,31 @@ mod storage {
                 .map_err(Into::into)
                 .into_report()
             };
-            db_call().await
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("mid_{merchant_id}_pid_{payment_id}");
+                    let captures: Vec<Capture> = db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            kv_wrapper(self, KvOperation::<Capture>::Scan("capture_*"), key)
+                                .await?
+                                .try_into_scan()
+                        },
+                        database_call,
+                    )
+                    .await?
+                    .into_iter()
+                    .filter(|capture| capture.authorized_attempt_id == authorized_attempt_id)
+                    .collect();
+
+                    // Captures which have already expired from redis are only present in the database
+                    if captures.is_empty() {
+                        database_call().await
+                    } else {
+                        Ok(captures)
+                    }
+                }
+            }
         }
     }
 }
@@ -213,12 +322,20 @@ impl CaptureInterface for MockDb {
     }
     async fn find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
         &self,
-        _merchant_id: &str,
-        _payment_id: &str,
-        _authorized_attempt_id: &str,
+        merchant_id: &str,
+        payment_id: &str,
+        authorized_attempt_id: &str,
         _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<types::Capture>, errors::StorageError> {
-        //Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let captures = self.captures.lock().await;
+        Ok(captures
+            .iter()
+            .filter(|capture| {
+                capture.merchant_id == merchant_id
+                    && capture.payment_id == payment_id
+                    && capture.authorized_attempt_id == authorized_attempt_id
+            })
+            .cloned()
+            .collect())
     }
 }
@@ -14,7 +14,7 @@ use crate::{
     types::{
         domain::{
             self,
-            behaviour::{Conversion, ReverseConversion},
+            behaviour::{Conversion, KeyStoreReverseConversion},
         },
         storage,
     },
@@ -59,6 +59,14 @@ where
         key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Customer>, errors::StorageError>;
 
+    async fn list_customers_by_merchant_id_after_customer_id(
+        &self,
+        merchant_id: &str,
+        last_customer_id: &str,
+        limit: i64,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError>;
+
     async fn insert_customer(
         &self,
         customer_data: domain::Customer,
@@ -85,7 +93,7 @@ impl CustomerInterface for Store {
             .map_err(Into::into)
             .into_report()?
             .async_map(|c| async {
-                c.convert(key_store.key.get_inner())
+                c.convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -122,7 +130,7 @@ impl CustomerInterface for Store {
         .map_err(Into::into)
         .into_report()
         .async_and_then(|c| async {
-            c.convert(key_store.key.get_inner())
+            c.convert_with_key_store(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError)
         })
@@ -142,7 +150,7 @@ impl CustomerInterface for Store {
                 .map_err(Into::into)
                 .into_report()
                 .async_and_then(|c| async {
-                    c.convert(key_store.key.get_inner())
+                    c.convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -170,7 +178,39 @@ impl CustomerInterface for Store {
         let customers = try_join_all(encrypted_customers.into_iter().map(
             |encrypted_customer| async {
                 encrypted_customer
-                    .convert(key_store.key.get_inner())
+                    .convert_with_key_store(key_store)
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)
+            },
+        ))
+        .await?;
+
+        Ok(customers)
+    }
+
+    async fn list_customers_by_merchant_id_after_customer_id(
+        &self,
+        merchant_id: &str,
+        last_customer_id: &str,
+        limit: i64,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+
+        let encrypted_customers = storage::Customer::list_by_merchant_id_after_customer_id(
+            &conn,
+            merchant_id,
+            last_customer_id,
+            limit,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()?;
+
+        let customers = try_join_all(encrypted_customers.into_iter().map(
+            |encrypted_customer| async {
+                encrypted_customer
+                    .convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             },
@@ -195,7 +235,7 @@ impl CustomerInterface for Store {
             .map_err(Into::into)
             .into_report()
             .async_and_then(|c| async {
-                c.convert(key_store.key.get_inner())
+                c.convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -233,7 +273,7 @@ impl CustomerInterface for MockDb {
             .cloned();
         customer
             .async_map(|c| async {
-                c.convert(key_store.key.get_inner())
+                c.convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -255,7 +295,7 @@ impl CustomerInterface for MockDb {
                 .map(|customer| async {
                     customer
                         .to_owned()
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 }),
@@ -265,6 +305,40 @@ impl CustomerInterface for MockDb {
         Ok(customers)
     }
 
+    async fn list_customers_by_merchant_id_after_customer_id(
+        &self,
+        merchant_id: &str,
+        last_customer_id: &str,
+        limit: i64,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+        let mut customers = self
+            .customers
+            .lock()
+            .await
+            .iter()
+            .filter(|customer| {
+                customer.merchant_id == merchant_id
+                    && customer.customer_id.as_str() > last_customer_id
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        customers.sort_by(|a, b| a.customer_id.cmp(&b.customer_id));
+
+        try_join_all(
+            customers
+                .into_iter()
+                .take(usize::try_from(limit).unwrap_or(usize::MAX))
+                .map(|customer| async {
+                    customer
+                        .convert_with_key_store(key_store)
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)
+                }),
+        )
+        .await
+    }
+
     #[instrument(skip_all)]
     async fn update_customer_by_customer_id_merchant_id(
         &self,
@@ -302,7 +376,7 @@ impl CustomerInterface for MockDb {
         customers.push(customer.clone());
 
         customer
-            .convert(key_store.key.get_inner())
+            .convert_with_key_store(key_store)
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
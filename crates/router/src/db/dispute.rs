@@ -1,129 +1,518 @@
 use error_stack::{IntoReport, ResultExt};
 
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection,
     core::errors::{self, CustomResult},
-    types::storage::{self, DisputeDbExt},
+    types::storage::{self as storage_types, enums},
 };
 
 #[async_trait::async_trait]
 pub trait DisputeInterface {
     async fn insert_dispute(
         &self,
-        dispute: storage::DisputeNew,
-    ) -> CustomResult<storage::Dispute, errors::StorageError>;
+        dispute: storage_types::DisputeNew,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Dispute, errors::StorageError>;
 
     async fn find_by_merchant_id_payment_id_connector_dispute_id(
         &self,
         merchant_id: &str,
         payment_id: &str,
         connector_dispute_id: &str,
-    ) -> CustomResult<Option<storage::Dispute>, errors::StorageError>;
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Option<storage_types::Dispute>, errors::StorageError>;
 
     async fn find_dispute_by_merchant_id_dispute_id(
         &self,
         merchant_id: &str,
         dispute_id: &str,
-    ) -> CustomResult<storage::Dispute, errors::StorageError>;
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Dispute, errors::StorageError>;
 
     async fn find_disputes_by_merchant_id(
         &self,
         merchant_id: &str,
         dispute_constraints: api_models::disputes::DisputeListConstraints,
-    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError>;
+    ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError>;
 
     async fn find_disputes_by_merchant_id_payment_id(
         &self,
         merchant_id: &str,
         payment_id: &str,
-    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError>;
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError>;
+
+    async fn get_dispute_analytics_records(
+        &self,
+        merchant_id: &str,
+        profile_id: Option<String>,
+        connector: Option<String>,
+        start_time: time::PrimitiveDateTime,
+        end_time: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage_types::DisputeAnalyticsRecord>, errors::StorageError>;
 
     async fn update_dispute(
         &self,
-        this: storage::Dispute,
-        dispute: storage::DisputeUpdate,
-    ) -> CustomResult<storage::Dispute, errors::StorageError>;
+        this: storage_types::Dispute,
+        dispute: storage_types::DisputeUpdate,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Dispute, errors::StorageError>;
 }
 
-#[async_trait::async_trait]
-impl DisputeInterface for Store {
-    async fn insert_dispute(
-        &self,
-        dispute: storage::DisputeNew,
-    ) -> CustomResult<storage::Dispute, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        dispute
-            .insert(&conn)
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use error_stack::IntoReport;
+
+    use super::DisputeInterface;
+    use crate::{
+        connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{self as storage_types, enums, DisputeDbExt},
+    };
+
+    #[async_trait::async_trait]
+    impl DisputeInterface for Store {
+        async fn insert_dispute(
+            &self,
+            dispute: storage_types::DisputeNew,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            dispute
+                .insert(&conn)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn find_by_merchant_id_payment_id_connector_dispute_id(
+            &self,
+            merchant_id: &str,
+            payment_id: &str,
+            connector_dispute_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Option<storage_types::Dispute>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Dispute::find_by_merchant_id_payment_id_connector_dispute_id(
+                &conn,
+                merchant_id,
+                payment_id,
+                connector_dispute_id,
+            )
             .await
             .map_err(Into::into)
             .into_report()
-    }
+        }
 
-    async fn find_by_merchant_id_payment_id_connector_dispute_id(
-        &self,
-        merchant_id: &str,
-        payment_id: &str,
-        connector_dispute_id: &str,
-    ) -> CustomResult<Option<storage::Dispute>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Dispute::find_by_merchant_id_payment_id_connector_dispute_id(
-            &conn,
-            merchant_id,
-            payment_id,
-            connector_dispute_id,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
-    }
+        async fn find_dispute_by_merchant_id_dispute_id(
+            &self,
+            merchant_id: &str,
+            dispute_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Dispute::find_by_merchant_id_dispute_id(&conn, merchant_id, dispute_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
 
-    async fn find_dispute_by_merchant_id_dispute_id(
-        &self,
-        merchant_id: &str,
-        dispute_id: &str,
-    ) -> CustomResult<storage::Dispute, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Dispute::find_by_merchant_id_dispute_id(&conn, merchant_id, dispute_id)
-            .await
-            .map_err(Into::into)
-            .into_report()
-    }
+        async fn find_disputes_by_merchant_id(
+            &self,
+            merchant_id: &str,
+            dispute_constraints: api_models::disputes::DisputeListConstraints,
+        ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Dispute::filter_by_constraints(&conn, merchant_id, dispute_constraints)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
 
-    async fn find_disputes_by_merchant_id(
-        &self,
-        merchant_id: &str,
-        dispute_constraints: api_models::disputes::DisputeListConstraints,
-    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Dispute::filter_by_constraints(&conn, merchant_id, dispute_constraints)
+        async fn get_dispute_analytics_records(
+            &self,
+            merchant_id: &str,
+            profile_id: Option<String>,
+            connector: Option<String>,
+            start_time: time::PrimitiveDateTime,
+            end_time: time::PrimitiveDateTime,
+        ) -> CustomResult<Vec<storage_types::DisputeAnalyticsRecord>, errors::StorageError>
+        {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Dispute::get_analytics_records(
+                &conn,
+                merchant_id,
+                profile_id,
+                connector,
+                start_time,
+                end_time,
+            )
             .await
             .map_err(Into::into)
             .into_report()
-    }
+        }
 
-    async fn find_disputes_by_merchant_id_payment_id(
-        &self,
-        merchant_id: &str,
-        payment_id: &str,
-    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Dispute::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
-            .await
-            .map_err(Into::into)
-            .into_report()
+        async fn find_disputes_by_merchant_id_payment_id(
+            &self,
+            merchant_id: &str,
+            payment_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Dispute::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn update_dispute(
+            &self,
+            this: storage_types::Dispute,
+            dispute: storage_types::DisputeUpdate,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            this.update(&conn, dispute)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
     }
+}
 
-    async fn update_dispute(
-        &self,
-        this: storage::Dispute,
-        dispute: storage::DisputeUpdate,
-    ) -> CustomResult<storage::Dispute, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        this.update(&conn, dispute)
+#[cfg(feature = "kv_store")]
+mod storage {
+    use error_stack::{IntoReport, ResultExt};
+    use redis_interface::HsetnxReply;
+    use storage_impl::redis::kv_store::{kv_wrapper, KvOperation};
+
+    use super::DisputeInterface;
+    use crate::{
+        connection,
+        core::errors::{self, CustomResult},
+        db::reverse_lookup::ReverseLookupInterface,
+        services::Store,
+        types::storage::{self as storage_types, enums, kv, DisputeDbExt},
+        utils::{self, db_utils},
+    };
+
+    #[async_trait::async_trait]
+    impl DisputeInterface for Store {
+        async fn insert_dispute(
+            &self,
+            dispute: storage_types::DisputeNew,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    dispute
+                        .insert(&conn)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("mid_{}_pid_{}", dispute.merchant_id, dispute.payment_id);
+                    let field = format!("dispute_{}", dispute.dispute_id);
+                    let now = common_utils::date_time::now();
+
+                    let created_dispute = storage_types::Dispute {
+                        id: 0,
+                        dispute_id: dispute.dispute_id.clone(),
+                        amount: dispute.amount.clone(),
+                        currency: dispute.currency.clone(),
+                        dispute_stage: dispute.dispute_stage,
+                        dispute_status: dispute.dispute_status,
+                        payment_id: dispute.payment_id.clone(),
+                        attempt_id: dispute.attempt_id.clone(),
+                        merchant_id: dispute.merchant_id.clone(),
+                        connector_status: dispute.connector_status.clone(),
+                        connector_dispute_id: dispute.connector_dispute_id.clone(),
+                        connector_reason: dispute.connector_reason.clone(),
+                        connector_reason_code: dispute.connector_reason_code.clone(),
+                        challenge_required_by: dispute.challenge_required_by,
+                        connector_created_at: dispute.connector_created_at,
+                        connector_updated_at: dispute.connector_updated_at,
+                        created_at: now,
+                        modified_at: now,
+                        connector: dispute.connector.clone(),
+                        evidence: dispute
+                            .evidence
+                            .clone()
+                            .unwrap_or_else(|| serde_json::json!({}).into()),
+                        profile_id: dispute.profile_id.clone(),
+                    };
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Insert {
+                            insertable: kv::Insertable::Dispute(dispute),
+                        },
+                    };
+
+                    match kv_wrapper::<storage_types::Dispute, _, _>(
+                        self,
+                        KvOperation::<storage_types::Dispute>::HSetNx(
+                            &field,
+                            &created_dispute,
+                            redis_entry,
+                        ),
+                        &key,
+                    )
+                    .await
+                    .change_context(errors::StorageError::KVError)?
+                    .try_into_hsetnx()
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "dispute",
+                            key: Some(created_dispute.dispute_id),
+                        })
+                        .into_report(),
+                        Ok(HsetnxReply::KeySet) => {
+                            let reverse_lookup = storage_types::ReverseLookupNew {
+                                sk_id: field,
+                                lookup_id: format!(
+                                    "{}_{}",
+                                    created_dispute.merchant_id, created_dispute.dispute_id
+                                ),
+                                pk_id: key,
+                                source: "dispute".to_string(),
+                                updated_by: storage_scheme.to_string(),
+                            };
+                            self.insert_reverse_lookup(reverse_lookup, storage_scheme)
+                                .await?;
+                            Ok(created_dispute)
+                        }
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }
+        }
+
+        async fn find_by_merchant_id_payment_id_connector_dispute_id(
+            &self,
+            merchant_id: &str,
+            payment_id: &str,
+            connector_dispute_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Option<storage_types::Dispute>, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage_types::Dispute::find_by_merchant_id_payment_id_connector_dispute_id(
+                    &conn,
+                    merchant_id,
+                    payment_id,
+                    connector_dispute_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            };
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let disputes = self
+                        .find_disputes_by_merchant_id_payment_id(
+                            merchant_id,
+                            payment_id,
+                            storage_scheme,
+                        )
+                        .await?;
+                    match disputes
+                        .into_iter()
+                        .find(|dispute| dispute.connector_dispute_id == connector_dispute_id)
+                    {
+                        Some(dispute) => Ok(Some(dispute)),
+                        None => database_call().await,
+                    }
+                }
+            }
+        }
+
+        async fn find_dispute_by_merchant_id_dispute_id(
+            &self,
+            merchant_id: &str,
+            dispute_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage_types::Dispute::find_by_merchant_id_dispute_id(
+                    &conn,
+                    merchant_id,
+                    dispute_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            };
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let lookup_id = format!("{merchant_id}_{dispute_id}");
+                    let lookup = match self
+                        .get_lookup_by_lookup_id(&lookup_id, storage_scheme)
+                        .await
+                    {
+                        Ok(lookup) => lookup,
+                        // Disputes created before the merchant moved to the KV store have no
+                        // reverse lookup
+                        Err(error) if error.current_context().is_db_not_found() => {
+                            return database_call().await
+                        }
+                        Err(error) => return Err(error),
+                    };
+
+                    let key = &lookup.pk_id;
+                    db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            kv_wrapper(
+                                self,
+                                KvOperation::<storage_types::Dispute>::HGet(&lookup.sk_id),
+                                key,
+                            )
+                            .await?
+                            .try_into_hget()
+                        },
+                        database_call,
+                    )
+                    .await
+                }
+            }
+        }
+
+        async fn find_disputes_by_merchant_id(
+            &self,
+            merchant_id: &str,
+            dispute_constraints: api_models::disputes::DisputeListConstraints,
+        ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Dispute::filter_by_constraints(&conn, merchant_id, dispute_constraints)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn get_dispute_analytics_records(
+            &self,
+            merchant_id: &str,
+            profile_id: Option<String>,
+            connector: Option<String>,
+            start_time: time::PrimitiveDateTime,
+            end_time: time::PrimitiveDateTime,
+        ) -> CustomResult<Vec<storage_types::DisputeAnalyticsRecord>, errors::StorageError>
+        {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Dispute::get_analytics_records(
+                &conn,
+                merchant_id,
+                profile_id,
+                connector,
+                start_time,
+                end_time,
+            )
             .await
             .map_err(Into::into)
             .into_report()
+        }
+
+        async fn find_disputes_by_merchant_id_payment_id(
+            &self,
+            merchant_id: &str,
+            payment_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage_types::Dispute::find_by_merchant_id_payment_id(
+                    &conn,
+                    merchant_id,
+                    payment_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            };
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("mid_{merchant_id}_pid_{payment_id}");
+                    let disputes: Vec<storage_types::Dispute> =
+                        db_utils::try_redis_get_else_try_database_get(
+                            async {
+                                kv_wrapper(
+                                    self,
+                                    KvOperation::<storage_types::Dispute>::Scan("dispute_*"),
+                                    key,
+                                )
+                                .await?
+                                .try_into_scan()
+                            },
+                            database_call,
+                        )
+                        .await?;
+
+                    if disputes.is_empty() {
+                        database_call().await
+                    } else {
+                        Ok(disputes)
+                    }
+                }
+            }
+        }
+
+        async fn update_dispute(
+            &self,
+            this: storage_types::Dispute,
+            dispute: storage_types::DisputeUpdate,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    this.update(&conn, dispute)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("mid_{}_pid_{}", this.merchant_id, this.payment_id);
+                    let field = format!("dispute_{}", this.dispute_id);
+
+                    let updated_dispute = dispute.clone().apply_changeset(this.clone());
+                    let redis_value =
+                        utils::Encode::<storage_types::Dispute>::encode_to_string_of_json(
+                            &updated_dispute,
+                        )
+                        .change_context(errors::StorageError::SerializationFailed)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: kv::Updateable::DisputeUpdate(kv::DisputeUpdateMems {
+                                orig: this,
+                                update_data: dispute,
+                            }),
+                        },
+                    };
+
+                    kv_wrapper::<(), _, _>(
+                        self,
+                        KvOperation::Hset::<storage_types::Dispute>(
+                            (&field, redis_value),
+                            redis_entry,
+                        ),
+                        &key,
+                    )
+                    .await
+                    .change_context(errors::StorageError::KVError)?
+                    .try_into_hset()
+                    .change_context(errors::StorageError::KVError)?;
+
+                    Ok(updated_dispute)
+                }
+            }
+        }
     }
 }
 
@@ -131,8 +520,9 @@ impl DisputeInterface for Store {
 impl DisputeInterface for MockDb {
     async fn insert_dispute(
         &self,
-        dispute: storage::DisputeNew,
-    ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        dispute: storage_types::DisputeNew,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
         let evidence = dispute.evidence.ok_or(errors::StorageError::MockDbError)?;
 
         let mut locked_disputes = self.disputes.lock().await;
@@ -146,7 +536,7 @@ impl DisputeInterface for MockDb {
 
         let now = common_utils::date_time::now();
 
-        let new_dispute = storage::Dispute {
+        let new_dispute = storage_types::Dispute {
             id: locked_disputes
                 .len()
                 .try_into()
@@ -183,7 +573,8 @@ impl DisputeInterface for MockDb {
         merchant_id: &str,
         payment_id: &str,
         connector_dispute_id: &str,
-    ) -> CustomResult<Option<storage::Dispute>, errors::StorageError> {
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Option<storage_types::Dispute>, errors::StorageError> {
         Ok(self
             .disputes
             .lock()
@@ -201,7 +592,8 @@ impl DisputeInterface for MockDb {
         &self,
         merchant_id: &str,
         dispute_id: &str,
-    ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
         let locked_disputes = self.disputes.lock().await;
 
         locked_disputes
@@ -216,7 +608,7 @@ impl DisputeInterface for MockDb {
         &self,
         merchant_id: &str,
         dispute_constraints: api_models::disputes::DisputeListConstraints,
-    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+    ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError> {
         let locked_disputes = self.disputes.lock().await;
 
         Ok(locked_disputes
@@ -283,11 +675,47 @@ impl DisputeInterface for MockDb {
             .collect())
     }
 
+    async fn get_dispute_analytics_records(
+        &self,
+        merchant_id: &str,
+        profile_id: Option<String>,
+        connector: Option<String>,
+        start_time: time::PrimitiveDateTime,
+        end_time: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage_types::DisputeAnalyticsRecord>, errors::StorageError> {
+        let locked_disputes = self.disputes.lock().await;
+
+        Ok(locked_disputes
+            .iter()
+            .filter(|d| {
+                d.merchant_id == merchant_id
+                    && d.created_at >= start_time
+                    && d.created_at <= end_time
+                    && profile_id
+                        .as_ref()
+                        .map_or(true, |profile_id| d.profile_id.as_ref() == Some(profile_id))
+                    && connector
+                        .as_ref()
+                        .map_or(true, |connector| connector == &d.connector)
+            })
+            .map(|d| storage_types::DisputeAnalyticsRecord {
+                connector: d.connector.clone(),
+                connector_reason_code: d.connector_reason_code.clone(),
+                dispute_stage: d.dispute_stage,
+                dispute_status: d.dispute_status,
+                currency: d.currency.clone(),
+                amount: d.amount.clone(),
+                created_at: d.created_at,
+            })
+            .collect())
+    }
+
     async fn find_disputes_by_merchant_id_payment_id(
         &self,
         merchant_id: &str,
         payment_id: &str,
-    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError> {
         let locked_disputes = self.disputes.lock().await;
 
         Ok(locked_disputes
@@ -299,9 +727,10 @@ impl DisputeInterface for MockDb {
 
     async fn update_dispute(
         &self,
-        this: storage::Dispute,
-        dispute: storage::DisputeUpdate,
-    ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        this: storage_types::Dispute,
+        dispute: storage_types::DisputeUpdate,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
         let mut locked_disputes = self.disputes.lock().await;
 
         let dispute_to_update = locked_disputes
@@ -312,7 +741,7 @@ impl DisputeInterface for MockDb {
         let now = common_utils::date_time::now();
 
         match dispute {
-            storage::DisputeUpdate::Update {
+            storage_types::DisputeUpdate::Update {
                 dispute_stage,
                 dispute_status,
                 connector_status,
@@ -341,7 +770,7 @@ impl DisputeInterface for MockDb {
                 dispute_to_update.dispute_status = dispute_status;
                 dispute_to_update.connector_status = connector_status;
             }
-            storage::DisputeUpdate::StatusUpdate {
+            storage_types::DisputeUpdate::StatusUpdate {
                 dispute_status,
                 connector_status,
             } => {
@@ -350,7 +779,7 @@ impl DisputeInterface for MockDb {
                 }
                 dispute_to_update.dispute_status = dispute_status;
             }
-            storage::DisputeUpdate::EvidenceUpdate { evidence } => {
+            storage_types::DisputeUpdate::EvidenceUpdate { evidence } => {
                 dispute_to_update.evidence = evidence;
             }
         }
@@ -368,7 +797,7 @@ mod tests {
         use api_models::disputes::DisputeListConstraints;
         use diesel_models::{
             dispute::DisputeNew,
-            enums::{DisputeStage, DisputeStatus},
+            enums::{DisputeStage, DisputeStatus, MerchantStorageScheme},
         };
         use masking::Secret;
         use redis_interface::RedisSettings;
@@ -416,13 +845,16 @@ mod tests {
                 .expect("Failed to create a mock DB");
 
             let created_dispute = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_1".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: "merchant_1".into(),
-                    payment_id: "payment_1".into(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_1".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: "merchant_1".into(),
+                        payment_id: "payment_1".into(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
@@ -447,24 +879,30 @@ mod tests {
                 .expect("Failed to create Mock store");
 
             let created_dispute = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_1".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: "merchant_1".into(),
-                    payment_id: "payment_1".into(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_1".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: "merchant_1".into(),
+                        payment_id: "payment_1".into(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let _ = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_2".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: "merchant_1".into(),
-                    payment_id: "payment_1".into(),
-                    connector_dispute_id: "connector_dispute_2".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_2".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: "merchant_1".into(),
+                        payment_id: "payment_1".into(),
+                        connector_dispute_id: "connector_dispute_2".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
@@ -473,6 +911,7 @@ mod tests {
                     "merchant_1",
                     "payment_1",
                     "connector_dispute_1",
+                    MerchantStorageScheme::PostgresOnly,
                 )
                 .await
                 .unwrap();
@@ -490,29 +929,39 @@ mod tests {
                 .expect("Failed to create Mock store");
 
             let created_dispute = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_1".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: "merchant_1".into(),
-                    payment_id: "payment_1".into(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_1".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: "merchant_1".into(),
+                        payment_id: "payment_1".into(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let _ = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_2".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: "merchant_1".into(),
-                    payment_id: "payment_1".into(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_2".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: "merchant_1".into(),
+                        payment_id: "payment_1".into(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let found_dispute = mockdb
-                .find_dispute_by_merchant_id_dispute_id("merchant_1", "dispute_1")
+                .find_dispute_by_merchant_id_dispute_id(
+                    "merchant_1",
+                    "dispute_1",
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
@@ -527,24 +976,30 @@ mod tests {
                 .expect("Failed to create Mock store");
 
             let created_dispute = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_1".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: "merchant_1".into(),
-                    payment_id: "payment_1".into(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_1".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: "merchant_1".into(),
+                        payment_id: "payment_1".into(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let _ = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_2".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: "merchant_2".into(),
-                    payment_id: "payment_1".into(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_2".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: "merchant_2".into(),
+                        payment_id: "payment_1".into(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
@@ -581,29 +1036,39 @@ mod tests {
                 .expect("Failed to create Mock store");
 
             let created_dispute = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_1".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: "merchant_1".into(),
-                    payment_id: "payment_1".into(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_1".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: "merchant_1".into(),
+                        payment_id: "payment_1".into(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let _ = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_2".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: "merchant_2".into(),
-                    payment_id: "payment_1".into(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_2".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: "merchant_2".into(),
+                        payment_id: "payment_1".into(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let found_disputes = mockdb
-                .find_disputes_by_merchant_id_payment_id("merchant_1", "payment_1")
+                .find_disputes_by_merchant_id_payment_id(
+                    "merchant_1",
+                    "payment_1",
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
@@ -637,13 +1102,16 @@ mod tests {
                     .expect("Failed to create Mock store");
 
                 let created_dispute = mockdb
-                    .insert_dispute(create_dispute_new(DisputeNewIds {
-                        dispute_id: "dispute_1".into(),
-                        attempt_id: "attempt_1".into(),
-                        merchant_id: "merchant_1".into(),
-                        payment_id: "payment_1".into(),
-                        connector_dispute_id: "connector_dispute_1".into(),
-                    }))
+                    .insert_dispute(
+                        create_dispute_new(DisputeNewIds {
+                            dispute_id: "dispute_1".into(),
+                            attempt_id: "attempt_1".into(),
+                            merchant_id: "merchant_1".into(),
+                            payment_id: "payment_1".into(),
+                            connector_dispute_id: "connector_dispute_1".into(),
+                        }),
+                        MerchantStorageScheme::PostgresOnly,
+                    )
                     .await
                     .unwrap();
 
@@ -659,6 +1127,7 @@ mod tests {
                             challenge_required_by: Some(datetime!(2019-01-10 0:00)),
                             connector_updated_at: Some(datetime!(2019-01-11 0:00)),
                         },
+                        MerchantStorageScheme::PostgresOnly,
                     )
                     .await
                     .unwrap();
@@ -717,13 +1186,16 @@ mod tests {
                     .expect("Failed to create Mock store");
 
                 let created_dispute = mockdb
-                    .insert_dispute(create_dispute_new(DisputeNewIds {
-                        dispute_id: "dispute_1".into(),
-                        attempt_id: "attempt_1".into(),
-                        merchant_id: "merchant_1".into(),
-                        payment_id: "payment_1".into(),
-                        connector_dispute_id: "connector_dispute_1".into(),
-                    }))
+                    .insert_dispute(
+                        create_dispute_new(DisputeNewIds {
+                            dispute_id: "dispute_1".into(),
+                            attempt_id: "attempt_1".into(),
+                            merchant_id: "merchant_1".into(),
+                            payment_id: "payment_1".into(),
+                            connector_dispute_id: "connector_dispute_1".into(),
+                        }),
+                        MerchantStorageScheme::PostgresOnly,
+                    )
                     .await
                     .unwrap();
 
@@ -734,6 +1206,7 @@ mod tests {
                             dispute_status: DisputeStatus::DisputeExpired,
                             connector_status: Some("updated_connector_status".into()),
                         },
+                        MerchantStorageScheme::PostgresOnly,
                     )
                     .await
                     .unwrap();
@@ -792,13 +1265,16 @@ mod tests {
                     .expect("Failed to create Mock store");
 
                 let created_dispute = mockdb
-                    .insert_dispute(create_dispute_new(DisputeNewIds {
-                        dispute_id: "dispute_1".into(),
-                        attempt_id: "attempt_1".into(),
-                        merchant_id: "merchant_1".into(),
-                        payment_id: "payment_1".into(),
-                        connector_dispute_id: "connector_dispute_1".into(),
-                    }))
+                    .insert_dispute(
+                        create_dispute_new(DisputeNewIds {
+                            dispute_id: "dispute_1".into(),
+                            attempt_id: "attempt_1".into(),
+                            merchant_id: "merchant_1".into(),
+                            payment_id: "payment_1".into(),
+                            connector_dispute_id: "connector_dispute_1".into(),
+                        }),
+                        MerchantStorageScheme::PostgresOnly,
+                    )
                     .await
                     .unwrap();
 
@@ -808,6 +1284,7 @@ mod tests {
                         DisputeUpdate::EvidenceUpdate {
                             evidence: Secret::from(Value::String("updated_evidence".into())),
                         },
+                        MerchantStorageScheme::PostgresOnly,
                     )
                     .await
                     .unwrap();
@@ -1,44 +1,320 @@
 use error_stack::{IntoReport, ResultExt};
 
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection,
     core::errors::{self, CustomResult},
-    types::storage,
+    types::storage::{self as storage_types, enums},
 };
 
 #[async_trait::async_trait]
 pub trait EventInterface {
     async fn insert_event(
         &self,
-        event: storage::EventNew,
-    ) -> CustomResult<storage::Event, errors::StorageError>;
+        event: storage_types::EventNew,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Event, errors::StorageError>;
+    async fn find_event_by_event_id(
+        &self,
+        event_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Event, errors::StorageError>;
+    async fn find_event_by_merchant_id_event_id(
+        &self,
+        merchant_id: &str,
+        event_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Event, errors::StorageError>;
+    async fn list_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        event_constraints: api_models::webhook_events::EventListConstraints,
+    ) -> CustomResult<Vec<storage_types::Event>, errors::StorageError>;
     async fn update_event(
         &self,
         event_id: String,
-        event: storage::EventUpdate,
-    ) -> CustomResult<storage::Event, errors::StorageError>;
+        event: storage_types::EventUpdate,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Event, errors::StorageError>;
 }
 
-#[async_trait::async_trait]
-impl EventInterface for Store {
-    async fn insert_event(
-        &self,
-        event: storage::EventNew,
-    ) -> CustomResult<storage::Event, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        event.insert(&conn).await.map_err(Into::into).into_report()
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use error_stack::IntoReport;
+
+    use super::EventInterface;
+    use crate::{
+        connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{self as storage_types, enums, EventDbExt},
+    };
+
+    #[async_trait::async_trait]
+    impl EventInterface for Store {
+        async fn insert_event(
+            &self,
+            event: storage_types::EventNew,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Event, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            event.insert(&conn).await.map_err(Into::into).into_report()
+        }
+        async fn find_event_by_event_id(
+            &self,
+            event_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Event, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Event::find_by_event_id(&conn, event_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+        async fn find_event_by_merchant_id_event_id(
+            &self,
+            merchant_id: &str,
+            event_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Event, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Event::find_by_merchant_id_event_id(&conn, merchant_id, event_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+        async fn list_events_by_merchant_id(
+            &self,
+            merchant_id: &str,
+            event_constraints: api_models::webhook_events::EventListConstraints,
+        ) -> CustomResult<Vec<storage_types::Event>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Event::filter_by_constraints(&conn, merchant_id, event_constraints)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+        async fn update_event(
+            &self,
+            event_id: String,
+            event: storage_types::EventUpdate,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Event, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::Event::update(&conn, &event_id, event)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
     }
-    async fn update_event(
-        &self,
-        event_id: String,
-        event: storage::EventUpdate,
-    ) -> CustomResult<storage::Event, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        storage::Event::update(&conn, &event_id, event)
-            .await
-            .map_err(Into::into)
-            .into_report()
+}
+
+#[cfg(feature = "kv_store")]
+mod storage {
+    use error_stack::{IntoReport, ResultExt};
+    use redis_interface::HsetnxReply;
+    use storage_impl::redis::kv_store::{kv_wrapper, KvOperation};
+
+    use super::EventInterface;
+    use crate::{
+        connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{self as storage_types, enums, kv, EventDbExt},
+        utils::{self, db_utils},
+    };
+
+    // Event ids are unique across merchants, and the events of a webhook can be looked up by
+    // the event id alone while retrying its delivery
+    fn get_event_key_and_field(event_id: &str) -> (String, String) {
+        (format!("ev_{event_id}"), format!("event_{event_id}"))
+    }
+
+    #[async_trait::async_trait]
+    impl EventInterface for Store {
+        async fn insert_event(
+            &self,
+            event: storage_types::EventNew,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Event, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    event.insert(&conn).await.map_err(Into::into).into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let (key, field) = get_event_key_and_field(&event.event_id);
+
+                    let created_event = storage_types::Event {
+                        id: 0,
+                        event_id: event.event_id.clone(),
+                        event_type: event.event_type,
+                        event_class: event.event_class,
+                        is_webhook_notified: event.is_webhook_notified,
+                        intent_reference_id: event.intent_reference_id.clone(),
+                        primary_object_id: event.primary_object_id.clone(),
+                        primary_object_type: event.primary_object_type,
+                        created_at: common_utils::date_time::now(),
+                        merchant_id: event.merchant_id.clone(),
+                        business_profile_id: event.business_profile_id.clone(),
+                        request: event.request.clone(),
+                    };
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Insert {
+                            insertable: kv::Insertable::Event(event),
+                        },
+                    };
+
+                    match kv_wrapper::<storage_types::Event, _, _>(
+                        self,
+                        KvOperation::<storage_types::Event>::HSetNx(
+                            &field,
+                            &created_event,
+                            redis_entry,
+                        ),
+                        &key,
+                    )
+                    .await
+                    .change_context(errors::StorageError::KVError)?
+                    .try_into_hsetnx()
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "event",
+                            key: Some(created_event.event_id),
+                        })
+                        .into_report(),
+                        Ok(HsetnxReply::KeySet) => Ok(created_event),
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }
+        }
+
+        async fn find_event_by_event_id(
+            &self,
+            event_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Event, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage_types::Event::find_by_event_id(&conn, event_id)
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+            };
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let (key, field) = get_event_key_and_field(event_id);
+                    db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            kv_wrapper(self, KvOperation::<storage_types::Event>::HGet(&field), key)
+                                .await?
+                                .try_into_hget()
+                        },
+                        database_call,
+                    )
+                    .await
+                }
+            }
+        }
+
+        async fn find_event_by_merchant_id_event_id(
+            &self,
+            merchant_id: &str,
+            event_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Event, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage_types::Event::find_by_merchant_id_event_id(&conn, merchant_id, event_id)
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+            };
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let event = self
+                        .find_event_by_event_id(event_id, storage_scheme)
+                        .await?;
+                    if event.merchant_id.as_deref() == Some(merchant_id) {
+                        Ok(event)
+                    } else {
+                        Err(errors::StorageError::ValueNotFound(format!(
+                            "No event available with merchant_id = {merchant_id} and event_id = {event_id}"
+                        )))
+                        .into_report()
+                    }
+                }
+            }
+        }
+
+        async fn list_events_by_merchant_id(
+            &self,
+            merchant_id: &str,
+            event_constraints: api_models::webhook_events::EventListConstraints,
+        ) -> CustomResult<Vec<storage_types::Event>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Event::filter_by_constraints(&conn, merchant_id, event_constraints)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn update_event(
+            &self,
+            event_id: String,
+            event: storage_types::EventUpdate,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Event, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    storage_types::Event::update(&conn, &event_id, event)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let (key, field) = get_event_key_and_field(&event_id);
+
+                    let origin = self
+                        .find_event_by_event_id(&event_id, storage_scheme)
+                        .await?;
+                    let updated_event = event.clone().apply_changeset(origin.clone());
+                    let redis_value =
+                        utils::Encode::<storage_types::Event>::encode_to_string_of_json(
+                            &updated_event,
+                        )
+                        .change_context(errors::StorageError::SerializationFailed)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: kv::Updateable::EventUpdate(kv::EventUpdateMems {
+                                orig: origin,
+                                update_data: event,
+                            }),
+                        },
+                    };
+
+                    kv_wrapper::<(), _, _>(
+                        self,
+                        KvOperation::Hset::<storage_types::Event>(
+                            (&field, redis_value),
+                            redis_entry,
+                        ),
+                        &key,
+                    )
+                    .await
+                    .change_context(errors::StorageError::KVError)?
+                    .try_into_hset()
+                    .change_context(errors::StorageError::KVError)?;
+
+                    Ok(updated_event)
+                }
+            }
+        }
     }
 }
 
@@ -46,12 +322,13 @@ impl EventInterface for Store {
 impl EventInterface for MockDb {
     async fn insert_event(
         &self,
-        event: storage::EventNew,
-    ) -> CustomResult<storage::Event, errors::StorageError> {
+        event: storage_types::EventNew,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Event, errors::StorageError> {
         let mut locked_events = self.events.lock().await;
         let now = common_utils::date_time::now();
 
-        let stored_event = storage::Event {
+        let stored_event = storage_types::Event {
             id: locked_events
                 .len()
                 .try_into()
@@ -65,17 +342,110 @@ impl EventInterface for MockDb {
             primary_object_id: event.primary_object_id,
             primary_object_type: event.primary_object_type,
             created_at: now,
+            merchant_id: event.merchant_id,
+            business_profile_id: event.business_profile_id,
+            request: event.request,
         };
 
         locked_events.push(stored_event.clone());
 
         Ok(stored_event)
     }
+    async fn find_event_by_event_id(
+        &self,
+        event_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Event, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        locked_events
+            .iter()
+            .find(|event| event.event_id == event_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No event available with event_id = {event_id}"
+                ))
+                .into(),
+            )
+    }
+    async fn find_event_by_merchant_id_event_id(
+        &self,
+        merchant_id: &str,
+        event_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Event, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        locked_events
+            .iter()
+            .find(|event| {
+                event.merchant_id.as_deref() == Some(merchant_id) && event.event_id == event_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No event available with merchant_id = {merchant_id} and event_id = {event_id}"
+                ))
+                .into(),
+            )
+    }
+    async fn list_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        event_constraints: api_models::webhook_events::EventListConstraints,
+    ) -> CustomResult<Vec<storage_types::Event>, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+
+        let events = locked_events
+            .iter()
+            .rev()
+            .filter(|event| {
+                event.merchant_id.as_deref() == Some(merchant_id)
+                    && event_constraints
+                        .profile_id
+                        .as_ref()
+                        .map_or(true, |profile_id| {
+                            event.business_profile_id.as_ref() == Some(profile_id)
+                        })
+                    && event_constraints
+                        .object_id
+                        .as_ref()
+                        .map_or(true, |object_id| &event.primary_object_id == object_id)
+                    && event_constraints
+                        .event_type
+                        .map_or(true, |event_type| event.event_type == event_type)
+                    && event_constraints.is_delivered.map_or(true, |is_delivered| {
+                        event.is_webhook_notified == is_delivered
+                    })
+                    && event_constraints
+                        .created_lt
+                        .map_or(true, |created_lt| event.created_at < created_lt)
+                    && event_constraints
+                        .created_gt
+                        .map_or(true, |created_gt| event.created_at > created_gt)
+            })
+            .skip(
+                event_constraints
+                    .offset
+                    .and_then(|offset| usize::try_from(offset).ok())
+                    .unwrap_or(0),
+            )
+            .take(
+                event_constraints
+                    .limit
+                    .and_then(|limit| usize::try_from(limit).ok())
+                    .unwrap_or(usize::MAX),
+            )
+            .cloned()
+            .collect();
+
+        Ok(events)
+    }
     async fn update_event(
         &self,
         event_id: String,
-        event: storage::EventUpdate,
-    ) -> CustomResult<storage::Event, errors::StorageError> {
+        event: storage_types::EventUpdate,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Event, errors::StorageError> {
         let mut locked_events = self.events.lock().await;
         let event_to_update = locked_events
             .iter_mut()
@@ -83,13 +453,16 @@ impl EventInterface for MockDb {
             .ok_or(errors::StorageError::MockDbError)?;
 
         match event {
-            storage::EventUpdate::UpdateWebhookNotified {
+            storage_types::EventUpdate::UpdateWebhookNotified {
                 is_webhook_notified,
             } => {
                 if let Some(is_webhook_notified) = is_webhook_notified {
                     event_to_update.is_webhook_notified = is_webhook_notified;
                 }
             }
+            storage_types::EventUpdate::UpdateRequest { request } => {
+                event_to_update.request = Some(request);
+            }
         }
 
         Ok(event_to_update.clone())
@@ -114,15 +487,21 @@ mod tests {
             .expect("Failed to create Mock store");
 
         let event1 = mockdb
-            .insert_event(storage::EventNew {
-                event_id: "test_event_id".into(),
-                event_type: enums::EventType::PaymentSucceeded,
-                event_class: enums::EventClass::Payments,
-                is_webhook_notified: false,
-                intent_reference_id: Some("test".into()),
-                primary_object_id: "primary_object_tet".into(),
-                primary_object_type: enums::EventObjectType::PaymentDetails,
-            })
+            .insert_event(
+                storage::EventNew {
+                    event_id: "test_event_id".into(),
+                    event_type: enums::EventType::PaymentSucceeded,
+                    event_class: enums::EventClass::Payments,
+                    is_webhook_notified: false,
+                    intent_reference_id: Some("test".into()),
+                    primary_object_id: "primary_object_tet".into(),
+                    primary_object_type: enums::EventObjectType::PaymentDetails,
+                    merchant_id: Some("merchant_1".into()),
+                    business_profile_id: None,
+                    request: None,
+                },
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
             .await
             .unwrap();
 
@@ -134,6 +513,7 @@ mod tests {
                 storage::EventUpdate::UpdateWebhookNotified {
                     is_webhook_notified: Some(true),
                 },
+                enums::MerchantStorageScheme::PostgresOnly,
             )
             .await
             .unwrap();
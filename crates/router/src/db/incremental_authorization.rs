@@ -0,0 +1,244 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait IncrementalAuthorizationInterface {
+    async fn insert_incremental_authorization(
+        &self,
+        authorization: storage::IncrementalAuthorizationNew,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError>;
+
+    async fn update_incremental_authorization(
+        &self,
+        this: storage::IncrementalAuthorization,
+        authorization: storage::IncrementalAuthorizationUpdate,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError>;
+
+    async fn find_all_incremental_authorizations_by_merchant_id_payment_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Vec<storage::IncrementalAuthorization>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl IncrementalAuthorizationInterface for Store {
+    async fn insert_incremental_authorization(
+        &self,
+        authorization: storage::IncrementalAuthorizationNew,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        authorization
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_incremental_authorization(
+        &self,
+        this: storage::IncrementalAuthorization,
+        authorization: storage::IncrementalAuthorizationUpdate,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update_with_authorization_id(&conn, authorization)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_all_incremental_authorizations_by_merchant_id_payment_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Vec<storage::IncrementalAuthorization>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::IncrementalAuthorization::find_all_by_merchant_id_payment_id_attempt_id(
+            &conn,
+            merchant_id,
+            payment_id,
+            attempt_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl IncrementalAuthorizationInterface for MockDb {
+    async fn insert_incremental_authorization(
+        &self,
+        authorization: storage::IncrementalAuthorizationNew,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError> {
+        let mut authorizations = self.incremental_authorizations.lock().await;
+
+        if authorizations
+            .iter()
+            .any(|stored| stored.authorization_id == authorization.authorization_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "authorization_id",
+                key: Some(authorization.authorization_id.clone()),
+            })?
+        }
+
+        let stored_authorization = storage::IncrementalAuthorization {
+            authorization_id: authorization.authorization_id,
+            merchant_id: authorization.merchant_id,
+            payment_id: authorization.payment_id,
+            attempt_id: authorization.attempt_id,
+            amount: authorization.amount,
+            previous_amount: authorization.previous_amount,
+            status: authorization.status,
+            reason: authorization.reason,
+            error_code: authorization.error_code,
+            error_message: authorization.error_message,
+            connector_authorization_id: authorization.connector_authorization_id,
+            created_at: authorization.created_at,
+            modified_at: authorization.modified_at,
+        };
+        authorizations.push(stored_authorization.clone());
+
+        Ok(stored_authorization)
+    }
+
+    async fn update_incremental_authorization(
+        &self,
+        this: storage::IncrementalAuthorization,
+        authorization: storage::IncrementalAuthorizationUpdate,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError> {
+        let mut authorizations = self.incremental_authorizations.lock().await;
+
+        let stored_authorization = authorizations
+            .iter_mut()
+            .find(|stored| stored.authorization_id == this.authorization_id)
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "Incremental authorization does not exist for {}",
+                    this.authorization_id
+                ))
+            })?;
+
+        *stored_authorization = authorization.apply_changeset(stored_authorization.clone());
+
+        Ok(stored_authorization.clone())
+    }
+
+    async fn find_all_incremental_authorizations_by_merchant_id_payment_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Vec<storage::IncrementalAuthorization>, errors::StorageError> {
+        let mut authorizations: Vec<_> = self
+            .incremental_authorizations
+            .lock()
+            .await
+            .iter()
+            .filter(|authorization| {
+                authorization.merchant_id == merchant_id
+                    && authorization.payment_id == payment_id
+                    && authorization.attempt_id == attempt_id
+            })
+            .cloned()
+            .collect();
+
+        authorizations.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+
+        Ok(authorizations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use diesel_models::enums;
+
+    use crate::{
+        db::{incremental_authorization::IncrementalAuthorizationInterface, MockDb},
+        types::storage,
+    };
+
+    fn create_authorization_new(
+        authorization_id: &str,
+        amount: i64,
+        previous_amount: i64,
+    ) -> storage::IncrementalAuthorizationNew {
+        let now = common_utils::date_time::now();
+        storage::IncrementalAuthorizationNew {
+            authorization_id: authorization_id.into(),
+            merchant_id: "merchant_1".into(),
+            payment_id: "pay_1".into(),
+            attempt_id: "pay_1_1".into(),
+            amount,
+            previous_amount,
+            status: enums::AuthorizationStatus::Processing,
+            reason: None,
+            error_code: None,
+            error_message: None,
+            connector_authorization_id: None,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_mockdb_incremental_authorization_interface() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+
+        let authorization = mockdb
+            .insert_incremental_authorization(create_authorization_new("auth_1", 1500, 1000))
+            .await
+            .unwrap();
+        mockdb
+            .insert_incremental_authorization(create_authorization_new("auth_2", 2000, 1500))
+            .await
+            .unwrap();
+
+        let duplicate = mockdb
+            .insert_incremental_authorization(create_authorization_new("auth_1", 1500, 1000))
+            .await;
+        assert!(duplicate.is_err());
+
+        let authorization = mockdb
+            .update_incremental_authorization(
+                authorization,
+                storage::IncrementalAuthorizationUpdate::StatusUpdate {
+                    status: enums::AuthorizationStatus::Success,
+                    error_code: None,
+                    error_message: None,
+                    connector_authorization_id: Some("conn_auth_1".into()),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(authorization.status, enums::AuthorizationStatus::Success);
+        assert_eq!(
+            authorization.connector_authorization_id.as_deref(),
+            Some("conn_auth_1")
+        );
+
+        let authorizations = mockdb
+            .find_all_incremental_authorizations_by_merchant_id_payment_id_attempt_id(
+                "merchant_1",
+                "pay_1",
+                "pay_1_1",
+            )
+            .await
+            .unwrap();
+        assert_eq!(authorizations.len(), 2);
+        assert_eq!(authorizations[0].authorization_id, "auth_1");
+    }
+}
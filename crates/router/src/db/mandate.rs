@@ -1,10 +1,9 @@
 use error_stack::{IntoReport, ResultExt};
 
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection,
     core::{errors, errors::CustomResult},
-    types::storage::{self, MandateDbExt},
+    types::storage::{self as storage_types, enums::MerchantStorageScheme},
 };
 
 #[async_trait::async_trait]
@@ -13,116 +12,705 @@ pub trait MandateInterface {
         &self,
         merchant_id: &str,
         mandate_id: &str,
-    ) -> CustomResult<storage::Mandate, errors::StorageError>;
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError>;
 
     async fn find_mandate_by_merchant_id_connector_mandate_id(
         &self,
         merchant_id: &str,
         connector_mandate_id: &str,
-    ) -> CustomResult<storage::Mandate, errors::StorageError>;
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError>;
 
     async fn find_mandate_by_merchant_id_customer_id(
         &self,
         merchant_id: &str,
         customer_id: &str,
-    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError>;
+    ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError>;
 
     async fn update_mandate_by_merchant_id_mandate_id(
         &self,
         merchant_id: &str,
         mandate_id: &str,
-        mandate: storage::MandateUpdate,
-    ) -> CustomResult<storage::Mandate, errors::StorageError>;
+        mandate: storage_types::MandateUpdate,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError>;
+
+    /// Adds `amount` to the amount captured through the mandate and counts the debit in the
+    /// frequency period starting at `frequency_period_start`. Fails with
+    /// `StorageError::MandateDebitLimitExceeded` if the debit exceeds the limits of the mandate.
+    async fn record_mandate_debit_by_merchant_id_mandate_id(
+        &self,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+        frequency_period_start: Option<time::PrimitiveDateTime>,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError>;
+
+    /// Reverts a debit recorded with `record_mandate_debit_by_merchant_id_mandate_id`
+    async fn release_mandate_debit_by_merchant_id_mandate_id(
+        &self,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+        frequency_period_start: Option<time::PrimitiveDateTime>,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError>;
 
     async fn find_mandates_by_merchant_id(
         &self,
         merchant_id: &str,
         mandate_constraints: api_models::mandates::MandateListConstraints,
-    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError>;
+    ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError>;
 
     async fn insert_mandate(
         &self,
-        mandate: storage::MandateNew,
-    ) -> CustomResult<storage::Mandate, errors::StorageError>;
+        mandate: storage_types::MandateNew,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError>;
 }
 
-#[async_trait::async_trait]
-impl MandateInterface for Store {
-    async fn find_mandate_by_merchant_id_mandate_id(
-        &self,
-        merchant_id: &str,
-        mandate_id: &str,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Mandate::find_by_merchant_id_mandate_id(&conn, merchant_id, mandate_id)
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use error_stack::IntoReport;
+
+    use super::MandateInterface;
+    use crate::{
+        connection,
+        core::{errors, errors::CustomResult},
+        services::Store,
+        types::storage::{self as storage_types, enums::MerchantStorageScheme, MandateDbExt},
+    };
+
+    #[async_trait::async_trait]
+    impl MandateInterface for Store {
+        async fn find_mandate_by_merchant_id_mandate_id(
+            &self,
+            merchant_id: &str,
+            mandate_id: &str,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::find_by_merchant_id_mandate_id(&conn, merchant_id, mandate_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn find_mandate_by_merchant_id_connector_mandate_id(
+            &self,
+            merchant_id: &str,
+            connector_mandate_id: &str,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::find_by_merchant_id_connector_mandate_id(
+                &conn,
+                merchant_id,
+                connector_mandate_id,
+            )
             .await
             .map_err(Into::into)
             .into_report()
-    }
+        }
 
-    async fn find_mandate_by_merchant_id_connector_mandate_id(
-        &self,
-        merchant_id: &str,
-        connector_mandate_id: &str,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Mandate::find_by_merchant_id_connector_mandate_id(
-            &conn,
-            merchant_id,
-            connector_mandate_id,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
-    }
+        async fn find_mandate_by_merchant_id_customer_id(
+            &self,
+            merchant_id: &str,
+            customer_id: &str,
+        ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
 
-    async fn find_mandate_by_merchant_id_customer_id(
-        &self,
-        merchant_id: &str,
-        customer_id: &str,
-    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Mandate::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+        async fn update_mandate_by_merchant_id_mandate_id(
+            &self,
+            merchant_id: &str,
+            mandate_id: &str,
+            mandate: storage_types::MandateUpdate,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::Mandate::update_by_merchant_id_mandate_id(
+                &conn,
+                merchant_id,
+                mandate_id,
+                mandate,
+            )
             .await
             .map_err(Into::into)
             .into_report()
-    }
+        }
 
-    async fn update_mandate_by_merchant_id_mandate_id(
-        &self,
-        merchant_id: &str,
-        mandate_id: &str,
-        mandate: storage::MandateUpdate,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        storage::Mandate::update_by_merchant_id_mandate_id(&conn, merchant_id, mandate_id, mandate)
+        async fn record_mandate_debit_by_merchant_id_mandate_id(
+            &self,
+            merchant_id: &str,
+            mandate_id: &str,
+            amount: i64,
+            frequency_period_start: Option<time::PrimitiveDateTime>,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            let mandate = storage_types::Mandate::record_debit_by_merchant_id_mandate_id(
+                &conn,
+                merchant_id,
+                mandate_id,
+                amount,
+                frequency_period_start,
+            )
             .await
             .map_err(Into::into)
-            .into_report()
-    }
+            .into_report()?;
 
-    async fn find_mandates_by_merchant_id(
-        &self,
-        merchant_id: &str,
-        mandate_constraints: api_models::mandates::MandateListConstraints,
-    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Mandate::filter_by_constraints(&conn, merchant_id, mandate_constraints)
+            match mandate {
+                Some(mandate) => Ok(mandate),
+                None => {
+                    // The mandate is not updated either when it does not exist or when the debit
+                    // exceeds its limits
+                    self.find_mandate_by_merchant_id_mandate_id(
+                        merchant_id,
+                        mandate_id,
+                        storage_scheme,
+                    )
+                    .await?;
+                    Err(errors::StorageError::MandateDebitLimitExceeded).into_report()
+                }
+            }
+        }
+
+        async fn release_mandate_debit_by_merchant_id_mandate_id(
+            &self,
+            merchant_id: &str,
+            mandate_id: &str,
+            amount: i64,
+            frequency_period_start: Option<time::PrimitiveDateTime>,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::Mandate::release_debit_by_merchant_id_mandate_id(
+                &conn,
+                merchant_id,
+                mandate_id,
+                amount,
+                frequency_period_start,
+            )
             .await
             .map_err(Into::into)
             .into_report()
+        }
+
+        async fn find_mandates_by_merchant_id(
+            &self,
+            merchant_id: &str,
+            mandate_constraints: api_models::mandates::MandateListConstraints,
+        ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::filter_by_constraints(&conn, merchant_id, mandate_constraints)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn insert_mandate(
+            &self,
+            mandate: storage_types::MandateNew,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            mandate
+                .insert(&conn)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
     }
+}
 
-    async fn insert_mandate(
-        &self,
-        mandate: storage::MandateNew,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        mandate
-            .insert(&conn)
-            .await
-            .map_err(Into::into)
+#[cfg(feature = "kv_store")]
+mod storage {
+    use std::time::Duration;
+
+    use actix_web::rt::time as actix_time;
+    use error_stack::{IntoReport, ResultExt};
+    use redis_interface::{HsetnxReply, SetnxReply};
+    use router_env::logger;
+    use storage_impl::redis::kv_store::{kv_wrapper, KvOperation, RedisConnInterface};
+
+    use super::MandateInterface;
+    use crate::{
+        connection,
+        core::{errors, errors::CustomResult},
+        db::reverse_lookup::ReverseLookupInterface,
+        services::Store,
+        types::storage::{self as storage_types, enums::MerchantStorageScheme, kv, MandateDbExt},
+        utils::{self, db_utils},
+    };
+
+    #[async_trait::async_trait]
+    impl MandateInterface for Store {
+        async fn find_mandate_by_merchant_id_mandate_id(
+            &self,
+            merchant_id: &str,
+            mandate_id: &str,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage_types::Mandate::find_by_merchant_id_mandate_id(
+                    &conn,
+                    merchant_id,
+                    mandate_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            };
+            match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => database_call().await,
+                MerchantStorageScheme::RedisKv => {
+                    let key = format!("mid_{merchant_id}_mandate_{mandate_id}");
+                    let field = format!("mandate_{mandate_id}");
+                    db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            kv_wrapper(
+                                self,
+                                KvOperation::<storage_types::Mandate>::HGet(&field),
+                                key,
+                            )
+                            .await?
+                            .try_into_hget()
+                        },
+                        database_call,
+                    )
+                    .await
+                }
+            }
+        }
+
+        async fn find_mandate_by_merchant_id_connector_mandate_id(
+            &self,
+            merchant_id: &str,
+            connector_mandate_id: &str,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage_types::Mandate::find_by_merchant_id_connector_mandate_id(
+                    &conn,
+                    merchant_id,
+                    connector_mandate_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            };
+            match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => database_call().await,
+                MerchantStorageScheme::RedisKv => {
+                    let lookup_id = format!("{merchant_id}_{connector_mandate_id}");
+                    let lookup = match self
+                        .get_lookup_by_lookup_id(&lookup_id, storage_scheme)
+                        .await
+                    {
+                        Ok(lookup) => lookup,
+                        // Mandates created before the merchant moved to the KV store have no
+                        // reverse lookup
+                        Err(error) if error.current_context().is_db_not_found() => {
+                            return database_call().await
+                        }
+                        Err(error) => return Err(error),
+                    };
+
+                    let key = &lookup.pk_id;
+                    db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            kv_wrapper(
+                                self,
+                                KvOperation::<storage_types::Mandate>::HGet(&lookup.sk_id),
+                                key,
+                            )
+                            .await?
+                            .try_into_hget()
+                        },
+                        database_call,
+                    )
+                    .await
+                }
+            }
+        }
+
+        async fn find_mandate_by_merchant_id_customer_id(
+            &self,
+            merchant_id: &str,
+            customer_id: &str,
+        ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn update_mandate_by_merchant_id_mandate_id(
+            &self,
+            merchant_id: &str,
+            mandate_id: &str,
+            mandate: storage_types::MandateUpdate,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    storage_types::Mandate::update_by_merchant_id_mandate_id(
+                        &conn,
+                        merchant_id,
+                        mandate_id,
+                        mandate,
+                    )
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+                }
+                MerchantStorageScheme::RedisKv => {
+                    let key = format!("mid_{merchant_id}_mandate_{mandate_id}");
+                    let field = format!("mandate_{mandate_id}");
+
+                    let origin = self
+                        .find_mandate_by_merchant_id_mandate_id(
+                            merchant_id,
+                            mandate_id,
+                            storage_scheme,
+                        )
+                        .await?;
+                    let updated_mandate = mandate.clone().apply_changeset(origin.clone());
+                    let redis_value =
+                        utils::Encode::<storage_types::Mandate>::encode_to_string_of_json(
+                            &updated_mandate,
+                        )
+                        .change_context(errors::StorageError::SerializationFailed)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: kv::Updateable::MandateUpdate(kv::MandateUpdateMems {
+                                orig: origin,
+                                update_data: mandate,
+                            }),
+                        },
+                    };
+
+                    kv_wrapper::<(), _, _>(
+                        self,
+                        KvOperation::Hset::<storage_types::Mandate>(
+                            (&field, redis_value),
+                            redis_entry,
+                        ),
+                        &key,
+                    )
+                    .await
+                    .change_context(errors::StorageError::KVError)?
+                    .try_into_hset()
+                    .change_context(errors::StorageError::KVError)?;
+
+                    Ok(updated_mandate)
+                }
+            }
+        }
+
+        async fn record_mandate_debit_by_merchant_id_mandate_id(
+            &self,
+            merchant_id: &str,
+            mandate_id: &str,
+            amount: i64,
+            frequency_period_start: Option<time::PrimitiveDateTime>,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    let mandate = storage_types::Mandate::record_debit_by_merchant_id_mandate_id(
+                        &conn,
+                        merchant_id,
+                        mandate_id,
+                        amount,
+                        frequency_period_start,
+                    )
+                    .await
+                    .map_err(Into::into)
+                    .into_report()?;
+
+                    match mandate {
+                        Some(mandate) => Ok(mandate),
+                        None => {
+                            // The mandate is not updated either when it does not exist or when
+                            // the debit exceeds its limits
+                            self.find_mandate_by_merchant_id_mandate_id(
+                                merchant_id,
+                                mandate_id,
+                                storage_scheme,
+                            )
+                            .await?;
+                            Err(errors::StorageError::MandateDebitLimitExceeded).into_report()
+                        }
+                    }
+                }
+                MerchantStorageScheme::RedisKv => {
+                    // The drainer replays the update on the database, so the absolute values are
+                    // computed from the mandate stored in redis. The mandate is locked while the
+                    // debit is recorded so that concurrent debits do not read the same values.
+                    self.update_mandate_debit_with_lock(
+                        merchant_id,
+                        mandate_id,
+                        storage_scheme,
+                        |mandate| {
+                            utils::when(
+                                !mandate.is_debit_within_limits(amount, frequency_period_start),
+                                || {
+                                    Err(errors::StorageError::MandateDebitLimitExceeded)
+                                        .into_report()
+                                },
+                            )?;
+                            Ok(mandate.get_debit_update(amount, frequency_period_start))
+                        },
+                    )
+                    .await
+                }
+            }
+        }
+
+        async fn release_mandate_debit_by_merchant_id_mandate_id(
+            &self,
+            merchant_id: &str,
+            mandate_id: &str,
+            amount: i64,
+            frequency_period_start: Option<time::PrimitiveDateTime>,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    storage_types::Mandate::release_debit_by_merchant_id_mandate_id(
+                        &conn,
+                        merchant_id,
+                        mandate_id,
+                        amount,
+                        frequency_period_start,
+                    )
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+                }
+                MerchantStorageScheme::RedisKv => {
+                    self.update_mandate_debit_with_lock(
+                        merchant_id,
+                        mandate_id,
+                        storage_scheme,
+                        |mandate| {
+                            Ok(mandate.get_debit_release_update(amount, frequency_period_start))
+                        },
+                    )
+                    .await
+                }
+            }
+        }
+
+        async fn find_mandates_by_merchant_id(
+            &self,
+            merchant_id: &str,
+            mandate_constraints: api_models::mandates::MandateListConstraints,
+        ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Mandate::filter_by_constraints(&conn, merchant_id, mandate_constraints)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn insert_mandate(
+            &self,
+            mandate: storage_types::MandateNew,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    mandate
+                        .insert(&conn)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                MerchantStorageScheme::RedisKv => {
+                    let key = format!("mid_{}_mandate_{}", mandate.merchant_id, mandate.mandate_id);
+                    let field = format!("mandate_{}", mandate.mandate_id);
+
+                    let created_mandate = storage_types::Mandate {
+                        id: 0,
+                        mandate_id: mandate.mandate_id.clone(),
+                        customer_id: mandate.customer_id.clone(),
+                        merchant_id: mandate.merchant_id.clone(),
+                        payment_method_id: mandate.payment_method_id.clone(),
+                        mandate_status: mandate.mandate_status,
+                        mandate_type: mandate.mandate_type,
+                        customer_accepted_at: mandate.customer_accepted_at,
+                        customer_ip_address: mandate.customer_ip_address.clone(),
+                        customer_user_agent: mandate.customer_user_agent.clone(),
+                        network_transaction_id: mandate.network_transaction_id.clone(),
+                        previous_attempt_id: mandate.previous_attempt_id.clone(),
+                        created_at: mandate
+                            .created_at
+                            .unwrap_or_else(common_utils::date_time::now),
+                        mandate_amount: mandate.mandate_amount,
+                        mandate_currency: mandate.mandate_currency,
+                        amount_captured: mandate.amount_captured,
+                        connector: mandate.connector.clone(),
+                        connector_mandate_id: mandate.connector_mandate_id.clone(),
+                        start_date: mandate.start_date,
+                        end_date: mandate.end_date,
+                        metadata: mandate.metadata.clone(),
+                        connector_mandate_ids: mandate.connector_mandate_ids.clone(),
+                        original_payment_id: mandate.original_payment_id.clone(),
+                        max_amount_per_transaction: mandate.max_amount_per_transaction,
+                        frequency: mandate.frequency,
+                        max_debits_per_frequency: mandate.max_debits_per_frequency,
+                        frequency_period_start: None,
+                        frequency_debit_count: None,
+                    };
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Insert {
+                            insertable: kv::Insertable::Mandate(mandate),
+                        },
+                    };
+
+                    match kv_wrapper::<storage_types::Mandate, _, _>(
+                        self,
+                        KvOperation::<storage_types::Mandate>::HSetNx(
+                            &field,
+                            &created_mandate,
+                            redis_entry,
+                        ),
+                        &key,
+                    )
+                    .await
+                    .change_context(errors::StorageError::KVError)?
+                    .try_into_hsetnx()
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "mandate",
+                            key: Some(created_mandate.mandate_id),
+                        })
+                        .into_report(),
+                        Ok(HsetnxReply::KeySet) => {
+                            if let Some(connector_mandate_id) =
+                                created_mandate.connector_mandate_id.as_ref()
+                            {
+                                let reverse_lookup = storage_types::ReverseLookupNew {
+                                    sk_id: field,
+                                    lookup_id: format!(
+                                        "{}_{}",
+                                        created_mandate.merchant_id, connector_mandate_id
+                                    ),
+                                    pk_id: key,
+                                    source: "mandate".to_string(),
+                                    updated_by: storage_scheme.to_string(),
+                                };
+                                self.insert_reverse_lookup(reverse_lookup, storage_scheme)
+                                    .await?;
+                            }
+                            Ok(created_mandate)
+                        }
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }
+        }
+    }
+
+    type MandateDebitUpdateResult =
+        CustomResult<storage_types::MandateUpdate, errors::StorageError>;
+
+    const MANDATE_DEBIT_LOCK_EXPIRY_SECONDS: i64 = 30;
+    const MANDATE_DEBIT_LOCK_RETRIES: u32 = 10;
+    const MANDATE_DEBIT_LOCK_RETRY_DELAY_MILLISECONDS: u64 = 100;
+
+    impl Store {
+        /// Updates the debits of the mandate with the update computed by `get_update` from the
+        /// current mandate, while holding a lock on the debits of the mandate
+        async fn update_mandate_debit_with_lock<G>(
+            &self,
+            merchant_id: &str,
+            mandate_id: &str,
+            storage_scheme: MerchantStorageScheme,
+            get_update: G,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError>
+        where
+            G: FnOnce(&storage_types::Mandate) -> MandateDebitUpdateResult + Send,
+        {
+            let redis_conn = self
+                .get_redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?;
+            let lock_key = format!("mid_{merchant_id}_mandate_{mandate_id}_debit_lock");
+            let lock_token = acquire_mandate_debit_lock(&redis_conn, &lock_key).await?;
+
+            let result = async {
+                let mandate = self
+                    .find_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id, storage_scheme)
+                    .await?;
+                let mandate_update = get_update(&mandate)?;
+                self.update_mandate_by_merchant_id_mandate_id(
+                    merchant_id,
+                    mandate_id,
+                    mandate_update,
+                    storage_scheme,
+                )
+                .await
+            }
+            .await;
+
+            // The lock may have expired and been acquired by another debit in the meantime, so it
+            // is only deleted if it still holds the token with which it was acquired
+            redis_conn
+                .delete_key_if_value_matches(&lock_key, &lock_token)
+                .await
+                .map_err(|error| logger::error!(mandate_debit_unlock_error=?error))
+                .ok();
+            result
+        }
+    }
+
+    /// Acquires the lock on the debits of the mandate and returns the token held by the lock
+    async fn acquire_mandate_debit_lock(
+        redis_conn: &redis_interface::RedisConnectionPool,
+        lock_key: &str,
+    ) -> CustomResult<String, errors::StorageError> {
+        let lock_token = uuid::Uuid::new_v4().to_string();
+        for _retry in 0..MANDATE_DEBIT_LOCK_RETRIES {
+            let lock_result = redis_conn
+                .set_key_if_not_exists_with_expiry(
+                    lock_key,
+                    lock_token.as_str(),
+                    Some(MANDATE_DEBIT_LOCK_EXPIRY_SECONDS),
+                )
+                .await
+                .map_err(Into::<errors::StorageError>::into)?;
+
+            match lock_result {
+                SetnxReply::KeySet => return Ok(lock_token),
+                SetnxReply::KeyNotSet => {
+                    actix_time::sleep(Duration::from_millis(
+                        MANDATE_DEBIT_LOCK_RETRY_DELAY_MILLISECONDS,
+                    ))
+                    .await
+                }
+            }
+        }
+        Err(errors::StorageError::KVError)
             .into_report()
+            .attach_printable("Unable to acquire the lock for recording the mandate debit")
     }
 }
 
@@ -132,7 +720,8 @@ impl MandateInterface for MockDb {
         &self,
         merchant_id: &str,
         mandate_id: &str,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
         self.mandates
             .lock()
             .await
@@ -147,7 +736,8 @@ impl MandateInterface for MockDb {
         &self,
         merchant_id: &str,
         connector_mandate_id: &str,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
         self.mandates
             .lock()
             .await
@@ -165,7 +755,7 @@ impl MandateInterface for MockDb {
         &self,
         merchant_id: &str,
         customer_id: &str,
-    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
+    ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
         return Ok(self
             .mandates
             .lock()
@@ -182,8 +772,9 @@ impl MandateInterface for MockDb {
         &self,
         merchant_id: &str,
         mandate_id: &str,
-        mandate_update: storage::MandateUpdate,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        mandate_update: storage_types::MandateUpdate,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
         let mut mandates = self.mandates.lock().await;
         match mandates
             .iter_mut()
@@ -191,17 +782,26 @@ impl MandateInterface for MockDb {
         {
             Some(mandate) => {
                 match mandate_update {
-                    storage::MandateUpdate::StatusUpdate { mandate_status } => {
+                    storage_types::MandateUpdate::StatusUpdate { mandate_status } => {
                         mandate.mandate_status = mandate_status;
                     }
-                    storage::MandateUpdate::CaptureAmountUpdate { amount_captured } => {
+                    storage_types::MandateUpdate::CaptureAmountUpdate { amount_captured } => {
                         mandate.amount_captured = amount_captured;
                     }
-                    storage::MandateUpdate::ConnectorReferenceUpdate {
+                    storage_types::MandateUpdate::ConnectorReferenceUpdate {
                         connector_mandate_ids,
                     } => {
                         mandate.connector_mandate_ids = connector_mandate_ids;
                     }
+                    storage_types::MandateUpdate::DebitUpdate {
+                        amount_captured,
+                        frequency_period_start,
+                        frequency_debit_count,
+                    } => {
+                        mandate.amount_captured = amount_captured;
+                        mandate.frequency_period_start = frequency_period_start;
+                        mandate.frequency_debit_count = frequency_debit_count;
+                    }
                 }
                 Ok(mandate.clone())
             }
@@ -211,11 +811,54 @@ impl MandateInterface for MockDb {
         }
     }
 
+    async fn record_mandate_debit_by_merchant_id_mandate_id(
+        &self,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+        frequency_period_start: Option<time::PrimitiveDateTime>,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+        let mut mandates = self.mandates.lock().await;
+        let mandate = mandates
+            .iter_mut()
+            .find(|mandate| mandate.merchant_id == merchant_id && mandate.mandate_id == mandate_id)
+            .ok_or_else(|| errors::StorageError::ValueNotFound("mandate not found".to_string()))?;
+        if !mandate.is_debit_within_limits(amount, frequency_period_start) {
+            return Err(errors::StorageError::MandateDebitLimitExceeded.into());
+        }
+
+        *mandate = mandate
+            .get_debit_update(amount, frequency_period_start)
+            .apply_changeset(mandate.clone());
+        Ok(mandate.clone())
+    }
+
+    async fn release_mandate_debit_by_merchant_id_mandate_id(
+        &self,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+        frequency_period_start: Option<time::PrimitiveDateTime>,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+        let mut mandates = self.mandates.lock().await;
+        let mandate = mandates
+            .iter_mut()
+            .find(|mandate| mandate.merchant_id == merchant_id && mandate.mandate_id == mandate_id)
+            .ok_or_else(|| errors::StorageError::ValueNotFound("mandate not found".to_string()))?;
+
+        *mandate = mandate
+            .get_debit_release_update(amount, frequency_period_start)
+            .apply_changeset(mandate.clone());
+        Ok(mandate.clone())
+    }
+
     async fn find_mandates_by_merchant_id(
         &self,
         merchant_id: &str,
         mandate_constraints: api_models::mandates::MandateListConstraints,
-    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
+    ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
         let mandates = self.mandates.lock().await;
         let mandates_iter = mandates.iter().filter(|mandate| {
             let mut checker = mandate.merchant_id == merchant_id;
@@ -243,7 +886,7 @@ impl MandateInterface for MockDb {
             checker
         });
 
-        let mandates: Vec<storage::Mandate> = if let Some(limit) = mandate_constraints.limit {
+        let mandates: Vec<storage_types::Mandate> = if let Some(limit) = mandate_constraints.limit {
             #[allow(clippy::as_conversions)]
             mandates_iter
                 .take((if limit < 0 { 0 } else { limit }) as usize)
@@ -257,10 +900,11 @@ impl MandateInterface for MockDb {
 
     async fn insert_mandate(
         &self,
-        mandate_new: storage::MandateNew,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        mandate_new: storage_types::MandateNew,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
         let mut mandates = self.mandates.lock().await;
-        let mandate = storage::Mandate {
+        let mandate = storage_types::Mandate {
             id: mandates
                 .len()
                 .try_into()
@@ -290,6 +934,11 @@ impl MandateInterface for MockDb {
             end_date: mandate_new.end_date,
             metadata: mandate_new.metadata,
             connector_mandate_ids: mandate_new.connector_mandate_ids,
+            max_amount_per_transaction: mandate_new.max_amount_per_transaction,
+            frequency: mandate_new.frequency,
+            max_debits_per_frequency: mandate_new.max_debits_per_frequency,
+            frequency_period_start: None,
+            frequency_debit_count: None,
         };
         mandates.push(mandate.clone());
         Ok(mandate)
@@ -15,7 +15,7 @@ use crate::{
         self,
         domain::{
             self,
-            behaviour::{Conversion, ReverseConversion},
+            behaviour::{Conversion, KeyStoreReverseConversion},
         },
         storage,
     },
@@ -190,7 +190,7 @@ impl MerchantConnectorAccountInterface for Store {
         {
             find_call()
                 .await?
-                .convert(key_store.key.get_inner())
+                .convert_with_key_store(key_store)
                 .await
                 .change_context(errors::StorageError::DeserializationFailed)
         }
@@ -205,7 +205,7 @@ impl MerchantConnectorAccountInterface for Store {
             )
             .await
             .async_and_then(|item| async {
-                item.convert(key_store.key.get_inner())
+                item.convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -235,7 +235,7 @@ impl MerchantConnectorAccountInterface for Store {
         {
             find_call()
                 .await?
-                .convert(key_store.key.get_inner())
+                .convert_with_key_store(key_store)
                 .await
                 .change_context(errors::StorageError::DeserializationFailed)
         }
@@ -250,7 +250,7 @@ impl MerchantConnectorAccountInterface for Store {
             )
             .await
             .async_and_then(|item| async {
-                item.convert(key_store.key.get_inner())
+                item.convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -295,7 +295,7 @@ impl MerchantConnectorAccountInterface for Store {
             Ordering::Equal => match mca_list.first() {
                 Some(mca) => mca
                     .to_owned()
-                    .convert(key_store.key.get_inner())
+                    .convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DeserializationFailed),
                 None => Err(
@@ -321,7 +321,7 @@ impl MerchantConnectorAccountInterface for Store {
         .map_err(Into::into)
         .into_report()
         .async_and_then(|item| async {
-            item.convert(key_store.key.get_inner())
+            item.convert_with_key_store(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError)
         })
@@ -342,7 +342,7 @@ impl MerchantConnectorAccountInterface for Store {
             .map_err(Into::into)
             .into_report()
             .async_and_then(|item| async {
-                item.convert(key_store.key.get_inner())
+                item.convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -364,7 +364,7 @@ impl MerchantConnectorAccountInterface for Store {
                 let mut output = Vec::with_capacity(items.len());
                 for item in items.into_iter() {
                     output.push(
-                        item.convert(key_store.key.get_inner())
+                        item.convert_with_key_store(key_store)
                             .await
                             .change_context(errors::StorageError::DecryptionError)?,
                     )
@@ -398,7 +398,7 @@ impl MerchantConnectorAccountInterface for Store {
                 .map_err(Into::into)
                 .into_report()
                 .async_and_then(|item| async {
-                    item.convert(key_store.key.get_inner())
+                    item.convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -493,7 +493,7 @@ impl MerchantConnectorAccountInterface for MockDb {
             .cloned()
             .async_map(|account| async {
                 account
-                    .convert(key_store.key.get_inner())
+                    .convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -544,7 +544,7 @@ impl MerchantConnectorAccountInterface for MockDb {
             Ordering::Equal => match mca_list.first() {
                 Some(mca) => mca
                     .to_owned()
-                    .convert(key_store.key.get_inner())
+                    .convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DeserializationFailed),
                 None => Err(
@@ -574,7 +574,7 @@ impl MerchantConnectorAccountInterface for MockDb {
         match maybe_mca {
             Some(mca) => mca
                 .to_owned()
-                .convert(key_store.key.get_inner())
+                .convert_with_key_store(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError),
             None => Err(errors::StorageError::ValueNotFound(
@@ -602,7 +602,7 @@ impl MerchantConnectorAccountInterface for MockDb {
             .cloned()
             .async_map(|account| async {
                 account
-                    .convert(key_store.key.get_inner())
+                    .convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -654,7 +654,7 @@ impl MerchantConnectorAccountInterface for MockDb {
         };
         accounts.push(account.clone());
         account
-            .convert(key_store.key.get_inner())
+            .convert_with_key_store(key_store)
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -684,7 +684,7 @@ impl MerchantConnectorAccountInterface for MockDb {
         for account in accounts.into_iter() {
             output.push(
                 account
-                    .convert(key_store.key.get_inner())
+                    .convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)?,
             )
@@ -712,7 +712,7 @@ impl MerchantConnectorAccountInterface for MockDb {
             })
             .async_map(|account| async {
                 account
-                    .convert(key_store.key.get_inner())
+                    .convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -809,6 +809,8 @@ mod merchant_connector_account_cache_tests {
                 .await
                 .unwrap(),
                 created_at: datetime!(2023-02-01 0:00),
+                version: 1,
+                previous_key: None,
             },
             &master_key.to_vec().into(),
         )
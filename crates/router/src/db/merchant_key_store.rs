@@ -28,6 +28,13 @@ pub trait MerchantKeyStoreInterface {
         key: &Secret<Vec<u8>>,
     ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError>;
 
+    async fn update_merchant_key_store_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        merchant_key_store: domain::MerchantKeyStoreUpdate,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError>;
+
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
         merchant_id: &str,
@@ -97,6 +104,48 @@ impl MerchantKeyStoreInterface for Store {
         }
     }
 
+    async fn update_merchant_key_store_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        merchant_key_store: domain::MerchantKeyStoreUpdate,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+        let update_func = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            diesel_models::merchant_key_store::MerchantKeyStore::update_by_merchant_id(
+                &conn,
+                merchant_id,
+                merchant_key_store.into(),
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            update_func()
+                .await?
+                .convert(key)
+                .await
+                .change_context(errors::StorageError::DecryptionError)
+        }
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            let key_store_cache_key = format!("merchant_key_store_{}", merchant_id);
+            super::cache::publish_and_redact(
+                self,
+                CacheKind::Accounts(key_store_cache_key.into()),
+                update_func,
+            )
+            .await?
+            .convert(key)
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+        }
+    }
+
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
         merchant_id: &str,
@@ -179,6 +228,37 @@ impl MerchantKeyStoreInterface for MockDb {
             .change_context(errors::StorageError::DecryptionError)
     }
 
+    async fn update_merchant_key_store_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        merchant_key_store: domain::MerchantKeyStoreUpdate,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+        let mut merchant_key_stores = self.merchant_key_store.lock().await;
+        let merchant_key = merchant_key_stores
+            .iter_mut()
+            .find(|merchant_key| merchant_key.merchant_id == merchant_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No merchant key store found for merchant_id = {}",
+                merchant_id
+            )))?;
+
+        let diesel_models::merchant_key_store::MerchantKeyStoreUpdateInternal {
+            key: updated_key,
+            version,
+            previous_key,
+        } = merchant_key_store.into();
+        merchant_key.key = updated_key;
+        merchant_key.version = version;
+        merchant_key.previous_key = previous_key;
+
+        merchant_key
+            .clone()
+            .convert(key)
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
         merchant_id: &str,
@@ -227,6 +307,8 @@ mod tests {
                     .await
                     .unwrap(),
                     created_at: datetime!(2023-02-01 0:00),
+                    version: 1,
+                    previous_key: None,
                 },
                 &master_key.to_vec().into(),
             )
@@ -252,6 +334,8 @@ mod tests {
                     .await
                     .unwrap(),
                     created_at: datetime!(2023-02-01 0:00),
+                    version: 1,
+                    previous_key: None,
                 },
                 &master_key.to_vec().into(),
             )
@@ -267,5 +351,27 @@ mod tests {
             .get_merchant_key_store_by_merchant_id(merchant_id, &vec![0; 32].into())
             .await;
         assert!(find_merchant_key_with_incorrect_master_key_result.is_err());
+
+        let rotated_merchant_key = mock_db
+            .update_merchant_key_store_by_merchant_id(
+                merchant_id,
+                domain::MerchantKeyStoreUpdate::KeyRotation {
+                    key: domain::types::encrypt(
+                        services::generate_aes256_key().unwrap().to_vec().into(),
+                        master_key,
+                    )
+                    .await
+                    .unwrap(),
+                    previous_key: merchant_key1.key.clone(),
+                    version: merchant_key1.version + 1,
+                },
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(rotated_merchant_key.version, 2);
+        assert_ne!(rotated_merchant_key.key, merchant_key1.key);
+        assert_eq!(rotated_merchant_key.previous_key, Some(merchant_key1.key));
     }
 }
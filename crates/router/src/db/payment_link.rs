@@ -4,7 +4,7 @@ use super::{MockDb, Store};
 use crate::{
     connection,
     core::errors::{self, CustomResult},
-    types::storage,
+    types::storage::{self, PaymentLinkDbExt},
 };
 
 #[async_trait::async_trait]
@@ -18,6 +18,18 @@ pub trait PaymentLinkInterface {
         &self,
         _payment_link: storage::PaymentLinkNew,
     ) -> CustomResult<storage::PaymentLink, errors::StorageError>;
+
+    async fn update_payment_link(
+        &self,
+        this: storage::PaymentLink,
+        payment_link: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError>;
+
+    async fn list_payment_links_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        payment_link_constraints: api_models::payments::PaymentLinkListConstraints,
+    ) -> CustomResult<Vec<storage::PaymentLink>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -44,23 +56,231 @@ impl PaymentLinkInterface for Store {
             .map_err(Into::into)
             .into_report()
     }
+
+    async fn update_payment_link(
+        &self,
+        this: storage::PaymentLink,
+        payment_link: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PaymentLink::update_by_payment_link_id(&conn, &this.payment_link_id, payment_link)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_payment_links_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        payment_link_constraints: api_models::payments::PaymentLinkListConstraints,
+    ) -> CustomResult<Vec<storage::PaymentLink>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentLink::filter_by_constraints(&conn, merchant_id, payment_link_constraints)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
 }
 
 #[async_trait::async_trait]
 impl PaymentLinkInterface for MockDb {
     async fn insert_payment_link(
         &self,
-        _payment_link: storage::PaymentLinkNew,
+        payment_link: storage::PaymentLinkNew,
     ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut locked_payment_links = self.payment_link.lock().await;
+
+        if locked_payment_links
+            .iter()
+            .any(|stored| stored.payment_link_id == payment_link.payment_link_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "payment_link_id",
+                key: Some(payment_link.payment_link_id.clone()),
+            })?
+        }
+
+        let now = common_utils::date_time::now();
+        let stored_payment_link = storage::PaymentLink {
+            payment_link_id: payment_link.payment_link_id,
+            payment_id: payment_link.payment_id,
+            link_to_pay: payment_link.link_to_pay,
+            merchant_id: payment_link.merchant_id,
+            amount: payment_link.amount,
+            currency: payment_link.currency,
+            created_at: payment_link.created_at.unwrap_or(now),
+            last_modified_at: payment_link.last_modified_at.unwrap_or(now),
+            fulfilment_time: payment_link.fulfilment_time,
+            status: payment_link.status,
+        };
+        locked_payment_links.push(stored_payment_link.clone());
+
+        Ok(stored_payment_link)
     }
 
     async fn find_payment_link_by_payment_link_id(
         &self,
-        _payment_link_id: &str,
+        payment_link_id: &str,
     ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
-        // TODO: Implement function for `MockDb`x
-        Err(errors::StorageError::MockDbError)?
+        self.payment_link
+            .lock()
+            .await
+            .iter()
+            .find(|payment_link| payment_link.payment_link_id == payment_link_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "Payment link does not exist for {payment_link_id}"
+                ))
+                .into()
+            })
+    }
+
+    async fn update_payment_link(
+        &self,
+        this: storage::PaymentLink,
+        payment_link: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let mut locked_payment_links = self.payment_link.lock().await;
+
+        let stored_payment_link = locked_payment_links
+            .iter_mut()
+            .find(|stored| stored.payment_link_id == this.payment_link_id)
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "Payment link does not exist for {}",
+                    this.payment_link_id
+                ))
+            })?;
+
+        *stored_payment_link = payment_link.apply_changeset(stored_payment_link.clone());
+
+        Ok(stored_payment_link.clone())
+    }
+
+    async fn list_payment_links_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        payment_link_constraints: api_models::payments::PaymentLinkListConstraints,
+    ) -> CustomResult<Vec<storage::PaymentLink>, errors::StorageError> {
+        let created_at_matches = |created_at| {
+            payment_link_constraints
+                .created
+                .map_or(true, |created| created_at == created)
+                && payment_link_constraints
+                    .created_lt
+                    .map_or(true, |created_lt| created_at < created_lt)
+                && payment_link_constraints
+                    .created_gt
+                    .map_or(true, |created_gt| created_at > created_gt)
+                && payment_link_constraints
+                    .created_lte
+                    .map_or(true, |created_lte| created_at <= created_lte)
+                && payment_link_constraints
+                    .created_gte
+                    .map_or(true, |created_gte| created_at >= created_gte)
+        };
+
+        let mut payment_links: Vec<_> = self
+            .payment_link
+            .lock()
+            .await
+            .iter()
+            .filter(|payment_link| {
+                payment_link.merchant_id == merchant_id
+                    && payment_link_constraints
+                        .payment_id
+                        .as_ref()
+                        .map_or(true, |payment_id| &payment_link.payment_id == payment_id)
+                    && payment_link_constraints
+                        .status
+                        .map_or(true, |status| payment_link.status == status)
+                    && created_at_matches(payment_link.created_at)
+            })
+            .cloned()
+            .collect();
+
+        payment_links.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        if let Some(limit) = payment_link_constraints
+            .limit
+            .and_then(|limit| usize::try_from(limit).ok())
+        {
+            payment_links.truncate(limit);
+        }
+
+        Ok(payment_links)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use diesel_models::enums;
+
+    use crate::{
+        db::{payment_link::PaymentLinkInterface, MockDb},
+        types::storage,
+    };
+
+    fn create_payment_link_new(payment_link_id: &str) -> storage::PaymentLinkNew {
+        storage::PaymentLinkNew {
+            payment_link_id: payment_link_id.into(),
+            payment_id: format!("pay_{payment_link_id}"),
+            link_to_pay: format!("https://example.com/{payment_link_id}"),
+            merchant_id: "merchant_1".into(),
+            amount: 1000,
+            currency: Some(enums::Currency::USD),
+            created_at: None,
+            last_modified_at: None,
+            fulfilment_time: None,
+            status: enums::PaymentLinkStatus::Active,
+        }
+    }
+
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_mockdb_payment_link_interface() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+
+        let payment_link = mockdb
+            .insert_payment_link(create_payment_link_new("plink_1"))
+            .await
+            .unwrap();
+        mockdb
+            .insert_payment_link(create_payment_link_new("plink_2"))
+            .await
+            .unwrap();
+
+        let payment_link = mockdb
+            .update_payment_link(
+                payment_link,
+                storage::PaymentLinkUpdate::StatusUpdate {
+                    status: enums::PaymentLinkStatus::Cancelled,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(payment_link.status, enums::PaymentLinkStatus::Cancelled);
+
+        let active_payment_links = mockdb
+            .list_payment_links_by_merchant_id(
+                "merchant_1",
+                api_models::payments::PaymentLinkListConstraints {
+                    limit: None,
+                    payment_id: None,
+                    status: Some(enums::PaymentLinkStatus::Active),
+                    created: None,
+                    created_lt: None,
+                    created_gt: None,
+                    created_lte: None,
+                    created_gte: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(active_payment_links.len(), 1);
+        assert_eq!(active_payment_links[0].payment_link_id, "plink_2");
     }
 }
@@ -1,11 +1,12 @@
 use diesel_models::payment_method::PaymentMethodUpdateInternal;
 use error_stack::{IntoReport, ResultExt};
+use time::PrimitiveDateTime;
 
 use super::{MockDb, Store};
 use crate::{
     connection,
     core::errors::{self, CustomResult},
-    types::storage,
+    types::storage::{self, enums as storage_enums},
 };
 
 #[async_trait::async_trait]
@@ -21,6 +22,21 @@ pub trait PaymentMethodInterface {
         merchant_id: &str,
     ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError>;
 
+    async fn list_payment_methods_by_merchant_id_after_payment_method_id(
+        &self,
+        merchant_id: &str,
+        last_payment_method_id: &str,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError>;
+
+    async fn find_payment_methods_by_merchant_id_payment_method_created_before(
+        &self,
+        merchant_id: &str,
+        payment_method: storage_enums::PaymentMethod,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError>;
+
     async fn insert_payment_method(
         &self,
         payment_method_new: storage::PaymentMethodNew,
@@ -89,6 +105,44 @@ impl PaymentMethodInterface for Store {
             .into_report()
     }
 
+    async fn list_payment_methods_by_merchant_id_after_payment_method_id(
+        &self,
+        merchant_id: &str,
+        last_payment_method_id: &str,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentMethod::list_by_merchant_id_after_payment_method_id(
+            &conn,
+            merchant_id,
+            last_payment_method_id,
+            limit,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn find_payment_methods_by_merchant_id_payment_method_created_before(
+        &self,
+        merchant_id: &str,
+        payment_method: storage_enums::PaymentMethod,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentMethod::find_by_merchant_id_payment_method_created_before(
+            &conn,
+            merchant_id,
+            payment_method,
+            created_before,
+            limit,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
     async fn delete_payment_method_by_merchant_id_payment_method_id(
         &self,
         merchant_id: &str,
@@ -187,6 +241,54 @@ impl PaymentMethodInterface for MockDb {
         }
     }
 
+    async fn list_payment_methods_by_merchant_id_after_payment_method_id(
+        &self,
+        merchant_id: &str,
+        last_payment_method_id: &str,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        let mut payment_methods = self
+            .payment_methods
+            .lock()
+            .await
+            .iter()
+            .filter(|pm| {
+                pm.merchant_id == merchant_id
+                    && pm.payment_method_id.as_str() > last_payment_method_id
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        payment_methods.sort_by(|a, b| a.payment_method_id.cmp(&b.payment_method_id));
+        payment_methods.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+
+        Ok(payment_methods)
+    }
+
+    async fn find_payment_methods_by_merchant_id_payment_method_created_before(
+        &self,
+        merchant_id: &str,
+        payment_method: storage_enums::PaymentMethod,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        let mut payment_methods = self
+            .payment_methods
+            .lock()
+            .await
+            .iter()
+            .filter(|pm| {
+                pm.merchant_id == merchant_id
+                    && pm.payment_method == payment_method
+                    && pm.created_at < created_before
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        payment_methods.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        payment_methods.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+
+        Ok(payment_methods)
+    }
+
     async fn delete_payment_method_by_merchant_id_payment_method_id(
         &self,
         merchant_id: &str,
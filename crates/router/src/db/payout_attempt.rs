@@ -1,10 +1,7 @@
-use error_stack::IntoReport;
-
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection,
     core::errors::{self, CustomResult},
-    types::storage,
+    types::storage::{self as storage_types, enums},
 };
 
 #[async_trait::async_trait]
@@ -13,59 +10,408 @@ pub trait PayoutAttemptInterface {
         &self,
         _merchant_id: &str,
         _payout_id: &str,
-    ) -> CustomResult<storage::PayoutAttempt, errors::StorageError>;
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::PayoutAttempt, errors::StorageError>;
+
+    async fn find_payout_attempt_by_merchant_id_connector_payout_id(
+        &self,
+        _merchant_id: &str,
+        _connector_payout_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::PayoutAttempt, errors::StorageError>;
 
     async fn update_payout_attempt_by_merchant_id_payout_id(
         &self,
         _merchant_id: &str,
         _payout_id: &str,
-        _payout: storage::PayoutAttemptUpdate,
-    ) -> CustomResult<storage::PayoutAttempt, errors::StorageError>;
+        _payout: storage_types::PayoutAttemptUpdate,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::PayoutAttempt, errors::StorageError>;
 
     async fn insert_payout_attempt(
         &self,
-        _payout: storage::PayoutAttemptNew,
-    ) -> CustomResult<storage::PayoutAttempt, errors::StorageError>;
-}
+        _payout: storage_types::PayoutAttemptNew,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::PayoutAttempt, errors::StorageError>;
 
-#[async_trait::async_trait]
-impl PayoutAttemptInterface for Store {
-    async fn find_payout_attempt_by_merchant_id_payout_id(
+    #[cfg(feature = "olap")]
+    async fn get_filters_for_payouts(
         &self,
-        merchant_id: &str,
-        payout_id: &str,
-    ) -> CustomResult<storage::PayoutAttempt, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::PayoutAttempt::find_by_merchant_id_payout_id(&conn, merchant_id, payout_id)
+        _payouts: &[storage_types::Payouts],
+        _merchant_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<api_models::payouts::PayoutListFilters, errors::StorageError>;
+}
+
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use error_stack::IntoReport;
+
+    use super::PayoutAttemptInterface;
+    use crate::{
+        connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{self as storage_types, enums},
+    };
+
+    #[async_trait::async_trait]
+    impl PayoutAttemptInterface for Store {
+        async fn find_payout_attempt_by_merchant_id_payout_id(
+            &self,
+            merchant_id: &str,
+            payout_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::PayoutAttempt, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::PayoutAttempt::find_by_merchant_id_payout_id(
+                &conn,
+                merchant_id,
+                payout_id,
+            )
             .await
             .map_err(Into::into)
             .into_report()
-    }
+        }
 
-    async fn update_payout_attempt_by_merchant_id_payout_id(
-        &self,
-        merchant_id: &str,
-        payout_id: &str,
-        payout: storage::PayoutAttemptUpdate,
-    ) -> CustomResult<storage::PayoutAttempt, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        storage::PayoutAttempt::update_by_merchant_id_payout_id(
-            &conn,
-            merchant_id,
-            payout_id,
-            payout,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
+        async fn find_payout_attempt_by_merchant_id_connector_payout_id(
+            &self,
+            merchant_id: &str,
+            connector_payout_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::PayoutAttempt, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::PayoutAttempt::find_by_merchant_id_connector_payout_id(
+                &conn,
+                merchant_id,
+                connector_payout_id,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
+        async fn update_payout_attempt_by_merchant_id_payout_id(
+            &self,
+            merchant_id: &str,
+            payout_id: &str,
+            payout: storage_types::PayoutAttemptUpdate,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::PayoutAttempt, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::PayoutAttempt::update_by_merchant_id_payout_id(
+                &conn,
+                merchant_id,
+                payout_id,
+                payout,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
+        async fn insert_payout_attempt(
+            &self,
+            payout: storage_types::PayoutAttemptNew,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::PayoutAttempt, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            payout.insert(&conn).await.map_err(Into::into).into_report()
+        }
+
+        #[cfg(feature = "olap")]
+        async fn get_filters_for_payouts(
+            &self,
+            payouts: &[storage_types::Payouts],
+            merchant_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<api_models::payouts::PayoutListFilters, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            <storage_types::PayoutAttempt as storage_types::PayoutAttemptDbExt>::get_filters_for_payouts(
+                &conn,
+                payouts,
+                merchant_id,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
     }
+}
 
-    async fn insert_payout_attempt(
-        &self,
-        payout: storage::PayoutAttemptNew,
-    ) -> CustomResult<storage::PayoutAttempt, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        payout.insert(&conn).await.map_err(Into::into).into_report()
+#[cfg(feature = "kv_store")]
+mod storage {
+    use error_stack::{IntoReport, ResultExt};
+    use redis_interface::HsetnxReply;
+    use storage_impl::redis::kv_store::{kv_wrapper, KvOperation};
+
+    use super::PayoutAttemptInterface;
+    use crate::{
+        connection,
+        core::errors::{self, CustomResult},
+        db::reverse_lookup::ReverseLookupInterface,
+        services::Store,
+        types::storage::{self as storage_types, enums, kv},
+        utils::{self, db_utils},
+    };
+
+    #[async_trait::async_trait]
+    impl PayoutAttemptInterface for Store {
+        async fn find_payout_attempt_by_merchant_id_payout_id(
+            &self,
+            merchant_id: &str,
+            payout_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::PayoutAttempt, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage_types::PayoutAttempt::find_by_merchant_id_payout_id(
+                    &conn,
+                    merchant_id,
+                    payout_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            };
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("mid_{merchant_id}_po_{payout_id}");
+                    let field = format!("poa_{payout_id}");
+                    db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            kv_wrapper(
+                                self,
+                                KvOperation::<storage_types::PayoutAttempt>::HGet(&field),
+                                key,
+                            )
+                            .await?
+                            .try_into_hget()
+                        },
+                        database_call,
+                    )
+                    .await
+                }
+            }
+        }
+
+        async fn find_payout_attempt_by_merchant_id_connector_payout_id(
+            &self,
+            merchant_id: &str,
+            connector_payout_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::PayoutAttempt, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage_types::PayoutAttempt::find_by_merchant_id_connector_payout_id(
+                    &conn,
+                    merchant_id,
+                    connector_payout_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            };
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let lookup_id = format!("{merchant_id}_{connector_payout_id}");
+                    let lookup = match self
+                        .get_lookup_by_lookup_id(&lookup_id, storage_scheme)
+                        .await
+                    {
+                        Ok(lookup) => lookup,
+                        // Payout attempts that received their connector reference before the
+                        // merchant moved to the KV store have no reverse lookup
+                        Err(error) if error.current_context().is_db_not_found() => {
+                            return database_call().await
+                        }
+                        Err(error) => return Err(error),
+                    };
+
+                    let key = &lookup.pk_id;
+                    db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            kv_wrapper(
+                                self,
+                                KvOperation::<storage_types::PayoutAttempt>::HGet(&lookup.sk_id),
+                                key,
+                            )
+                            .await?
+                            .try_into_hget()
+                        },
+                        database_call,
+                    )
+                    .await
+                }
+            }
+        }
+
+        async fn update_payout_attempt_by_merchant_id_payout_id(
+            &self,
+            merchant_id: &str,
+            payout_id: &str,
+            payout: storage_types::PayoutAttemptUpdate,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::PayoutAttempt, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    storage_types::PayoutAttempt::update_by_merchant_id_payout_id(
+                        &conn,
+                        merchant_id,
+                        payout_id,
+                        payout,
+                    )
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("mid_{merchant_id}_po_{payout_id}");
+                    let field = format!("poa_{payout_id}");
+
+                    let origin = self
+                        .find_payout_attempt_by_merchant_id_payout_id(
+                            merchant_id,
+                            payout_id,
+                            storage_scheme,
+                        )
+                        .await?;
+                    let updated_attempt = payout.clone().apply_changeset(origin.clone());
+                    let new_connector_payout_id = (!updated_attempt.connector_payout_id.is_empty()
+                        && updated_attempt.connector_payout_id != origin.connector_payout_id)
+                        .then(|| updated_attempt.connector_payout_id.clone());
+                    let redis_value =
+                        utils::Encode::<storage_types::PayoutAttempt>::encode_to_string_of_json(
+                            &updated_attempt,
+                        )
+                        .change_context(errors::StorageError::SerializationFailed)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: kv::Updateable::PayoutAttemptUpdate(
+                                kv::PayoutAttemptUpdateMems {
+                                    orig: origin,
+                                    update_data: payout,
+                                },
+                            ),
+                        },
+                    };
+
+                    kv_wrapper::<(), _, _>(
+                        self,
+                        KvOperation::Hset::<storage_types::PayoutAttempt>(
+                            (&field, redis_value),
+                            redis_entry,
+                        ),
+                        &key,
+                    )
+                    .await
+                    .change_context(errors::StorageError::KVError)?
+                    .try_into_hset()
+                    .change_context(errors::StorageError::KVError)?;
+
+                    if let Some(connector_payout_id) = new_connector_payout_id {
+                        let reverse_lookup = storage_types::ReverseLookupNew {
+                            sk_id: field,
+                            lookup_id: format!("{merchant_id}_{connector_payout_id}"),
+                            pk_id: key,
+                            source: "payout_attempt".to_string(),
+                            updated_by: storage_scheme.to_string(),
+                        };
+                        self.insert_reverse_lookup(reverse_lookup, storage_scheme)
+                            .await?;
+                    }
+
+                    Ok(updated_attempt)
+                }
+            }
+        }
+
+        async fn insert_payout_attempt(
+            &self,
+            new: storage_types::PayoutAttemptNew,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::PayoutAttempt, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    new.insert(&conn).await.map_err(Into::into).into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("mid_{}_po_{}", new.merchant_id, new.payout_id);
+                    let field = format!("poa_{}", new.payout_id);
+                    let now = common_utils::date_time::now();
+
+                    let created_attempt = storage_types::PayoutAttempt {
+                        payout_attempt_id: new.payout_attempt_id.clone(),
+                        payout_id: new.payout_id.clone(),
+                        customer_id: new.customer_id.clone(),
+                        merchant_id: new.merchant_id.clone(),
+                        address_id: new.address_id.clone(),
+                        connector: new.connector.clone(),
+                        connector_payout_id: new.connector_payout_id.clone(),
+                        payout_token: new.payout_token.clone(),
+                        status: new.status,
+                        is_eligible: new.is_eligible,
+                        error_message: new.error_message.clone(),
+                        error_code: new.error_code.clone(),
+                        business_country: new.business_country,
+                        business_label: new.business_label.clone(),
+                        created_at: new.created_at.unwrap_or(now),
+                        last_modified_at: new.last_modified_at.unwrap_or(now),
+                        profile_id: new.profile_id.clone(),
+                    };
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Insert {
+                            insertable: kv::Insertable::PayoutAttempt(new),
+                        },
+                    };
+
+                    match kv_wrapper::<storage_types::PayoutAttempt, _, _>(
+                        self,
+                        KvOperation::<storage_types::PayoutAttempt>::HSetNx(
+                            &field,
+                            &created_attempt,
+                            redis_entry,
+                        ),
+                        &key,
+                    )
+                    .await
+                    .change_context(errors::StorageError::KVError)?
+                    .try_into_hsetnx()
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "payout attempt",
+                            key: Some(created_attempt.payout_id),
+                        })
+                        .into_report(),
+                        Ok(HsetnxReply::KeySet) => Ok(created_attempt),
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }
+        }
+        #[cfg(feature = "olap")]
+        async fn get_filters_for_payouts(
+            &self,
+            payouts: &[storage_types::Payouts],
+            merchant_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<api_models::payouts::PayoutListFilters, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            <storage_types::PayoutAttempt as storage_types::PayoutAttemptDbExt>::get_filters_for_payouts(
+                &conn,
+                payouts,
+                merchant_id,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
     }
 }
 
@@ -75,7 +421,18 @@ impl PayoutAttemptInterface for MockDb {
         &self,
         _merchant_id: &str,
         _payout_id: &str,
-    ) -> CustomResult<storage::PayoutAttempt, errors::StorageError> {
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::PayoutAttempt, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payout_attempt_by_merchant_id_connector_payout_id(
+        &self,
+        _merchant_id: &str,
+        _connector_payout_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::PayoutAttempt, errors::StorageError> {
         // TODO: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
@@ -84,16 +441,29 @@ impl PayoutAttemptInterface for MockDb {
         &self,
         _merchant_id: &str,
         _payout_id: &str,
-        _payout: storage::PayoutAttemptUpdate,
-    ) -> CustomResult<storage::PayoutAttempt, errors::StorageError> {
+        _payout: storage_types::PayoutAttemptUpdate,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::PayoutAttempt, errors::StorageError> {
         // TODO: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
 
     async fn insert_payout_attempt(
         &self,
-        _payout: storage::PayoutAttemptNew,
-    ) -> CustomResult<storage::PayoutAttempt, errors::StorageError> {
+        _payout: storage_types::PayoutAttemptNew,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::PayoutAttempt, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "olap")]
+    async fn get_filters_for_payouts(
+        &self,
+        _payouts: &[storage_types::Payouts],
+        _merchant_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<api_models::payouts::PayoutListFilters, errors::StorageError> {
         // TODO: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
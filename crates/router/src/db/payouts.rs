@@ -1,10 +1,7 @@
-use error_stack::IntoReport;
-
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection,
     core::errors::{self, CustomResult},
-    types::storage,
+    types::storage::{self as storage_types, enums},
 };
 
 #[async_trait::async_trait]
@@ -13,54 +10,446 @@ pub trait PayoutsInterface {
         &self,
         _merchant_id: &str,
         _payout_id: &str,
-    ) -> CustomResult<storage::Payouts, errors::StorageError>;
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Payouts, errors::StorageError>;
 
     async fn update_payout_by_merchant_id_payout_id(
         &self,
         _merchant_id: &str,
         _payout_id: &str,
-        _payout: storage::PayoutsUpdate,
-    ) -> CustomResult<storage::Payouts, errors::StorageError>;
+        _payout: storage_types::PayoutsUpdate,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Payouts, errors::StorageError>;
 
     async fn insert_payout(
         &self,
-        _payout: storage::PayoutsNew,
-    ) -> CustomResult<storage::Payouts, errors::StorageError>;
-}
+        _payout: storage_types::PayoutsNew,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Payouts, errors::StorageError>;
 
-#[async_trait::async_trait]
-impl PayoutsInterface for Store {
-    async fn find_payout_by_merchant_id_payout_id(
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_by_constraints(
+        &self,
+        _merchant_id: &str,
+        _constraints: &api_models::payouts::PayoutListConstraints,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage_types::Payouts>, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_and_attempts(
+        &self,
+        _merchant_id: &str,
+        _constraints: &api_models::payouts::PayoutListFilterConstraints,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<
+        Vec<(storage_types::Payouts, storage_types::PayoutAttempt)>,
+        errors::StorageError,
+    >;
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_by_time_range_constraints(
+        &self,
+        _merchant_id: &str,
+        _time_range: &api_models::payments::TimeRange,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage_types::Payouts>, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn get_total_count_of_filtered_payouts(
         &self,
-        merchant_id: &str,
-        payout_id: &str,
-    ) -> CustomResult<storage::Payouts, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Payouts::find_by_merchant_id_payout_id(&conn, merchant_id, payout_id)
+        _merchant_id: &str,
+        _constraints: &api_models::payouts::PayoutListFilterConstraints,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<i64, errors::StorageError>;
+}
+
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use error_stack::IntoReport;
+
+    use super::PayoutsInterface;
+    use crate::{
+        connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{self as storage_types, enums},
+    };
+
+    #[async_trait::async_trait]
+    impl PayoutsInterface for Store {
+        async fn find_payout_by_merchant_id_payout_id(
+            &self,
+            merchant_id: &str,
+            payout_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Payouts, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Payouts::find_by_merchant_id_payout_id(&conn, merchant_id, payout_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn update_payout_by_merchant_id_payout_id(
+            &self,
+            merchant_id: &str,
+            payout_id: &str,
+            payout: storage_types::PayoutsUpdate,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Payouts, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::Payouts::update_by_merchant_id_payout_id(
+                &conn,
+                merchant_id,
+                payout_id,
+                payout,
+            )
             .await
             .map_err(Into::into)
             .into_report()
-    }
+        }
 
-    async fn update_payout_by_merchant_id_payout_id(
-        &self,
-        merchant_id: &str,
-        payout_id: &str,
-        payout: storage::PayoutsUpdate,
-    ) -> CustomResult<storage::Payouts, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        storage::Payouts::update_by_merchant_id_payout_id(&conn, merchant_id, payout_id, payout)
+        async fn insert_payout(
+            &self,
+            payout: storage_types::PayoutsNew,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Payouts, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            payout.insert(&conn).await.map_err(Into::into).into_report()
+        }
+        #[cfg(feature = "olap")]
+        async fn filter_payouts_by_constraints(
+            &self,
+            merchant_id: &str,
+            constraints: &api_models::payouts::PayoutListConstraints,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage_types::Payouts>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            <storage_types::Payouts as storage_types::PayoutsDbExt>::filter_by_constraints(
+                &conn,
+                merchant_id,
+                constraints,
+            )
             .await
             .map_err(Into::into)
             .into_report()
+        }
+
+        #[cfg(feature = "olap")]
+        async fn filter_payouts_and_attempts(
+            &self,
+            merchant_id: &str,
+            constraints: &api_models::payouts::PayoutListFilterConstraints,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<
+            Vec<(storage_types::Payouts, storage_types::PayoutAttempt)>,
+            errors::StorageError,
+        > {
+            let conn = connection::pg_connection_read(self).await?;
+            <storage_types::Payouts as storage_types::PayoutsDbExt>::filter_with_attempts_by_constraints(
+                &conn,
+                merchant_id,
+                constraints,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
+        #[cfg(feature = "olap")]
+        async fn filter_payouts_by_time_range_constraints(
+            &self,
+            merchant_id: &str,
+            time_range: &api_models::payments::TimeRange,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage_types::Payouts>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            <storage_types::Payouts as storage_types::PayoutsDbExt>::filter_by_time_range(
+                &conn,
+                merchant_id,
+                time_range,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
+        #[cfg(feature = "olap")]
+        async fn get_total_count_of_filtered_payouts(
+            &self,
+            merchant_id: &str,
+            constraints: &api_models::payouts::PayoutListFilterConstraints,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<i64, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            <storage_types::Payouts as storage_types::PayoutsDbExt>::get_filtered_payouts_count(
+                &conn,
+                merchant_id,
+                constraints,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
     }
+}
 
-    async fn insert_payout(
-        &self,
-        payout: storage::PayoutsNew,
-    ) -> CustomResult<storage::Payouts, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        payout.insert(&conn).await.map_err(Into::into).into_report()
+#[cfg(feature = "kv_store")]
+mod storage {
+    use error_stack::{IntoReport, ResultExt};
+    use redis_interface::HsetnxReply;
+    use storage_impl::redis::kv_store::{kv_wrapper, KvOperation};
+
+    use super::PayoutsInterface;
+    use crate::{
+        connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{self as storage_types, enums, kv},
+        utils::{self, db_utils},
+    };
+
+    #[async_trait::async_trait]
+    impl PayoutsInterface for Store {
+        async fn find_payout_by_merchant_id_payout_id(
+            &self,
+            merchant_id: &str,
+            payout_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Payouts, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage_types::Payouts::find_by_merchant_id_payout_id(&conn, merchant_id, payout_id)
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+            };
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("mid_{merchant_id}_po_{payout_id}");
+                    let field = format!("po_{payout_id}");
+                    db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            kv_wrapper(
+                                self,
+                                KvOperation::<storage_types::Payouts>::HGet(&field),
+                                key,
+                            )
+                            .await?
+                            .try_into_hget()
+                        },
+                        database_call,
+                    )
+                    .await
+                }
+            }
+        }
+
+        async fn update_payout_by_merchant_id_payout_id(
+            &self,
+            merchant_id: &str,
+            payout_id: &str,
+            payout: storage_types::PayoutsUpdate,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Payouts, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    storage_types::Payouts::update_by_merchant_id_payout_id(
+                        &conn,
+                        merchant_id,
+                        payout_id,
+                        payout,
+                    )
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("mid_{merchant_id}_po_{payout_id}");
+                    let field = format!("po_{payout_id}");
+
+                    let origin = self
+                        .find_payout_by_merchant_id_payout_id(
+                            merchant_id,
+                            payout_id,
+                            storage_scheme,
+                        )
+                        .await?;
+                    let updated_payout = payout.clone().apply_changeset(origin.clone());
+                    let redis_value =
+                        utils::Encode::<storage_types::Payouts>::encode_to_string_of_json(
+                            &updated_payout,
+                        )
+                        .change_context(errors::StorageError::SerializationFailed)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: kv::Updateable::PayoutsUpdate(kv::PayoutsUpdateMems {
+                                orig: origin,
+                                update_data: payout,
+                            }),
+                        },
+                    };
+
+                    kv_wrapper::<(), _, _>(
+                        self,
+                        KvOperation::Hset::<storage_types::Payouts>(
+                            (&field, redis_value),
+                            redis_entry,
+                        ),
+                        &key,
+                    )
+                    .await
+                    .change_context(errors::StorageError::KVError)?
+                    .try_into_hset()
+                    .change_context(errors::StorageError::KVError)?;
+
+                    Ok(updated_payout)
+                }
+            }
+        }
+
+        async fn insert_payout(
+            &self,
+            new: storage_types::PayoutsNew,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Payouts, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    new.insert(&conn).await.map_err(Into::into).into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("mid_{}_po_{}", new.merchant_id, new.payout_id);
+                    let field = format!("po_{}", new.payout_id);
+                    let now = common_utils::date_time::now();
+
+                    let created_payout = storage_types::Payouts {
+                        payout_id: new.payout_id.clone(),
+                        merchant_id: new.merchant_id.clone(),
+                        customer_id: new.customer_id.clone(),
+                        address_id: new.address_id.clone(),
+                        payout_type: new.payout_type,
+                        payout_method_id: new.payout_method_id.clone(),
+                        amount: new.amount,
+                        destination_currency: new.destination_currency,
+                        source_currency: new.source_currency,
+                        description: new.description.clone(),
+                        recurring: new.recurring,
+                        auto_fulfill: new.auto_fulfill,
+                        return_url: new.return_url.clone(),
+                        entity_type: new.entity_type,
+                        metadata: new.metadata.clone(),
+                        created_at: new.created_at.unwrap_or(now),
+                        last_modified_at: new.last_modified_at.unwrap_or(now),
+                    };
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Insert {
+                            insertable: kv::Insertable::Payouts(new),
+                        },
+                    };
+
+                    match kv_wrapper::<storage_types::Payouts, _, _>(
+                        self,
+                        KvOperation::<storage_types::Payouts>::HSetNx(
+                            &field,
+                            &created_payout,
+                            redis_entry,
+                        ),
+                        &key,
+                    )
+                    .await
+                    .change_context(errors::StorageError::KVError)?
+                    .try_into_hsetnx()
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "payout",
+                            key: Some(created_payout.payout_id),
+                        })
+                        .into_report(),
+                        Ok(HsetnxReply::KeySet) => Ok(created_payout),
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }
+        }
+        #[cfg(feature = "olap")]
+        async fn filter_payouts_by_constraints(
+            &self,
+            merchant_id: &str,
+            constraints: &api_models::payouts::PayoutListConstraints,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage_types::Payouts>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            <storage_types::Payouts as storage_types::PayoutsDbExt>::filter_by_constraints(
+                &conn,
+                merchant_id,
+                constraints,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
+        #[cfg(feature = "olap")]
+        async fn filter_payouts_and_attempts(
+            &self,
+            merchant_id: &str,
+            constraints: &api_models::payouts::PayoutListFilterConstraints,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<
+            Vec<(storage_types::Payouts, storage_types::PayoutAttempt)>,
+            errors::StorageError,
+        > {
+            let conn = connection::pg_connection_read(self).await?;
+            <storage_types::Payouts as storage_types::PayoutsDbExt>::filter_with_attempts_by_constraints(
+                &conn,
+                merchant_id,
+                constraints,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
+        #[cfg(feature = "olap")]
+        async fn filter_payouts_by_time_range_constraints(
+            &self,
+            merchant_id: &str,
+            time_range: &api_models::payments::TimeRange,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage_types::Payouts>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            <storage_types::Payouts as storage_types::PayoutsDbExt>::filter_by_time_range(
+                &conn,
+                merchant_id,
+                time_range,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
+        #[cfg(feature = "olap")]
+        async fn get_total_count_of_filtered_payouts(
+            &self,
+            merchant_id: &str,
+            constraints: &api_models::payouts::PayoutListFilterConstraints,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<i64, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            <storage_types::Payouts as storage_types::PayoutsDbExt>::get_filtered_payouts_count(
+                &conn,
+                merchant_id,
+                constraints,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
     }
 }
 
@@ -70,7 +459,8 @@ impl PayoutsInterface for MockDb {
         &self,
         _merchant_id: &str,
         _payout_id: &str,
-    ) -> CustomResult<storage::Payouts, errors::StorageError> {
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Payouts, errors::StorageError> {
         // TODO: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
@@ -79,16 +469,65 @@ impl PayoutsInterface for MockDb {
         &self,
         _merchant_id: &str,
         _payout_id: &str,
-        _payout: storage::PayoutsUpdate,
-    ) -> CustomResult<storage::Payouts, errors::StorageError> {
+        _payout: storage_types::PayoutsUpdate,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Payouts, errors::StorageError> {
         // TODO: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
 
     async fn insert_payout(
         &self,
-        _payout: storage::PayoutsNew,
-    ) -> CustomResult<storage::Payouts, errors::StorageError> {
+        _payout: storage_types::PayoutsNew,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Payouts, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_by_constraints(
+        &self,
+        _merchant_id: &str,
+        _constraints: &api_models::payouts::PayoutListConstraints,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage_types::Payouts>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_and_attempts(
+        &self,
+        _merchant_id: &str,
+        _constraints: &api_models::payouts::PayoutListFilterConstraints,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<
+        Vec<(storage_types::Payouts, storage_types::PayoutAttempt)>,
+        errors::StorageError,
+    > {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_by_time_range_constraints(
+        &self,
+        _merchant_id: &str,
+        _time_range: &api_models::payments::TimeRange,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage_types::Payouts>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "olap")]
+    async fn get_total_count_of_filtered_payouts(
+        &self,
+        _merchant_id: &str,
+        _constraints: &api_models::payouts::PayoutListFilterConstraints,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<i64, errors::StorageError> {
         // TODO: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
@@ -368,6 +368,7 @@ mod storage {
                         refund_reason: new.refund_reason.clone(),
                         profile_id: new.profile_id.clone(),
                         updated_by: new.updated_by.clone(),
+                        capture_id: new.capture_id.clone(),
                     };
 
                     let field = format!(
@@ -796,6 +797,7 @@ impl RefundInterface for MockDb {
             refund_reason: new.refund_reason.clone(),
             profile_id: new.profile_id,
             updated_by: new.updated_by,
+            capture_id: new.capture_id,
         };
         refunds.push(refund.clone());
         Ok(refund)
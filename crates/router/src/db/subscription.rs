@@ -0,0 +1,293 @@
+use error_stack::{IntoReport, ResultExt};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait SubscriptionInterface {
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &str,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn find_subscriptions_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError>;
+
+    async fn update_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &str,
+        subscription_id: &str,
+        subscription: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for Store {
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        subscription
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &str,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Subscription::find_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn find_subscriptions_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Subscription::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &str,
+        subscription_id: &str,
+        subscription: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Subscription::update_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+            subscription,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for MockDb {
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let mut locked_subscriptions = self.subscriptions.lock().await;
+
+        if locked_subscriptions.iter().any(|existing| {
+            existing.merchant_id == subscription.merchant_id
+                && existing.subscription_id == subscription.subscription_id
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "subscription",
+                key: Some(subscription.subscription_id),
+            })?
+        }
+
+        let now = common_utils::date_time::now();
+        let stored_subscription = storage::Subscription {
+            id: locked_subscriptions
+                .len()
+                .try_into()
+                .into_report()
+                .change_context(errors::StorageError::MockDbError)?,
+            subscription_id: subscription.subscription_id,
+            merchant_id: subscription.merchant_id,
+            customer_id: subscription.customer_id,
+            mandate_id: subscription.mandate_id,
+            profile_id: subscription.profile_id,
+            status: subscription.status,
+            amount: subscription.amount,
+            currency: subscription.currency,
+            billing_interval: subscription.billing_interval,
+            interval_count: subscription.interval_count,
+            trial_end: subscription.trial_end,
+            billing_anchor: subscription.billing_anchor,
+            current_period_start: None,
+            current_period_end: None,
+            next_billing_at: subscription.next_billing_at,
+            latest_payment_id: None,
+            description: subscription.description,
+            metadata: subscription.metadata,
+            cancelled_at: None,
+            created_at: now,
+            modified_at: now,
+        };
+
+        locked_subscriptions.push(stored_subscription.clone());
+
+        Ok(stored_subscription)
+    }
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &str,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        self.subscriptions
+            .lock()
+            .await
+            .iter()
+            .find(|subscription| {
+                subscription.merchant_id == merchant_id
+                    && subscription.subscription_id == subscription_id
+            })
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "Subscription does not exist for {subscription_id}"
+                ))
+                .into()
+            })
+    }
+
+    async fn find_subscriptions_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        Ok(self
+            .subscriptions
+            .lock()
+            .await
+            .iter()
+            .filter(|subscription| {
+                subscription.merchant_id == merchant_id && subscription.customer_id == customer_id
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn update_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &str,
+        subscription_id: &str,
+        subscription: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let mut locked_subscriptions = self.subscriptions.lock().await;
+
+        let stored_subscription = locked_subscriptions
+            .iter_mut()
+            .find(|stored| {
+                stored.merchant_id == merchant_id && stored.subscription_id == subscription_id
+            })
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "Subscription does not exist for {subscription_id}"
+                ))
+            })?;
+
+        *stored_subscription = subscription.apply_changeset(stored_subscription.clone());
+
+        Ok(stored_subscription.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use diesel_models::enums;
+
+    use crate::{
+        db::{subscription::SubscriptionInterface, MockDb},
+        types::storage,
+    };
+
+    fn create_subscription_new(subscription_id: &str) -> storage::SubscriptionNew {
+        let billing_anchor = common_utils::date_time::now();
+        storage::SubscriptionNew {
+            subscription_id: subscription_id.into(),
+            merchant_id: "merchant_1".into(),
+            customer_id: "customer_1".into(),
+            mandate_id: "man_1".into(),
+            profile_id: None,
+            status: enums::SubscriptionStatus::Active,
+            amount: 1000,
+            currency: enums::Currency::USD,
+            billing_interval: enums::SubscriptionInterval::Month,
+            interval_count: 1,
+            trial_end: None,
+            billing_anchor,
+            next_billing_at: Some(billing_anchor),
+            description: None,
+            metadata: None,
+        }
+    }
+
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_mockdb_subscription_interface() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+
+        mockdb
+            .insert_subscription(create_subscription_new("sub_1"))
+            .await
+            .unwrap();
+        assert!(mockdb
+            .insert_subscription(create_subscription_new("sub_1"))
+            .await
+            .is_err());
+
+        let cancelled_subscription = mockdb
+            .update_subscription_by_merchant_id_subscription_id(
+                "merchant_1",
+                "sub_1",
+                storage::SubscriptionUpdate::CancelUpdate,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            cancelled_subscription.status,
+            enums::SubscriptionStatus::Cancelled
+        );
+        assert!(cancelled_subscription.next_billing_at.is_none());
+        assert!(cancelled_subscription.cancelled_at.is_some());
+
+        let found_subscription = mockdb
+            .find_subscription_by_merchant_id_subscription_id("merchant_1", "sub_1")
+            .await
+            .unwrap();
+        assert_eq!(
+            found_subscription.status,
+            enums::SubscriptionStatus::Cancelled
+        );
+
+        let customer_subscriptions = mockdb
+            .find_subscriptions_by_merchant_id_customer_id("merchant_1", "customer_1")
+            .await
+            .unwrap();
+        assert_eq!(customer_subscriptions.len(), 1);
+    }
+}
@@ -0,0 +1,97 @@
+use error_stack::{IntoReport, ResultExt};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait WebhookDeliveryAttemptInterface {
+    async fn insert_webhook_delivery_attempt(
+        &self,
+        delivery_attempt: storage::WebhookDeliveryAttemptNew,
+    ) -> CustomResult<storage::WebhookDeliveryAttempt, errors::StorageError>;
+
+    async fn find_webhook_delivery_attempts_by_merchant_id_event_id(
+        &self,
+        merchant_id: &str,
+        event_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookDeliveryAttempt>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl WebhookDeliveryAttemptInterface for Store {
+    async fn insert_webhook_delivery_attempt(
+        &self,
+        delivery_attempt: storage::WebhookDeliveryAttemptNew,
+    ) -> CustomResult<storage::WebhookDeliveryAttempt, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        delivery_attempt
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_webhook_delivery_attempts_by_merchant_id_event_id(
+        &self,
+        merchant_id: &str,
+        event_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookDeliveryAttempt>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookDeliveryAttempt::find_by_merchant_id_event_id(&conn, merchant_id, event_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookDeliveryAttemptInterface for MockDb {
+    async fn insert_webhook_delivery_attempt(
+        &self,
+        delivery_attempt: storage::WebhookDeliveryAttemptNew,
+    ) -> CustomResult<storage::WebhookDeliveryAttempt, errors::StorageError> {
+        let mut locked_delivery_attempts = self.webhook_delivery_attempts.lock().await;
+
+        let stored_delivery_attempt = storage::WebhookDeliveryAttempt {
+            id: locked_delivery_attempts
+                .len()
+                .try_into()
+                .into_report()
+                .change_context(errors::StorageError::MockDbError)?,
+            attempt_id: delivery_attempt.attempt_id,
+            event_id: delivery_attempt.event_id,
+            merchant_id: delivery_attempt.merchant_id,
+            delivery_attempt: delivery_attempt.delivery_attempt,
+            is_success: delivery_attempt.is_success,
+            response_status_code: delivery_attempt.response_status_code,
+            response_body: delivery_attempt.response_body,
+            error_message: delivery_attempt.error_message,
+            latency_ms: delivery_attempt.latency_ms,
+            created_at: common_utils::date_time::now(),
+        };
+
+        locked_delivery_attempts.push(stored_delivery_attempt.clone());
+
+        Ok(stored_delivery_attempt)
+    }
+
+    async fn find_webhook_delivery_attempts_by_merchant_id_event_id(
+        &self,
+        merchant_id: &str,
+        event_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookDeliveryAttempt>, errors::StorageError> {
+        let locked_delivery_attempts = self.webhook_delivery_attempts.lock().await;
+
+        Ok(locked_delivery_attempts
+            .iter()
+            .filter(|delivery_attempt| {
+                delivery_attempt.merchant_id == merchant_id && delivery_attempt.event_id == event_id
+            })
+            .cloned()
+            .collect())
+    }
+}
@@ -131,6 +131,7 @@ pub fn mk_app(
             .service(routes::PaymentMethods::server(state.clone()))
             .service(routes::EphemeralKey::server(state.clone()))
             .service(routes::Webhooks::server(state.clone()))
+            .service(routes::Subscriptions::server(state.clone()))
             .service(routes::PaymentLink::server(state.clone()));
     }
 
@@ -141,6 +142,7 @@ pub fn mk_app(
             .service(routes::ApiKeys::server(state.clone()))
             .service(routes::Files::server(state.clone()))
             .service(routes::Disputes::server(state.clone()))
+            .service(routes::WebhookEvents::server(state.clone()))
     }
 
     #[cfg(all(feature = "olap", feature = "kms"))]
@@ -61,6 +61,7 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "Disputes", description = "Manage disputes"),
         // (name = "API Key", description = "Create and manage API Keys"),
         (name = "Payouts", description = "Create and manage payouts"),
+        (name = "Subscriptions", description = "Create and manage subscriptions billed through mandates"),
         (name = "payment link", description = "Create payment link"),
     ),
     paths(
@@ -89,6 +90,10 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::payments::payments_connector_session,
     // crate::routes::payments::payments_redirect_response,
         crate::routes::payments::payments_cancel,
+        crate::routes::payments::payments_approve,
+        crate::routes::payments::payments_reject,
+        crate::routes::payments::payments_incremental_authorization,
+        crate::routes::payments::payments_list_captures,
         crate::routes::payments::payments_list,
         crate::routes::payment_methods::create_payment_method_api,
         crate::routes::payment_methods::list_payment_method_api,
@@ -109,12 +114,21 @@ Never share your secret api keys. Keep them guarded and secure.
         // crate::routes::api_keys::api_key_list,
         crate::routes::disputes::retrieve_disputes_list,
         crate::routes::disputes::retrieve_dispute,
+        crate::routes::disputes::get_disputes_analytics,
+        crate::routes::disputes::export_disputes,
         crate::routes::payouts::payouts_create,
         crate::routes::payouts::payouts_cancel,
         crate::routes::payouts::payouts_fulfill,
         crate::routes::payouts::payouts_retrieve,
         crate::routes::payouts::payouts_update,
-        crate::routes::payment_link::payment_link_retrieve
+        crate::routes::payouts::payouts_list,
+        crate::routes::subscriptions::subscriptions_create,
+        crate::routes::subscriptions::subscriptions_retrieve,
+        crate::routes::subscriptions::subscriptions_cancel,
+        crate::routes::payment_link::payment_link_retrieve,
+        crate::routes::payment_link::payment_link_list,
+        crate::routes::payment_link::payment_link_update,
+        crate::routes::payment_link::payment_link_cancel
     ),
     components(schemas(
         crate::types::api::refunds::RefundRequest,
@@ -144,6 +158,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::AcceptedCurrencies,
         api_models::enums::RoutingAlgorithm,
         api_models::enums::PaymentType,
+        api_models::enums::SubscriptionStatus,
+        api_models::enums::SubscriptionInterval,
+        api_models::enums::PaymentLinkStatus,
         api_models::enums::PaymentMethod,
         api_models::enums::PaymentMethodType,
         api_models::enums::ConnectorType,
@@ -157,6 +174,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::PaymentMethod,
         api_models::enums::PaymentMethodIssuerCode,
         api_models::enums::MandateStatus,
+        api_models::enums::MandateFrequency,
         api_models::enums::PaymentExperience,
         api_models::enums::BankNames,
         api_models::enums::CardNetwork,
@@ -166,9 +184,12 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::FieldType,
         api_models::enums::FrmAction,
         api_models::enums::FrmPreferredFlowTypes,
+        api_models::enums::FrmConnectors,
         api_models::enums::RetryAction,
+        api_models::enums::InstallmentInterestBearer,
         api_models::enums::AttemptStatus,
         api_models::enums::CaptureStatus,
+        api_models::enums::AuthorizationStatus,
         api_models::enums::ReconStatus,
         api_models::admin::MerchantConnectorCreate,
         api_models::admin::MerchantConnectorUpdate,
@@ -184,6 +205,14 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::PaymentLinkColorSchema,
         api_models::disputes::DisputeResponse,
         api_models::disputes::DisputeResponsePaymentsRetrieve,
+        api_models::disputes::DisputePolicy,
+        api_models::disputes::DisputeAnalyticsRequest,
+        api_models::disputes::DisputeAnalyticsGranularity,
+        api_models::disputes::DisputeAnalyticsResponse,
+        api_models::disputes::DisputeMetrics,
+        api_models::disputes::DisputeAmount,
+        api_models::disputes::DisputeMetricsBucket,
+        api_models::disputes::DisputeTimeBucket,
         api_models::payments::AddressDetails,
         api_models::payments::BankDebitData,
         api_models::payments::AliPayQr,
@@ -277,6 +306,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::ApplePayWalletData,
         api_models::payments::ApplepayPaymentMethod,
         api_models::payments::PaymentsCancelRequest,
+        api_models::payments::PaymentsApproveRequest,
+        api_models::payments::PaymentsRejectRequest,
+        api_models::payments::PaymentsIncrementalAuthorizationRequest,
+        api_models::payments::IncrementalAuthorizationResponse,
         api_models::payments::PaymentListConstraints,
         api_models::payments::PaymentListResponse,
         api_models::payments::CashappQr,
@@ -300,15 +333,20 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::BacsBankTransferInstructions,
         api_models::payments::RedirectResponse,
         api_models::payments::RequestSurchargeDetails,
+        api_models::payments::InstallmentData,
         api_models::payments::PaymentAttemptResponse,
         api_models::payments::CaptureResponse,
+        api_models::payments::CaptureListResponse,
         api_models::payment_methods::RequiredFieldInfo,
+        api_models::payment_methods::InstallmentPlanTypes,
         api_models::refunds::RefundListRequest,
         api_models::refunds::RefundListResponse,
         api_models::refunds::TimeRange,
         api_models::mandates::MandateRevokedResponse,
         api_models::mandates::MandateResponse,
         api_models::mandates::MandateCardDetails,
+        api_models::subscriptions::SubscriptionCreateRequest,
+        api_models::subscriptions::SubscriptionResponse,
         api_models::ephemeral_key::EphemeralKeyCreateResponse,
         api_models::payments::CustomerDetails,
         api_models::payments::GiftCardData,
@@ -320,6 +358,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payouts::BacsBankTransfer,
         api_models::payouts::SepaBankTransfer,
         api_models::payouts::PayoutCreateResponse,
+        api_models::payouts::PayoutListConstraints,
+        api_models::payouts::PayoutListResponse,
         api_models::payouts::PayoutRetrieveBody,
         api_models::payouts::PayoutRetrieveRequest,
         api_models::payouts::PayoutActionRequest,
@@ -338,6 +378,7 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::types::api::admin::MerchantDetails,
         crate::types::api::admin::WebhookDetails,
         crate::types::api::api_keys::ApiKeyExpiration,
+        crate::types::api::api_keys::ApiKeyScope,
         crate::types::api::api_keys::CreateApiKeyRequest,
         crate::types::api::api_keys::CreateApiKeyResponse,
         crate::types::api::api_keys::RetrieveApiKeyResponse,
@@ -346,6 +387,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::RetrievePaymentLinkRequest,
         api_models::payments::PaymentLinkResponse,
         api_models::payments::RetrievePaymentLinkResponse,
+        api_models::payments::PaymentLinkListConstraints,
+        api_models::payments::PaymentLinkUpdateRequest,
         api_models::payments::PaymentLinkInitiateRequest,
         api_models::payments::PaymentLinkObject
     )),
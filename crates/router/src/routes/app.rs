@@ -2,7 +2,8 @@ use std::sync::Arc;
 
 use actix_web::{web, Scope};
 #[cfg(feature = "email")]
-use external_services::email::{AwsSes, EmailClient};
+use external_services::email::{self, EmailClient};
+use external_services::file_storage::{self, FileStorageInterface};
 #[cfg(feature = "kms")]
 use external_services::kms::{self, decrypt::KmsDecrypt};
 use router_env::tracing_actix_web::RequestId;
@@ -17,12 +18,12 @@ use super::payouts::*;
 #[cfg(all(feature = "olap", feature = "kms"))]
 use super::verification::{apple_pay_merchant_registration, retrieve_apple_pay_verified_domains};
 #[cfg(feature = "olap")]
-use super::{admin::*, api_keys::*, disputes::*, files::*};
+use super::{admin::*, api_keys::*, disputes::*, files::*, webhook_events::*};
 use super::{cache::*, health::*, payment_link::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{configs::*, customers::*, mandates::*, payments::*, refunds::*};
 #[cfg(feature = "oltp")]
-use super::{ephemeral_key::*, payment_methods::*, webhooks::*};
+use super::{ephemeral_key::*, payment_methods::*, subscriptions::*, webhooks::*};
 use crate::{
     configs::settings,
     db::{StorageImpl, StorageInterface},
@@ -42,6 +43,7 @@ pub struct AppState {
     #[cfg(feature = "kms")]
     pub kms_secrets: Arc<settings::ActiveKmsSecrets>,
     pub api_client: Box<dyn crate::services::ApiClient>,
+    pub file_storage_client: Arc<dyn FileStorageInterface>,
 }
 
 impl scheduler::SchedulerAppState for AppState {
@@ -134,7 +136,13 @@ impl AppState {
         .expect("Failed while performing KMS decryption");
 
         #[cfg(feature = "email")]
-        let email_client = Arc::new(AwsSes::new(&conf.email).await);
+        #[allow(clippy::expect_used)]
+        let email_client = email::create_email_client(&conf.email)
+            .await
+            .expect("Failed to create email client");
+
+        let file_storage_client =
+            file_storage::create_file_storage_client(&conf.file_storage).await;
         Self {
             flow_name: String::from("default"),
             store,
@@ -145,6 +153,7 @@ impl AppState {
             kms_secrets: Arc::new(kms_secrets),
             api_client,
             event_handler: Box::<EventLogger>::default(),
+            file_storage_client,
         }
     }
 
@@ -246,9 +255,23 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/cancel").route(web::post().to(payments_cancel)),
                 )
+                .service(
+                    web::resource("/{payment_id}/approve").route(web::post().to(payments_approve)),
+                )
+                .service(
+                    web::resource("/{payment_id}/reject").route(web::post().to(payments_reject)),
+                )
                 .service(
                     web::resource("/{payment_id}/capture").route(web::post().to(payments_capture)),
                 )
+                .service(
+                    web::resource("/{payment_id}/incremental_authorization")
+                        .route(web::post().to(payments_incremental_authorization)),
+                )
+                .service(
+                    web::resource("/{payment_id}/captures")
+                        .route(web::get().to(payments_list_captures)),
+                )
                 .service(
                     web::resource("/redirect/{payment_id}/{merchant_id}/{attempt_id}")
                         .route(web::get().to(payments_start)),
@@ -349,9 +372,22 @@ pub struct Payouts;
 #[cfg(feature = "payouts")]
 impl Payouts {
     pub fn server(state: AppState) -> Scope {
-        let route = web::scope("/payouts").app_data(web::Data::new(state));
+        let mut route = web::scope("/payouts").app_data(web::Data::new(state));
+        route = route.service(web::resource("/create").route(web::post().to(payouts_create)));
+
+        #[cfg(feature = "olap")]
+        {
+            route = route
+                .service(
+                    web::resource("/list")
+                        .route(web::get().to(payouts_list))
+                        .route(web::post().to(payouts_list_by_filter)),
+                )
+                .service(
+                    web::resource("/filter").route(web::post().to(payouts_list_available_filters)),
+                );
+        }
         route
-            .service(web::resource("/create").route(web::post().to(payouts_create)))
             .service(web::resource("/{payout_id}/cancel").route(web::post().to(payouts_cancel)))
             .service(web::resource("/{payout_id}/fulfill").route(web::post().to(payouts_fulfill)))
             .service(
@@ -397,6 +433,10 @@ impl MerchantAccount {
                     .route(web::post().to(merchant_account_toggle_kv))
                     .route(web::get().to(merchant_account_kv_status)),
             )
+            .service(
+                web::resource("/{id}/keys/rotate")
+                    .route(web::post().to(merchant_account_rotate_key)),
+            )
             .service(
                 web::resource("/{id}")
                     .route(web::get().to(retrieve_merchant_account))
@@ -474,6 +514,24 @@ impl Mandates {
     }
 }
 
+pub struct Subscriptions;
+
+#[cfg(feature = "oltp")]
+impl Subscriptions {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/subscriptions")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(subscriptions_create)))
+            .service(
+                web::resource("/{subscription_id}").route(web::get().to(subscriptions_retrieve)),
+            )
+            .service(
+                web::resource("/{subscription_id}/cancel")
+                    .route(web::post().to(subscriptions_cancel)),
+            )
+    }
+}
+
 pub struct Webhooks;
 
 #[cfg(feature = "oltp")]
@@ -530,6 +588,21 @@ impl ApiKeys {
     }
 }
 
+pub struct WebhookEvents;
+
+#[cfg(feature = "olap")]
+impl WebhookEvents {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/events/{merchant_id}")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(list_webhook_events)))
+            .service(web::resource("/{event_id}").route(web::get().to(retrieve_webhook_event)))
+            .service(
+                web::resource("/{event_id}/retry").route(web::post().to(redeliver_webhook_event)),
+            )
+    }
+}
+
 pub struct Disputes;
 
 #[cfg(feature = "olap")]
@@ -538,6 +611,8 @@ impl Disputes {
         web::scope("/disputes")
             .app_data(web::Data::new(state))
             .service(web::resource("/list").route(web::get().to(retrieve_disputes_list)))
+            .service(web::resource("/analytics").route(web::post().to(get_disputes_analytics)))
+            .service(web::resource("/export").route(web::get().to(export_disputes)))
             .service(web::resource("/accept/{dispute_id}").route(web::post().to(accept_dispute)))
             .service(
                 web::resource("/evidence")
@@ -594,8 +669,15 @@ impl PaymentLink {
     pub fn server(state: AppState) -> Scope {
         web::scope("/payment_link")
             .app_data(web::Data::new(state))
+            .service(web::resource("/list").route(web::get().to(payment_link_list)))
+            .service(
+                web::resource("/{payment_link_id}")
+                    .route(web::get().to(payment_link_retrieve))
+                    .route(web::post().to(payment_link_update)),
+            )
             .service(
-                web::resource("/{payment_link_id}").route(web::get().to(payment_link_retrieve)),
+                web::resource("/{payment_link_id}/cancel")
+                    .route(web::post().to(payment_link_cancel)),
             )
             .service(
                 web::resource("{merchant_id}/{payment_id}")
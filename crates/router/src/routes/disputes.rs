@@ -92,6 +92,81 @@ pub async fn retrieve_disputes_list(
     )
     .await
 }
+/// Disputes - Dispute Analytics
+#[utoipa::path(
+    post,
+    path = "/disputes/analytics",
+    request_body = DisputeAnalyticsRequest,
+    responses(
+        (status = 200, description = "The dispute analytics were retrieved successfully", body = DisputeAnalyticsResponse),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Disputes",
+    operation_id = "Retrieve Dispute Analytics",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputesAnalytics))]
+pub async fn get_disputes_analytics(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<dispute_models::DisputeAnalyticsRequest>,
+) -> HttpResponse {
+    let flow = Flow::DisputesAnalytics;
+    let payload = json_payload.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req| disputes::get_dispute_analytics(state, auth.merchant_account, req),
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+/// Disputes - Export Disputes
+#[utoipa::path(
+    get,
+    path = "/disputes/export",
+    params(
+        ("limit" = Option<i64>, Query, description = "The maximum number of Dispute Objects to include in the export"),
+        ("dispute_status" = Option<DisputeStatus>, Query, description = "The status of dispute"),
+        ("dispute_stage" = Option<DisputeStage>, Query, description = "The stage of dispute"),
+        ("reason" = Option<String>, Query, description = "The reason for dispute"),
+        ("connector" = Option<String>, Query, description = "The connector linked to dispute"),
+        ("received_time" = Option<PrimitiveDateTime>, Query, description = "The time at which dispute is received"),
+        ("received_time.lt" = Option<PrimitiveDateTime>, Query, description = "Time less than the dispute received time"),
+        ("received_time.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the dispute received time"),
+        ("received_time.lte" = Option<PrimitiveDateTime>, Query, description = "Time less than or equals to the dispute received time"),
+        ("received_time.gte" = Option<PrimitiveDateTime>, Query, description = "Time greater than or equals to the dispute received time"),
+    ),
+    responses(
+        (status = 200, description = "The disputes were exported as CSV successfully", content_type = "text/csv", body = String),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Disputes",
+    operation_id = "Export Disputes",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputesExport))]
+pub async fn export_disputes(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<dispute_models::DisputeListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::DisputesExport;
+    let payload = payload.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req| disputes::export_disputes(state, auth.merchant_account, req),
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
 /// Disputes - Accept Dispute
 #[utoipa::path(
     get,
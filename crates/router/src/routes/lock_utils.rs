@@ -22,6 +22,8 @@ pub enum ApiIdentifier {
     Verification,
     ApiKeys,
     PaymentLink,
+    WebhookEvents,
+    Subscriptions,
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -31,6 +33,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantsAccountRetrieve
             | Flow::MerchantsAccountUpdate
             | Flow::MerchantsAccountDelete
+            | Flow::MerchantKeyRotate
             | Flow::MerchantAccountList => Self::MerchantAccount,
 
             Flow::MerchantConnectorsCreate
@@ -71,6 +74,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsCancel
             | Flow::PaymentsApprove
             | Flow::PaymentsReject
+            | Flow::PaymentsIncrementalAuthorization
+            | Flow::PaymentsCapturesList
             | Flow::PaymentsSessionToken
             | Flow::PaymentsStart
             | Flow::PaymentsList
@@ -81,6 +86,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PayoutsUpdate
             | Flow::PayoutsCancel
             | Flow::PayoutsFulfill
+            | Flow::PayoutsList
+            | Flow::PayoutsFilter
             | Flow::PayoutsAccounts => Self::Payouts,
 
             Flow::RefundsCreate
@@ -98,6 +105,8 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::DisputesRetrieve
             | Flow::DisputesList
+            | Flow::DisputesAnalytics
+            | Flow::DisputesExport
             | Flow::DisputesEvidenceSubmit
             | Flow::AttachDisputeEvidence
             | Flow::RetrieveDisputeEvidence => Self::Disputes,
@@ -116,7 +125,19 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::Verification => Self::Verification,
 
-            Flow::PaymentLinkInitiate | Flow::PaymentLinkRetrieve => Self::PaymentLink,
+            Flow::PaymentLinkInitiate
+            | Flow::PaymentLinkRetrieve
+            | Flow::PaymentLinkList
+            | Flow::PaymentLinkUpdate
+            | Flow::PaymentLinkCancel => Self::PaymentLink,
+
+            Flow::WebhookEventList | Flow::WebhookEventRetrieve | Flow::WebhookEventRedeliver => {
+                Self::WebhookEvents
+            }
+
+            Flow::SubscriptionsCreate | Flow::SubscriptionsRetrieve | Flow::SubscriptionsCancel => {
+                Self::Subscriptions
+            }
         }
     }
 }
@@ -34,6 +34,10 @@ counter_metric!(MANUAL_RETRY_REQUEST_COUNT, GLOBAL_METER);
 counter_metric!(MANUAL_RETRY_COUNT, GLOBAL_METER);
 counter_metric!(MANUAL_RETRY_VALIDATION_FAILED, GLOBAL_METER);
 
+// Auto retry metrics
+counter_metric!(AUTO_RETRY_PAYMENT_COUNT, GLOBAL_METER);
+counter_metric!(AUTO_RETRY_EXHAUSTED_COUNT, GLOBAL_METER);
+
 counter_metric!(STORED_TO_LOCKER, GLOBAL_METER);
 counter_metric!(GET_FROM_LOCKER, GLOBAL_METER);
 counter_metric!(DELETE_FROM_LOCKER, GLOBAL_METER);
@@ -80,3 +80,126 @@ pub async fn initiate_payment_link(
     )
     .await
 }
+
+/// Payment Link - List
+///
+/// To list the payment links created by the merchant, filtered by the given constraints
+#[utoipa::path(
+    get,
+    path = "/payment_link/list",
+    params(
+        ("limit" = Option<i64>, Query, description = "The maximum number of Payment Link Objects to include in the response"),
+        ("payment_id" = Option<String>, Query, description = "The identifier for payment"),
+        ("status" = Option<PaymentLinkStatus>, Query, description = "The status of the payment link"),
+        ("created" = Option<PrimitiveDateTime>, Query, description = "The time at which the payment link was created"),
+        ("created.lt" = Option<PrimitiveDateTime>, Query, description = "Time less than the payment link created time"),
+        ("created.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the payment link created time"),
+        ("created.lte" = Option<PrimitiveDateTime>, Query, description = "Time less than or equals to the payment link created time"),
+        ("created.gte" = Option<PrimitiveDateTime>, Query, description = "Time greater than or equals to the payment link created time"),
+    ),
+    responses(
+        (status = 200, description = "The payment link list was retrieved successfully", body = Vec<RetrievePaymentLinkResponse>),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Payments",
+    operation_id = "List Payment Links",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkList))]
+pub async fn payment_link_list(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Query<api_models::payments::PaymentLinkListConstraints>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkList;
+    let payload = payload.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req| list_payment_links(state, auth.merchant_account, req),
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Payment Link - Update
+///
+/// To update the amount or the expiry of a Payment Link, before the payment is made
+#[utoipa::path(
+    post,
+    path = "/payment_link/{payment_link_id}",
+    params(
+        ("payment_link_id" = String, Path, description = "The identifier for payment link")
+    ),
+    request_body = PaymentLinkUpdateRequest,
+    responses(
+        (status = 200, description = "The payment link was updated successfully", body = RetrievePaymentLinkResponse),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payments",
+    operation_id = "Update a Payment Link",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkUpdate))]
+pub async fn payment_link_update(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_models::payments::PaymentLinkUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkUpdate;
+    let payment_link_id = path.into_inner();
+    let payload = json_payload.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req| {
+            update_payment_link(state, auth.merchant_account, payment_link_id.clone(), req)
+        },
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Payment Link - Cancel
+///
+/// To cancel a Payment Link, the payment is cancelled along with it
+#[utoipa::path(
+    post,
+    path = "/payment_link/{payment_link_id}/cancel",
+    params(
+        ("payment_link_id" = String, Path, description = "The identifier for payment link")
+    ),
+    responses(
+        (status = 200, description = "The payment link was cancelled successfully", body = RetrievePaymentLinkResponse),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payments",
+    operation_id = "Cancel a Payment Link",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkCancel))]
+pub async fn payment_link_cancel(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkCancel;
+    let payment_link_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_link_id,
+        |state, auth, req| cancel_payment_link(state, auth.merchant_account, req),
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
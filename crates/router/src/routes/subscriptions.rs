@@ -0,0 +1,122 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, subscriptions},
+    services::{api, authentication as auth},
+    types::api::subscriptions as subscription_types,
+};
+
+/// Subscriptions - Create
+///
+/// Create a subscription which bills the customer through a multi-use mandate at a fixed interval
+#[utoipa::path(
+    post,
+    path = "/subscriptions",
+    request_body = SubscriptionCreateRequest,
+    responses(
+        (status = 200, description = "Subscription created", body = SubscriptionResponse),
+        (status = 400, description = "Missing mandatory fields or invalid mandate")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Create a Subscription",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionsCreate))]
+pub async fn subscriptions_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<subscription_types::SubscriptionCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionsCreate;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req| subscriptions::create_subscription(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Subscriptions - Retrieve
+///
+/// Retrieve a subscription
+#[utoipa::path(
+    get,
+    path = "/subscriptions/{subscription_id}",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for subscription")
+    ),
+    responses(
+        (status = 200, description = "Subscription retrieved", body = SubscriptionResponse),
+        (status = 404, description = "Subscription does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Retrieve a Subscription",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionsRetrieve))]
+pub async fn subscriptions_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionsRetrieve;
+    let subscription_id = subscription_types::SubscriptionId {
+        subscription_id: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        subscription_id,
+        |state, auth, req| subscriptions::retrieve_subscription(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Subscriptions - Cancel
+///
+/// Cancel a subscription, the customer is not billed again
+#[utoipa::path(
+    post,
+    path = "/subscriptions/{subscription_id}/cancel",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for subscription")
+    ),
+    responses(
+        (status = 200, description = "Subscription cancelled", body = SubscriptionResponse),
+        (status = 400, description = "Subscription has already been cancelled"),
+        (status = 404, description = "Subscription does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Cancel a Subscription",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionsCancel))]
+pub async fn subscriptions_cancel(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionsCancel;
+    let subscription_id = subscription_types::SubscriptionId {
+        subscription_id: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        subscription_id,
+        |state, auth, req| subscriptions::cancel_subscription(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
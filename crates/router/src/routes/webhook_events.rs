@@ -0,0 +1,142 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, webhooks::webhook_events},
+    services::{api, authentication as auth},
+};
+
+/// Events - List
+///
+/// List the events for which outgoing webhooks were sent to the merchant.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of events to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of events to skip"),
+        ("profile_id" = Option<String>, Query, description = "Only include events of this business profile"),
+        ("object_id" = Option<String>, Query, description = "Only include events about this payment, refund, dispute or mandate"),
+        ("event_type" = Option<EventType>, Query, description = "Only include events of this type"),
+        ("is_delivered" = Option<bool>, Query, description = "Only include events which were (or were not) delivered to the merchant"),
+        ("created.lt" = Option<PrimitiveDateTime>, Query, description = "Only include events created before this time"),
+        ("created.gt" = Option<PrimitiveDateTime>, Query, description = "Only include events created after this time"),
+    ),
+    responses(
+        (status = 200, description = "List of events retrieved successfully", body = Vec<EventListItemResponse>),
+    ),
+    tag = "Event",
+    operation_id = "List all events associated with a merchant account",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventList))]
+pub async fn list_webhook_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<api_models::webhook_events::EventListConstraints>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventList;
+    let merchant_id = path.into_inner();
+    let constraints = query.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (merchant_id, constraints),
+        |state, _, (merchant_id, constraints)| {
+            webhook_events::list_events(state, merchant_id, constraints)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Events - Retrieve
+///
+/// Retrieve an event along with the webhook request sent to the merchant and all the attempts
+/// made to deliver it.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id}/{event_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("event_id" = String, Path, description = "The unique identifier for the event")
+    ),
+    responses(
+        (status = 200, description = "Event retrieved successfully", body = EventRetrieveResponse),
+        (status = 404, description = "Event not found")
+    ),
+    tag = "Event",
+    operation_id = "Retrieve an event",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventRetrieve))]
+pub async fn retrieve_webhook_event(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventRetrieve;
+    let (merchant_id, event_id) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (merchant_id, event_id),
+        |state, _, (merchant_id, event_id)| {
+            webhook_events::retrieve_event(state, merchant_id, event_id)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Events - Redeliver
+///
+/// Send the webhook of an event to the merchant again. The outcome of the delivery is available
+/// in the delivery attempts of the returned event.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/{event_id}/retry",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("event_id" = String, Path, description = "The unique identifier for the event")
+    ),
+    responses(
+        (status = 200, description = "Webhook re-sent to the merchant", body = EventRetrieveResponse),
+        (status = 404, description = "Event not found"),
+        (status = 412, description = "The webhook of the event cannot be re-sent")
+    ),
+    tag = "Event",
+    operation_id = "Redeliver the webhook of an event",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventRedeliver))]
+pub async fn redeliver_webhook_event(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventRedeliver;
+    let (merchant_id, event_id) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (merchant_id, event_id),
+        |state, _, (merchant_id, event_id)| {
+            webhook_events::redeliver_event(state, merchant_id, event_id)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
@@ -1,5 +1,7 @@
 pub mod api;
 pub mod authentication;
+#[cfg(feature = "email")]
+pub mod email;
 pub mod encryption;
 pub mod logger;
 
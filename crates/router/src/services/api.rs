@@ -677,6 +677,8 @@ pub struct PaymentLinkFormData {
     pub js_script: String,
     pub css_script: String,
     pub sdk_url: String,
+    /// Template configured by the merchant which is rendered instead of the default page
+    pub custom_template: Option<String>,
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -746,7 +748,7 @@ pub enum AuthFlow {
 
 #[instrument(skip(request, payload, state, func, api_auth), fields(merchant_id))]
 pub async fn server_wrap_util<'a, 'b, A, U, T, Q, F, Fut, E, OErr>(
-    flow: &'a impl router_env::types::FlowMetric,
+    flow: &'a router_env::Flow,
     state: web::Data<A>,
     request: &'a HttpRequest,
     payload: T,
@@ -778,7 +780,7 @@ where
     let start_instant = Instant::now();
 
     let auth_out = api_auth
-        .authenticate_and_fetch(request.headers(), &request_state)
+        .authenticate_and_fetch(request.headers(), &request_state, flow)
         .await
         .switch()?;
 
@@ -844,7 +846,7 @@ where
     fields(request_method, request_url_path)
 )]
 pub async fn server_wrap<'a, A, T, U, Q, F, Fut, E>(
-    flow: impl router_env::types::FlowMetric,
+    flow: router_env::Flow,
     state: web::Data<A>,
     request: &'a HttpRequest,
     payload: T,
@@ -1126,6 +1128,7 @@ impl Authenticate for api_models::payments::PaymentsRetrieveRequest {}
 impl Authenticate for api_models::payments::PaymentsCancelRequest {}
 impl Authenticate for api_models::payments::PaymentsCaptureRequest {}
 impl Authenticate for api_models::payments::PaymentsStartRequest {}
+impl Authenticate for api_models::payments::PaymentsRejectRequest {}
 
 pub fn build_redirection_form(
     form: &RedirectForm,
@@ -1400,7 +1403,9 @@ mod tests {
 pub fn build_payment_link_html(
     payment_link_data: PaymentLinkFormData,
 ) -> CustomResult<String, errors::ApiErrorResponse> {
-    let html_template = include_str!("../core/payment_link/payment_link.html").to_string();
+    let html_template = payment_link_data
+        .custom_template
+        .unwrap_or_else(|| include_str!("../core/payment_link/payment_link.html").to_string());
 
     let mut tera = Tera::default();
 
@@ -1,5 +1,7 @@
+use std::net::IpAddr;
+
 use actix_web::http::header::HeaderMap;
-use api_models::{payment_methods::PaymentMethodListRequest, payments};
+use api_models::{api_keys::ApiKeyScope, payment_methods::PaymentMethodListRequest, payments};
 use async_trait::async_trait;
 use common_utils::date_time;
 use error_stack::{report, IntoReport, ResultExt};
@@ -7,6 +9,7 @@ use error_stack::{report, IntoReport, ResultExt};
 use external_services::kms::{self, decrypt::KmsDecrypt};
 use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
 use masking::{PeekInterface, StrongSecret};
+use router_env::Flow;
 
 use crate::{
     configs::settings,
@@ -21,6 +24,8 @@ use crate::{
     utils::OptionExt,
 };
 
+mod scopes;
+
 pub struct AuthenticationData {
     pub merchant_account: domain::MerchantAccount,
     pub key_store: domain::MerchantKeyStore,
@@ -52,6 +57,7 @@ where
         &self,
         request_headers: &HeaderMap,
         state: &A,
+        flow: &Flow,
     ) -> RouterResult<T>;
 }
 
@@ -69,6 +75,7 @@ where
         &self,
         _request_headers: &HeaderMap,
         _state: &A,
+        _flow: &Flow,
     ) -> RouterResult<()> {
         Ok(())
     }
@@ -83,6 +90,7 @@ where
         &self,
         request_headers: &HeaderMap,
         state: &A,
+        flow: &Flow,
     ) -> RouterResult<AuthenticationData> {
         let api_key = get_api_key(request_headers)
             .change_context(errors::ApiErrorResponse::Unauthorized)?
@@ -123,6 +131,34 @@ where
                 .attach_printable("API key has expired");
         }
 
+        // Requests of flows outside the scopes of the API key are rejected
+        if let Some(scopes) = &stored_api_key.scopes {
+            let scopes = scopes
+                .iter()
+                .filter_map(|scope| scope.parse().ok())
+                .collect::<Vec<ApiKeyScope>>();
+            if !scopes::is_flow_permitted(flow, &scopes) {
+                return Err(report!(errors::ApiErrorResponse::AccessForbidden {
+                    resource: flow.to_string(),
+                }))
+                .attach_printable("Flow is not permitted by the scopes of the API key");
+            }
+        }
+
+        if let Some(allowed_ips) = &stored_api_key.allowed_ips {
+            let trusted_proxy_hops = state.conf().api_keys.trusted_proxy_hops;
+            let client_ip = get_client_ip(request_headers, trusted_proxy_hops);
+            let is_ip_allowed = client_ip.map_or(false, |client_ip| {
+                allowed_ips
+                    .iter()
+                    .any(|allowed_ip| allowed_ip.parse::<IpAddr>().ok() == Some(client_ip))
+            });
+            if !is_ip_allowed {
+                return Err(report!(errors::ApiErrorResponse::Unauthorized))
+                    .attach_printable("API key is not allowed to be used from the client IP");
+            }
+        }
+
         let key_store = state
             .store()
             .get_merchant_key_store_by_merchant_id(
@@ -183,6 +219,7 @@ where
         &self,
         request_headers: &HeaderMap,
         state: &A,
+        _flow: &Flow,
     ) -> RouterResult<()> {
         let request_admin_api_key =
             get_api_key(request_headers).change_context(errors::ApiErrorResponse::Unauthorized)?;
@@ -216,6 +253,7 @@ where
         &self,
         _request_headers: &HeaderMap,
         state: &A,
+        _flow: &Flow,
     ) -> RouterResult<AuthenticationData> {
         let key_store = state
             .store()
@@ -264,6 +302,7 @@ where
         &self,
         request_headers: &HeaderMap,
         state: &A,
+        _flow: &Flow,
     ) -> RouterResult<AuthenticationData> {
         let publishable_key =
             get_api_key(request_headers).change_context(errors::ApiErrorResponse::Unauthorized)?;
@@ -300,6 +339,7 @@ where
         &self,
         request_headers: &HeaderMap,
         state: &A,
+        _flow: &Flow,
     ) -> RouterResult<()> {
         let mut token = get_jwt(request_headers)?;
         token = strip_jwt_token(token)?;
@@ -323,6 +363,7 @@ where
         &self,
         request_headers: &HeaderMap,
         state: &A,
+        _flow: &Flow,
     ) -> RouterResult<AuthenticationData> {
         let mut token = get_jwt(request_headers)?;
         token = strip_jwt_token(token)?;
@@ -507,6 +548,23 @@ pub fn get_api_key(headers: &HeaderMap) -> RouterResult<&str> {
     get_header_value_by_key("api-key".into(), headers)?.get_required_value("api_key")
 }
 
+/// Get the IP address of the client from the `X-Forwarded-For` header. Every proxy in front of
+/// the application appends the address of its peer to the header, so only the entries added by
+/// the trusted proxies can be relied upon, the entries to their left are set by the client.
+fn get_client_ip(headers: &HeaderMap, trusted_proxy_hops: usize) -> Option<IpAddr> {
+    let forwarded_for = headers
+        .get_all(crate::headers::X_FORWARDED_FOR)
+        .iter()
+        .map(|header| header.to_str().ok())
+        .collect::<Option<Vec<_>>>()?;
+    forwarded_for
+        .iter()
+        .flat_map(|header| header.split(','))
+        .rev()
+        .nth(trusted_proxy_hops.checked_sub(1)?)
+        .and_then(|client_ip| client_ip.trim().parse().ok())
+}
+
 pub fn get_header_value_by_key(key: String, headers: &HeaderMap) -> RouterResult<Option<&str>> {
     headers
         .get(&key)
@@ -551,3 +609,74 @@ where
     }
     default_auth
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use actix_web::http::header::{HeaderName, HeaderValue};
+
+    use super::*;
+
+    fn forwarded_for_headers(values: &[&str]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for value in values {
+            headers.append(
+                HeaderName::from_static("x-forwarded-for"),
+                HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        headers
+    }
+
+    #[test]
+    fn test_client_ip_is_added_by_trusted_proxy() {
+        let headers = forwarded_for_headers(&["203.0.113.10"]);
+
+        assert_eq!(
+            get_client_ip(&headers, 1),
+            Some("203.0.113.10".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_spoofed_leading_entry_is_ignored() {
+        // The client sets the first entry, the load balancer appends the actual client address
+        let headers = forwarded_for_headers(&["198.51.100.1, 203.0.113.10"]);
+        assert_eq!(
+            get_client_ip(&headers, 1),
+            Some("203.0.113.10".parse().unwrap())
+        );
+
+        // Same as above, with the load balancer appending a separate header line
+        let headers = forwarded_for_headers(&["198.51.100.1", "203.0.113.10"]);
+        assert_eq!(
+            get_client_ip(&headers, 1),
+            Some("203.0.113.10".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_client_ip_behind_multiple_proxies() {
+        let headers = forwarded_for_headers(&["198.51.100.1, 203.0.113.10, 10.0.0.2"]);
+
+        assert_eq!(
+            get_client_ip(&headers, 2),
+            Some("203.0.113.10".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_client_ip_is_not_found_without_trusted_entries() {
+        assert_eq!(get_client_ip(&HeaderMap::new(), 1), None);
+        assert_eq!(
+            get_client_ip(&forwarded_for_headers(&["10.0.0.2"]), 2),
+            None
+        );
+        assert_eq!(get_client_ip(&forwarded_for_headers(&["invalid"]), 1), None);
+        assert_eq!(
+            get_client_ip(&forwarded_for_headers(&["203.0.113.10"]), 0),
+            None
+        );
+    }
+}
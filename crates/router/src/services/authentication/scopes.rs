@@ -0,0 +1,162 @@
+use api_models::api_keys::ApiKeyScope;
+use router_env::Flow;
+
+/// Get the scopes of an API key, any of which permits requests of the flow. The flows which are
+/// not permitted by any scope can only be requested using API keys which are not restricted to
+/// scopes.
+pub fn get_permitting_scopes(flow: &Flow) -> &'static [ApiKeyScope] {
+    match flow {
+        Flow::PaymentsCreate
+        | Flow::PaymentsUpdate
+        | Flow::PaymentsConfirm
+        | Flow::PaymentsCapture
+        | Flow::PaymentsCancel
+        | Flow::PaymentsApprove
+        | Flow::PaymentsReject
+        | Flow::PaymentsIncrementalAuthorization
+        | Flow::PaymentsSessionToken
+        | Flow::PaymentsStart
+        | Flow::PaymentsRedirect
+        | Flow::CustomersCreate
+        | Flow::CustomersUpdate
+        | Flow::CustomersDelete
+        | Flow::EphemeralKeyCreate
+        | Flow::EphemeralKeyDelete
+        | Flow::MandatesRevoke
+        | Flow::PaymentMethodsCreate
+        | Flow::PaymentMethodsUpdate
+        | Flow::PaymentMethodsDelete
+        | Flow::ValidatePaymentMethod
+        | Flow::PaymentLinkInitiate
+        | Flow::PaymentLinkUpdate
+        | Flow::PaymentLinkCancel
+        | Flow::SubscriptionsCreate
+        | Flow::SubscriptionsCancel => &[ApiKeyScope::PaymentsWrite],
+
+        Flow::PaymentsRetrieve
+        | Flow::PaymentsList
+        | Flow::PaymentsCapturesList
+        | Flow::CustomersRetrieve
+        | Flow::CustomersList
+        | Flow::CustomersGetMandates
+        | Flow::MandatesRetrieve
+        | Flow::MandatesList
+        | Flow::PaymentMethodsList
+        | Flow::CustomerPaymentMethodsList
+        | Flow::PaymentMethodsRetrieve
+        | Flow::CardsInfo
+        | Flow::PaymentLinkRetrieve
+        | Flow::PaymentLinkList
+        | Flow::SubscriptionsRetrieve => &[ApiKeyScope::PaymentsWrite, ApiKeyScope::ReadOnly],
+
+        Flow::RefundsCreate | Flow::RefundsUpdate => &[ApiKeyScope::RefundsWrite],
+
+        Flow::RefundsRetrieve | Flow::RefundsList => {
+            &[ApiKeyScope::RefundsWrite, ApiKeyScope::ReadOnly]
+        }
+
+        Flow::PayoutsCreate | Flow::PayoutsUpdate | Flow::PayoutsCancel | Flow::PayoutsFulfill => {
+            &[ApiKeyScope::PayoutsWrite]
+        }
+
+        Flow::PayoutsRetrieve | Flow::PayoutsList | Flow::PayoutsFilter | Flow::PayoutsAccounts => {
+            &[ApiKeyScope::PayoutsWrite, ApiKeyScope::ReadOnly]
+        }
+
+        Flow::DisputesEvidenceSubmit
+        | Flow::AttachDisputeEvidence
+        | Flow::CreateFile
+        | Flow::DeleteFile => &[ApiKeyScope::Disputes],
+
+        Flow::DisputesRetrieve
+        | Flow::DisputesList
+        | Flow::DisputesAnalytics
+        | Flow::DisputesExport
+        | Flow::RetrieveDisputeEvidence
+        | Flow::RetrieveFile => &[ApiKeyScope::Disputes, ApiKeyScope::ReadOnly],
+
+        Flow::MerchantsAccountRetrieve
+        | Flow::MerchantConnectorsRetrieve
+        | Flow::MerchantConnectorsList
+        | Flow::BusinessProfileRetrieve
+        | Flow::BusinessProfileList
+        | Flow::WebhookEventList
+        | Flow::WebhookEventRetrieve => &[ApiKeyScope::ReadOnly],
+
+        Flow::MerchantsAccountCreate
+        | Flow::MerchantsAccountUpdate
+        | Flow::MerchantsAccountDelete
+        | Flow::MerchantKeyRotate
+        | Flow::MerchantAccountList
+        | Flow::MerchantConnectorsCreate
+        | Flow::MerchantConnectorsUpdate
+        | Flow::MerchantConnectorsDelete
+        | Flow::ConfigKeyCreate
+        | Flow::ConfigKeyFetch
+        | Flow::ConfigKeyUpdate
+        | Flow::CreateConfigKey
+        | Flow::IncomingWebhookReceive
+        | Flow::ApiKeyCreate
+        | Flow::ApiKeyRetrieve
+        | Flow::ApiKeyUpdate
+        | Flow::ApiKeyRevoke
+        | Flow::ApiKeyList
+        | Flow::CacheInvalidate
+        | Flow::BusinessProfileCreate
+        | Flow::BusinessProfileUpdate
+        | Flow::BusinessProfileDelete
+        | Flow::Verification
+        | Flow::WebhookEventRedeliver => &[],
+    }
+}
+
+/// Check whether any of the scopes of an API key permits requests of the flow
+pub fn is_flow_permitted(flow: &Flow, scopes: &[ApiKeyScope]) -> bool {
+    get_permitting_scopes(flow)
+        .iter()
+        .any(|scope| scopes.contains(scope))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_only_scope_permits_only_read_flows() {
+        let scopes = [ApiKeyScope::ReadOnly];
+
+        assert!(is_flow_permitted(&Flow::PaymentsRetrieve, &scopes));
+        assert!(is_flow_permitted(&Flow::RefundsList, &scopes));
+        assert!(is_flow_permitted(&Flow::DisputesExport, &scopes));
+        assert!(!is_flow_permitted(&Flow::PaymentsCreate, &scopes));
+        assert!(!is_flow_permitted(&Flow::RefundsCreate, &scopes));
+        assert!(!is_flow_permitted(&Flow::DisputesEvidenceSubmit, &scopes));
+    }
+
+    #[test]
+    fn test_write_scopes_are_limited_to_their_resources() {
+        let scopes = [ApiKeyScope::PaymentsWrite, ApiKeyScope::RefundsWrite];
+
+        assert!(is_flow_permitted(&Flow::PaymentsConfirm, &scopes));
+        assert!(is_flow_permitted(&Flow::CustomersCreate, &scopes));
+        assert!(is_flow_permitted(&Flow::RefundsCreate, &scopes));
+        assert!(is_flow_permitted(&Flow::RefundsRetrieve, &scopes));
+        assert!(!is_flow_permitted(&Flow::PayoutsCreate, &scopes));
+        assert!(!is_flow_permitted(&Flow::AttachDisputeEvidence, &scopes));
+    }
+
+    #[test]
+    fn test_administrative_flows_are_not_permitted_by_any_scope() {
+        let scopes = [
+            ApiKeyScope::PaymentsWrite,
+            ApiKeyScope::RefundsWrite,
+            ApiKeyScope::PayoutsWrite,
+            ApiKeyScope::ReadOnly,
+            ApiKeyScope::Disputes,
+        ];
+
+        assert!(!is_flow_permitted(&Flow::ApiKeyCreate, &scopes));
+        assert!(!is_flow_permitted(&Flow::MerchantsAccountUpdate, &scopes));
+        assert!(!is_flow_permitted(&Flow::WebhookEventRedeliver, &scopes));
+    }
+}
@@ -0,0 +1,100 @@
+//! Emails sent to merchants, with their bodies rendered from templates
+
+use error_stack::{IntoReport, ResultExt};
+use tera::{Context, Tera};
+
+use crate::core::errors::{self, RouterResult};
+
+/// The subject and body of an email
+#[derive(Debug)]
+pub struct EmailContents {
+    pub subject: String,
+    pub body: String,
+}
+
+/// An email whose body is rendered from a tera template
+pub trait EmailTemplate {
+    /// Name of the template, the template is rendered without escaping unless the name ends with
+    /// `.html`
+    const NAME: &'static str;
+    const TEMPLATE: &'static str;
+
+    fn get_subject(&self) -> String;
+    fn get_context(&self) -> Context;
+
+    fn render(&self) -> RouterResult<EmailContents> {
+        let mut tera = Tera::default();
+        tera.add_raw_template(Self::NAME, Self::TEMPLATE)
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!("Failed to parse the email template {}", Self::NAME)
+            })?;
+        let body = tera
+            .render(Self::NAME, &self.get_context())
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!("Failed to render the email template {}", Self::NAME)
+            })?;
+
+        Ok(EmailContents {
+            subject: self.get_subject(),
+            body,
+        })
+    }
+}
+
+/// Reminder sent to the merchant ahead of the expiry of an API key
+#[derive(Debug)]
+pub struct ApiKeyExpiryReminder {
+    pub merchant_name: Option<String>,
+    pub api_key_name: String,
+    pub api_key_prefix: String,
+    pub expires_in: u8,
+}
+
+impl EmailTemplate for ApiKeyExpiryReminder {
+    const NAME: &'static str = "api_key_expiry_reminder.txt";
+    const TEMPLATE: &'static str = include_str!("email/templates/api_key_expiry_reminder.txt");
+
+    fn get_subject(&self) -> String {
+        "API Key Expiry Notice".to_string()
+    }
+
+    fn get_context(&self) -> Context {
+        let mut context = Context::new();
+        if let Some(merchant_name) = &self.merchant_name {
+            context.insert("merchant_name", merchant_name);
+        }
+        context.insert("api_key_name", &self.api_key_name);
+        context.insert("api_key_prefix", &self.api_key_prefix);
+        context.insert("expires_in", &self.expires_in);
+        context
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_api_key_expiry_reminder_render() {
+        let email = ApiKeyExpiryReminder {
+            merchant_name: None,
+            api_key_name: "Production key".to_string(),
+            api_key_prefix: "snd_abc".to_string(),
+            expires_in: 7,
+        }
+        .render()
+        .unwrap();
+
+        assert_eq!(email.subject, "API Key Expiry Notice");
+        assert!(email.body.starts_with("Dear Merchant,"));
+        assert!(email
+            .body
+            .contains("your API key \"Production key\" (snd_abc...) will expire in 7 days"));
+    }
+}
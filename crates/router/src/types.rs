@@ -58,6 +58,11 @@ pub type PaymentsApproveRouterData =
     RouterData<api::Approve, PaymentsApproveData, PaymentsResponseData>;
 pub type PaymentsSessionRouterData =
     RouterData<api::Session, PaymentsSessionData, PaymentsResponseData>;
+pub type PaymentsIncrementalAuthorizationRouterData = RouterData<
+    api::IncrementalAuthorization,
+    PaymentsIncrementalAuthorizationData,
+    PaymentsResponseData,
+>;
 pub type RefundsRouterData<F> = RouterData<F, RefundsData, RefundsResponseData>;
 pub type RefundExecuteRouterData = RouterData<api::Execute, RefundsData, RefundsResponseData>;
 pub type RefundSyncRouterData = RouterData<api::RSync, RefundsData, RefundsResponseData>;
@@ -136,6 +141,11 @@ pub type PaymentsSessionType =
     dyn services::ConnectorIntegration<api::Session, PaymentsSessionData, PaymentsResponseData>;
 pub type PaymentsVoidType =
     dyn services::ConnectorIntegration<api::Void, PaymentsCancelData, PaymentsResponseData>;
+pub type PaymentsIncrementalAuthorizationType = dyn services::ConnectorIntegration<
+    api::IncrementalAuthorization,
+    PaymentsIncrementalAuthorizationData,
+    PaymentsResponseData,
+>;
 pub type TokenizationType = dyn services::ConnectorIntegration<
     api::PaymentMethodToken,
     PaymentMethodTokenizationData,
@@ -380,6 +390,8 @@ pub struct PaymentsAuthorizeData {
     pub payment_method_type: Option<storage_enums::PaymentMethodType>,
     pub surcharge_details: Option<api_models::payment_methods::SurchargeDetailsResponse>,
     pub customer_id: Option<String>,
+    pub installment_data: Option<api_models::payments::InstallmentData>,
+    pub request_incremental_authorization: bool,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -509,6 +521,18 @@ pub struct PaymentsApproveData {
     pub currency: Option<storage_enums::Currency>,
 }
 
+#[derive(Debug, Default, Clone)]
+pub struct PaymentsIncrementalAuthorizationData {
+    /// Total amount that should be authorized once the increment succeeds
+    pub total_amount: i64,
+    /// Difference between the new total and the currently authorized amount
+    pub additional_amount: i64,
+    pub currency: storage_enums::Currency,
+    pub reason: Option<String>,
+    pub connector_transaction_id: String,
+    pub connector_meta: Option<serde_json::Value>,
+}
+
 #[derive(Debug, Clone)]
 pub struct PaymentsSessionData {
     pub amount: i64,
@@ -570,6 +594,7 @@ impl Capturable for SetupMandateRequestData {}
 impl Capturable for PaymentsCancelData {}
 impl Capturable for PaymentsApproveData {}
 impl Capturable for PaymentsRejectData {}
+impl Capturable for PaymentsIncrementalAuthorizationData {}
 impl Capturable for PaymentsSessionData {}
 impl Capturable for PaymentsSyncData {}
 
@@ -668,6 +693,12 @@ pub enum PaymentsResponseData {
         session_token: Option<api::SessionToken>,
         connector_response_reference_id: Option<String>,
     },
+    IncrementalAuthorizationResponse {
+        status: storage_enums::AuthorizationStatus,
+        connector_authorization_id: Option<String>,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -715,6 +746,8 @@ pub struct RefundsData {
     /// Arbitrary metadata required for refund
     pub connector_metadata: Option<serde_json::Value>,
     pub browser_info: Option<BrowserInformation>,
+    /// Connector reference of the capture against which this refund is issued
+    pub connector_capture_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
@@ -1078,6 +1111,8 @@ impl From<&SetupMandateRouterData> for PaymentsAuthorizeData {
             payment_method_type: None,
             customer_id: None,
             surcharge_details: None,
+            installment_data: None,
+            request_incremental_authorization: false,
         }
     }
 }
@@ -11,6 +11,7 @@ pub mod payment_methods;
 pub mod payments;
 pub mod payouts;
 pub mod refunds;
+pub mod subscriptions;
 pub mod webhooks;
 
 use std::{fmt::Debug, str::FromStr};
@@ -19,7 +20,7 @@ use error_stack::{report, IntoReport, ResultExt};
 
 pub use self::{
     admin::*, api_keys::*, configs::*, customers::*, disputes::*, files::*, payment_methods::*,
-    payments::*, payouts::*, refunds::*, webhooks::*,
+    payments::*, payouts::*, refunds::*, subscriptions::*, webhooks::*,
 };
 use super::ErrorResponse;
 use crate::{
@@ -218,6 +219,9 @@ pub enum PayoutConnectorChoice {
 pub enum ConnectorCallType {
     Multiple(Vec<SessionConnectorData>),
     Single(ConnectorData),
+    /// Connectors ranked by the routing algorithm, the first one is called and the rest are
+    /// fallbacks for the same payment
+    Retryable(Vec<ConnectorData>),
 }
 
 #[cfg(feature = "payouts")]
@@ -231,6 +235,15 @@ impl ConnectorCallType {
     pub fn is_single(&self) -> bool {
         matches!(self, Self::Single(_))
     }
+
+    /// The connector to be called first, `None` for session calls to multiple connectors
+    pub fn first_connector(&self) -> Option<&ConnectorData> {
+        match self {
+            Self::Single(connector) => Some(connector),
+            Self::Retryable(connectors) => connectors.first(),
+            Self::Multiple(_) => None,
+        }
+    }
 }
 
 #[cfg(feature = "payouts")]
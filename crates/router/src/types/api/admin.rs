@@ -7,6 +7,7 @@ pub use api_models::admin::{
     PaymentMethodsEnabled, PayoutRoutingAlgorithm, PayoutStraightThroughAlgorithm,
     RoutingAlgorithm, StraightThroughAlgorithm, ToggleKVRequest, ToggleKVResponse, WebhookDetails,
 };
+use api_models::{admin::PaymentLinkConfig, disputes::DisputePolicy};
 use common_utils::ext_traits::ValueExt;
 use error_stack::ResultExt;
 use masking::Secret;
@@ -72,6 +73,14 @@ impl ForeignTryFrom<storage::business_profile::BusinessProfile> for BusinessProf
             frm_routing_algorithm: item.frm_routing_algorithm,
             payout_routing_algorithm: item.payout_routing_algorithm,
             applepay_verified_domains: item.applepay_verified_domains,
+            dispute_policy: item
+                .dispute_policy
+                .map(|dispute_policy| dispute_policy.parse_value("DisputePolicy"))
+                .transpose()?,
+            payment_link_config: item
+                .payment_link_config
+                .map(|payment_link_config| payment_link_config.parse_value("PaymentLinkConfig"))
+                .transpose()?,
         })
     }
 }
@@ -100,6 +109,30 @@ impl ForeignTryFrom<(domain::MerchantAccount, BusinessProfileCreate)>
             })
             .transpose()?;
 
+        let dispute_policy = request
+            .dispute_policy
+            .as_ref()
+            .map(|dispute_policy| {
+                common_utils::ext_traits::Encode::<DisputePolicy>::encode_to_value(dispute_policy)
+                    .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                        field_name: "dispute policy",
+                    })
+            })
+            .transpose()?;
+
+        let payment_link_config = request
+            .payment_link_config
+            .as_ref()
+            .map(|payment_link_config| {
+                common_utils::ext_traits::Encode::<PaymentLinkConfig>::encode_to_value(
+                    payment_link_config,
+                )
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "payment_link_config",
+                })
+            })
+            .transpose()?;
+
         let payment_response_hash_key = request
             .payment_response_hash_key
             .or(merchant_account.payment_response_hash_key)
@@ -139,6 +172,8 @@ impl ForeignTryFrom<(domain::MerchantAccount, BusinessProfileCreate)>
                 .or(merchant_account.payout_routing_algorithm),
             is_recon_enabled: merchant_account.is_recon_enabled,
             applepay_verified_domains: request.applepay_verified_domains,
+            dispute_policy,
+            payment_link_config,
         })
     }
 }
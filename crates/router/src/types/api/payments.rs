@@ -1,11 +1,12 @@
 pub use api_models::payments::{
-    AcceptanceType, Address, AddressDetails, Amount, AuthenticationForStartResponse, Card,
-    CryptoData, CustomerAcceptance, HeaderPayload, MandateAmountData, MandateData,
-    MandateTransactionType, MandateType, MandateValidationFields, NextActionType, OnlineMandate,
-    PayLaterData, PaymentIdType, PaymentListConstraints, PaymentListFilterConstraints,
-    PaymentListFilters, PaymentListResponse, PaymentListResponseV2, PaymentMethodData,
-    PaymentMethodDataResponse, PaymentOp, PaymentRetrieveBody, PaymentRetrieveBodyWithCredentials,
-    PaymentsApproveRequest, PaymentsCancelRequest, PaymentsCaptureRequest, PaymentsRedirectRequest,
+    AcceptanceType, Address, AddressDetails, Amount, AuthenticationForStartResponse,
+    CaptureListResponse, CaptureResponse, Card, CryptoData, CustomerAcceptance, HeaderPayload,
+    MandateAmountData, MandateData, MandateTransactionType, MandateType, MandateValidationFields,
+    NextActionType, OnlineMandate, PayLaterData, PaymentIdType, PaymentListConstraints,
+    PaymentListFilterConstraints, PaymentListFilters, PaymentListResponse, PaymentListResponseV2,
+    PaymentMethodData, PaymentMethodDataResponse, PaymentOp, PaymentRetrieveBody,
+    PaymentRetrieveBodyWithCredentials, PaymentsApproveRequest, PaymentsCancelRequest,
+    PaymentsCaptureRequest, PaymentsIncrementalAuthorizationRequest, PaymentsRedirectRequest,
     PaymentsRedirectionResponse, PaymentsRejectRequest, PaymentsRequest, PaymentsResponse,
     PaymentsResponseForm, PaymentsRetrieveRequest, PaymentsSessionRequest, PaymentsSessionResponse,
     PaymentsStartRequest, PgRedirectResponse, PhoneDetails, RedirectionResponse, SessionToken,
@@ -69,6 +70,9 @@ pub struct Reject;
 #[derive(Debug, Clone)]
 pub struct Session;
 
+#[derive(Debug, Clone)]
+pub struct IncrementalAuthorization;
+
 #[derive(Debug, Clone)]
 pub struct PaymentMethodToken;
 
@@ -154,6 +158,15 @@ pub trait PaymentCapture:
 {
 }
 
+pub trait PaymentIncrementalAuthorization:
+    api::ConnectorIntegration<
+    IncrementalAuthorization,
+    types::PaymentsIncrementalAuthorizationData,
+    types::PaymentsResponseData,
+>
+{
+}
+
 pub trait PaymentSession:
     api::ConnectorIntegration<Session, types::PaymentsSessionData, types::PaymentsResponseData>
 {
@@ -210,6 +223,7 @@ pub trait Payment:
     + PaymentVoid
     + PaymentApprove
     + PaymentReject
+    + PaymentIncrementalAuthorization
     + MandateSetup
     + PaymentSession
     + PaymentToken
@@ -0,0 +1,3 @@
+pub use api_models::subscriptions::{
+    SubscriptionCreateRequest, SubscriptionId, SubscriptionResponse,
+};
@@ -74,8 +74,18 @@ impl behaviour::Conversion for Address {
         other: Self::DstType,
         key: &Secret<Vec<u8>>,
     ) -> CustomResult<Self, ValidationError> {
+        Self::convert_back_with_previous_key(other, key, None).await
+    }
+
+    async fn convert_back_with_previous_key(
+        other: Self::DstType,
+        key: &Secret<Vec<u8>>,
+        previous_key: Option<&Secret<Vec<u8>>>,
+    ) -> CustomResult<Self, ValidationError> {
+        let previous_key = previous_key.map(|previous_key| previous_key.peek().as_slice());
         async {
-            let inner_decrypt = |inner| types::decrypt(inner, key.peek());
+            let inner_decrypt =
+                |inner| types::decrypt_with_fallback(inner, key.peek(), previous_key);
             Ok(Self {
                 id: other.id,
                 address_id: other.address_id,
@@ -1,5 +1,6 @@
 use common_utils::errors::{CustomResult, ValidationError};
 
+use super::MerchantKeyStore;
 use crate::pii::Secret;
 
 /// Trait for converting domain types to storage models
@@ -16,6 +17,17 @@ pub trait Conversion {
     where
         Self: Sized;
 
+    /// Same as `convert_back`, but each encrypted field which cannot be decrypted with `key` is
+    /// decrypted with `previous_key` instead, since a record may have been only partially
+    /// re-encrypted since the last key rotation.
+    async fn convert_back_with_previous_key(
+        item: Self::DstType,
+        key: &Secret<Vec<u8>>,
+        previous_key: Option<&Secret<Vec<u8>>>,
+    ) -> CustomResult<Self, ValidationError>
+    where
+        Self: Sized;
+
     async fn construct_new(self) -> CustomResult<Self::NewDstType, ValidationError>;
 }
 
@@ -30,3 +42,32 @@ impl<T: Send, U: Conversion<DstType = T>> ReverseConversion<U> for T {
         U::convert_back(self, key).await
     }
 }
+
+/// Trait for converting storage models encrypted with a merchant key back into domain types.
+/// Fields which have not been re-encrypted since the last key rotation are decrypted with the
+/// previous version of the key.
+#[async_trait::async_trait]
+pub trait KeyStoreReverseConversion<SrcType: Conversion> {
+    async fn convert_with_key_store(
+        self,
+        key_store: &MerchantKeyStore,
+    ) -> CustomResult<SrcType, ValidationError>;
+}
+
+#[async_trait::async_trait]
+impl<T: Send, U: Conversion<DstType = T>> KeyStoreReverseConversion<U> for T {
+    async fn convert_with_key_store(
+        self,
+        key_store: &MerchantKeyStore,
+    ) -> CustomResult<U, ValidationError> {
+        U::convert_back_with_previous_key(
+            self,
+            key_store.key.get_inner(),
+            key_store
+                .previous_key
+                .as_ref()
+                .map(|previous_key| previous_key.get_inner()),
+        )
+        .await
+    }
+}
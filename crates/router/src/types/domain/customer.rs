@@ -55,9 +55,23 @@ impl super::behaviour::Conversion for Customer {
     where
         Self: Sized,
     {
+        Self::convert_back_with_previous_key(item, key, None).await
+    }
+
+    async fn convert_back_with_previous_key(
+        item: Self::DstType,
+        key: &Secret<Vec<u8>>,
+        previous_key: Option<&Secret<Vec<u8>>>,
+    ) -> CustomResult<Self, ValidationError>
+    where
+        Self: Sized,
+    {
+        let previous_key = previous_key.map(|previous_key| previous_key.peek().as_slice());
         async {
-            let inner_decrypt = |inner| types::decrypt(inner, key.peek());
-            let inner_decrypt_email = |inner| types::decrypt(inner, key.peek());
+            let inner_decrypt =
+                |inner| types::decrypt_with_fallback(inner, key.peek(), previous_key);
+            let inner_decrypt_email =
+                |inner| types::decrypt_with_fallback(inner, key.peek(), previous_key);
             Ok(Self {
                 id: Some(item.id),
                 customer_id: item.customer_id,
@@ -189,6 +189,18 @@ impl super::behaviour::Conversion for MerchantAccount {
     where
         Self: Sized,
     {
+        Self::convert_back_with_previous_key(item, key, None).await
+    }
+
+    async fn convert_back_with_previous_key(
+        item: Self::DstType,
+        key: &Secret<Vec<u8>>,
+        previous_key: Option<&Secret<Vec<u8>>>,
+    ) -> CustomResult<Self, ValidationError>
+    where
+        Self: Sized,
+    {
+        let previous_key = previous_key.map(|previous_key| previous_key.peek().as_slice());
         async {
             Ok(Self {
                 id: Some(item.id),
@@ -199,11 +211,15 @@ impl super::behaviour::Conversion for MerchantAccount {
                 redirect_to_merchant_with_http_post: item.redirect_to_merchant_with_http_post,
                 merchant_name: item
                     .merchant_name
-                    .async_lift(|inner| types::decrypt(inner, key.peek()))
+                    .async_lift(|inner| {
+                        types::decrypt_with_fallback(inner, key.peek(), previous_key)
+                    })
                     .await?,
                 merchant_details: item
                     .merchant_details
-                    .async_lift(|inner| types::decrypt(inner, key.peek()))
+                    .async_lift(|inner| {
+                        types::decrypt_with_fallback(inner, key.peek(), previous_key)
+                    })
                     .await?,
                 webhook_details: item.webhook_details,
                 sub_merchants_enabled: item.sub_merchants_enabled,
@@ -97,19 +97,49 @@ impl behaviour::Conversion for MerchantConnectorAccount {
         other: Self::DstType,
         key: &Secret<Vec<u8>>,
     ) -> CustomResult<Self, ValidationError> {
+        Self::convert_back_with_previous_key(other, key, None).await
+    }
+
+    async fn convert_back_with_previous_key(
+        other: Self::DstType,
+        key: &Secret<Vec<u8>>,
+        previous_key: Option<&Secret<Vec<u8>>>,
+    ) -> CustomResult<Self, ValidationError> {
+        let connector_account_details: Encryptable<Secret<serde_json::Value>> =
+            match previous_key {
+                Some(previous_key) => {
+                    match Encryptable::decrypt(
+                        other.connector_account_details.clone(),
+                        key.peek(),
+                        GcmAes256,
+                    )
+                    .await
+                    {
+                        Ok(connector_account_details) => Ok(connector_account_details),
+                        Err(_) => {
+                            Encryptable::decrypt(
+                                other.connector_account_details,
+                                previous_key.peek(),
+                                GcmAes256,
+                            )
+                            .await
+                        }
+                    }
+                }
+                None => {
+                    Encryptable::decrypt(other.connector_account_details, key.peek(), GcmAes256)
+                        .await
+                }
+            }
+            .change_context(ValidationError::InvalidValue {
+                message: "Failed while decrypting connector account details".to_string(),
+            })?;
+
         Ok(Self {
             id: Some(other.id),
             merchant_id: other.merchant_id,
             connector_name: other.connector_name,
-            connector_account_details: Encryptable::decrypt(
-                other.connector_account_details,
-                key.peek(),
-                GcmAes256,
-            )
-            .await
-            .change_context(ValidationError::InvalidValue {
-                message: "Failed while decrypting connector account details".to_string(),
-            })?,
+            connector_account_details,
             test_mode: other.test_mode,
             disabled: other.disabled,
             merchant_connector_id: other.merchant_connector_id,
@@ -2,13 +2,14 @@ use common_utils::{
     crypto::{Encryptable, GcmAes256},
     custom_serde, date_time,
 };
+use diesel_models::merchant_key_store::MerchantKeyStoreUpdateInternal;
 use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
 use time::PrimitiveDateTime;
 
 use crate::{
     errors::{CustomResult, ValidationError},
-    types::domain::types::TypeEncryption,
+    types::domain::types::{self, AsyncLift, TypeEncryption},
 };
 
 #[derive(Clone, Debug, serde::Serialize)]
@@ -17,6 +18,35 @@ pub struct MerchantKeyStore {
     pub key: Encryptable<Secret<Vec<u8>>>,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    pub version: i32,
+    /// The key which was in use before the last rotation, kept to read the data which has not
+    /// been re-encrypted yet
+    pub previous_key: Option<Encryptable<Secret<Vec<u8>>>>,
+}
+
+#[derive(Debug)]
+pub enum MerchantKeyStoreUpdate {
+    KeyRotation {
+        key: Encryptable<Secret<Vec<u8>>>,
+        previous_key: Encryptable<Secret<Vec<u8>>>,
+        version: i32,
+    },
+}
+
+impl From<MerchantKeyStoreUpdate> for MerchantKeyStoreUpdateInternal {
+    fn from(merchant_key_store_update: MerchantKeyStoreUpdate) -> Self {
+        match merchant_key_store_update {
+            MerchantKeyStoreUpdate::KeyRotation {
+                key,
+                previous_key,
+                version,
+            } => Self {
+                key: key.into(),
+                version,
+                previous_key: Some(previous_key.into()),
+            },
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -28,6 +58,8 @@ impl super::behaviour::Conversion for MerchantKeyStore {
             key: self.key.into(),
             merchant_id: self.merchant_id,
             created_at: self.created_at,
+            version: self.version,
+            previous_key: self.previous_key.map(Into::into),
         })
     }
 
@@ -46,14 +78,36 @@ impl super::behaviour::Conversion for MerchantKeyStore {
                 })?,
             merchant_id: item.merchant_id,
             created_at: item.created_at,
+            version: item.version,
+            previous_key: item
+                .previous_key
+                .async_lift(|inner| types::decrypt(inner, key.peek()))
+                .await
+                .change_context(ValidationError::InvalidValue {
+                    message: "Failed while decrypting previous merchant key".to_string(),
+                })?,
         })
     }
 
+    async fn convert_back_with_previous_key(
+        item: Self::DstType,
+        key: &Secret<Vec<u8>>,
+        _previous_key: Option<&Secret<Vec<u8>>>,
+    ) -> CustomResult<Self, ValidationError>
+    where
+        Self: Sized,
+    {
+        // The merchant key store is encrypted with the master key, which is not rotated along
+        // with the merchant key
+        Self::convert_back(item, key).await
+    }
+
     async fn construct_new(self) -> CustomResult<Self::NewDstType, ValidationError> {
         Ok(diesel_models::merchant_key_store::MerchantKeyStoreNew {
             merchant_id: self.merchant_id,
             key: self.key.into(),
             created_at: date_time::now(),
+            version: self.version,
         })
     }
 }
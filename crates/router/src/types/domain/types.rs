@@ -226,3 +226,23 @@ where
     .await
     .transpose()
 }
+
+/// Decrypts `inner` with `key`, falling back to `previous_key` for data which was encrypted
+/// before the last rotation of the merchant key and has not been re-encrypted yet.
+#[inline]
+pub async fn decrypt_with_fallback<T: Clone, S: masking::Strategy<T>>(
+    inner: Option<Encryption>,
+    key: &[u8],
+    previous_key: Option<&[u8]>,
+) -> CustomResult<Option<crypto::Encryptable<Secret<T, S>>>, errors::CryptoError>
+where
+    crypto::Encryptable<Secret<T, S>>: TypeEncryption<T, crypto::GcmAes256, S>,
+{
+    match previous_key {
+        Some(previous_key) => match decrypt(inner.clone(), key).await {
+            Ok(decrypted) => Ok(decrypted),
+            Err(_) => decrypt(inner, previous_key).await,
+        },
+        None => decrypt(inner, key).await,
+    }
+}
@@ -11,6 +11,7 @@ pub mod enums;
 pub mod ephemeral_key;
 pub mod events;
 pub mod file;
+pub mod incremental_authorization;
 #[cfg(feature = "kv_store")]
 pub mod kv;
 pub mod locker_mock_up;
@@ -29,6 +30,8 @@ pub mod payout_attempt;
 pub mod payouts;
 mod query;
 pub mod refund;
+pub mod subscription;
+pub mod webhook_delivery_attempt;
 
 pub use data_models::payments::{
     payment_attempt::{PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate},
@@ -38,10 +41,10 @@ pub use data_models::payments::{
 
 pub use self::{
     address::*, api_keys::*, capture::*, cards_info::*, configs::*, connector_response::*,
-    customers::*, dispute::*, ephemeral_key::*, events::*, file::*, locker_mock_up::*, mandate::*,
-    merchant_account::*, merchant_connector_account::*, merchant_key_store::*, payment_link::*,
-    payment_method::*, payout_attempt::*, payouts::*, process_tracker::*, refund::*,
-    reverse_lookup::*,
+    customers::*, dispute::*, ephemeral_key::*, events::*, file::*, incremental_authorization::*,
+    locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
+    merchant_key_store::*, payment_link::*, payment_method::*, payout_attempt::*, payouts::*,
+    process_tracker::*, refund::*, reverse_lookup::*, subscription::*, webhook_delivery_attempt::*,
 };
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
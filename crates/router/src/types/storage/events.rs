@@ -1 +1,67 @@
-pub use diesel_models::events::{Event, EventNew, EventUpdate};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+pub use diesel_models::events::{Event, EventNew, EventUpdate, OutgoingWebhookTrackingData};
+use diesel_models::{errors, query::generics::db_metrics, schema::events::dsl};
+use error_stack::{IntoReport, ResultExt};
+
+use crate::{connection::PgPooledConn, logger};
+
+#[async_trait::async_trait]
+pub trait EventDbExt: Sized {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        event_list_constraints: api_models::webhook_events::EventListConstraints,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl EventDbExt for Event {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        event_list_constraints: api_models::webhook_events::EventListConstraints,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(profile_id) = event_list_constraints.profile_id {
+            filter = filter.filter(dsl::business_profile_id.eq(profile_id));
+        }
+        if let Some(object_id) = event_list_constraints.object_id {
+            filter = filter.filter(dsl::primary_object_id.eq(object_id));
+        }
+        if let Some(event_type) = event_list_constraints.event_type {
+            filter = filter.filter(dsl::event_type.eq(event_type));
+        }
+        if let Some(is_delivered) = event_list_constraints.is_delivered {
+            filter = filter.filter(dsl::is_webhook_notified.eq(is_delivered));
+        }
+        if let Some(created_lt) = event_list_constraints.created_lt {
+            filter = filter.filter(dsl::created_at.lt(created_lt));
+        }
+        if let Some(created_gt) = event_list_constraints.created_gt {
+            filter = filter.filter(dsl::created_at.gt(created_gt));
+        }
+        if let Some(limit) = event_list_constraints.limit {
+            filter = filter.limit(limit);
+        }
+        if let Some(offset) = event_list_constraints.offset {
+            filter = filter.offset(offset);
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::NotFound)
+        .attach_printable_lazy(|| "Error filtering records by predicate")
+    }
+}
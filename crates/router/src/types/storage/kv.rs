@@ -1,4 +1,6 @@
 pub use diesel_models::kv::{
-    AddressUpdateMems, ConnectorResponseUpdateMems, DBOperation, Insertable,
-    PaymentAttemptUpdateMems, PaymentIntentUpdateMems, RefundUpdateMems, TypedSql, Updateable,
+    AddressUpdateMems, CaptureUpdateMems, ConnectorResponseUpdateMems, DBOperation,
+    DisputeUpdateMems, EventUpdateMems, Insertable, MandateUpdateMems, PaymentAttemptUpdateMems,
+    PaymentIntentUpdateMems, PayoutAttemptUpdateMems, PayoutsUpdateMems, RefundUpdateMems,
+    TypedSql, Updateable,
 };
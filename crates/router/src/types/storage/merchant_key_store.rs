@@ -1 +1,3 @@
-pub use diesel_models::merchant_key_store::MerchantKeyStore;
+pub use diesel_models::merchant_key_store::{
+    KeyRotationStage, KeyRotationTrackingData, MerchantKeyStore,
+};
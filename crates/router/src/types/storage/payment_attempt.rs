@@ -1,7 +1,9 @@
 pub use data_models::payments::payment_attempt::{
     PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate,
 };
-use diesel_models::{capture::CaptureNew, enums};
+use diesel_models::{
+    capture::CaptureNew, enums, incremental_authorization::IncrementalAuthorizationNew,
+};
 use error_stack::ResultExt;
 
 use crate::{
@@ -16,6 +18,12 @@ pub trait PaymentAttemptExt {
     ) -> RouterResult<CaptureNew>;
 
     fn get_next_capture_id(&self) -> String;
+    fn make_new_incremental_authorization(
+        &self,
+        amount: i64,
+        reason: Option<String>,
+        authorization_count: usize,
+    ) -> IncrementalAuthorizationNew;
     fn get_intent_status(&self, amount_captured: Option<i64>) -> enums::IntentStatus;
     fn get_total_amount(&self) -> i64;
 }
@@ -60,6 +68,30 @@ impl PaymentAttemptExt for PaymentAttempt {
         format!("{}_{}", self.attempt_id.clone(), next_sequence_number)
     }
 
+    fn make_new_incremental_authorization(
+        &self,
+        amount: i64,
+        reason: Option<String>,
+        authorization_count: usize,
+    ) -> IncrementalAuthorizationNew {
+        let now = common_utils::date_time::now();
+        IncrementalAuthorizationNew {
+            authorization_id: format!("{}_auth_{}", self.attempt_id, authorization_count + 1),
+            merchant_id: self.merchant_id.clone(),
+            payment_id: self.payment_id.clone(),
+            attempt_id: self.attempt_id.clone(),
+            amount,
+            previous_amount: self.amount,
+            status: enums::AuthorizationStatus::Processing,
+            reason,
+            error_code: None,
+            error_message: None,
+            connector_authorization_id: None,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
     fn get_intent_status(&self, amount_captured: Option<i64>) -> enums::IntentStatus {
         let intent_status = enums::IntentStatus::foreign_from(self.status);
         if intent_status == enums::IntentStatus::Cancelled && amount_captured > Some(0) {
@@ -1 +1,69 @@
-pub use diesel_models::payment_link::{PaymentLink, PaymentLinkNew};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+pub use diesel_models::payment_link::{
+    PaymentLink, PaymentLinkNew, PaymentLinkTrackingData, PaymentLinkUpdate,
+};
+use diesel_models::{errors, query::generics::db_metrics, schema::payment_link::dsl};
+use error_stack::{IntoReport, ResultExt};
+
+use crate::{connection::PgPooledConn, logger};
+
+#[async_trait::async_trait]
+pub trait PaymentLinkDbExt: Sized {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_link_list_constraints: api_models::payments::PaymentLinkListConstraints,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentLinkDbExt for PaymentLink {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_link_list_constraints: api_models::payments::PaymentLinkListConstraints,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(payment_id) = payment_link_list_constraints.payment_id {
+            filter = filter.filter(dsl::payment_id.eq(payment_id));
+        }
+        if let Some(status) = payment_link_list_constraints.status {
+            filter = filter.filter(dsl::status.eq(status));
+        }
+        if let Some(created) = payment_link_list_constraints.created {
+            filter = filter.filter(dsl::created_at.eq(created));
+        }
+        if let Some(created_lt) = payment_link_list_constraints.created_lt {
+            filter = filter.filter(dsl::created_at.lt(created_lt));
+        }
+        if let Some(created_gt) = payment_link_list_constraints.created_gt {
+            filter = filter.filter(dsl::created_at.gt(created_gt));
+        }
+        if let Some(created_lte) = payment_link_list_constraints.created_lte {
+            filter = filter.filter(dsl::created_at.le(created_lte));
+        }
+        if let Some(created_gte) = payment_link_list_constraints.created_gte {
+            filter = filter.filter(dsl::created_at.ge(created_gte));
+        }
+        if let Some(limit) = payment_link_list_constraints.limit {
+            filter = filter.limit(limit);
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::NotFound)
+        .attach_printable_lazy(|| "Error filtering records by predicate")
+    }
+}
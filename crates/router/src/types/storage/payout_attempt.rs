@@ -1,9 +1,86 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
 pub use diesel_models::payout_attempt::{
     PayoutAttempt, PayoutAttemptNew, PayoutAttemptUpdate, PayoutAttemptUpdateInternal,
 };
+use diesel_models::{
+    enums::{Currency, PayoutStatus, PayoutType},
+    errors,
+    payouts::Payouts,
+    schema::payout_attempt::dsl,
+};
+use error_stack::{IntoReport, ResultExt};
+
+use crate::connection::PgPooledConn;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct PayoutRoutingData {
     pub routed_through: Option<String>,
     pub algorithm: Option<api_models::admin::PayoutStraightThroughAlgorithm>,
 }
+
+#[async_trait::async_trait]
+pub trait PayoutAttemptDbExt: Sized {
+    async fn get_filters_for_payouts(
+        conn: &PgPooledConn,
+        payouts: &[Payouts],
+        merchant_id: &str,
+    ) -> CustomResult<api_models::payouts::PayoutListFilters, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutAttemptDbExt for PayoutAttempt {
+    async fn get_filters_for_payouts(
+        conn: &PgPooledConn,
+        payouts: &[Payouts],
+        merchant_id: &str,
+    ) -> CustomResult<api_models::payouts::PayoutListFilters, errors::DatabaseError> {
+        let payout_ids = payouts
+            .iter()
+            .map(|payout| payout.payout_id.clone())
+            .collect::<Vec<String>>();
+
+        let filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::payout_id.eq_any(payout_ids));
+
+        let filter_connector: Vec<String> = filter
+            .clone()
+            .select(dsl::connector)
+            .distinct()
+            .order_by(dsl::connector.asc())
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering records by connector")?;
+
+        let filter_status: Vec<PayoutStatus> = filter
+            .select(dsl::status)
+            .distinct()
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering records by payout status")?;
+
+        let mut filter_currency: Vec<Currency> = Vec::new();
+        let mut filter_payout_type: Vec<PayoutType> = Vec::new();
+        for payout in payouts {
+            if !filter_currency.contains(&payout.destination_currency) {
+                filter_currency.push(payout.destination_currency);
+            }
+            if !filter_payout_type.contains(&payout.payout_type) {
+                filter_payout_type.push(payout.payout_type);
+            }
+        }
+
+        Ok(api_models::payouts::PayoutListFilters {
+            connector: filter_connector,
+            currency: filter_currency,
+            status: filter_status,
+            payout_type: filter_payout_type,
+        })
+    }
+}
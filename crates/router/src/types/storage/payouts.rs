@@ -1 +1,243 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{associations::HasTable, ExpressionMethods, JoinOnDsl, QueryDsl};
 pub use diesel_models::payouts::{Payouts, PayoutsNew, PayoutsUpdate, PayoutsUpdateInternal};
+use diesel_models::{
+    errors,
+    payout_attempt::PayoutAttempt,
+    query::generics::db_metrics,
+    schema::{payout_attempt::dsl as poa_dsl, payouts::dsl},
+};
+use error_stack::{IntoReport, ResultExt};
+
+use crate::{connection::PgPooledConn, logger};
+
+#[async_trait::async_trait]
+pub trait PayoutsDbExt: Sized {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: &api_models::payouts::PayoutListConstraints,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+
+    async fn filter_by_time_range(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        time_range: &api_models::payments::TimeRange,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+
+    async fn filter_with_attempts_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: &api_models::payouts::PayoutListFilterConstraints,
+    ) -> CustomResult<Vec<(Self, PayoutAttempt)>, errors::DatabaseError>;
+
+    async fn get_filtered_payouts_count(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: &api_models::payouts::PayoutListFilterConstraints,
+    ) -> CustomResult<i64, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutsDbExt for Payouts {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: &api_models::payouts::PayoutListConstraints,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .limit(constraints.limit.into())
+            .into_boxed();
+
+        if let Some(customer_id) = &constraints.customer_id {
+            filter = filter.filter(dsl::customer_id.eq(customer_id.to_owned()));
+        }
+        if let Some(starting_after) = &constraints.starting_after {
+            let starting_at =
+                Self::find_by_merchant_id_payout_id(conn, merchant_id, starting_after)
+                    .await?
+                    .created_at;
+            filter = filter.filter(dsl::created_at.ge(starting_at));
+        }
+        if let Some(ending_before) = &constraints.ending_before {
+            let ending_at = Self::find_by_merchant_id_payout_id(conn, merchant_id, ending_before)
+                .await?
+                .created_at;
+            filter = filter.filter(dsl::created_at.le(ending_at));
+        }
+        if let Some(created) = constraints.created {
+            filter = filter.filter(dsl::created_at.eq(created));
+        }
+        if let Some(created_lt) = constraints.created_lt {
+            filter = filter.filter(dsl::created_at.lt(created_lt));
+        }
+        if let Some(created_gt) = constraints.created_gt {
+            filter = filter.filter(dsl::created_at.gt(created_gt));
+        }
+        if let Some(created_lte) = constraints.created_lte {
+            filter = filter.filter(dsl::created_at.le(created_lte));
+        }
+        if let Some(created_gte) = constraints.created_gte {
+            filter = filter.filter(dsl::created_at.ge(created_gte));
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::NotFound)
+        .attach_printable_lazy(|| "Error filtering payouts by predicate")
+    }
+
+    async fn filter_by_time_range(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        time_range: &api_models::payments::TimeRange,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        let end_time = time_range
+            .end_time
+            .unwrap_or_else(common_utils::date_time::now);
+
+        let filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::created_at.ge(time_range.start_time))
+            .filter(dsl::created_at.le(end_time))
+            .order(dsl::created_at.desc());
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::NotFound)
+        .attach_printable_lazy(|| "Error filtering payouts by time range")
+    }
+
+    async fn filter_with_attempts_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: &api_models::payouts::PayoutListFilterConstraints,
+    ) -> CustomResult<Vec<(Self, PayoutAttempt)>, errors::DatabaseError> {
+        let mut filter = <Self as HasTable>::table()
+            .inner_join(
+                diesel_models::schema::payout_attempt::table
+                    .on(poa_dsl::payout_id.eq(dsl::payout_id)),
+            )
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .limit(constraints.limit.into())
+            .offset(constraints.offset.unwrap_or_default().into())
+            .into_boxed();
+
+        if let Some(payout_id) = &constraints.payout_id {
+            filter = filter.filter(dsl::payout_id.eq(payout_id.to_owned()));
+        }
+        if let Some(profile_id) = &constraints.profile_id {
+            filter = filter.filter(poa_dsl::profile_id.eq(profile_id.to_owned()));
+        }
+        if let Some(customer_id) = &constraints.customer_id {
+            filter = filter.filter(dsl::customer_id.eq(customer_id.to_owned()));
+        }
+        if let Some(time_range) = constraints.time_range {
+            filter = filter.filter(dsl::created_at.ge(time_range.start_time));
+
+            if let Some(end_time) = time_range.end_time {
+                filter = filter.filter(dsl::created_at.le(end_time));
+            }
+        }
+        if let Some(connector) = &constraints.connector {
+            let connector = connector
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>();
+            filter = filter.filter(poa_dsl::connector.eq_any(connector));
+        }
+        if let Some(currency) = &constraints.currency {
+            filter = filter.filter(dsl::destination_currency.eq_any(currency.clone()));
+        }
+        if let Some(status) = &constraints.status {
+            filter = filter.filter(poa_dsl::status.eq_any(status.clone()));
+        }
+        if let Some(payout_type) = &constraints.payout_type {
+            filter = filter.filter(dsl::payout_type.eq_any(payout_type.clone()));
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async::<(Self, PayoutAttempt)>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::NotFound)
+        .attach_printable_lazy(|| "Error filtering payouts and payout attempts by predicate")
+    }
+
+    async fn get_filtered_payouts_count(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: &api_models::payouts::PayoutListFilterConstraints,
+    ) -> CustomResult<i64, errors::DatabaseError> {
+        let mut filter = <Self as HasTable>::table()
+            .inner_join(
+                diesel_models::schema::payout_attempt::table
+                    .on(poa_dsl::payout_id.eq(dsl::payout_id)),
+            )
+            .count()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .into_boxed();
+
+        if let Some(payout_id) = &constraints.payout_id {
+            filter = filter.filter(dsl::payout_id.eq(payout_id.to_owned()));
+        }
+        if let Some(profile_id) = &constraints.profile_id {
+            filter = filter.filter(poa_dsl::profile_id.eq(profile_id.to_owned()));
+        }
+        if let Some(customer_id) = &constraints.customer_id {
+            filter = filter.filter(dsl::customer_id.eq(customer_id.to_owned()));
+        }
+        if let Some(time_range) = constraints.time_range {
+            filter = filter.filter(dsl::created_at.ge(time_range.start_time));
+
+            if let Some(end_time) = time_range.end_time {
+                filter = filter.filter(dsl::created_at.le(end_time));
+            }
+        }
+        if let Some(connector) = &constraints.connector {
+            let connector = connector
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>();
+            filter = filter.filter(poa_dsl::connector.eq_any(connector));
+        }
+        if let Some(currency) = &constraints.currency {
+            filter = filter.filter(dsl::destination_currency.eq_any(currency.clone()));
+        }
+        if let Some(status) = &constraints.status {
+            filter = filter.filter(poa_dsl::status.eq_any(status.clone()));
+        }
+        if let Some(payout_type) = &constraints.payout_type {
+            filter = filter.filter(dsl::payout_type.eq_any(payout_type.clone()));
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        filter
+            .get_result_async::<i64>(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::NotFound)
+            .attach_printable_lazy(|| "Error filtering count of payouts")
+    }
+}
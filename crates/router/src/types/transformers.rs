@@ -85,6 +85,9 @@ impl ForeignFrom<storage_enums::AttemptStatus> for storage_enums::IntentStatus {
             storage_enums::AttemptStatus::Unresolved => Self::RequiresMerchantAction,
 
             storage_enums::AttemptStatus::PartialCharged => Self::PartiallyCaptured,
+            storage_enums::AttemptStatus::PartialChargedAndChargeable => {
+                Self::PartiallyCapturedAndCapturable
+            }
             storage_enums::AttemptStatus::Started
             | storage_enums::AttemptStatus::AuthenticationSuccessful
             | storage_enums::AttemptStatus::Authorizing
@@ -112,7 +115,8 @@ impl ForeignTryFrom<storage_enums::AttemptStatus> for storage_enums::CaptureStat
     ) -> errors::RouterResult<Self> {
         match attempt_status {
             storage_enums::AttemptStatus::Charged
-            | storage_enums::AttemptStatus::PartialCharged => Ok(Self::Charged),
+            | storage_enums::AttemptStatus::PartialCharged
+            | storage_enums::AttemptStatus::PartialChargedAndChargeable => Ok(Self::Charged),
             storage_enums::AttemptStatus::Pending
             | storage_enums::AttemptStatus::CaptureInitiated => Ok(Self::Pending),
             storage_enums::AttemptStatus::Failure
@@ -136,7 +140,7 @@ impl ForeignTryFrom<storage_enums::AttemptStatus> for storage_enums::CaptureStat
             | storage_enums::AttemptStatus::ConfirmationAwaited
             | storage_enums::AttemptStatus::DeviceDataCollectionPending => {
                 Err(errors::ApiErrorResponse::PreconditionFailed {
-                    message: "AttemptStatus must be one of these for multiple partial captures [Charged, PartialCharged, Pending, CaptureInitiated, Failure, CaptureFailed]".into(),
+                    message: "AttemptStatus must be one of these for multiple partial captures [Charged, PartialCharged, PartialChargedAndChargeable, Pending, CaptureInitiated, Failure, CaptureFailed]".into(),
                 }.into())
             }
         }
@@ -177,6 +181,9 @@ impl ForeignFrom<storage_enums::MandateAmountData> for api_models::payments::Man
             start_date: from.start_date,
             end_date: from.end_date,
             metadata: from.metadata,
+            max_amount_per_transaction: from.max_amount_per_transaction,
+            frequency: from.frequency,
+            max_debits_per_frequency: from.max_debits_per_frequency,
         }
     }
 }
@@ -211,6 +218,9 @@ impl ForeignFrom<api_models::payments::MandateData> for data_models::mandates::M
                             start_date: i.start_date,
                             end_date: i.end_date,
                             metadata: i.metadata,
+                            max_amount_per_transaction: i.max_amount_per_transaction,
+                            frequency: i.frequency,
+                            max_debits_per_frequency: i.max_debits_per_frequency,
                         },
                     ))
                 }
@@ -222,6 +232,9 @@ impl ForeignFrom<api_models::payments::MandateData> for data_models::mandates::M
                             start_date: i.start_date,
                             end_date: i.end_date,
                             metadata: i.metadata,
+                            max_amount_per_transaction: i.max_amount_per_transaction,
+                            frequency: i.frequency,
+                            max_debits_per_frequency: i.max_debits_per_frequency,
                         },
                     )
                 }
@@ -241,6 +254,9 @@ impl ForeignFrom<api_models::payments::MandateAmountData> for storage_enums::Man
             start_date: from.start_date,
             end_date: from.end_date,
             metadata: from.metadata,
+            max_amount_per_transaction: from.max_amount_per_transaction,
+            frequency: from.frequency,
+            max_debits_per_frequency: from.max_debits_per_frequency,
         }
     }
 }
@@ -261,7 +277,8 @@ impl ForeignFrom<api_enums::IntentStatus> for Option<storage_enums::EventType> {
             api_enums::IntentStatus::RequiresPaymentMethod
             | api_enums::IntentStatus::RequiresConfirmation
             | api_enums::IntentStatus::RequiresCapture
-            | api_enums::IntentStatus::PartiallyCaptured => None,
+            | api_enums::IntentStatus::PartiallyCaptured
+            | api_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
         }
     }
 }
@@ -420,6 +437,25 @@ impl ForeignFrom<storage_enums::MandateStatus> for Option<storage_enums::EventTy
     }
 }
 
+impl ForeignFrom<storage_enums::PayoutStatus> for Option<storage_enums::EventType> {
+    fn foreign_from(value: storage_enums::PayoutStatus) -> Self {
+        match value {
+            storage_enums::PayoutStatus::Pending => Some(storage_enums::EventType::PayoutInitiated),
+            storage_enums::PayoutStatus::Success => Some(storage_enums::EventType::PayoutSuccess),
+            storage_enums::PayoutStatus::Failed | storage_enums::PayoutStatus::Ineligible => {
+                Some(storage_enums::EventType::PayoutFailed)
+            }
+            storage_enums::PayoutStatus::Cancelled => {
+                Some(storage_enums::EventType::PayoutCancelled)
+            }
+            storage_enums::PayoutStatus::Reversed => Some(storage_enums::EventType::PayoutReversed),
+            storage_enums::PayoutStatus::RequiresCreation
+            | storage_enums::PayoutStatus::RequiresPayoutMethodData
+            | storage_enums::PayoutStatus::RequiresFulfillment => None,
+        }
+    }
+}
+
 impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::RefundStatus {
     type Error = errors::ValidationError;
 
@@ -452,6 +488,48 @@ impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enum
     }
 }
 
+impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent>
+    for storage_enums::AuthorizationStatus
+{
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(
+        value: api_models::webhooks::IncomingWebhookEvent,
+    ) -> Result<Self, Self::Error> {
+        match value {
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentAuthorizationSuccess => {
+                Ok(Self::Success)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentAuthorizationFailure => {
+                Ok(Self::Failure)
+            }
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "incoming_webhook_event_type",
+            }),
+        }
+    }
+}
+
+impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::PayoutStatus {
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(
+        value: api_models::webhooks::IncomingWebhookEvent,
+    ) -> Result<Self, Self::Error> {
+        match value {
+            api_models::webhooks::IncomingWebhookEvent::PayoutSuccess => Ok(Self::Success),
+            api_models::webhooks::IncomingWebhookEvent::PayoutFailure
+            | api_models::webhooks::IncomingWebhookEvent::PayoutExpired => Ok(Self::Failed),
+            api_models::webhooks::IncomingWebhookEvent::PayoutProcessing => Ok(Self::Pending),
+            api_models::webhooks::IncomingWebhookEvent::PayoutCancelled => Ok(Self::Cancelled),
+            api_models::webhooks::IncomingWebhookEvent::PayoutReversed => Ok(Self::Reversed),
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "incoming_webhook_event_type",
+            }),
+        }
+    }
+}
+
 impl ForeignFrom<storage::Config> for api_types::Config {
     fn foreign_from(config: storage::Config) -> Self {
         Self {
@@ -514,6 +592,8 @@ impl
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            scopes: parse_api_key_scopes(api_key.scopes),
+            allowed_ips: api_key.allowed_ips,
         }
     }
 }
@@ -528,10 +608,23 @@ impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::Retr
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            scopes: parse_api_key_scopes(api_key.scopes),
+            allowed_ips: api_key.allowed_ips,
         }
     }
 }
 
+fn parse_api_key_scopes(
+    scopes: Option<Vec<String>>,
+) -> Option<Vec<api_models::api_keys::ApiKeyScope>> {
+    scopes.map(|scopes| {
+        scopes
+            .iter()
+            .filter_map(|scope| scope.parse().ok())
+            .collect()
+    })
+}
+
 impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
     for diesel_models::api_keys::ApiKeyUpdate
 {
@@ -541,6 +634,13 @@ impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
             description: api_key.description,
             expires_at: api_key.expiration.map(Into::into),
             last_used: None,
+            scopes: api_key
+                .scopes
+                .map(|scopes| scopes.iter().map(ToString::to_string).collect()),
+            // An empty list of IP addresses removes the restriction on the IP addresses
+            allowed_ips: api_key
+                .allowed_ips
+                .map(|allowed_ips| (!allowed_ips.is_empty()).then_some(allowed_ips)),
         }
     }
 }
@@ -595,6 +695,40 @@ impl ForeignFrom<storage::Dispute> for api_models::disputes::DisputeResponse {
     }
 }
 
+impl ForeignFrom<storage::Event> for api_models::webhook_events::EventListItemResponse {
+    fn foreign_from(event: storage::Event) -> Self {
+        Self {
+            event_id: event.event_id,
+            merchant_id: event.merchant_id,
+            profile_id: event.business_profile_id,
+            object_id: event.primary_object_id,
+            event_type: event.event_type,
+            is_delivered: event.is_webhook_notified,
+            created: event.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::WebhookDeliveryAttempt>
+    for api_models::webhook_events::WebhookDeliveryAttemptResponse
+{
+    fn foreign_from(delivery_attempt: storage::WebhookDeliveryAttempt) -> Self {
+        Self {
+            attempt_id: delivery_attempt.attempt_id,
+            event_id: delivery_attempt.event_id,
+            delivery_attempt: delivery_attempt.delivery_attempt,
+            is_success: delivery_attempt.is_success,
+            response_status_code: delivery_attempt
+                .response_status_code
+                .and_then(|status_code| u16::try_from(status_code).ok()),
+            response_body: delivery_attempt.response_body,
+            error_message: delivery_attempt.error_message,
+            latency_ms: delivery_attempt.latency_ms,
+            created: delivery_attempt.created_at,
+        }
+    }
+}
+
 impl ForeignFrom<storage::Dispute> for api_models::disputes::DisputeResponsePaymentsRetrieve {
     fn foreign_from(dispute: storage::Dispute) -> Self {
         Self {
@@ -741,6 +875,22 @@ impl ForeignFrom<storage::Capture> for api_models::payments::CaptureResponse {
     }
 }
 
+impl ForeignFrom<storage::IncrementalAuthorization>
+    for api_models::payments::IncrementalAuthorizationResponse
+{
+    fn foreign_from(authorization: storage::IncrementalAuthorization) -> Self {
+        Self {
+            authorization_id: authorization.authorization_id,
+            amount: authorization.amount,
+            previous_amount: authorization.previous_amount,
+            status: authorization.status,
+            reason: authorization.reason,
+            error_code: authorization.error_code,
+            error_message: authorization.error_message,
+        }
+    }
+}
+
 impl ForeignFrom<api_models::payouts::Bank> for api_enums::PaymentMethodType {
     fn foreign_from(value: api_models::payouts::Bank) -> Self {
         match value {
@@ -842,6 +992,33 @@ impl ForeignFrom<storage::PaymentLink> for api_models::payments::RetrievePayment
             created_at: payment_link_object.created_at,
             last_modified_at: payment_link_object.last_modified_at,
             link_expiry: payment_link_object.fulfilment_time,
+            status: payment_link_object.status,
+        }
+    }
+}
+
+impl ForeignFrom<storage::Subscription> for api_models::subscriptions::SubscriptionResponse {
+    fn foreign_from(subscription: storage::Subscription) -> Self {
+        Self {
+            subscription_id: subscription.subscription_id,
+            merchant_id: subscription.merchant_id,
+            customer_id: subscription.customer_id,
+            mandate_id: subscription.mandate_id,
+            profile_id: subscription.profile_id,
+            status: subscription.status,
+            amount: subscription.amount,
+            currency: subscription.currency,
+            interval: subscription.billing_interval,
+            interval_count: subscription.interval_count,
+            trial_end: subscription.trial_end,
+            current_period_start: subscription.current_period_start,
+            current_period_end: subscription.current_period_end,
+            next_billing_at: subscription.next_billing_at,
+            latest_payment_id: subscription.latest_payment_id,
+            description: subscription.description,
+            metadata: subscription.metadata,
+            cancelled_at: subscription.cancelled_at,
+            created_at: subscription.created_at,
         }
     }
 }
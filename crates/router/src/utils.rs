@@ -42,7 +42,7 @@ use crate::{
             types::{encrypt_optional, AsyncLift},
         },
         storage,
-        transformers::{ForeignTryFrom, ForeignTryInto},
+        transformers::{ForeignInto, ForeignTryFrom, ForeignTryInto},
     },
 };
 
@@ -303,6 +303,7 @@ pub async fn find_payment_intent_from_mandate_id_type(
             .find_mandate_by_merchant_id_mandate_id(
                 &merchant_account.merchant_id,
                 mandate_id.as_str(),
+                merchant_account.storage_scheme,
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?,
@@ -310,6 +311,7 @@ pub async fn find_payment_intent_from_mandate_id_type(
             .find_mandate_by_merchant_id_connector_mandate_id(
                 &merchant_account.merchant_id,
                 connector_mandate_id.as_str(),
+                merchant_account.storage_scheme,
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?,
@@ -327,6 +329,31 @@ pub async fn find_payment_intent_from_mandate_id_type(
     .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
 }
 
+pub async fn find_payout_attempt_from_payout_id_type(
+    db: &dyn StorageInterface,
+    payout_id_type: webhooks::PayoutIdType,
+    merchant_account: &domain::MerchantAccount,
+) -> CustomResult<storage::PayoutAttempt, errors::ApiErrorResponse> {
+    match payout_id_type {
+        webhooks::PayoutIdType::PayoutId(payout_id) => db
+            .find_payout_attempt_by_merchant_id_payout_id(
+                &merchant_account.merchant_id,
+                &payout_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound),
+        webhooks::PayoutIdType::ConnectorPayoutId(connector_payout_id) => db
+            .find_payout_attempt_by_merchant_id_connector_payout_id(
+                &merchant_account.merchant_id,
+                &connector_payout_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound),
+    }
+}
+
 pub async fn get_profile_id_using_object_reference_id(
     db: &dyn StorageInterface,
     object_reference_id: webhooks::ObjectReferenceId,
@@ -336,37 +363,73 @@ pub async fn get_profile_id_using_object_reference_id(
     match merchant_account.default_profile.as_ref() {
         Some(profile_id) => Ok(profile_id.clone()),
         _ => {
-            let payment_intent = match object_reference_id {
+            let (business_country, business_label, profile_id) = match object_reference_id {
                 webhooks::ObjectReferenceId::PaymentId(payment_id_type) => {
-                    find_payment_intent_from_payment_id_type(db, payment_id_type, merchant_account)
-                        .await?
+                    let payment_intent = find_payment_intent_from_payment_id_type(
+                        db,
+                        payment_id_type,
+                        merchant_account,
+                    )
+                    .await?;
+                    (
+                        payment_intent.business_country,
+                        payment_intent.business_label,
+                        payment_intent.profile_id,
+                    )
                 }
                 webhooks::ObjectReferenceId::RefundId(refund_id_type) => {
-                    find_payment_intent_from_refund_id_type(
+                    let payment_intent = find_payment_intent_from_refund_id_type(
                         db,
                         refund_id_type,
                         merchant_account,
                         connector_name,
                     )
-                    .await?
+                    .await?;
+                    (
+                        payment_intent.business_country,
+                        payment_intent.business_label,
+                        payment_intent.profile_id,
+                    )
                 }
                 webhooks::ObjectReferenceId::MandateId(mandate_id_type) => {
-                    find_payment_intent_from_mandate_id_type(db, mandate_id_type, merchant_account)
-                        .await?
+                    let payment_intent = find_payment_intent_from_mandate_id_type(
+                        db,
+                        mandate_id_type,
+                        merchant_account,
+                    )
+                    .await?;
+                    (
+                        payment_intent.business_country,
+                        payment_intent.business_label,
+                        payment_intent.profile_id,
+                    )
+                }
+                webhooks::ObjectReferenceId::PayoutId(payout_id_type) => {
+                    let payout_attempt = find_payout_attempt_from_payout_id_type(
+                        db,
+                        payout_id_type,
+                        merchant_account,
+                    )
+                    .await?;
+                    (
+                        payout_attempt.business_country,
+                        payout_attempt.business_label,
+                        payout_attempt.profile_id,
+                    )
                 }
             };
 
             let profile_id = utils::get_profile_id_from_business_details(
-                payment_intent.business_country,
-                payment_intent.business_label.as_ref(),
+                business_country,
+                business_label.as_ref(),
                 merchant_account,
-                payment_intent.profile_id.as_ref(),
+                profile_id.as_ref(),
                 db,
                 false,
             )
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("profile_id is not set in payment_intent")?;
+            .attach_printable("profile_id is not set in payment_intent or payout_attempt")?;
 
             Ok(profile_id)
         }
@@ -753,6 +816,7 @@ where
                 webhooks_core::create_event_and_trigger_appropriate_outgoing_webhook(
                     state.clone(),
                     merchant_account,
+                    payments_response_json.profile_id.clone(),
                     event_type,
                     diesel_models::enums::EventClass::Payments,
                     None,
@@ -767,3 +831,35 @@ where
 
     Ok(())
 }
+
+#[cfg(feature = "payouts")]
+pub async fn trigger_payouts_webhook(
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &crate::core::payouts::PayoutData,
+    state: &crate::routes::AppState,
+) -> RouterResult<()> {
+    let payout_attempt = &payout_data.payout_attempt;
+    let event_type: Option<enums::EventType> = payout_attempt.status.foreign_into();
+
+    if let Some(event_type) = event_type {
+        let payout_response: api_models::payouts::PayoutCreateResponse =
+            (payout_data.payouts.clone(), payout_attempt.clone()).foreign_into();
+
+        Box::pin(
+            webhooks_core::create_event_and_trigger_appropriate_outgoing_webhook(
+                state.clone(),
+                merchant_account.clone(),
+                payout_attempt.profile_id.clone(),
+                event_type,
+                diesel_models::enums::EventClass::Payouts,
+                None,
+                payout_attempt.payout_id.clone(),
+                diesel_models::enums::EventObjectType::PayoutDetails,
+                webhooks::OutgoingWebhookContent::PayoutDetails(Box::new(payout_response)),
+            ),
+        )
+        .await?;
+    }
+
+    Ok(())
+}
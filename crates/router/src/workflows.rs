@@ -1,3 +1,10 @@
+#[cfg(feature = "email")]
+pub mod api_key_expiry;
+pub mod dispute_deadline;
+pub mod merchant_key_rotation;
+pub mod outgoing_webhook_retry;
+pub mod payment_link_expiry;
 pub mod payment_sync;
 pub mod refund_router;
+pub mod subscription_billing;
 pub mod tokenized_data;
@@ -1,132 +1,150 @@
 use common_utils::ext_traits::ValueExt;
-use diesel_models::enums::{self as storage_enums};
+use error_stack::ResultExt;
+use masking::ExposeInterface;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors,
+};
 
-use super::{ApiKeyExpiryWorkflow, ProcessTrackerWorkflow};
 use crate::{
+    db::StorageInterface,
     errors,
-    logger::error,
     routes::AppState,
-    types::{
-        api,
-        storage::{self, ProcessTrackerExt},
-    },
-    utils::OptionExt,
+    services::email::{ApiKeyExpiryReminder, EmailTemplate},
+    types::{api, storage, storage::enums},
 };
 
+pub struct ApiKeyExpiryWorkflow;
+
 #[async_trait::async_trait]
-impl ProcessTrackerWorkflow for ApiKeyExpiryWorkflow {
+impl ProcessTrackerWorkflow<AppState> for ApiKeyExpiryWorkflow {
     async fn execute_workflow<'a>(
         &'a self,
         state: &'a AppState,
         process: storage::ProcessTracker,
-    ) -> Result<(), errors::ProcessTrackerError> {
-        let db = &*state.store;
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
         let tracking_data: storage::ApiKeyExpiryWorkflow = process
             .tracking_data
             .clone()
             .parse_value("ApiKeyExpiryWorkflow")?;
 
-        let key_store = state
-            .store
+        // The API key could have been deleted after the task was scheduled, revoking the key
+        // finishes the task on its own
+        let api_key = match db
+            .find_api_key_by_merchant_id_key_id_optional(
+                &tracking_data.merchant_id,
+                &tracking_data.key_id,
+            )
+            .await?
+        {
+            Some(api_key) => api_key,
+            None => {
+                return process
+                    .finish_with_status(db.as_scheduler(), "API_KEY_NOT_FOUND".to_string())
+                    .await
+            }
+        };
+
+        let key_store = db
             .get_merchant_key_store_by_merchant_id(
-                tracking_data.merchant_id.as_str(),
-                &state.store.get_master_key().to_vec().into(),
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
             )
             .await?;
 
         let merchant_account = db
-            .find_merchant_account_by_merchant_id(tracking_data.merchant_id.as_str(), &key_store)
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
             .await?;
 
         let email_id = merchant_account
             .merchant_details
-            .parse_value::<api::MerchantDetails>("MerchantDetails")?
-            .primary_email;
+            .clone()
+            .map(|merchant_details| {
+                merchant_details
+                    .into_inner()
+                    .expose()
+                    .parse_value::<api::MerchantDetails>("MerchantDetails")
+            })
+            .transpose()?
+            .and_then(|merchant_details| merchant_details.primary_email)
+            .ok_or(sch_errors::ProcessTrackerError::MissingRequiredField)?;
 
         let task_id = process.id.clone();
 
         let retry_count = process.retry_count;
 
-        let expires_in = tracking_data
+        let expires_in = *tracking_data
             .expiry_reminder_days
             .get(
                 usize::try_from(retry_count)
-                    .map_err(|_| errors::ProcessTrackerError::TypeConversionError)?,
+                    .map_err(|_| sch_errors::ProcessTrackerError::TypeConversionError)?,
             )
-            .ok_or(errors::ProcessTrackerError::EApiErrorResponse(
-                errors::ApiErrorResponse::InvalidDataValue {
-                    field_name: "index",
-                }
-                .into(),
-            ))?;
+            .ok_or(sch_errors::ProcessTrackerError::EApiErrorResponse)?;
+
+        let email = ApiKeyExpiryReminder {
+            merchant_name: merchant_account
+                .merchant_name
+                .map(|merchant_name| merchant_name.into_inner().expose()),
+            api_key_name: api_key.name,
+            api_key_prefix: api_key.prefix,
+            expires_in,
+        }
+        .render()?;
 
         state
             .email_client
             .clone()
-            .send_email(
-                email_id.ok_or_else(|| errors::ProcessTrackerError::MissingRequiredField)?,
-                "API Key Expiry Notice".to_string(),
-                format!("Dear Merchant,\n
-It has come to our attention that your API key will expire in {expires_in} days. To ensure uninterrupted access to our platform and continued smooth operation of your services, we kindly request that you take the necessary actions as soon as possible.\n\n
-Thanks,\n
-Team Hyperswitch"),
-            )
+            .send_email(email_id, email.subject, email.body)
             .await
-            .map_err(|_| errors::ProcessTrackerError::FlowExecutionError {
-                flow: "ApiKeyExpiryWorkflow",
-            })?;
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to send API key expiry reminder email")?;
 
-        // If all the mails have been sent, then retry_count would be equal to length of the expiry_reminder_days vector
-        if retry_count
-            == i32::try_from(tracking_data.expiry_reminder_days.len() - 1)
-                .map_err(|_| errors::ProcessTrackerError::TypeConversionError)?
-        {
-            process
-                .finish_with_status(db, format!("COMPLETED_BY_PT_{task_id}"))
-                .await?
-        }
-        // If tasks are remaining that has to be scheduled
-        else {
-            let expiry_reminder_day = tracking_data
-                .expiry_reminder_days
-                .get(
-                    usize::try_from(retry_count + 1)
-                        .map_err(|_| errors::ProcessTrackerError::TypeConversionError)?,
+        // If all the mails have been sent, then retry_count would be equal to length of the
+        // expiry_reminder_days vector
+        let next_expiry_reminder_day = tracking_data.expiry_reminder_days.get(
+            usize::try_from(retry_count + 1)
+                .map_err(|_| sch_errors::ProcessTrackerError::TypeConversionError)?,
+        );
+        match next_expiry_reminder_day {
+            None => {
+                process
+                    .finish_with_status(db.as_scheduler(), format!("COMPLETED_BY_PT_{task_id}"))
+                    .await
+            }
+            // If tasks are remaining that has to be scheduled
+            Some(expiry_reminder_day) => {
+                let updated_schedule_time = tracking_data.api_key_expiry.map(|api_key_expiry| {
+                    api_key_expiry
+                        .saturating_sub(time::Duration::days(i64::from(*expiry_reminder_day)))
+                });
+                let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(retry_count + 1),
+                    schedule_time: updated_schedule_time,
+                    tracking_data: None,
+                    business_status: None,
+                    status: Some(enums::ProcessTrackerStatus::New),
+                    updated_at: Some(common_utils::date_time::now()),
+                };
+                db.process_tracker_update_process_status_by_ids(
+                    vec![task_id],
+                    updated_process_tracker_data,
                 )
-                .ok_or(errors::ProcessTrackerError::EApiErrorResponse(
-                    errors::ApiErrorResponse::InvalidDataValue {
-                        field_name: "index",
-                    }
-                    .into(),
-                ))?;
-
-            let updated_schedule_time = tracking_data.api_key_expiry.map(|api_key_expiry| {
-                api_key_expiry.saturating_sub(time::Duration::days(i64::from(*expiry_reminder_day)))
-            });
-            let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
-                name: None,
-                retry_count: Some(retry_count + 1),
-                schedule_time: updated_schedule_time,
-                tracking_data: None,
-                business_status: None,
-                status: Some(storage_enums::ProcessTrackerStatus::New),
-                updated_at: Some(common_utils::date_time::now()),
-            };
-            let task_ids = vec![task_id];
-            db.process_tracker_update_process_status_by_ids(task_ids, updated_process_tracker_data)
                 .await?;
-        }
 
-        Ok(())
+                Ok(())
+            }
+        }
     }
 
     async fn error_handler<'a>(
         &'a self,
-        _state: &'a AppState,
+        state: &'a AppState,
         process: storage::ProcessTracker,
-        _error: errors::ProcessTrackerError,
-    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
-        error!(%process.id, "Failed while executing workflow");
-        Ok(())
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
     }
 }
@@ -0,0 +1,302 @@
+use std::str::FromStr;
+
+use api_models::{disputes as dispute_models, webhooks};
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+#[cfg(feature = "email")]
+use masking::ExposeInterface;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors,
+};
+
+use crate::{
+    core::{
+        disputes,
+        errors::{RouterResult, StorageErrorExt},
+        webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    types::{
+        api, domain,
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+};
+
+pub const DISPUTE_DEADLINE_WORKFLOW: &str = "DISPUTE_DEADLINE_WORKFLOW";
+pub const DISPUTE_DEADLINE_TASK: &str = "DISPUTE_DEADLINE";
+
+pub struct DisputeDeadlineWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for DisputeDeadlineWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::DisputeDeadlineTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("DisputeDeadlineTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let dispute = db
+            .find_dispute_by_merchant_id_dispute_id(
+                &tracking_data.merchant_id,
+                &tracking_data.dispute_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // The dispute could have been accepted or challenged by the merchant, or closed by the
+        // connector, after the task was scheduled
+        let current_time = common_utils::date_time::now();
+        let deadline = match dispute.challenge_required_by {
+            Some(deadline)
+                if dispute.dispute_status == enums::DisputeStatus::DisputeOpened
+                    && current_time < deadline =>
+            {
+                deadline
+            }
+            _ => {
+                return process
+                    .finish_with_status(db.as_scheduler(), "DISPUTE_NOT_OPEN".to_string())
+                    .await
+            }
+        };
+
+        let dispute_policy = get_dispute_policy(db, &merchant_account, &dispute)
+            .await?
+            .unwrap_or_default();
+
+        // The amount below which disputes are accepted only applies to disputes in its currency
+        let auto_accept_below_amount = dispute_policy
+            .auto_accept_below_amount
+            .zip(enums::Currency::from_str(&dispute.currency).ok())
+            .and_then(|(auto_accept_below_amounts, currency)| {
+                auto_accept_below_amounts.get(&currency).copied()
+            });
+        let is_below_auto_accept_amount = auto_accept_below_amount
+            .zip(dispute.amount.parse::<i64>().ok())
+            .map_or(false, |(auto_accept_below_amount, amount)| {
+                amount < auto_accept_below_amount
+            });
+        if is_below_auto_accept_amount {
+            Box::pin(disputes::accept_dispute(
+                state.clone(),
+                merchant_account,
+                key_store,
+                api::DisputeId {
+                    dispute_id: dispute.dispute_id,
+                },
+            ))
+            .await?;
+            return process
+                .finish_with_status(db.as_scheduler(), "DISPUTE_AUTO_ACCEPTED".to_string())
+                .await;
+        }
+
+        let is_auto_submit_due = dispute_policy
+            .auto_submit_evidence_before
+            .map_or(false, |before| {
+                disputes::get_time_before_deadline(deadline, before) <= current_time
+            });
+        if is_auto_submit_due {
+            let dispute_evidence: api::DisputeEvidence =
+                dispute.evidence.clone().parse_value("DisputeEvidence")?;
+            if let Some(submit_evidence_request) =
+                disputes::transformers::get_submit_evidence_request(
+                    dispute.dispute_id.clone(),
+                    dispute_evidence,
+                )
+            {
+                Box::pin(disputes::submit_evidence(
+                    state.clone(),
+                    merchant_account,
+                    key_store,
+                    submit_evidence_request,
+                ))
+                .await?;
+                return process
+                    .finish_with_status(db.as_scheduler(), "EVIDENCE_AUTO_SUBMITTED".to_string())
+                    .await;
+            }
+        }
+
+        // Apart from the first run, the task is only scheduled at reminder or evidence submission
+        // times, a reminder is sent only if this run was scheduled for it
+        let reminder_offsets = &state.conf.dispute_deadline.reminder_offsets;
+        let reminder_offset =
+            disputes::get_due_reminder_offset(deadline, reminder_offsets, current_time).filter(
+                |offset| {
+                    process.retry_count == 0
+                        || Some(disputes::get_time_before_deadline(deadline, *offset))
+                            >= process.schedule_time
+                },
+            );
+        if let Some(reminder_offset) = reminder_offset {
+            send_deadline_reminder(state, &merchant_account, &dispute, reminder_offset).await;
+        }
+
+        match disputes::get_next_deadline_check_time(
+            deadline,
+            reminder_offsets,
+            dispute_policy.auto_submit_evidence_before,
+            current_time,
+        ) {
+            Some(schedule_time) => process.retry(db.as_scheduler(), schedule_time).await,
+            None => {
+                process
+                    .finish_with_status(db.as_scheduler(), "DEADLINE_REMINDERS_SENT".to_string())
+                    .await
+            }
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Get the dispute policy of the business profile under which the disputed payment was made
+async fn get_dispute_policy(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    dispute: &storage::Dispute,
+) -> RouterResult<Option<dispute_models::DisputePolicy>> {
+    let profile_id = match dispute.profile_id.clone() {
+        Some(profile_id) => Some(profile_id),
+        None => {
+            db.find_payment_intent_by_payment_id_merchant_id(
+                &dispute.payment_id,
+                &merchant_account.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?
+            .profile_id
+        }
+    };
+    let profile_id = match profile_id {
+        Some(profile_id) => profile_id,
+        None => return Ok(None),
+    };
+
+    db.find_business_profile_by_profile_id(&profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id,
+        })?
+        .dispute_policy
+        .map(|dispute_policy| dispute_policy.parse_value("DisputePolicy"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the dispute policy of the business profile")
+}
+
+async fn send_deadline_reminder(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    dispute: &storage::Dispute,
+    reminder_offset: u32,
+) {
+    // A reminder is sent for every configured offset, so the offset is a part of the event id
+    let event_type = enums::EventType::DisputeEvidenceDue;
+    let event_id = format!("{}_{event_type}_{reminder_offset}", dispute.dispute_id);
+    let dispute_response = dispute_models::DisputeResponse::foreign_from(dispute.clone());
+
+    Box::pin(
+        webhooks_core::create_event_with_id_and_trigger_appropriate_outgoing_webhook(
+            state.clone(),
+            merchant_account.clone(),
+            dispute.profile_id.clone(),
+            event_id,
+            event_type,
+            enums::EventClass::Disputes,
+            None,
+            dispute.dispute_id.clone(),
+            enums::EventObjectType::DisputeDetails,
+            webhooks::OutgoingWebhookContent::DisputeDetails(Box::new(dispute_response)),
+        ),
+    )
+    .await
+    .map_err(|error| logger::warn!(dispute_deadline_webhook_error=?error))
+    .ok();
+
+    #[cfg(feature = "email")]
+    if state.conf.dispute_deadline.send_reminder_emails {
+        send_deadline_reminder_email(state, merchant_account, dispute)
+            .await
+            .map_err(|error| logger::warn!(dispute_deadline_email_error=?error))
+            .ok();
+    }
+}
+
+#[cfg(feature = "email")]
+async fn send_deadline_reminder_email(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    dispute: &storage::Dispute,
+) -> RouterResult<()> {
+    let primary_email = merchant_account
+        .merchant_details
+        .clone()
+        .map(|merchant_details| {
+            merchant_details
+                .into_inner()
+                .expose()
+                .parse_value::<api::MerchantDetails>("MerchantDetails")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse merchant details")?
+        .and_then(|merchant_details| merchant_details.primary_email);
+    let primary_email = match primary_email {
+        Some(primary_email) => primary_email,
+        None => return Ok(()),
+    };
+    let deadline = dispute
+        .challenge_required_by
+        .map(|deadline| format!(" by {deadline} UTC"))
+        .unwrap_or_default();
+
+    state
+        .email_client
+        .clone()
+        .send_email(
+            primary_email,
+            "Dispute Evidence Deadline Reminder".to_string(),
+            format!(
+                "Dear Merchant,\n
+The payment {} has been disputed. Evidence to challenge the dispute {} has to be submitted{deadline}, the dispute will be lost otherwise.\n\n
+Thanks,\n
+Team Hyperswitch",
+                dispute.payment_id, dispute.dispute_id
+            ),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to send dispute deadline reminder email")
+}
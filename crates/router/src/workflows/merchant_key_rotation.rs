@@ -0,0 +1,406 @@
+use common_utils::{
+    crypto::{Encryptable, GcmAes256},
+    ext_traits::ValueExt,
+    pii,
+};
+use diesel_models::encryption::Encryption;
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors,
+};
+
+use crate::{
+    db::StorageInterface,
+    errors::{self, CustomResult},
+    routes::AppState,
+    types::{
+        domain::{self, types::TypeEncryption},
+        storage::{self, enums},
+    },
+    utils,
+};
+
+pub const KEY_ROTATION_WORKFLOW: &str = "MERCHANT_KEY_ROTATION_WORKFLOW";
+pub const KEY_ROTATION_TASK: &str = "MERCHANT_KEY_ROTATION";
+pub const KEY_ROTATION_COMPLETED_STATUS: &str = "KEY_ROTATION_COMPLETED";
+
+/// Number of customers, addresses or payment methods re-encrypted in a single run of the task
+const KEY_ROTATION_BATCH_SIZE: i64 = 100;
+
+pub struct MerchantKeyRotationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for MerchantKeyRotationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::KeyRotationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("KeyRotationTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        // A key can only be rotated again once the data is re-encrypted with it, this is only
+        // reachable if the task was resumed after the rotation which succeeded it
+        if key_store.version != tracking_data.version {
+            return process
+                .finish_with_status(db.as_scheduler(), "KEY_ROTATION_SUPERSEDED".to_string())
+                .await;
+        }
+
+        let last_processed_id = tracking_data.last_processed_id.as_deref();
+        let (next_stage, last_processed_id) = match tracking_data.stage {
+            storage::KeyRotationStage::MerchantAccount => {
+                reencrypt_merchant_account(db, &key_store).await?;
+                (
+                    Some(storage::KeyRotationStage::MerchantConnectorAccounts),
+                    None,
+                )
+            }
+            storage::KeyRotationStage::MerchantConnectorAccounts => {
+                reencrypt_merchant_connector_accounts(db, &key_store).await?;
+                (Some(storage::KeyRotationStage::Customers), None)
+            }
+            storage::KeyRotationStage::Customers => {
+                match reencrypt_customers(db, &key_store, last_processed_id).await? {
+                    Some(last_customer_id) => (
+                        Some(storage::KeyRotationStage::Customers),
+                        Some(last_customer_id),
+                    ),
+                    None => (Some(storage::KeyRotationStage::Addresses), None),
+                }
+            }
+            storage::KeyRotationStage::Addresses => {
+                match reencrypt_addresses(db, &key_store, last_processed_id).await? {
+                    Some(last_address_id) => (
+                        Some(storage::KeyRotationStage::Addresses),
+                        Some(last_address_id),
+                    ),
+                    None => (Some(storage::KeyRotationStage::PaymentMethods), None),
+                }
+            }
+            storage::KeyRotationStage::PaymentMethods => {
+                match reencrypt_payment_methods(db, &key_store, last_processed_id).await? {
+                    Some(last_payment_method_id) => (
+                        Some(storage::KeyRotationStage::PaymentMethods),
+                        Some(last_payment_method_id),
+                    ),
+                    None => (None, None),
+                }
+            }
+        };
+
+        match next_stage {
+            // The progress is persisted after every stage or batch, so that the task resumes
+            // from there if it is interrupted
+            Some(stage) => {
+                let updated_tracking_data = storage::KeyRotationTrackingData {
+                    stage,
+                    last_processed_id,
+                    ..tracking_data
+                };
+                let updated_tracking_data =
+                    utils::Encode::<storage::KeyRotationTrackingData>::encode_to_value(
+                        &updated_tracking_data,
+                    )?;
+                let current_time = common_utils::date_time::now();
+                db.process_tracker_update_process_status_by_ids(
+                    vec![process.id],
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: None,
+                        schedule_time: Some(current_time),
+                        tracking_data: Some(updated_tracking_data),
+                        business_status: None,
+                        status: Some(enums::ProcessTrackerStatus::New),
+                        updated_at: Some(current_time),
+                    },
+                )
+                .await?;
+                Ok(())
+            }
+            None => {
+                process
+                    .finish_with_status(
+                        db.as_scheduler(),
+                        KEY_ROTATION_COMPLETED_STATUS.to_string(),
+                    )
+                    .await
+            }
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Encrypt the decrypted value again with the current key of the merchant
+async fn reencrypt<E: Clone, S>(
+    value: Encryptable<Secret<E, S>>,
+    key: &[u8],
+) -> CustomResult<Encryptable<Secret<E, S>>, errors::StorageError>
+where
+    S: masking::Strategy<E>,
+    Encryptable<Secret<E, S>>: TypeEncryption<E, GcmAes256, S>,
+{
+    domain::types::encrypt(value.into_inner(), key)
+        .await
+        .change_context(errors::StorageError::EncryptionError)
+}
+
+async fn reencrypt_optional<E: Clone, S>(
+    value: Option<Encryptable<Secret<E, S>>>,
+    key: &[u8],
+) -> CustomResult<Option<Encryptable<Secret<E, S>>>, errors::StorageError>
+where
+    Secret<E, S>: Send,
+    S: masking::Strategy<E>,
+    Encryptable<Secret<E, S>>: TypeEncryption<E, GcmAes256, S>,
+{
+    domain::types::encrypt_optional(value.map(Encryptable::into_inner), key)
+        .await
+        .change_context(errors::StorageError::EncryptionError)
+}
+
+async fn reencrypt_merchant_account(
+    db: &dyn StorageInterface,
+    key_store: &domain::MerchantKeyStore,
+) -> CustomResult<(), errors::StorageError> {
+    let key = key_store.key.get_inner().peek();
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&key_store.merchant_id, key_store)
+        .await?;
+
+    let merchant_account_update = storage::MerchantAccountUpdate::Update {
+        merchant_name: reencrypt_optional(merchant_account.merchant_name.clone(), key).await?,
+        merchant_details: reencrypt_optional(merchant_account.merchant_details.clone(), key)
+            .await?,
+        return_url: None,
+        webhook_details: None,
+        sub_merchants_enabled: None,
+        parent_merchant_id: None,
+        enable_payment_response_hash: None,
+        payment_response_hash_key: None,
+        redirect_to_merchant_with_http_post: None,
+        publishable_key: None,
+        locker_id: None,
+        metadata: None,
+        routing_algorithm: None,
+        primary_business_details: None,
+        intent_fulfillment_time: None,
+        frm_routing_algorithm: None,
+        payout_routing_algorithm: None,
+        default_profile: None,
+        payment_link_config: None,
+    };
+    db.update_merchant(merchant_account, merchant_account_update, key_store)
+        .await?;
+    Ok(())
+}
+
+async fn reencrypt_merchant_connector_accounts(
+    db: &dyn StorageInterface,
+    key_store: &domain::MerchantKeyStore,
+) -> CustomResult<(), errors::StorageError> {
+    let key = key_store.key.get_inner().peek();
+    let merchant_connector_accounts = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &key_store.merchant_id,
+            true,
+            key_store,
+        )
+        .await?;
+
+    for merchant_connector_account in merchant_connector_accounts {
+        let merchant_connector_account_update = domain::MerchantConnectorAccountUpdate::Update {
+            merchant_id: None,
+            connector_type: None,
+            connector_name: None,
+            connector_account_details: Some(
+                reencrypt(
+                    merchant_connector_account.connector_account_details.clone(),
+                    key,
+                )
+                .await?,
+            ),
+            test_mode: None,
+            disabled: None,
+            merchant_connector_id: None,
+            payment_methods_enabled: None,
+            metadata: None,
+            frm_configs: None,
+            connector_webhook_details: None,
+            applepay_verified_domains: None,
+            pm_auth_config: None,
+            connector_label: None,
+        };
+        db.update_merchant_connector_account(
+            merchant_connector_account,
+            merchant_connector_account_update.into(),
+            key_store,
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+/// Re-encrypt the next batch of customers, returns the identifier of the last customer in the
+/// batch if there could be more customers left to re-encrypt
+async fn reencrypt_customers(
+    db: &dyn StorageInterface,
+    key_store: &domain::MerchantKeyStore,
+    last_customer_id: Option<&str>,
+) -> CustomResult<Option<String>, errors::StorageError> {
+    let key = key_store.key.get_inner().peek();
+    let customers = db
+        .list_customers_by_merchant_id_after_customer_id(
+            &key_store.merchant_id,
+            last_customer_id.unwrap_or_default(),
+            KEY_ROTATION_BATCH_SIZE,
+            key_store,
+        )
+        .await?;
+    let is_last_batch = customers.len() < usize::try_from(KEY_ROTATION_BATCH_SIZE).unwrap_or(0);
+
+    let mut last_customer_id = None;
+    for customer in customers {
+        let customer_update = storage::CustomerUpdate::Update {
+            name: reencrypt_optional(customer.name, key).await?,
+            email: reencrypt_optional(customer.email, key).await?,
+            phone: Box::new(reencrypt_optional(customer.phone, key).await?),
+            description: None,
+            phone_country_code: None,
+            metadata: None,
+            connector_customer: None,
+            address_id: None,
+        };
+        db.update_customer_by_customer_id_merchant_id(
+            customer.customer_id.clone(),
+            customer.merchant_id,
+            customer_update,
+            key_store,
+        )
+        .await?;
+        last_customer_id = Some(customer.customer_id);
+    }
+
+    Ok(last_customer_id.filter(|_| !is_last_batch))
+}
+
+/// Re-encrypt the next batch of addresses, returns the identifier of the last address in the
+/// batch if there could be more addresses left to re-encrypt
+async fn reencrypt_addresses(
+    db: &dyn StorageInterface,
+    key_store: &domain::MerchantKeyStore,
+    last_address_id: Option<&str>,
+) -> CustomResult<Option<String>, errors::StorageError> {
+    let key = key_store.key.get_inner().peek();
+    let addresses = db
+        .list_addresses_by_merchant_id_after_address_id(
+            &key_store.merchant_id,
+            last_address_id.unwrap_or_default(),
+            KEY_ROTATION_BATCH_SIZE,
+            key_store,
+        )
+        .await?;
+    let is_last_batch = addresses.len() < usize::try_from(KEY_ROTATION_BATCH_SIZE).unwrap_or(0);
+
+    let mut last_address_id = None;
+    for address in addresses {
+        let address_update = storage::AddressUpdate::Update {
+            city: None,
+            country: None,
+            line1: reencrypt_optional(address.line1, key).await?,
+            line2: reencrypt_optional(address.line2, key).await?,
+            line3: reencrypt_optional(address.line3, key).await?,
+            state: reencrypt_optional(address.state, key).await?,
+            zip: reencrypt_optional(address.zip, key).await?,
+            first_name: reencrypt_optional(address.first_name, key).await?,
+            last_name: reencrypt_optional(address.last_name, key).await?,
+            phone_number: reencrypt_optional(address.phone_number, key).await?,
+            country_code: None,
+            updated_by: address.updated_by,
+        };
+        db.update_address(address.address_id.clone(), address_update, key_store)
+            .await?;
+        last_address_id = Some(address.address_id);
+    }
+
+    Ok(last_address_id.filter(|_| !is_last_batch))
+}
+
+/// Re-encrypt the next batch of payment methods, returns the identifier of the last payment
+/// method in the batch if there could be more payment methods left to re-encrypt
+async fn reencrypt_payment_methods(
+    db: &dyn StorageInterface,
+    key_store: &domain::MerchantKeyStore,
+    last_payment_method_id: Option<&str>,
+) -> CustomResult<Option<String>, errors::StorageError> {
+    let key = key_store.key.get_inner().peek();
+    let payment_methods = db
+        .list_payment_methods_by_merchant_id_after_payment_method_id(
+            &key_store.merchant_id,
+            last_payment_method_id.unwrap_or_default(),
+            KEY_ROTATION_BATCH_SIZE,
+        )
+        .await?;
+    let is_last_batch =
+        payment_methods.len() < usize::try_from(KEY_ROTATION_BATCH_SIZE).unwrap_or(0);
+
+    let mut last_payment_method_id = None;
+    for payment_method in payment_methods {
+        let payment_method_id = payment_method.payment_method_id.clone();
+        if payment_method.payment_method_data.is_some() {
+            let payment_method_data =
+                decrypt_payment_method_data(payment_method.payment_method_data.clone(), key_store)
+                    .await?;
+            let payment_method_update = storage::PaymentMethodUpdate::PaymentMethodDataUpdate {
+                payment_method_data: reencrypt_optional(payment_method_data, key)
+                    .await?
+                    .map(Into::into),
+            };
+            db.update_payment_method(payment_method, payment_method_update)
+                .await?;
+        }
+        last_payment_method_id = Some(payment_method_id);
+    }
+
+    Ok(last_payment_method_id.filter(|_| !is_last_batch))
+}
+
+/// Payment method data is stored without a domain model, so it is decrypted here with the
+/// current key of the merchant, falling back to the previous key
+async fn decrypt_payment_method_data(
+    payment_method_data: Option<Encryption>,
+    key_store: &domain::MerchantKeyStore,
+) -> CustomResult<Option<Encryptable<pii::SecretSerdeValue>>, errors::StorageError> {
+    let key = key_store.key.get_inner().peek();
+    let decrypted_data = match domain::types::decrypt(payment_method_data.clone(), key).await {
+        Ok(data) => Ok(data),
+        Err(error) => match key_store.previous_key.as_ref() {
+            Some(previous_key) => {
+                domain::types::decrypt(payment_method_data, previous_key.get_inner().peek()).await
+            }
+            None => Err(error),
+        },
+    };
+    decrypted_data.change_context(errors::StorageError::DecryptionError)
+}
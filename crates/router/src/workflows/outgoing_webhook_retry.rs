@@ -0,0 +1,188 @@
+use api_models::webhook_events::OutgoingWebhookRequestContent;
+use common_utils::ext_traits::ValueExt;
+use masking::ExposeInterface;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors, utils as scheduler_utils,
+};
+
+use crate::{
+    configs::settings::OutgoingWebhookRetrySettings,
+    core::{metrics, webhooks},
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    types::storage,
+    utils::OptionExt,
+};
+
+pub const OUTGOING_WEBHOOK_RETRY_WORKFLOW: &str = "OUTGOING_WEBHOOK_RETRY_WORKFLOW";
+pub const OUTGOING_WEBHOOK_RETRY_TASK: &str = "OUTGOING_WEBHOOK_RETRY";
+
+pub struct OutgoingWebhookRetryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for OutgoingWebhookRetryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::OutgoingWebhookTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("OutgoingWebhookTrackingData")?;
+
+        // The storage scheme of the merchant is only known once the event has been found, so the
+        // event is looked up in redis before the database to find it with either storage scheme
+        let event = db
+            .find_event_by_event_id(
+                &tracking_data.event_id,
+                storage::enums::MerchantStorageScheme::RedisKv,
+            )
+            .await?;
+
+        // The webhook could have been delivered by another attempt in the meantime
+        if event.is_webhook_notified {
+            let id = process.id.clone();
+            return process
+                .finish_with_status(db.as_scheduler(), format!("COMPLETED_BY_PT_{id}"))
+                .await;
+        }
+
+        let merchant_id = event
+            .merchant_id
+            .clone()
+            .get_required_value("merchant_id")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+            .await?;
+
+        // The merchant could have removed the webhook url since the first attempt
+        let webhook_url = match webhooks::get_webhook_url(&merchant_account) {
+            Ok(webhook_url) => webhook_url,
+            Err(error) => {
+                logger::warn!(outgoing_webhook_retry_error=?error);
+                return process
+                    .finish_with_status(db.as_scheduler(), "WEBHOOK_URL_NOT_CONFIGURED".to_string())
+                    .await;
+            }
+        };
+
+        // The request is delivered exactly as it was first sent, with the same signature
+        let request_content: OutgoingWebhookRequestContent = event
+            .request
+            .clone()
+            .get_required_value("request")?
+            .expose()
+            .parse_value("OutgoingWebhookRequestContent")?;
+
+        match webhooks::deliver_webhook_to_merchant(
+            state,
+            &webhook_url,
+            &merchant_id,
+            &event.event_id,
+            &request_content,
+            storage::enums::WebhookDeliveryAttempt::AutomaticRetry,
+            merchant_account.storage_scheme,
+        )
+        .await
+        {
+            Ok(()) => {
+                let id = process.id.clone();
+                process
+                    .finish_with_status(db.as_scheduler(), format!("COMPLETED_BY_PT_{id}"))
+                    .await?
+            }
+            Err(error) => {
+                logger::warn!(outgoing_webhook_retry_error=?error);
+                match get_outgoing_webhook_retry_schedule_time(
+                    &state.conf.webhooks.outgoing_retry,
+                    process.retry_count + 1,
+                ) {
+                    Some(schedule_time) => process.retry(db.as_scheduler(), schedule_time).await?,
+                    None => {
+                        metrics::WEBHOOK_OUTGOING_RETRIES_EXHAUSTED_COUNT.add(
+                            &metrics::CONTEXT,
+                            1,
+                            &[metrics::KeyValue::new("merchant_id", merchant_id.clone())],
+                        );
+                        process
+                            .finish_with_status(db.as_scheduler(), "RETRIES_EXCEEDED".to_string())
+                            .await?
+                    }
+                }
+            }
+        };
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Get the time at which the webhook should be retried
+///
+/// The delay before the first retry is `initial_delay`, and it is doubled on every subsequent
+/// retry until it reaches `max_delay`. Returns `None` once `max_retries` retries have been made.
+pub fn get_outgoing_webhook_retry_schedule_time(
+    settings: &OutgoingWebhookRetrySettings,
+    retry_count: i32,
+) -> Option<time::PrimitiveDateTime> {
+    let retry_count = u32::try_from(retry_count).ok()?;
+    if retry_count >= settings.max_retries {
+        return None;
+    }
+
+    let delay = 2_u32
+        .checked_pow(retry_count)
+        .and_then(|multiplier| settings.initial_delay.checked_mul(multiplier))
+        .map_or(settings.max_delay, |delay| delay.min(settings.max_delay));
+
+    scheduler_utils::get_time_from_delta(i32::try_from(delay).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_outgoing_webhook_retry_schedule_time() {
+        let settings = OutgoingWebhookRetrySettings {
+            max_retries: 3,
+            initial_delay: 60,
+            max_delay: 200,
+        };
+        let now = common_utils::date_time::now();
+        let delays = (0..3)
+            .map(|retry_count| {
+                (get_outgoing_webhook_retry_schedule_time(&settings, retry_count).unwrap() - now)
+                    .whole_seconds()
+            })
+            .collect::<Vec<_>>();
+
+        // Allow for the time elapsed while computing the schedule times
+        assert!(delays[0] >= 60 && delays[0] <= 61);
+        assert!(delays[1] >= 120 && delays[1] <= 121);
+        assert!(delays[2] >= 200 && delays[2] <= 201);
+        assert!(get_outgoing_webhook_retry_schedule_time(&settings, 3).is_none());
+    }
+}
@@ -0,0 +1,82 @@
+use common_utils::ext_traits::ValueExt;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors,
+};
+
+use crate::{
+    core::payment_link as payment_link_core,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    types::storage::{self, enums},
+};
+
+pub const PAYMENT_LINK_EXPIRY_WORKFLOW: &str = "PAYMENT_LINK_EXPIRY_WORKFLOW";
+pub const PAYMENT_LINK_EXPIRY_TASK: &str = "PAYMENT_LINK_EXPIRY";
+
+pub struct PaymentLinkExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for PaymentLinkExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::PaymentLinkTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentLinkTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let payment_link = db
+            .find_payment_link_by_payment_link_id(&tracking_data.payment_link_id)
+            .await?;
+
+        // The link could have been paid or cancelled after the task was scheduled
+        if payment_link.status != enums::PaymentLinkStatus::Active {
+            return process
+                .finish_with_status(db.as_scheduler(), "PAYMENT_LINK_NOT_ACTIVE".to_string())
+                .await;
+        }
+
+        match payment_link.fulfilment_time {
+            Some(fulfilment_time) if fulfilment_time > common_utils::date_time::now() => {
+                process.retry(db.as_scheduler(), fulfilment_time).await
+            }
+            Some(_) => {
+                payment_link_core::expire_payment_link(db, &merchant_account, payment_link).await?;
+                process
+                    .finish_with_status(db.as_scheduler(), "PAYMENT_LINK_EXPIRED".to_string())
+                    .await
+            }
+            None => {
+                process
+                    .finish_with_status(db.as_scheduler(), "PAYMENT_LINK_NO_EXPIRY".to_string())
+                    .await
+            }
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,351 @@
+use api_models::{payments as payment_types, webhooks};
+use common_utils::ext_traits::ValueExt;
+use error_stack::{IntoReport, ResultExt};
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors, utils as scheduler_utils,
+};
+
+use crate::{
+    core::{
+        errors::{RouterResult, StorageErrorExt},
+        payment_methods::Oss,
+        payments, subscriptions, webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+};
+
+pub const SUBSCRIPTION_BILLING_WORKFLOW: &str = "SUBSCRIPTION_BILLING_WORKFLOW";
+pub const SUBSCRIPTION_BILLING_TASK: &str = "SUBSCRIPTION_BILLING";
+
+pub struct SubscriptionBillingWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for SubscriptionBillingWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::SubscriptionTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("SubscriptionTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let subscription = db
+            .find_subscription_by_merchant_id_subscription_id(
+                &tracking_data.merchant_id,
+                &tracking_data.subscription_id,
+            )
+            .await?;
+
+        // The subscription could have been cancelled after the task was picked up
+        let billing_time = match subscription.next_billing_at {
+            Some(billing_time)
+                if !matches!(
+                    subscription.status,
+                    enums::SubscriptionStatus::Cancelled | enums::SubscriptionStatus::Unpaid
+                ) =>
+            {
+                billing_time
+            }
+            _ => {
+                return process
+                    .finish_with_status(db.as_scheduler(), "SUBSCRIPTION_NOT_BILLABLE".to_string())
+                    .await
+            }
+        };
+
+        let payment_response = create_renewal_payment(
+            state,
+            merchant_account.clone(),
+            key_store,
+            &subscription,
+            billing_time,
+            process.retry_count,
+        )
+        .await
+        .map_err(|error| logger::warn!(subscription_renewal_payment_error=?error))
+        .ok();
+        let latest_payment_id = payment_response
+            .as_ref()
+            .and_then(|payment_response| payment_response.payment_id.clone());
+        let is_renewal_successful = payment_response.map_or(false, |payment_response| {
+            matches!(
+                payment_response.status,
+                enums::IntentStatus::Succeeded
+                    | enums::IntentStatus::Processing
+                    | enums::IntentStatus::RequiresCapture
+            )
+        });
+
+        if is_renewal_successful {
+            let next_billing_time =
+                subscriptions::get_next_billing_time(&subscription, billing_time)
+                    .ok_or(sch_errors::ProcessTrackerError::UnexpectedFlow)?;
+            let subscription = db
+                .update_subscription_by_merchant_id_subscription_id(
+                    &subscription.merchant_id,
+                    &subscription.subscription_id,
+                    storage::SubscriptionUpdate::RenewalSuccessUpdate {
+                        current_period_start: billing_time,
+                        current_period_end: next_billing_time,
+                        latest_payment_id,
+                    },
+                )
+                .await?;
+
+            trigger_subscription_webhook(
+                state,
+                merchant_account,
+                subscription,
+                billing_time,
+                enums::EventType::SubscriptionRenewalSucceeded,
+            )
+            .await;
+
+            return process.reset(db.as_scheduler(), next_billing_time).await;
+        }
+
+        match get_dunning_retry_schedule_time(state, process.retry_count) {
+            Some(schedule_time) => {
+                db.update_subscription_by_merchant_id_subscription_id(
+                    &subscription.merchant_id,
+                    &subscription.subscription_id,
+                    storage::SubscriptionUpdate::RenewalFailureUpdate {
+                        status: enums::SubscriptionStatus::PastDue,
+                        latest_payment_id,
+                    },
+                )
+                .await?;
+                process.retry(db.as_scheduler(), schedule_time).await
+            }
+            None => {
+                let subscription = db
+                    .update_subscription_by_merchant_id_subscription_id(
+                        &subscription.merchant_id,
+                        &subscription.subscription_id,
+                        storage::SubscriptionUpdate::RenewalFailureUpdate {
+                            status: enums::SubscriptionStatus::Unpaid,
+                            latest_payment_id,
+                        },
+                    )
+                    .await?;
+
+                trigger_subscription_webhook(
+                    state,
+                    merchant_account,
+                    subscription,
+                    billing_time,
+                    enums::EventType::SubscriptionRenewalFailed,
+                )
+                .await;
+
+                process
+                    .finish_with_status(db.as_scheduler(), "RETRIES_EXCEEDED".to_string())
+                    .await
+            }
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Charges the customer for the current billing cycle through the mandate of the subscription.
+/// The payment made by a previous run of the task for the same billing cycle and retry is
+/// retrieved instead, so that the customer is not charged twice.
+async fn create_renewal_payment(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    subscription: &storage::Subscription,
+    billing_time: time::PrimitiveDateTime,
+    retry_count: i32,
+) -> RouterResult<payment_types::PaymentsResponse> {
+    let payment_id = get_renewal_payment_id(subscription, billing_time, retry_count);
+
+    let existing_payment = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound);
+    let is_payment_created = match existing_payment {
+        Ok(_) => true,
+        Err(error)
+            if matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::PaymentNotFound
+            ) =>
+        {
+            false
+        }
+        Err(error) => return Err(error),
+    };
+
+    let response = if is_payment_created {
+        let retrieve_request = payment_types::PaymentsRetrieveRequest {
+            resource_id: payment_types::PaymentIdType::PaymentIntentId(payment_id),
+            merchant_id: Some(merchant_account.merchant_id.clone()),
+            force_sync: true,
+            ..Default::default()
+        };
+
+        Box::pin(payments::payments_core::<
+            api::PSync,
+            api::PaymentsResponse,
+            _,
+            _,
+            _,
+            Oss,
+        >(
+            state.clone(),
+            merchant_account,
+            key_store,
+            payments::PaymentStatus,
+            retrieve_request,
+            services::AuthFlow::Merchant,
+            payments::CallConnectorAction::Trigger,
+            api::HeaderPayload::default(),
+        ))
+        .await?
+    } else {
+        let payment_request = payment_types::PaymentsRequest {
+            payment_id: Some(payment_types::PaymentIdType::PaymentIntentId(payment_id)),
+            amount: Some(subscription.amount.into()),
+            currency: Some(subscription.currency),
+            confirm: Some(true),
+            off_session: Some(true),
+            customer_id: Some(subscription.customer_id.clone()),
+            mandate_id: Some(subscription.mandate_id.clone()),
+            profile_id: subscription.profile_id.clone(),
+            description: subscription.description.clone(),
+            metadata: subscription.metadata.clone(),
+            ..Default::default()
+        };
+
+        Box::pin(payments::payments_core::<
+            api::Authorize,
+            api::PaymentsResponse,
+            _,
+            _,
+            _,
+            Oss,
+        >(
+            state.clone(),
+            merchant_account,
+            key_store,
+            payments::PaymentCreate,
+            payment_request,
+            services::AuthFlow::Merchant,
+            payments::CallConnectorAction::Trigger,
+            api::HeaderPayload::default(),
+        ))
+        .await?
+    };
+
+    match response {
+        services::ApplicationResponse::Json(payment_response)
+        | services::ApplicationResponse::JsonWithHeaders((payment_response, _)) => {
+            Ok(payment_response)
+        }
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("Unexpected response from payments core"),
+    }
+}
+
+/// Every renewal payment is identified by the subscription, the billing cycle and the dunning
+/// retry it is made for
+fn get_renewal_payment_id(
+    subscription: &storage::Subscription,
+    billing_time: time::PrimitiveDateTime,
+    retry_count: i32,
+) -> String {
+    format!(
+        "{}_{}_{retry_count}",
+        subscription.subscription_id,
+        billing_time.assume_utc().unix_timestamp()
+    )
+}
+
+/// Get the time at which a failed renewal payment should be retried, `None` once all the retries
+/// configured for dunning have been made
+fn get_dunning_retry_schedule_time(
+    state: &AppState,
+    retry_count: i32,
+) -> Option<time::PrimitiveDateTime> {
+    let delay = state
+        .conf
+        .subscriptions
+        .dunning_retry_delays
+        .get(usize::try_from(retry_count).ok()?)?;
+    scheduler_utils::get_time_from_delta(i32::try_from(*delay).ok())
+}
+
+/// The same events are raised for every billing cycle, so the billing time is a part of the event id
+async fn trigger_subscription_webhook(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    subscription: storage::Subscription,
+    billing_time: time::PrimitiveDateTime,
+    event_type: enums::EventType,
+) {
+    let subscription_id = subscription.subscription_id.clone();
+    let profile_id = subscription.profile_id.clone();
+    let subscription_response = api::SubscriptionResponse::foreign_from(subscription);
+    let event_id = format!(
+        "{subscription_id}_{event_type}_{}",
+        billing_time.assume_utc().unix_timestamp()
+    );
+
+    Box::pin(
+        webhooks_core::create_event_with_id_and_trigger_appropriate_outgoing_webhook(
+            state.clone(),
+            merchant_account,
+            profile_id,
+            event_id,
+            event_type,
+            enums::EventClass::Subscriptions,
+            None,
+            subscription_id,
+            enums::EventObjectType::SubscriptionDetails,
+            webhooks::OutgoingWebhookContent::SubscriptionDetails(Box::new(subscription_response)),
+        ),
+    )
+    .await
+    .map_err(|error| logger::warn!(subscription_outgoing_webhook_error=?error))
+    .ok();
+}
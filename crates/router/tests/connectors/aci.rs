@@ -69,6 +69,8 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             complete_authorize_url: None,
             customer_id: None,
             surcharge_details: None,
+            installment_data: None,
+            request_incremental_authorization: false,
         },
         response: Err(types::ErrorResponse::default()),
         payment_method_id: None,
@@ -127,6 +129,7 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
             reason: None,
             connector_refund_id: None,
             browser_info: None,
+            connector_capture_id: None,
         },
         payment_method_id: None,
         response: Err(types::ErrorResponse::default()),
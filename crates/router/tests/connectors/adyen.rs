@@ -154,6 +154,8 @@ impl AdyenTest {
             complete_authorize_url: None,
             customer_id: None,
             surcharge_details: None,
+            installment_data: None,
+            request_incremental_authorization: false,
         })
     }
 }
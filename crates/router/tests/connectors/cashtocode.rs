@@ -66,6 +66,8 @@ impl CashtocodeTest {
             complete_authorize_url: None,
             customer_id: Some("John Doe".to_owned()),
             surcharge_details: None,
+            installment_data: None,
+            request_incremental_authorization: false,
         })
     }
 
@@ -92,6 +92,8 @@ fn payment_method_details() -> Option<types::PaymentsAuthorizeData> {
         capture_method: None,
         customer_id: None,
         surcharge_details: None,
+        installment_data: None,
+        request_incremental_authorization: false,
     })
 }
 
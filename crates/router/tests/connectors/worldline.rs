@@ -101,6 +101,8 @@ impl WorldlineTest {
             complete_authorize_url: None,
             customer_id: None,
             surcharge_details: None,
+            installment_data: None,
+            request_incremental_authorization: false,
         })
     }
 }
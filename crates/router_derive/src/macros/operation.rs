@@ -25,6 +25,8 @@ enum Derives {
     Verify,
     Session,
     SessionData,
+    IncrementalAuthorization,
+    IncrementalAuthorizationData,
 }
 
 impl From<String> for Derives {
@@ -47,6 +49,8 @@ impl From<String> for Derives {
             "setupmandatedata" => Self::SetupMandateData,
             "session" => Self::Session,
             "sessiondata" => Self::SessionData,
+            "incrementalauthorization" => Self::IncrementalAuthorization,
+            "incrementalauthorizationdata" => Self::IncrementalAuthorizationData,
             _ => Self::Authorize,
         }
     }
@@ -131,6 +135,12 @@ impl Conversion {
             }
             Derives::Session => syn::Ident::new("PaymentsSessionRequest", Span::call_site()),
             Derives::SessionData => syn::Ident::new("PaymentsSessionData", Span::call_site()),
+            Derives::IncrementalAuthorization => {
+                syn::Ident::new("PaymentsIncrementalAuthorizationRequest", Span::call_site())
+            }
+            Derives::IncrementalAuthorizationData => {
+                syn::Ident::new("PaymentsIncrementalAuthorizationData", Span::call_site())
+            }
         }
     }
 
@@ -347,6 +357,7 @@ pub fn operation_derive_inner(input: DeriveInput) -> syn::Result<proc_macro::Tok
                     PaymentsAuthorizeData,
                     PaymentsSessionData,
                     CompleteAuthorizeData,
+                    PaymentsIncrementalAuthorizationData,
 
                     api::{
                         PaymentsCaptureRequest,
@@ -357,6 +368,7 @@ pub fn operation_derive_inner(input: DeriveInput) -> syn::Result<proc_macro::Tok
                         PaymentsRequest,
                         PaymentsStartRequest,
                         PaymentsSessionRequest,
+                        PaymentsIncrementalAuthorizationRequest,
                         VerifyRequest
                     }
                 };
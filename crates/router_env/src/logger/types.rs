@@ -52,7 +52,7 @@ pub enum Tag {
 }
 
 /// API Flow
-#[derive(Debug, Display, Clone, PartialEq, Eq)]
+#[derive(Debug, Display, Clone, PartialEq, Eq, EnumString)]
 pub enum Flow {
     /// Merchants account create flow.
     MerchantsAccountCreate,
@@ -62,6 +62,8 @@ pub enum Flow {
     MerchantsAccountUpdate,
     /// Merchants account delete flow.
     MerchantsAccountDelete,
+    /// Merchant key rotation flow.
+    MerchantKeyRotate,
     /// Merchant Connectors create flow.
     MerchantConnectorsCreate,
     /// Merchant Connectors retrieve flow.
@@ -130,6 +132,10 @@ pub enum Flow {
     PaymentsApprove,
     /// Payments reject flow.
     PaymentsReject,
+    /// Payments incremental authorization flow.
+    PaymentsIncrementalAuthorization,
+    /// Payments captures list flow.
+    PaymentsCapturesList,
     /// Payments Session Token flow
     PaymentsSessionToken,
     /// Payments start flow.
@@ -151,6 +157,12 @@ pub enum Flow {
     #[cfg(feature = "payouts")]
     /// Payouts fulfill flow.
     PayoutsFulfill,
+    #[cfg(feature = "payouts")]
+    /// Payouts list flow.
+    PayoutsList,
+    #[cfg(feature = "payouts")]
+    /// Payouts filter flow.
+    PayoutsFilter,
     /// Payouts accounts flow.
     PayoutsAccounts,
     /// Payments Redirect flow.
@@ -181,6 +193,10 @@ pub enum Flow {
     DisputesRetrieve,
     /// Dispute List flow
     DisputesList,
+    /// Dispute Analytics flow
+    DisputesAnalytics,
+    /// Dispute Export flow
+    DisputesExport,
     /// Cards Info flow
     CardsInfo,
     /// Create File flow
@@ -203,6 +219,12 @@ pub enum Flow {
     PaymentLinkRetrieve,
     /// payment Link Initiate flow
     PaymentLinkInitiate,
+    /// Payment Link List flow
+    PaymentLinkList,
+    /// Payment Link Update flow
+    PaymentLinkUpdate,
+    /// Payment Link Cancel flow
+    PaymentLinkCancel,
     /// Create a business profile
     BusinessProfileCreate,
     /// Update a business profile
@@ -215,6 +237,18 @@ pub enum Flow {
     BusinessProfileList,
     /// Different verification flows
     Verification,
+    /// List the outgoing webhook events of a merchant
+    WebhookEventList,
+    /// Retrieve an outgoing webhook event along with its delivery attempts
+    WebhookEventRetrieve,
+    /// Manually re-send the outgoing webhook of an event
+    WebhookEventRedeliver,
+    /// Subscriptions create flow.
+    SubscriptionsCreate,
+    /// Subscriptions retrieve flow.
+    SubscriptionsRetrieve,
+    /// Subscriptions cancel flow.
+    SubscriptionsCancel,
 }
 
 ///
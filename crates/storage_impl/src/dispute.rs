@@ -0,0 +1,5 @@
+use diesel_models::dispute::Dispute;
+
+use crate::redis::kv_store::KvStorePartition;
+
+impl KvStorePartition for Dispute {}
@@ -57,6 +57,8 @@ pub enum StorageError {
     MockDbError,
     #[error("Customer with this id is Redacted")]
     CustomerRedacted,
+    #[error("The debit exceeds the limits of the mandate")]
+    MandateDebitLimitExceeded,
     #[error("Deserialization failure")]
     DeserializationFailed,
     #[error("Error while encrypting data")]
@@ -112,6 +114,7 @@ impl Into<DataStorageError> for &StorageError {
             StorageError::SerializationFailed => DataStorageError::SerializationFailed,
             StorageError::MockDbError => DataStorageError::MockDbError,
             StorageError::CustomerRedacted => DataStorageError::CustomerRedacted,
+            StorageError::MandateDebitLimitExceeded => DataStorageError::MandateDebitLimitExceeded,
             StorageError::DeserializationFailed => DataStorageError::DeserializationFailed,
             StorageError::EncryptionError => DataStorageError::EncryptionError,
             StorageError::DecryptionError => DataStorageError::DecryptionError,
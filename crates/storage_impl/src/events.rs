@@ -0,0 +1,5 @@
+use diesel_models::events::Event;
+
+use crate::redis::kv_store::KvStorePartition;
+
+impl KvStorePartition for Event {}
@@ -6,16 +6,21 @@ use error_stack::ResultExt;
 use masking::StrongSecret;
 use redis::{kv_store::RedisConnInterface, RedisStore};
 mod address;
+mod capture;
 pub mod config;
 pub mod connection;
 mod connector_response;
 mod consts;
 pub mod database;
+mod dispute;
 pub mod errors;
+mod events;
 mod lookup;
+mod mandate;
 pub mod metrics;
 pub mod mock_db;
 pub mod payments;
+mod payouts;
 pub mod redis;
 pub mod refund;
 mod reverse_lookup;
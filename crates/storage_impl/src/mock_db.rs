@@ -37,10 +37,15 @@ pub struct MockDb {
     pub lockers: Arc<Mutex<Vec<store::LockerMockUp>>>,
     pub mandates: Arc<Mutex<Vec<store::Mandate>>>,
     pub captures: Arc<Mutex<Vec<crate::store::capture::Capture>>>,
+    pub incremental_authorizations:
+        Arc<Mutex<Vec<store::incremental_authorization::IncrementalAuthorization>>>,
     pub merchant_key_store: Arc<Mutex<Vec<crate::store::merchant_key_store::MerchantKeyStore>>>,
     pub business_profiles: Arc<Mutex<Vec<crate::store::business_profile::BusinessProfile>>>,
     pub reverse_lookups: Arc<Mutex<Vec<store::ReverseLookup>>>,
     pub payment_link: Arc<Mutex<Vec<store::payment_link::PaymentLink>>>,
+    pub webhook_delivery_attempts:
+        Arc<Mutex<Vec<store::webhook_delivery_attempt::WebhookDeliveryAttempt>>>,
+    pub subscriptions: Arc<Mutex<Vec<store::subscription::Subscription>>>,
 }
 
 impl MockDb {
@@ -70,10 +75,13 @@ impl MockDb {
             lockers: Default::default(),
             mandates: Default::default(),
             captures: Default::default(),
+            incremental_authorizations: Default::default(),
             merchant_key_store: Default::default(),
             business_profiles: Default::default(),
             reverse_lookups: Default::default(),
             payment_link: Default::default(),
+            webhook_delivery_attempts: Default::default(),
+            subscriptions: Default::default(),
         })
     }
 }
@@ -141,6 +141,7 @@ impl PaymentAttemptInterface for MockDb {
             connector_response_reference_id: None,
             amount_capturable: payment_attempt.amount_capturable,
             updated_by: storage_scheme.to_string(),
+            installment_data: payment_attempt.installment_data,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
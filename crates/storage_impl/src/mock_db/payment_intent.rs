@@ -105,6 +105,7 @@ impl PaymentIntentInterface for MockDb {
             payment_confirm_source: new.payment_confirm_source,
             updated_by: storage_scheme.to_string(),
             surcharge_applicable: new.surcharge_applicable,
+            request_incremental_authorization: new.request_incremental_authorization,
         };
         payment_intents.push(payment_intent.clone());
         Ok(payment_intent)
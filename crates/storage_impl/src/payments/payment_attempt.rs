@@ -361,6 +361,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     connector_response_reference_id: None,
                     amount_capturable: payment_attempt.amount_capturable,
                     updated_by: storage_scheme.to_string(),
+                    installment_data: payment_attempt.installment_data.clone(),
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -869,6 +870,9 @@ impl DataModelExt for MandateAmountData {
             start_date: self.start_date,
             end_date: self.end_date,
             metadata: self.metadata,
+            max_amount_per_transaction: self.max_amount_per_transaction,
+            frequency: self.frequency,
+            max_debits_per_frequency: self.max_debits_per_frequency,
         }
     }
 
@@ -879,6 +883,9 @@ impl DataModelExt for MandateAmountData {
             start_date: storage_model.start_date,
             end_date: storage_model.end_date,
             metadata: storage_model.metadata,
+            max_amount_per_transaction: storage_model.max_amount_per_transaction,
+            frequency: storage_model.frequency,
+            max_debits_per_frequency: storage_model.max_debits_per_frequency,
         }
     }
 }
@@ -957,6 +964,7 @@ impl DataModelExt for PaymentAttempt {
             amount_capturable: self.amount_capturable,
 
             updated_by: self.updated_by,
+            installment_data: self.installment_data,
         }
     }
 
@@ -1007,6 +1015,7 @@ impl DataModelExt for PaymentAttempt {
             amount_capturable: storage_model.amount_capturable,
 
             updated_by: storage_model.updated_by,
+            installment_data: storage_model.installment_data,
         }
     }
 }
@@ -1057,6 +1066,7 @@ impl DataModelExt for PaymentAttemptNew {
             amount_capturable: self.amount_capturable,
 
             updated_by: self.updated_by,
+            installment_data: self.installment_data,
         }
     }
 
@@ -1105,6 +1115,7 @@ impl DataModelExt for PaymentAttemptNew {
             amount_capturable: storage_model.amount_capturable,
 
             updated_by: storage_model.updated_by,
+            installment_data: storage_model.installment_data,
         }
     }
 }
@@ -1182,6 +1193,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 amount_capturable,
                 surcharge_amount,
                 tax_amount,
+                installment_data,
                 updated_by,
             } => DieselPaymentAttemptUpdate::ConfirmUpdate {
                 amount,
@@ -1202,6 +1214,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 amount_capturable,
                 surcharge_amount,
                 tax_amount,
+                installment_data,
                 updated_by,
             },
             Self::VoidUpdate {
@@ -1329,6 +1342,15 @@ impl DataModelExt for PaymentAttemptUpdate {
                 amount_capturable,
                 updated_by,
             },
+            Self::IncrementalAuthorizationAmountUpdate {
+                amount,
+                amount_capturable,
+                updated_by,
+            } => DieselPaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
+                amount,
+                amount_capturable,
+                updated_by,
+            },
         }
     }
 
@@ -1402,6 +1424,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 amount_capturable,
                 surcharge_amount,
                 tax_amount,
+                installment_data,
                 updated_by,
             } => Self::ConfirmUpdate {
                 amount,
@@ -1422,6 +1445,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 amount_capturable,
                 surcharge_amount,
                 tax_amount,
+                installment_data,
                 updated_by,
             },
             DieselPaymentAttemptUpdate::VoidUpdate {
@@ -1549,6 +1573,15 @@ impl DataModelExt for PaymentAttemptUpdate {
                 amount_capturable,
                 updated_by,
             },
+            DieselPaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
+                amount,
+                amount_capturable,
+                updated_by,
+            } => Self::IncrementalAuthorizationAmountUpdate {
+                amount,
+                amount_capturable,
+                updated_by,
+            },
         }
     }
 }
@@ -97,6 +97,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
                     payment_confirm_source: new.payment_confirm_source,
                     updated_by: storage_scheme.to_string(),
                     surcharge_applicable: new.surcharge_applicable,
+                    request_incremental_authorization: new.request_incremental_authorization,
                 };
                 let redis_entry = kv::TypedSql {
                     op: kv::DBOperation::Insert {
@@ -754,6 +755,7 @@ impl DataModelExt for PaymentIntentNew {
             payment_confirm_source: self.payment_confirm_source,
             updated_by: self.updated_by,
             surcharge_applicable: self.surcharge_applicable,
+            request_incremental_authorization: self.request_incremental_authorization,
         }
     }
 
@@ -794,6 +796,7 @@ impl DataModelExt for PaymentIntentNew {
             payment_confirm_source: storage_model.payment_confirm_source,
             updated_by: storage_model.updated_by,
             surcharge_applicable: storage_model.surcharge_applicable,
+            request_incremental_authorization: storage_model.request_incremental_authorization,
         }
     }
 }
@@ -839,6 +842,7 @@ impl DataModelExt for PaymentIntent {
             payment_confirm_source: self.payment_confirm_source,
             updated_by: self.updated_by,
             surcharge_applicable: self.surcharge_applicable,
+            request_incremental_authorization: self.request_incremental_authorization,
         }
     }
 
@@ -880,6 +884,7 @@ impl DataModelExt for PaymentIntent {
             payment_confirm_source: storage_model.payment_confirm_source,
             updated_by: storage_model.updated_by,
             surcharge_applicable: storage_model.surcharge_applicable,
+            request_incremental_authorization: storage_model.request_incremental_authorization,
         }
     }
 }
@@ -1016,6 +1021,12 @@ impl DataModelExt for PaymentIntentUpdate {
                 surcharge_applicable: Some(surcharge_applicable),
                 updated_by,
             },
+            Self::IncrementalAuthorizationAmountUpdate { amount, updated_by } => {
+                DieselPaymentIntentUpdate::IncrementalAuthorizationAmountUpdate {
+                    amount,
+                    updated_by,
+                }
+            }
         }
     }
 
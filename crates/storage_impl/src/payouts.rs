@@ -0,0 +1,6 @@
+use diesel_models::{payout_attempt::PayoutAttempt, payouts::Payouts};
+
+use crate::redis::kv_store::KvStorePartition;
+
+impl KvStorePartition for Payouts {}
+impl KvStorePartition for PayoutAttempt {}
@@ -0,0 +1,9 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS webhook_delivery_attempt;
+
+DROP INDEX IF EXISTS events_merchant_id_created_at_index;
+
+ALTER TABLE events
+DROP COLUMN IF EXISTS merchant_id,
+DROP COLUMN IF EXISTS business_profile_id,
+DROP COLUMN IF EXISTS request;
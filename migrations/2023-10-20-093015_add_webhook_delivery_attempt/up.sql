@@ -0,0 +1,25 @@
+-- Your SQL goes here
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS merchant_id VARCHAR(64) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS business_profile_id VARCHAR(64) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS request JSONB DEFAULT NULL;
+
+CREATE INDEX IF NOT EXISTS events_merchant_id_created_at_index ON events (merchant_id, created_at);
+
+CREATE TABLE IF NOT EXISTS webhook_delivery_attempt (
+    id SERIAL PRIMARY KEY,
+    attempt_id VARCHAR(64) NOT NULL,
+    event_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    delivery_attempt VARCHAR(32) NOT NULL,
+    is_success BOOLEAN NOT NULL,
+    response_status_code SMALLINT,
+    response_body TEXT,
+    error_message TEXT,
+    latency_ms BIGINT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS webhook_delivery_attempt_attempt_id_index ON webhook_delivery_attempt (attempt_id);
+
+CREATE INDEX IF NOT EXISTS webhook_delivery_attempt_event_id_index ON webhook_delivery_attempt (event_id);
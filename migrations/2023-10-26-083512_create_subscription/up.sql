@@ -0,0 +1,37 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS subscription (
+    id SERIAL PRIMARY KEY,
+    subscription_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    mandate_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    status VARCHAR(32) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    billing_interval VARCHAR(32) NOT NULL,
+    interval_count INTEGER NOT NULL DEFAULT 1,
+    trial_end TIMESTAMP,
+    billing_anchor TIMESTAMP NOT NULL,
+    current_period_start TIMESTAMP,
+    current_period_end TIMESTAMP,
+    next_billing_at TIMESTAMP,
+    latest_payment_id VARCHAR(64),
+    description VARCHAR(255),
+    metadata JSONB,
+    cancelled_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS subscription_merchant_id_subscription_id_index ON subscription (merchant_id, subscription_id);
+
+CREATE INDEX IF NOT EXISTS subscription_merchant_id_customer_id_index ON subscription (merchant_id, customer_id);
+
+ALTER TYPE "EventClass" ADD VALUE 'subscriptions';
+
+ALTER TYPE "EventObjectType" ADD VALUE 'subscription_details';
+
+ALTER TYPE "EventType" ADD VALUE 'subscription_renewal_succeeded';
+
+ALTER TYPE "EventType" ADD VALUE 'subscription_renewal_failed';
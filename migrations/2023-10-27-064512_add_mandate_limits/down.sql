@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE mandate
+DROP COLUMN IF EXISTS max_amount_per_transaction,
+DROP COLUMN IF EXISTS frequency,
+DROP COLUMN IF EXISTS max_debits_per_frequency,
+DROP COLUMN IF EXISTS frequency_period_start,
+DROP COLUMN IF EXISTS frequency_debit_count;
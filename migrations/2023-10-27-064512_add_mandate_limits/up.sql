@@ -0,0 +1,7 @@
+-- Your SQL goes here
+ALTER TABLE mandate
+ADD COLUMN IF NOT EXISTS max_amount_per_transaction BIGINT DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS frequency VARCHAR(32) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS max_debits_per_frequency INTEGER DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS frequency_period_start TIMESTAMP DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS frequency_debit_count INTEGER DEFAULT NULL;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS dispute_policy JSONB DEFAULT NULL;
+
+ALTER TYPE "EventType" ADD VALUE 'dispute_evidence_due';
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE payment_link
+ADD COLUMN IF NOT EXISTS status VARCHAR(32) NOT NULL DEFAULT 'active';
+
+CREATE INDEX payment_link_merchant_id_created_at_index ON payment_link (merchant_id, created_at);
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS incremental_authorization_merchant_id_payment_id_attempt_id_index;
+DROP TABLE IF EXISTS incremental_authorization;
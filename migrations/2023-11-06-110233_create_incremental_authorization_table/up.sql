@@ -0,0 +1,18 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS incremental_authorization (
+    authorization_id VARCHAR(64) NOT NULL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    attempt_id VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    previous_amount BIGINT NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    reason VARCHAR(255),
+    error_code VARCHAR(255),
+    error_message VARCHAR(255),
+    connector_authorization_id VARCHAR(128),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX incremental_authorization_merchant_id_payment_id_attempt_id_index ON incremental_authorization (merchant_id, payment_id, attempt_id);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE merchant_key_store DROP COLUMN IF EXISTS previous_key;
+
+ALTER TABLE merchant_key_store DROP COLUMN IF EXISTS version;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE merchant_key_store ADD COLUMN IF NOT EXISTS version INTEGER NOT NULL DEFAULT 1;
+
+ALTER TABLE merchant_key_store ADD COLUMN IF NOT EXISTS previous_key BYTEA;
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+UPDATE payment_intent
+SET status = 'partially_captured'
+WHERE status = 'partially_captured_and_capturable';
+
+UPDATE payment_attempt
+SET status = 'partial_charged'
+WHERE status = 'partial_charged_and_chargeable';
@@ -0,0 +1,17 @@
+-- Your SQL goes here
+-- Partially captured payments with multiple captures could be captured further until the final
+-- capture was introduced, so the ones whose remaining authorization was not released by a void
+-- are still capturable
+UPDATE payment_attempt
+SET status = 'partial_charged_and_chargeable'
+WHERE capture_method = 'manual_multiple'
+    AND status = 'partial_charged'
+    AND cancellation_reason IS NULL;
+
+UPDATE payment_intent
+SET status = 'partially_captured_and_capturable'
+FROM payment_attempt
+WHERE payment_intent.active_attempt_id = payment_attempt.attempt_id
+    AND payment_intent.merchant_id = payment_attempt.merchant_id
+    AND payment_intent.status = 'partially_captured'
+    AND payment_attempt.status = 'partial_charged_and_chargeable';